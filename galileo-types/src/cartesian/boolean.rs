@@ -0,0 +1,977 @@
+//! Boolean operations on polygons.
+//!
+//! Union, intersection, difference and symmetric difference of the geometries implementing [`AreaGeometry`] (polygons
+//! and multipolygons) are calculated by overlaying their contours:
+//! 1. all segments of both geometries are split at the points where they intersect or touch each other;
+//! 2. every part of a segment is classified as lying inside or outside the other geometry, or on its boundary;
+//! 3. the parts that bound the result of the operation are linked into the contours of the result.
+//!
+//! Vertices closer than a small fraction of the size of the geometries are merged, so the operations are stable for
+//! touching geometries, shared edges and collinear segments. The input polygons are expected to be valid, i.e. their
+//! contours must not intersect each other. Invalid polygons can be repaired with
+//! [`make_valid_polygon`](crate::cartesian::validity::make_valid_polygon).
+//!
+//! Outer contours of the resulting polygons are oriented counterclockwise and the holes clockwise.
+
+use crate::cartesian::impls::contour::ClosedContour;
+use crate::cartesian::impls::multipolygon::MultiPolygon;
+use crate::cartesian::impls::polygon::Polygon;
+use crate::cartesian::traits::cartesian_point::{CartesianPoint2d, NewCartesianPoint2d};
+use crate::contour::ClosedContour as _;
+use std::collections::{HashMap, HashSet};
+
+/// Distance at which points are considered the same, relative to the size of the geometries.
+const RELATIVE_TOLERANCE: f64 = 1e-10;
+
+/// Contour with the coordinates as `f64`, without the closing point.
+pub(crate) type Ring = Vec<[f64; 2]>;
+
+/// Start and end of a segment.
+type SegmentCoords = ([f64; 2], [f64; 2]);
+
+/// Boolean operation on two area geometries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BooleanOp {
+    /// Area covered by any of the geometries.
+    Union,
+    /// Area covered by both geometries.
+    Intersection,
+    /// Area covered by the first geometry, but not by the second one.
+    Difference,
+    /// Area covered by exactly one of the geometries.
+    SymmetricDifference,
+}
+
+impl BooleanOp {
+    /// Applies the operation to the geometries.
+    pub fn apply<A, B, P>(&self, a: &A, b: &B) -> MultiPolygon<P>
+    where
+        A: AreaGeometry<Point = P>,
+        B: AreaGeometry<Point = P>,
+        P: NewCartesianPoint2d,
+    {
+        to_multi_polygon(overlay(&rings(a), &rings(b), *self))
+    }
+}
+
+/// Geometry that covers an area: a polygon or a multipolygon.
+///
+/// Provides boolean operations with other area geometries, see the [module docs](self).
+pub trait AreaGeometry {
+    type Point: CartesianPoint2d;
+
+    /// Returns the points of all contours of the geometry.
+    fn rings(&self) -> Vec<Vec<&Self::Point>>;
+
+    /// Area covered by any of the geometries.
+    fn union(&self, other: &impl AreaGeometry<Point = Self::Point>) -> MultiPolygon<Self::Point>
+    where
+        Self: Sized,
+        Self::Point: NewCartesianPoint2d,
+    {
+        BooleanOp::Union.apply(self, other)
+    }
+
+    /// Area covered by both geometries.
+    fn intersection(
+        &self,
+        other: &impl AreaGeometry<Point = Self::Point>,
+    ) -> MultiPolygon<Self::Point>
+    where
+        Self: Sized,
+        Self::Point: NewCartesianPoint2d,
+    {
+        BooleanOp::Intersection.apply(self, other)
+    }
+
+    /// Area covered by this geometry, but not by the other one.
+    fn difference(
+        &self,
+        other: &impl AreaGeometry<Point = Self::Point>,
+    ) -> MultiPolygon<Self::Point>
+    where
+        Self: Sized,
+        Self::Point: NewCartesianPoint2d,
+    {
+        BooleanOp::Difference.apply(self, other)
+    }
+
+    /// Area covered by exactly one of the geometries.
+    fn symmetric_difference(
+        &self,
+        other: &impl AreaGeometry<Point = Self::Point>,
+    ) -> MultiPolygon<Self::Point>
+    where
+        Self: Sized,
+        Self::Point: NewCartesianPoint2d,
+    {
+        BooleanOp::SymmetricDifference.apply(self, other)
+    }
+}
+
+impl<P, C, T> AreaGeometry for T
+where
+    P: CartesianPoint2d,
+    C: crate::contour::ClosedContour<Point = P>,
+    T: crate::polygon::Polygon<Contour = C>,
+{
+    type Point = P;
+
+    fn rings(&self) -> Vec<Vec<&P>> {
+        self.iter_contours()
+            .map(|c| c.iter_points().collect())
+            .collect()
+    }
+}
+
+impl<P: CartesianPoint2d> AreaGeometry for MultiPolygon<P> {
+    type Point = P;
+
+    fn rings(&self) -> Vec<Vec<&P>> {
+        self.parts
+            .iter()
+            .flat_map(|p| p.iter_contours().map(|c| c.iter_points().collect()))
+            .collect()
+    }
+}
+
+pub(crate) fn rings<A>(geometry: &A) -> Vec<Ring>
+where
+    A: AreaGeometry,
+    A::Point: CartesianPoint2d<Num = f64>,
+{
+    geometry
+        .rings()
+        .into_iter()
+        .map(|ring| ring.iter().map(|p| [p.x(), p.y()]).collect())
+        .collect()
+}
+
+pub(crate) fn to_multi_polygon<P: NewCartesianPoint2d>(rings: Vec<Ring>) -> MultiPolygon<P> {
+    let to_contour =
+        |ring: &Ring| ClosedContour::new(ring.iter().map(|c| P::new(c[0], c[1])).collect());
+    build_polygons(rings)
+        .into_iter()
+        .map(|(outer, holes)| {
+            Polygon::new(to_contour(&outer), holes.iter().map(to_contour).collect())
+        })
+        .collect::<Vec<_>>()
+        .into()
+}
+
+/// Union of any number of area geometries given as sets of rings. The geometries are merged pairwise, so that every
+/// overlay operation has operands of similar size.
+pub(crate) fn union_all(mut geometries: Vec<Vec<Ring>>) -> Vec<Ring> {
+    while geometries.len() > 1 {
+        let mut merged = Vec::with_capacity(geometries.len() / 2 + 1);
+        let mut iter = geometries.into_iter();
+        while let Some(a) = iter.next() {
+            match iter.next() {
+                Some(b) => merged.push(overlay(&a, &b, BooleanOp::Union)),
+                None => merged.push(a),
+            }
+        }
+        geometries = merged;
+    }
+
+    geometries.pop().unwrap_or_default()
+}
+
+/// Applies the operation to two geometries given as sets of rings. Returns the rings of the result, with the outer
+/// contours oriented counterclockwise and the holes clockwise.
+pub(crate) fn overlay(a: &[Ring], b: &[Ring], op: BooleanOp) -> Vec<Ring> {
+    let operands = [normalize(a), normalize(b)];
+    let Some(tolerance) = tolerance(operands.iter().flatten().flatten()) else {
+        return vec![];
+    };
+
+    let mut edges = vec![];
+    for (operand, rings) in operands.iter().enumerate() {
+        for ring in rings {
+            for (i, &from) in ring.iter().enumerate() {
+                edges.push(Edge {
+                    from,
+                    to: ring[(i + 1) % ring.len()],
+                    operand,
+                });
+            }
+        }
+    }
+
+    let edges = cancel_opposite(split_edges(&edges, tolerance));
+
+    let indices = [RingIndex::new(&operands[0]), RingIndex::new(&operands[1])];
+    let mut directed: [HashSet<(Key, Key)>; 2] = Default::default();
+    for edge in &edges {
+        directed[edge.operand].insert((key(edge.from), key(edge.to)));
+    }
+
+    let mut selected = vec![];
+    for edge in &edges {
+        let other = 1 - edge.operand;
+        let location = if directed[other].contains(&(key(edge.from), key(edge.to))) {
+            Location::SameBoundary
+        } else if directed[other].contains(&(key(edge.to), key(edge.from))) {
+            Location::OppositeBoundary
+        } else if indices[other].contains(midpoint(edge.from, edge.to)) {
+            Location::Inside
+        } else {
+            Location::Outside
+        };
+
+        match select(op, edge.operand, location) {
+            Selection::Keep => selected.push((edge.from, edge.to)),
+            Selection::Reverse => selected.push((edge.to, edge.from)),
+            Selection::Drop => {}
+        }
+    }
+
+    link_rings(&selected)
+        .into_iter()
+        .filter(|ring| signed_area(ring).abs() > tolerance * tolerance)
+        .collect()
+}
+
+/// Area covered by the rings with the even-odd rule, i.e. the points lying inside an odd number of the rings. Unlike
+/// [`overlay`], the rings may intersect themselves and each other. Returns the rings of the area oriented the same way
+/// as the result of [`overlay`].
+pub(crate) fn even_odd_area(rings: &[Ring]) -> Vec<Ring> {
+    let rings: Vec<Ring> = rings.iter().filter_map(clean_ring).collect();
+    let Some(tolerance) = tolerance(rings.iter().flatten()) else {
+        return vec![];
+    };
+
+    let mut edges = vec![];
+    for ring in &rings {
+        for (i, &from) in ring.iter().enumerate() {
+            edges.push(Edge {
+                from,
+                to: ring[(i + 1) % ring.len()],
+                operand: 0,
+            });
+        }
+    }
+
+    // A segment passed an even number of times does not change the parity on its sides, so it is not a part of the
+    // boundary.
+    let mut positions: HashMap<(Key, Key), usize> = HashMap::new();
+    let mut counted: Vec<(SegmentCoords, usize)> = vec![];
+    for edge in split_edges(&edges, tolerance) {
+        let segment = if key(edge.from) < key(edge.to) {
+            (edge.from, edge.to)
+        } else {
+            (edge.to, edge.from)
+        };
+        let position = *positions
+            .entry((key(segment.0), key(segment.1)))
+            .or_insert_with(|| {
+                counted.push((segment, 0));
+                counted.len() - 1
+            });
+        counted[position].1 += 1;
+    }
+    let segments: Vec<SegmentCoords> = counted
+        .into_iter()
+        .filter(|(_, count)| count % 2 == 1)
+        .map(|(segment, _)| segment)
+        .collect();
+
+    // Every segment is directed to have the area on its left. The side of the area is found with the ray going from
+    // the middle of the segment to the right, or up for horizontal segments.
+    let transpose = |p: [f64; 2]| [p[1], p[0]];
+    let rows = RingIndex::from_segments(segments.clone());
+    let columns = RingIndex::from_segments(
+        segments
+            .iter()
+            .map(|&(a, b)| (transpose(a), transpose(b)))
+            .collect(),
+    );
+    let directed: Vec<SegmentCoords> = segments
+        .iter()
+        .enumerate()
+        .map(|(i, &(a, b))| {
+            let middle = midpoint(a, b);
+            let keep = if a[1] != b[1] {
+                rows.contains_except(middle, i) == (b[1] < a[1])
+            } else {
+                columns.contains_except(transpose(middle), i) == (b[0] > a[0])
+            };
+
+            if keep {
+                (a, b)
+            } else {
+                (b, a)
+            }
+        })
+        .collect();
+
+    link_rings(&directed)
+        .into_iter()
+        .filter(|ring| signed_area(ring).abs() > tolerance * tolerance)
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Edge {
+    from: [f64; 2],
+    to: [f64; 2],
+    operand: usize,
+}
+
+/// Position of a part of a segment relative to the other operand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Location {
+    Inside,
+    Outside,
+    /// The other operand has the same segment going in the same direction, so both operands are on the same side of
+    /// it.
+    SameBoundary,
+    /// The other operand has the same segment going in the opposite direction, so the operands are on the different
+    /// sides of it.
+    OppositeBoundary,
+}
+
+enum Selection {
+    Keep,
+    Reverse,
+    Drop,
+}
+
+/// Selects the segments bounding the result. Segments are oriented with the interior of their operand on the left.
+/// Segments shared by both operands are taken from the first operand only.
+fn select(op: BooleanOp, operand: usize, location: Location) -> Selection {
+    let is_first = operand == 0;
+    match (op, location) {
+        (BooleanOp::Union, Location::Outside) => Selection::Keep,
+        (BooleanOp::Union, Location::SameBoundary) if is_first => Selection::Keep,
+        (BooleanOp::Intersection, Location::Inside) => Selection::Keep,
+        (BooleanOp::Intersection, Location::SameBoundary) if is_first => Selection::Keep,
+        (BooleanOp::Difference, Location::Outside) if is_first => Selection::Keep,
+        (BooleanOp::Difference, Location::OppositeBoundary) if is_first => Selection::Keep,
+        (BooleanOp::Difference, Location::Inside) if !is_first => Selection::Reverse,
+        (BooleanOp::SymmetricDifference, Location::Outside) => Selection::Keep,
+        (BooleanOp::SymmetricDifference, Location::Inside) => Selection::Reverse,
+        _ => Selection::Drop,
+    }
+}
+
+/// Removes repeated points and degenerate rings, and orients the rings so that the covered area is on their left:
+/// rings nested in an even number of other rings counterclockwise, others clockwise.
+fn normalize(rings: &[Ring]) -> Vec<Ring> {
+    let mut normalized: Vec<Ring> = rings
+        .iter()
+        .filter_map(clean_ring)
+        .filter(|ring| signed_area(ring) != 0.0)
+        .collect();
+
+    let depths: Vec<usize> = normalized
+        .iter()
+        .enumerate()
+        .map(|(i, ring)| {
+            normalized
+                .iter()
+                .enumerate()
+                .filter(|&(j, other)| {
+                    // Rings may touch each other, so the first point that is not on the other ring is checked.
+                    j != i
+                        && ring
+                            .iter()
+                            .find_map(|&point| point_in_ring_strict(point, other))
+                            .unwrap_or(false)
+                })
+                .count()
+        })
+        .collect();
+
+    for (ring, depth) in normalized.iter_mut().zip(depths) {
+        if (signed_area(ring) > 0.0) != (depth % 2 == 0) {
+            ring.reverse();
+        }
+    }
+
+    normalized
+}
+
+/// Removes repeated and non-finite points and the closing point of the ring. Returns `None` if less than three
+/// points are left.
+fn clean_ring(ring: &Ring) -> Option<Ring> {
+    let mut cleaned: Ring = Vec::with_capacity(ring.len());
+    for &point in ring {
+        if cleaned.last() != Some(&point) && point.iter().all(|c| c.is_finite()) {
+            cleaned.push(point);
+        }
+    }
+    while cleaned.len() > 1 && cleaned.first() == cleaned.last() {
+        cleaned.pop();
+    }
+
+    (cleaned.len() >= 3).then_some(cleaned)
+}
+
+/// Distance at which the points are merged, or `None` if there are no points.
+fn tolerance<'a>(points: impl Iterator<Item = &'a [f64; 2]>) -> Option<f64> {
+    let mut min = [f64::INFINITY; 2];
+    let mut max = [f64::NEG_INFINITY; 2];
+    for point in points {
+        min = [min[0].min(point[0]), min[1].min(point[1])];
+        max = [max[0].max(point[0]), max[1].max(point[1])];
+    }
+
+    let extent = (max[0] - min[0])
+        .max(max[1] - min[1])
+        .max(max[0].abs())
+        .max(max[1].abs())
+        .max(min[0].abs())
+        .max(min[1].abs());
+    (extent.is_finite() && extent > 0.0).then_some(extent * RELATIVE_TOLERANCE)
+}
+
+/// Splits the edges at the points where they intersect or touch other edges.
+fn split_edges(edges: &[Edge], tolerance: f64) -> Vec<Edge> {
+    let mut splits: Vec<Vec<[f64; 2]>> = vec![vec![]; edges.len()];
+
+    // Sweep along the x axis: only the edges with overlapping x ranges are checked.
+    let x_range = |edge: &Edge| (edge.from[0].min(edge.to[0]), edge.from[0].max(edge.to[0]));
+    let mut order: Vec<usize> = (0..edges.len()).collect();
+    order.sort_by(|&a, &b| x_range(&edges[a]).0.total_cmp(&x_range(&edges[b]).0));
+
+    for (k, &i) in order.iter().enumerate() {
+        let max_x = x_range(&edges[i]).1 + tolerance;
+        for &j in &order[k + 1..] {
+            if x_range(&edges[j]).0 > max_x {
+                break;
+            }
+
+            intersect(i, j, edges, tolerance, &mut splits);
+        }
+    }
+
+    let mut snapper = Snapper::new(tolerance);
+    let mut result = Vec::with_capacity(edges.len());
+    for (edge, mut points) in edges.iter().zip(splits) {
+        let direction = sub(edge.to, edge.from);
+        points.sort_by(|a, b| {
+            dot(sub(*a, edge.from), direction).total_cmp(&dot(sub(*b, edge.from), direction))
+        });
+
+        let mut from = snapper.snap(edge.from);
+        for point in points.into_iter().chain(std::iter::once(edge.to)) {
+            let to = snapper.snap(point);
+            if to != from {
+                result.push(Edge {
+                    from,
+                    to,
+                    operand: edge.operand,
+                });
+                from = to;
+            }
+        }
+    }
+
+    result
+}
+
+/// Finds the common points of two edges and adds them to the split points of the edges.
+fn intersect(i: usize, j: usize, edges: &[Edge], tolerance: f64, splits: &mut [Vec<[f64; 2]>]) {
+    let (a, b) = (edges[i].from, edges[i].to);
+    let (c, d) = (edges[j].from, edges[j].to);
+    if a[1].max(b[1]) + tolerance < c[1].min(d[1]) || c[1].max(d[1]) + tolerance < a[1].min(b[1]) {
+        return;
+    }
+
+    let r = sub(b, a);
+    let s = sub(d, c);
+    let r_len = length(r);
+    let s_len = length(s);
+    if r_len <= tolerance || s_len <= tolerance {
+        return;
+    }
+
+    // Splits the edge `(from, to)` at the point if the point lies on it, and is not one of its ends.
+    let split = |from: [f64; 2], to: [f64; 2], point: [f64; 2], splits: &mut Vec<[f64; 2]>| {
+        let direction = sub(to, from);
+        let len = length(direction);
+        let along = dot(sub(point, from), direction) / len;
+        let across = cross(direction, sub(point, from)).abs() / len;
+        if across <= tolerance && along > tolerance && along < len - tolerance {
+            splits.push(point);
+        }
+    };
+
+    let den = cross(r, s);
+    if den.abs() <= RELATIVE_TOLERANCE * r_len * s_len {
+        // Parallel edges have common points only if they are collinear. Then the ends of each edge that lie on the
+        // other one split it.
+        for point in [c, d] {
+            split(a, b, point, &mut splits[i]);
+        }
+        for point in [a, b] {
+            split(c, d, point, &mut splits[j]);
+        }
+
+        return;
+    }
+
+    let ca = sub(c, a);
+    let t = cross(ca, s) / den;
+    let u = cross(ca, r) / den;
+    let (t_tolerance, u_tolerance) = (tolerance / r_len, tolerance / s_len);
+    if t < -t_tolerance || t > 1.0 + t_tolerance || u < -u_tolerance || u > 1.0 + u_tolerance {
+        return;
+    }
+
+    // Ends of the edges are used as the intersection point when possible, so that both edges are split at exactly the
+    // same point as the adjacent edges.
+    let point = if u <= u_tolerance {
+        c
+    } else if u >= 1.0 - u_tolerance {
+        d
+    } else if t <= t_tolerance {
+        a
+    } else if t >= 1.0 - t_tolerance {
+        b
+    } else {
+        [a[0] + t * r[0], a[1] + t * r[1]]
+    };
+
+    if t > t_tolerance && t < 1.0 - t_tolerance {
+        splits[i].push(point);
+    }
+    if u > u_tolerance && u < 1.0 - u_tolerance {
+        splits[j].push(point);
+    }
+}
+
+/// Removes pairs of the same segment going in the opposite directions in one operand, which appear when its polygons
+/// touch each other along a segment. Such segments have the operand on both sides, so they are not a part of its
+/// boundary.
+fn cancel_opposite(edges: Vec<Edge>) -> Vec<Edge> {
+    let directed: HashSet<(usize, Key, Key)> = edges
+        .iter()
+        .map(|edge| (edge.operand, key(edge.from), key(edge.to)))
+        .collect();
+
+    edges
+        .into_iter()
+        .filter(|edge| !directed.contains(&(edge.operand, key(edge.to), key(edge.from))))
+        .collect()
+}
+
+/// Links the directed segments into closed rings.
+fn link_rings(edges: &[SegmentCoords]) -> Vec<Ring> {
+    let mut outgoing: HashMap<Key, Vec<usize>> = HashMap::new();
+    for (index, edge) in edges.iter().enumerate() {
+        outgoing.entry(key(edge.0)).or_default().push(index);
+    }
+
+    let mut used = vec![false; edges.len()];
+    let mut rings = vec![];
+    for start in 0..edges.len() {
+        if used[start] {
+            continue;
+        }
+
+        let start_key = key(edges[start].0);
+        let mut ring = vec![];
+        let mut current = start;
+        loop {
+            used[current] = true;
+            let (from, to) = edges[current];
+            ring.push(from);
+            if key(to) == start_key {
+                if ring.len() >= 3 {
+                    rings.push(ring);
+                }
+                break;
+            }
+
+            // When several segments start at the same point, the one with the leftmost turn is taken. It keeps the
+            // result on the left side of the ring, so the polygons touching at one point are separated.
+            let incoming = sub(to, from);
+            let next = outgoing
+                .get(&key(to))
+                .into_iter()
+                .flatten()
+                .filter(|&&index| !used[index])
+                .max_by(|&&x, &&y| {
+                    turn(incoming, from, edges[x]).total_cmp(&turn(incoming, from, edges[y]))
+                });
+
+            match next {
+                Some(&next) => current = next,
+                // Not closed because of numeric errors, the ring is dropped.
+                None => break,
+            }
+        }
+    }
+
+    rings
+}
+
+/// Angle of the turn from the incoming direction to the edge. Going back along the incoming segment is the last
+/// option.
+fn turn(incoming: [f64; 2], incoming_from: [f64; 2], edge: SegmentCoords) -> f64 {
+    if edge.1 == incoming_from {
+        return -std::f64::consts::PI;
+    }
+
+    let direction = sub(edge.1, edge.0);
+    cross(incoming, direction).atan2(dot(incoming, direction))
+}
+
+/// Groups the rings into polygons: counterclockwise rings are outer contours, and every clockwise ring becomes a hole
+/// of the smallest outer contour it lies in.
+fn build_polygons(rings: Vec<Ring>) -> Vec<(Ring, Vec<Ring>)> {
+    let (mut outers, holes): (Vec<Ring>, Vec<Ring>) =
+        rings.into_iter().partition(|ring| signed_area(ring) > 0.0);
+    outers.sort_by(|a, b| signed_area(a).total_cmp(&signed_area(b)));
+
+    let mut polygons: Vec<(Ring, Vec<Ring>)> = outers.into_iter().map(|o| (o, vec![])).collect();
+    for hole in holes {
+        let container = polygons.iter_mut().find(|(outer, _)| {
+            hole.iter()
+                .find_map(|&point| point_in_ring_strict(point, outer))
+                .unwrap_or(false)
+        });
+        if let Some((_, holes)) = container {
+            holes.push(hole);
+        }
+    }
+
+    polygons
+}
+
+/// Index for point-in-polygon tests: the segments of the rings are grouped into horizontal bands, so that only the
+/// segments of one band are checked for every point.
+struct RingIndex {
+    segments: Vec<SegmentCoords>,
+    bands: Vec<Vec<usize>>,
+    min_y: f64,
+    max_y: f64,
+    band_height: f64,
+}
+
+impl RingIndex {
+    fn new(rings: &[Ring]) -> Self {
+        Self::from_segments(
+            rings
+                .iter()
+                .flat_map(|ring| {
+                    ring.iter()
+                        .enumerate()
+                        .map(|(i, &from)| (from, ring[(i + 1) % ring.len()]))
+                })
+                .collect(),
+        )
+    }
+
+    fn from_segments(segments: Vec<SegmentCoords>) -> Self {
+        let min_y = segments
+            .iter()
+            .map(|s| s.0[1].min(s.1[1]))
+            .fold(f64::INFINITY, f64::min);
+        let max_y = segments
+            .iter()
+            .map(|s| s.0[1].max(s.1[1]))
+            .fold(f64::NEG_INFINITY, f64::max);
+
+        let band_count = (segments.len() / 4).clamp(1, 4096);
+        let band_height = if max_y > min_y {
+            (max_y - min_y) / band_count as f64
+        } else {
+            1.0
+        };
+
+        let mut index = Self {
+            segments: vec![],
+            bands: vec![vec![]; band_count],
+            min_y,
+            max_y,
+            band_height,
+        };
+        for (i, segment) in segments.iter().enumerate() {
+            let from = index.band(segment.0[1].min(segment.1[1]));
+            let to = index.band(segment.0[1].max(segment.1[1]));
+            for band in &mut index.bands[from..=to] {
+                band.push(i);
+            }
+        }
+        index.segments = segments;
+
+        index
+    }
+
+    fn band(&self, y: f64) -> usize {
+        (((y - self.min_y) / self.band_height).floor().max(0.0) as usize).min(self.bands.len() - 1)
+    }
+
+    /// Even-odd test of the point against all the rings.
+    fn contains(&self, point: [f64; 2]) -> bool {
+        self.contains_except(point, usize::MAX)
+    }
+
+    /// Even-odd test of the point against all the segments except the one with the given index.
+    fn contains_except(&self, point: [f64; 2], except: usize) -> bool {
+        if self.segments.is_empty() || point[1] < self.min_y || point[1] > self.max_y {
+            return false;
+        }
+
+        let mut inside = false;
+        for &i in &self.bands[self.band(point[1])] {
+            let (a, b) = self.segments[i];
+            if i != except && crosses_ray(point, a, b) {
+                inside = !inside;
+            }
+        }
+
+        inside
+    }
+}
+
+/// Checks if the horizontal ray going from the point to the right crosses the segment.
+fn crosses_ray(point: [f64; 2], a: [f64; 2], b: [f64; 2]) -> bool {
+    (a[1] > point[1]) != (b[1] > point[1])
+        && point[0] < (b[0] - a[0]) * (point[1] - a[1]) / (b[1] - a[1]) + a[0]
+}
+
+fn point_in_ring(point: [f64; 2], ring: &Ring) -> bool {
+    let mut inside = false;
+    for (i, &a) in ring.iter().enumerate() {
+        if crosses_ray(point, a, ring[(i + 1) % ring.len()]) {
+            inside = !inside;
+        }
+    }
+
+    inside
+}
+
+/// Point-in-ring test that returns `None` if the point lies on the ring.
+fn point_in_ring_strict(point: [f64; 2], ring: &Ring) -> Option<bool> {
+    for (i, &a) in ring.iter().enumerate() {
+        let b = ring[(i + 1) % ring.len()];
+        let within = point[0] >= a[0].min(b[0])
+            && point[0] <= a[0].max(b[0])
+            && point[1] >= a[1].min(b[1])
+            && point[1] <= a[1].max(b[1]);
+        if within && cross(sub(b, a), sub(point, a)) == 0.0 {
+            return None;
+        }
+    }
+
+    Some(point_in_ring(point, ring))
+}
+
+/// Merges the points that are closer than the tolerance to each other.
+struct Snapper {
+    cell_size: f64,
+    cells: HashMap<(i64, i64), Vec<[f64; 2]>>,
+}
+
+impl Snapper {
+    fn new(tolerance: f64) -> Self {
+        Self {
+            cell_size: tolerance,
+            cells: HashMap::new(),
+        }
+    }
+
+    fn snap(&mut self, point: [f64; 2]) -> [f64; 2] {
+        let cell = (
+            (point[0] / self.cell_size).floor() as i64,
+            (point[1] / self.cell_size).floor() as i64,
+        );
+        for dx in -1..=1 {
+            for dy in -1..=1 {
+                if let Some(points) = self.cells.get(&(cell.0 + dx, cell.1 + dy)) {
+                    if let Some(existing) = points
+                        .iter()
+                        .find(|p| length(sub(**p, point)) <= self.cell_size)
+                    {
+                        return *existing;
+                    }
+                }
+            }
+        }
+
+        // Negative zero is replaced with zero, so that the keys of the same points are equal.
+        let point = [point[0] + 0.0, point[1] + 0.0];
+        self.cells.entry(cell).or_default().push(point);
+        point
+    }
+}
+
+type Key = (u64, u64);
+
+fn key(point: [f64; 2]) -> Key {
+    (point[0].to_bits(), point[1].to_bits())
+}
+
+pub(crate) fn signed_area(ring: &Ring) -> f64 {
+    ring.iter()
+        .zip(ring.iter().cycle().skip(1))
+        .map(|(a, b)| a[0] * b[1] - b[0] * a[1])
+        .sum::<f64>()
+        / 2.0
+}
+
+fn midpoint(a: [f64; 2], b: [f64; 2]) -> [f64; 2] {
+    [(a[0] + b[0]) / 2.0, (a[1] + b[1]) / 2.0]
+}
+
+fn sub(a: [f64; 2], b: [f64; 2]) -> [f64; 2] {
+    [a[0] - b[0], a[1] - b[1]]
+}
+
+fn dot(a: [f64; 2], b: [f64; 2]) -> f64 {
+    a[0] * b[0] + a[1] * b[1]
+}
+
+fn cross(a: [f64; 2], b: [f64; 2]) -> f64 {
+    a[0] * b[1] - a[1] * b[0]
+}
+
+fn length(a: [f64; 2]) -> f64 {
+    a[0].hypot(a[1])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cartesian::impls::point::Point2d;
+    use crate::cartesian::traits::contour::CartesianClosedContour;
+
+    fn square(x: f64, y: f64, size: f64) -> Polygon<Point2d> {
+        Polygon::new(
+            ClosedContour::new(vec![
+                Point2d::new(x, y),
+                Point2d::new(x + size, y),
+                Point2d::new(x + size, y + size),
+                Point2d::new(x, y + size),
+            ]),
+            vec![],
+        )
+    }
+
+    fn area(polygons: &MultiPolygon<Point2d>) -> f64 {
+        polygons
+            .parts()
+            .iter()
+            .map(|p| {
+                p.outer_contour.area_signed()
+                    + p.inner_contours
+                        .iter()
+                        .map(|c| c.area_signed())
+                        .sum::<f64>()
+            })
+            .sum()
+    }
+
+    #[test]
+    fn overlapping_squares() {
+        let a = square(0.0, 0.0, 2.0);
+        let b = square(1.0, 1.0, 2.0);
+
+        let union = a.union(&b);
+        assert_eq!(union.parts().len(), 1);
+        assert_eq!(union.parts()[0].outer_contour.points.len(), 8);
+        assert!((area(&union) - 7.0).abs() < 1e-9);
+
+        let intersection = a.intersection(&b);
+        assert_eq!(intersection.parts().len(), 1);
+        assert!((area(&intersection) - 1.0).abs() < 1e-9);
+
+        assert!((area(&a.difference(&b)) - 3.0).abs() < 1e-9);
+        assert!((area(&b.difference(&a)) - 3.0).abs() < 1e-9);
+
+        let symmetric_difference = a.symmetric_difference(&b);
+        assert_eq!(symmetric_difference.parts().len(), 2);
+        assert!((area(&symmetric_difference) - 6.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn disjoint_squares() {
+        let a = square(0.0, 0.0, 1.0);
+        let b = square(2.0, 0.0, 1.0);
+
+        assert_eq!(a.union(&b).parts().len(), 2);
+        assert!(a.intersection(&b).parts().is_empty());
+        assert!((area(&a.difference(&b)) - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn hole() {
+        let outer = square(0.0, 0.0, 4.0);
+        let inner = square(1.0, 1.0, 2.0);
+
+        let difference = outer.difference(&inner);
+        assert_eq!(difference.parts().len(), 1);
+        assert_eq!(difference.parts()[0].inner_contours.len(), 1);
+        assert!((area(&difference) - 12.0).abs() < 1e-9);
+
+        // The hole is filled back.
+        let union = difference.union(&inner);
+        assert_eq!(union.parts().len(), 1);
+        assert!(union.parts()[0].inner_contours.is_empty());
+        assert!((area(&union) - 16.0).abs() < 1e-9);
+
+        assert!(inner.difference(&outer).parts().is_empty());
+        assert!((area(&difference.intersection(&outer)) - 12.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn hole_touching_outer_contour() {
+        // The first point of the hole lies on the outer contour.
+        let polygon = Polygon::new(
+            square(0.0, 0.0, 10.0).outer_contour,
+            vec![ClosedContour::new(vec![
+                Point2d::new(10.0, 5.0),
+                Point2d::new(5.0, 8.0),
+                Point2d::new(5.0, 2.0),
+            ])],
+        );
+        let other = square(20.0, 0.0, 1.0);
+
+        let union = polygon.union(&other);
+        assert_eq!(union.parts().len(), 2);
+        assert!((area(&union) - 86.0).abs() < 1e-9);
+        assert!((area(&polygon.intersection(&square(0.0, 0.0, 10.0))) - 85.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn shared_edge() {
+        let a = square(0.0, 0.0, 1.0);
+        let b = square(1.0, 0.0, 1.0);
+
+        let union = a.union(&b);
+        assert_eq!(union.parts().len(), 1);
+        assert!(union.parts()[0].inner_contours.is_empty());
+        assert!((area(&union) - 2.0).abs() < 1e-9);
+
+        assert!(a.intersection(&b).parts().is_empty());
+        assert!((area(&a.difference(&b)) - 1.0).abs() < 1e-9);
+        assert!((area(&a.difference(&a)) - 0.0).abs() < 1e-9);
+        assert!((area(&a.intersection(&a)) - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn touching_at_vertex() {
+        let a = square(0.0, 0.0, 1.0);
+        let b = square(1.0, 1.0, 1.0);
+
+        let union = a.union(&b);
+        assert_eq!(union.parts().len(), 2);
+        for polygon in union.parts() {
+            assert_eq!(polygon.outer_contour.points.len(), 4);
+        }
+    }
+
+    #[test]
+    fn clockwise_input() {
+        let mut a = square(0.0, 0.0, 2.0);
+        a.outer_contour.points.reverse();
+        let b = square(1.0, 1.0, 2.0);
+
+        assert!((area(&a.union(&b)) - 7.0).abs() < 1e-9);
+        assert!((area(&a.intersection(&b)) - 1.0).abs() < 1e-9);
+    }
+}
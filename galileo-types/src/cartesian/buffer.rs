@@ -0,0 +1,285 @@
+//! Buffers of cartesian geometries.
+//!
+//! Buffer of a geometry is the area within the given distance from it. For example, buffer of a point is a circle, and
+//! buffer of a road line is the strip along the road. [`Buffer`] approximates the circular arcs of the buffer with
+//! straight segments, and merges the circles around the vertices and the rectangles around the segments of the
+//! geometry with the [boolean operations](crate::cartesian::boolean).
+
+use crate::cartesian::boolean::{
+    overlay, rings, to_multi_polygon, union_all, AreaGeometry, BooleanOp, Ring,
+};
+use crate::cartesian::impls::multipolygon::MultiPolygon;
+use crate::cartesian::traits::cartesian_point::{CartesianPoint2d, NewCartesianPoint2d};
+use crate::geometry::Geom;
+use crate::multi_contour::MultiContour as _;
+use crate::multi_point::MultiPoint as _;
+
+/// Default number of segments used to approximate a quarter of a circle.
+pub const DEFAULT_QUADRANT_SEGMENTS: usize = 8;
+
+/// Creates buffers of geometries, see the [module docs](self).
+///
+/// Polygons can be buffered with a negative distance, which shrinks them. For points and contours the buffer with the
+/// distance that is not positive is empty.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Buffer {
+    /// Distance from the geometry in the units of its coordinates.
+    pub distance: f64,
+    /// Number of segments used to approximate a quarter of a circle.
+    pub quadrant_segments: usize,
+}
+
+impl Buffer {
+    /// Buffer with the given distance and the default circle approximation.
+    pub fn new(distance: f64) -> Self {
+        Self {
+            distance,
+            quadrant_segments: DEFAULT_QUADRANT_SEGMENTS,
+        }
+    }
+
+    /// Sets the number of segments used to approximate a quarter of a circle.
+    pub fn with_quadrant_segments(mut self, quadrant_segments: usize) -> Self {
+        self.quadrant_segments = quadrant_segments.max(1);
+        self
+    }
+
+    /// Buffer of the geometry.
+    pub fn buffer<P: NewCartesianPoint2d>(&self, geom: &Geom<P>) -> MultiPolygon<P> {
+        match geom {
+            Geom::Point(point) => self.buffer_point(point),
+            Geom::MultiPoint(points) => self.merge(
+                points
+                    .iter_points()
+                    .filter_map(|p| self.circle([p.x(), p.y()]))
+                    .map(|circle| vec![circle])
+                    .collect(),
+            ),
+            Geom::Contour(contour) => self.buffer_contour(contour),
+            Geom::MultiContour(contours) => self.merge(
+                contours
+                    .contours()
+                    .flat_map(|c| self.contour_parts(c))
+                    .collect(),
+            ),
+            Geom::Polygon(polygon) => self.buffer_area(polygon),
+            Geom::MultiPolygon(polygons) => self.buffer_area(polygons),
+        }
+    }
+
+    /// Circle around the point.
+    pub fn buffer_point<P: NewCartesianPoint2d>(
+        &self,
+        point: &impl CartesianPoint2d<Num = f64>,
+    ) -> MultiPolygon<P> {
+        to_multi_polygon(self.circle([point.x(), point.y()]).into_iter().collect())
+    }
+
+    /// Buffer of the contour with round ends and joins.
+    pub fn buffer_contour<C, P>(&self, contour: &C) -> MultiPolygon<P>
+    where
+        C: crate::contour::Contour,
+        C::Point: CartesianPoint2d<Num = f64>,
+        P: NewCartesianPoint2d,
+    {
+        self.merge(self.contour_parts(contour))
+    }
+
+    /// Buffer of the polygon or the multipolygon. With a negative distance the area is shrunk by the distance.
+    pub fn buffer_area<A, P>(&self, area: &A) -> MultiPolygon<P>
+    where
+        A: AreaGeometry,
+        A::Point: CartesianPoint2d<Num = f64>,
+        P: NewCartesianPoint2d,
+    {
+        to_multi_polygon(self.buffer_rings(rings(area)))
+    }
+
+    fn buffer_rings(&self, rings: Vec<Ring>) -> Vec<Ring> {
+        if self.distance == 0.0 || !self.distance.is_finite() {
+            return overlay(&rings, &[], BooleanOp::Union);
+        }
+
+        // The area along the contours is added to the polygon, or cut from it for negative distance.
+        let strip = Self::new(self.distance.abs()).with_quadrant_segments(self.quadrant_segments);
+        let parts = rings
+            .iter()
+            .flat_map(|ring| strip.points_parts(ring, true))
+            .collect();
+        let strip = union_all(parts);
+
+        if self.distance > 0.0 {
+            overlay(&rings, &strip, BooleanOp::Union)
+        } else {
+            overlay(&rings, &strip, BooleanOp::Difference)
+        }
+    }
+
+    fn merge<P: NewCartesianPoint2d>(&self, parts: Vec<Vec<Ring>>) -> MultiPolygon<P> {
+        to_multi_polygon(union_all(parts))
+    }
+
+    fn contour_parts<C>(&self, contour: &C) -> Vec<Vec<Ring>>
+    where
+        C: crate::contour::Contour,
+        C::Point: CartesianPoint2d<Num = f64>,
+    {
+        let points: Vec<[f64; 2]> = contour.iter_points().map(|p| [p.x(), p.y()]).collect();
+        self.points_parts(&points, contour.is_closed())
+    }
+
+    /// Circles around the points and rectangles around the segments between them.
+    fn points_parts(&self, points: &[[f64; 2]], is_closed: bool) -> Vec<Vec<Ring>> {
+        let mut parts: Vec<Vec<Ring>> = points
+            .iter()
+            .filter_map(|&p| self.circle(p))
+            .map(|circle| vec![circle])
+            .collect();
+
+        let segments_count = if is_closed && points.len() > 2 {
+            points.len()
+        } else {
+            points.len().saturating_sub(1)
+        };
+        for i in 0..segments_count {
+            if let Some(rectangle) = self.rectangle(points[i], points[(i + 1) % points.len()]) {
+                parts.push(vec![rectangle]);
+            }
+        }
+
+        parts
+    }
+
+    fn circle(&self, center: [f64; 2]) -> Option<Ring> {
+        if self.distance <= 0.0 || !self.distance.is_finite() {
+            return None;
+        }
+
+        let count = self.quadrant_segments.max(1) * 4;
+        Some(
+            (0..count)
+                .map(|i| {
+                    let angle = std::f64::consts::TAU * i as f64 / count as f64;
+                    [
+                        center[0] + self.distance * angle.cos(),
+                        center[1] + self.distance * angle.sin(),
+                    ]
+                })
+                .collect(),
+        )
+    }
+
+    /// Rectangle with the segment as its middle line and the width of two distances.
+    fn rectangle(&self, from: [f64; 2], to: [f64; 2]) -> Option<Ring> {
+        let dx = to[0] - from[0];
+        let dy = to[1] - from[1];
+        let length = dx.hypot(dy);
+        if self.distance <= 0.0 || !self.distance.is_finite() || length == 0.0 {
+            return None;
+        }
+
+        let nx = -dy / length * self.distance;
+        let ny = dx / length * self.distance;
+        Some(vec![
+            [from[0] - nx, from[1] - ny],
+            [to[0] - nx, to[1] - ny],
+            [to[0] + nx, to[1] + ny],
+            [from[0] + nx, from[1] + ny],
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cartesian::impls::contour::{ClosedContour, Contour};
+    use crate::cartesian::impls::point::Point2d;
+    use crate::cartesian::impls::polygon::Polygon;
+    use crate::cartesian::traits::contour::CartesianClosedContour;
+
+    /// Area of the regular polygon with 32 vertices approximating the circle with radius 1.
+    const CIRCLE_AREA: f64 = 3.1214451522580524;
+
+    fn area(polygons: &MultiPolygon<Point2d>) -> f64 {
+        polygons
+            .parts()
+            .iter()
+            .map(|p| {
+                p.outer_contour.area_signed()
+                    + p.inner_contours
+                        .iter()
+                        .map(|c| c.area_signed())
+                        .sum::<f64>()
+            })
+            .sum()
+    }
+
+    fn square() -> Polygon<Point2d> {
+        Polygon::new(
+            ClosedContour::new(vec![
+                Point2d::new(0.0, 0.0),
+                Point2d::new(10.0, 0.0),
+                Point2d::new(10.0, 10.0),
+                Point2d::new(0.0, 10.0),
+            ]),
+            vec![],
+        )
+    }
+
+    #[test]
+    fn point() {
+        let buffer: MultiPolygon<Point2d> = Buffer::new(1.0).buffer_point(&Point2d::new(5.0, 5.0));
+        assert_eq!(buffer.parts().len(), 1);
+        assert_eq!(buffer.parts()[0].outer_contour.points.len(), 32);
+        assert!((area(&buffer) - CIRCLE_AREA).abs() < 1e-9);
+
+        let empty: MultiPolygon<Point2d> = Buffer::new(-1.0).buffer_point(&Point2d::new(5.0, 5.0));
+        assert!(empty.parts().is_empty());
+    }
+
+    #[test]
+    fn line() {
+        let line = Contour::open(vec![Point2d::new(0.0, 0.0), Point2d::new(10.0, 0.0)]);
+        let buffer: MultiPolygon<Point2d> = Buffer::new(1.0).buffer_contour(&line);
+
+        assert_eq!(buffer.parts().len(), 1);
+        assert!((area(&buffer) - 20.0 - CIRCLE_AREA).abs() < 1e-9);
+    }
+
+    #[test]
+    fn bent_line() {
+        let line = Contour::open(vec![
+            Point2d::new(0.0, 0.0),
+            Point2d::new(10.0, 0.0),
+            Point2d::new(10.0, 10.0),
+        ]);
+        let buffer: MultiPolygon<Point2d> = Buffer::new(1.0).buffer_contour(&line);
+
+        // The strips along the segments overlap in a unit square, and a quarter of the circle around the corner lies
+        // outside of both strips.
+        assert_eq!(buffer.parts().len(), 1);
+        assert!(buffer.parts()[0].inner_contours.is_empty());
+        assert!((area(&buffer) - 39.0 - 1.25 * CIRCLE_AREA).abs() < 1e-9);
+    }
+
+    #[test]
+    fn polygon() {
+        let grown: MultiPolygon<Point2d> = Buffer::new(1.0).buffer_area(&square());
+        assert_eq!(grown.parts().len(), 1);
+        assert!((area(&grown) - 140.0 - CIRCLE_AREA).abs() < 1e-9);
+
+        let shrunk: MultiPolygon<Point2d> = Buffer::new(-1.0).buffer_area(&square());
+        assert_eq!(shrunk.parts().len(), 1);
+        assert!((area(&shrunk) - 64.0).abs() < 1e-9);
+
+        let vanished: MultiPolygon<Point2d> = Buffer::new(-6.0).buffer_area(&square());
+        assert!(vanished.parts().is_empty());
+    }
+
+    #[test]
+    fn geometry() {
+        let points = Geom::MultiPoint(vec![Point2d::new(0.0, 0.0), Point2d::new(10.0, 0.0)].into());
+        assert_eq!(Buffer::new(1.0).buffer(&points).parts().len(), 2);
+        assert_eq!(Buffer::new(6.0).buffer(&points).parts().len(), 1);
+    }
+}
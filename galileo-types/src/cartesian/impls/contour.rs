@@ -1,4 +1,4 @@
-use crate::geo::traits::projection::Projection;
+use crate::geo::traits::projection::{Projection, ProjectionError};
 use crate::geometry_type::{ContourGeometryType, GeometryType};
 use serde::{Deserialize, Serialize};
 
@@ -37,7 +37,7 @@ impl<Point> Contour<Point> {
         }
     }
 
-    pub fn project_points<P, Proj>(&self, projection: &Proj) -> Option<Contour<P>>
+    pub fn project_points<P, Proj>(&self, projection: &Proj) -> Result<Contour<P>, ProjectionError>
     where
         Proj: Projection<InPoint = Point, OutPoint = P>,
     {
@@ -45,8 +45,8 @@ impl<Point> Contour<Point> {
             .points
             .iter()
             .map(|p| projection.project(p))
-            .collect::<Option<Vec<P>>>()?;
-        Some(Contour {
+            .collect::<Result<Vec<P>, _>>()?;
+        Ok(Contour {
             points,
             is_closed: self.is_closed,
         })
@@ -63,7 +63,10 @@ impl<Point> ClosedContour<Point> {
         Self { points }
     }
 
-    pub fn project_points<P, Proj>(&self, projection: &Proj) -> Option<ClosedContour<P>>
+    pub fn project_points<P, Proj>(
+        &self,
+        projection: &Proj,
+    ) -> Result<ClosedContour<P>, ProjectionError>
     where
         Proj: Projection<InPoint = Point, OutPoint = P>,
     {
@@ -71,8 +74,8 @@ impl<Point> ClosedContour<Point> {
             .points
             .iter()
             .map(|p| projection.project(p))
-            .collect::<Option<Vec<P>>>()?;
-        Some(ClosedContour { points })
+            .collect::<Result<Vec<P>, _>>()?;
+        Ok(ClosedContour { points })
     }
 }
 
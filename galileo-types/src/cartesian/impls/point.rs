@@ -1,7 +1,7 @@
 use crate::cartesian::traits::cartesian_point::{
     CartesianPoint2d, CartesianPoint3d, NewCartesianPoint2d, NewCartesianPoint3d,
 };
-use crate::geo::traits::projection::Projection;
+use crate::geo::traits::projection::{Projection, ProjectionError};
 use crate::geometry::{Geom, Geometry};
 use crate::geometry_type::{CartesianSpace2d, GeometryType, PointGeometryType};
 use crate::point::{CartesianPointType, Point};
@@ -72,7 +72,7 @@ impl<Num: Scalar> Geometry for Point3<Num> {
     fn project<P: Projection<InPoint = Self::Point> + ?Sized>(
         &self,
         projection: &P,
-    ) -> Option<Geom<P::OutPoint>> {
-        Some(Geom::Point(projection.project(self)?))
+    ) -> Result<Geom<P::OutPoint>, ProjectionError> {
+        Ok(Geom::Point(projection.project(self)?))
     }
 }
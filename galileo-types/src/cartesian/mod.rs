@@ -1,5 +1,9 @@
+pub mod boolean;
+pub mod buffer;
 pub mod impls;
 pub mod orient;
 pub mod rect;
+pub mod simplify;
 pub mod size;
 pub mod traits;
+pub mod validity;
@@ -0,0 +1,907 @@
+//! Simplification of cartesian geometries.
+//!
+//! [`Simplify`] removes the vertices of contours and polygons that do not change the shape of the geometry
+//! significantly at the given tolerance. Two algorithms are supported:
+//! * [Douglas-Peucker](SimplifyAlgorithm::DouglasPeucker) keeps the vertices that are farther than the tolerance from
+//!   the simplified line;
+//! * [Visvalingam-Whyatt](SimplifyAlgorithm::VisvalingamWhyatt) removes the vertices that form triangles with their
+//!   neighbours with the area smaller than the tolerance.
+//!
+//! Both algorithms can produce self-intersecting geometries or move one contour across another. The topology-preserving
+//! variants (see [`Simplify::preserving_topology`]) keep the vertices that would cause it. They compare every
+//! simplified segment with all the other segments of the geometry, so they are much slower on large geometries.
+
+use crate::cartesian::impls::contour::{ClosedContour, Contour};
+use crate::cartesian::impls::multipolygon::MultiPolygon;
+use crate::cartesian::impls::polygon::Polygon;
+use crate::cartesian::traits::cartesian_point::CartesianPoint2d;
+use crate::contour::Contour as _;
+use crate::geometry::Geom;
+use crate::impls::multi_contour::MultiContour;
+use crate::multi_point::MultiPoint as _;
+use num_traits::ToPrimitive;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+/// Algorithm used by [`Simplify`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SimplifyAlgorithm {
+    /// Ramer-Douglas-Peucker algorithm. The tolerance is the maximum distance between the original and the simplified
+    /// contour.
+    #[default]
+    DouglasPeucker,
+    /// Visvalingam-Whyatt algorithm. The tolerance is the minimum area of the triangle formed by a vertex and its
+    /// neighbours for the vertex to be kept.
+    VisvalingamWhyatt,
+}
+
+/// Simplifies cartesian geometries, see the [module docs](self).
+///
+/// The first and the last points of open contours are always kept. Closed contours are kept with at least three
+/// points, but polygon rings that collapse at the given tolerance are removed: a ring collapses if less then three
+/// points remain after Douglas-Peucker simplification, or if its area is smaller than the tolerance of
+/// Visvalingam-Whyatt simplification.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Simplify {
+    pub algorithm: SimplifyAlgorithm,
+    /// Tolerance in the units of the coordinates for Douglas-Peucker, and in the square units for Visvalingam-Whyatt.
+    pub tolerance: f64,
+    /// Do not remove the vertices if it would make the contours of the geometry intersect each other.
+    pub preserve_topology: bool,
+}
+
+impl Simplify {
+    /// Douglas-Peucker simplification with the given maximum distance.
+    pub fn douglas_peucker(tolerance: f64) -> Self {
+        Self {
+            algorithm: SimplifyAlgorithm::DouglasPeucker,
+            tolerance,
+            preserve_topology: false,
+        }
+    }
+
+    /// Visvalingam-Whyatt simplification with the given minimum area.
+    pub fn visvalingam_whyatt(tolerance: f64) -> Self {
+        Self {
+            algorithm: SimplifyAlgorithm::VisvalingamWhyatt,
+            tolerance,
+            preserve_topology: false,
+        }
+    }
+
+    /// Returns the topology-preserving variant of the simplification.
+    pub fn preserving_topology(mut self) -> Self {
+        self.preserve_topology = true;
+        self
+    }
+
+    /// Considers the tolerance to be set in pixels (or square pixels for Visvalingam-Whyatt) and returns the
+    /// simplification with the tolerance in the map units for the given resolution.
+    pub fn for_resolution(&self, resolution: f64) -> Self {
+        let scale = match self.algorithm {
+            SimplifyAlgorithm::DouglasPeucker => resolution,
+            SimplifyAlgorithm::VisvalingamWhyatt => resolution * resolution,
+        };
+
+        Self {
+            tolerance: self.tolerance * scale,
+            ..*self
+        }
+    }
+
+    /// Simplifies the geometry. Returns `None` if the geometry is a polygon or a multipolygon that collapses
+    /// completely.
+    pub fn simplify<P>(&self, geom: &Geom<P>) -> Option<Geom<P>>
+    where
+        P: CartesianPoint2d + Clone,
+        P::Num: ToPrimitive,
+    {
+        let simplified = match geom {
+            Geom::Point(p) => Geom::Point(p.clone()),
+            Geom::MultiPoint(points) => {
+                Geom::MultiPoint(points.iter_points().cloned().collect::<Vec<_>>().into())
+            }
+            Geom::Contour(contour) => Geom::Contour(self.simplify_contour(contour)),
+            Geom::MultiContour(contours) => {
+                Geom::MultiContour(self.simplify_multi_contour(contours))
+            }
+            Geom::Polygon(polygon) => Geom::Polygon(self.simplify_polygon(polygon)?),
+            Geom::MultiPolygon(polygons) => {
+                let simplified = self.simplify_multi_polygon(polygons);
+                if simplified.parts().is_empty() {
+                    return None;
+                }
+
+                Geom::MultiPolygon(simplified)
+            }
+        };
+
+        Some(simplified)
+    }
+
+    /// Simplifies the contour.
+    pub fn simplify_contour<C, P>(&self, contour: &C) -> Contour<P>
+    where
+        C: crate::contour::Contour<Point = P>,
+        P: CartesianPoint2d + Clone,
+        P::Num: ToPrimitive,
+    {
+        let line = Line::new(contour.iter_points(), contour.is_closed());
+        let kept = self.run(std::slice::from_ref(&line));
+
+        Contour::new(line.select(&kept[0]), contour.is_closed())
+    }
+
+    /// Simplifies all contours of the multi-contour. With topology preservation the contours are not allowed to cross
+    /// each other.
+    pub fn simplify_multi_contour<M, P>(&self, contours: &M) -> MultiContour<P>
+    where
+        M: crate::multi_contour::MultiContour,
+        M::Contour: crate::contour::Contour<Point = P>,
+        P: CartesianPoint2d + Clone,
+        P::Num: ToPrimitive,
+    {
+        let lines: Vec<_> = contours
+            .contours()
+            .map(|c| Line::new(c.iter_points(), c.is_closed()))
+            .collect();
+        let kept = self.run(&lines);
+
+        lines
+            .iter()
+            .zip(&kept)
+            .map(|(line, kept)| Contour::new(line.select(kept), line.closed))
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    /// Simplifies the polygon. Collapsed holes are removed. Returns `None` if the outer contour collapses.
+    pub fn simplify_polygon<Poly, P>(&self, polygon: &Poly) -> Option<Polygon<P>>
+    where
+        Poly: crate::polygon::Polygon,
+        Poly::Contour: crate::contour::Contour<Point = P>,
+        P: CartesianPoint2d + Clone,
+        P::Num: ToPrimitive,
+    {
+        let lines = polygon_lines(polygon);
+        let kept = self.run(&lines);
+
+        self.build_polygon(&lines, &kept)
+    }
+
+    /// Simplifies all polygons of the multipolygon, removing the ones that collapse. With topology preservation the
+    /// polygons are not allowed to cross each other.
+    pub fn simplify_multi_polygon<M, P>(&self, polygons: &M) -> MultiPolygon<P>
+    where
+        M: crate::multi_polygon::MultiPolygon,
+        <M::Polygon as crate::polygon::Polygon>::Contour: crate::contour::Contour<Point = P>,
+        P: CartesianPoint2d + Clone,
+        P::Num: ToPrimitive,
+    {
+        let mut lines = vec![];
+        let mut ranges = vec![];
+        for polygon in polygons.polygons() {
+            let start = lines.len();
+            lines.append(&mut polygon_lines(polygon));
+            ranges.push(start..lines.len());
+        }
+
+        let kept = self.run(&lines);
+        ranges
+            .into_iter()
+            .filter_map(|range| self.build_polygon(&lines[range.clone()], &kept[range]))
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    fn build_polygon<P: Clone>(
+        &self,
+        lines: &[Line<P>],
+        kept: &[Vec<usize>],
+    ) -> Option<Polygon<P>> {
+        if self.is_collapsed(&lines[0], &kept[0]) {
+            return None;
+        }
+
+        let inner_contours = lines[1..]
+            .iter()
+            .zip(&kept[1..])
+            .filter(|(line, kept)| !self.is_collapsed(line, kept))
+            .map(|(line, kept)| ClosedContour::new(line.select(kept)))
+            .collect();
+
+        Some(Polygon::new(
+            ClosedContour::new(lines[0].select(&kept[0])),
+            inner_contours,
+        ))
+    }
+
+    fn is_collapsed<P>(&self, line: &Line<P>, kept: &[usize]) -> bool {
+        if kept.len() < 3 {
+            return true;
+        }
+
+        match self.algorithm {
+            SimplifyAlgorithm::DouglasPeucker => false,
+            SimplifyAlgorithm::VisvalingamWhyatt => {
+                let coords: Vec<_> = kept.iter().map(|&i| line.coords[i]).collect();
+                ring_area(&coords) < self.tolerance
+            }
+        }
+    }
+
+    /// Returns the indices of the points of every line that are kept after simplification.
+    fn run<P>(&self, lines: &[Line<P>]) -> Vec<Vec<usize>> {
+        match self.algorithm {
+            SimplifyAlgorithm::DouglasPeucker => self.run_douglas_peucker(lines),
+            SimplifyAlgorithm::VisvalingamWhyatt => self.run_visvalingam_whyatt(lines),
+        }
+    }
+
+    fn run_douglas_peucker<P>(&self, lines: &[Line<P>]) -> Vec<Vec<usize>> {
+        // Closed lines are processed as open ones, going from the first point around back to it. The last index of such
+        // line refers to the first point.
+        let mut kept: Vec<Vec<bool>> = lines
+            .iter()
+            .map(|line| {
+                if line.coords.is_empty() {
+                    return vec![];
+                }
+
+                let mut kept = vec![false; line.end() + 1];
+                kept[0] = true;
+                *kept.last_mut().expect("not empty") = true;
+
+                let mut ranges = vec![(0, line.end())];
+                while let Some((start, end)) = ranges.pop() {
+                    if let Some((index, distance)) = line.farthest(start, end) {
+                        if distance > self.tolerance {
+                            kept[index] = true;
+                            ranges.push((start, index));
+                            ranges.push((index, end));
+                        }
+                    }
+                }
+
+                kept
+            })
+            .collect();
+
+        if self.preserve_topology {
+            // Points are added back to the simplified segments that break topology until there are no such segments
+            // left. In the worst case all the points are returned, so this always ends.
+            loop {
+                let segments = output_segments(lines, &kept);
+                let mut changed = false;
+                for segment in &segments {
+                    if segment.to > segment.from + 1 && breaks_topology(lines, &segments, segment) {
+                        if let Some((index, _)) =
+                            lines[segment.line].farthest(segment.from, segment.to)
+                        {
+                            kept[segment.line][index] = true;
+                            changed = true;
+                        }
+                    }
+                }
+
+                if !changed {
+                    break;
+                }
+            }
+        }
+
+        lines
+            .iter()
+            .zip(kept)
+            .map(|(line, kept)| {
+                (0..line.coords.len())
+                    .filter(|&index| kept[index])
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn run_visvalingam_whyatt<P>(&self, lines: &[Line<P>]) -> Vec<Vec<usize>> {
+        let mut state: Vec<VwLine> = lines.iter().map(VwLine::new).collect();
+        let mut queue = BinaryHeap::new();
+        for (line_index, line) in state.iter_mut().enumerate() {
+            if lines[line_index].coords.is_empty() {
+                continue;
+            }
+
+            for index in 0..line.alive.len() {
+                if line.is_removable(index) {
+                    let area = line.triangle_area(&lines[line_index], index);
+                    line.area[index] = area;
+                    queue.push(VwCandidate {
+                        area,
+                        line: line_index,
+                        index,
+                    });
+                }
+            }
+        }
+
+        while let Some(VwCandidate { area, line, index }) = queue.pop() {
+            if area >= self.tolerance {
+                break;
+            }
+
+            let current = &state[line];
+            if !current.alive[index] || current.area[index] != area || !current.is_removable(index)
+            {
+                continue;
+            }
+
+            if self.preserve_topology && !can_remove(lines, &state, line, index) {
+                continue;
+            }
+
+            let current = &mut state[line];
+            let (prev, next) = current.remove(index);
+            for neighbour in [prev, next] {
+                if current.is_removable(neighbour) {
+                    // Area of the neighbours is not allowed to go below the area of the removed point, so that the
+                    // points are removed in the order of their significance.
+                    let neighbour_area = current.triangle_area(&lines[line], neighbour).max(area);
+                    current.area[neighbour] = neighbour_area;
+                    queue.push(VwCandidate {
+                        area: neighbour_area,
+                        line,
+                        index: neighbour,
+                    });
+                }
+            }
+        }
+
+        state
+            .into_iter()
+            .map(|line| {
+                (0..line.alive.len())
+                    .filter(|&index| line.alive[index])
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+/// Points of a contour together with their coordinates as `f64`.
+struct Line<'a, P> {
+    points: Vec<&'a P>,
+    coords: Vec<[f64; 2]>,
+    closed: bool,
+}
+
+impl<'a, P> Line<'a, P> {
+    fn new(points: impl Iterator<Item = &'a P>, closed: bool) -> Self
+    where
+        P: CartesianPoint2d,
+        P::Num: ToPrimitive,
+    {
+        let mut points: Vec<&P> = points.collect();
+        if closed && points.len() > 1 && points[0].equal(points[points.len() - 1]) {
+            points.pop();
+        }
+
+        let coords = points
+            .iter()
+            .map(|p| {
+                [
+                    p.x().to_f64().unwrap_or(f64::NAN),
+                    p.y().to_f64().unwrap_or(f64::NAN),
+                ]
+            })
+            .collect();
+
+        Self {
+            points,
+            coords,
+            closed,
+        }
+    }
+
+    /// Last index of the line. For closed lines it refers to the first point.
+    fn end(&self) -> usize {
+        if self.closed {
+            self.coords.len()
+        } else {
+            self.coords.len().saturating_sub(1)
+        }
+    }
+
+    fn point(&self, index: usize) -> [f64; 2] {
+        self.coords[index % self.coords.len()]
+    }
+
+    /// Index of the point between `start` and `end` that is the farthest from the segment between them, and the
+    /// distance to it.
+    fn farthest(&self, start: usize, end: usize) -> Option<(usize, f64)> {
+        if start + 1 >= end {
+            return None;
+        }
+
+        let from = self.point(start);
+        let to = self.point(end);
+        (start + 1..end)
+            .map(|index| (index, distance_to_segment(self.point(index), from, to)))
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+    }
+
+    fn select(&self, indices: &[usize]) -> Vec<P>
+    where
+        P: Clone,
+    {
+        indices.iter().map(|&i| self.points[i].clone()).collect()
+    }
+}
+
+fn polygon_lines<Poly, P>(polygon: &Poly) -> Vec<Line<'_, P>>
+where
+    Poly: crate::polygon::Polygon,
+    Poly::Contour: crate::contour::Contour<Point = P>,
+    P: CartesianPoint2d,
+    P::Num: ToPrimitive,
+{
+    std::iter::once(polygon.outer_contour())
+        .chain(polygon.inner_contours())
+        .map(|c| Line::new(c.iter_points(), true))
+        .collect()
+}
+
+/// Segment of a simplified line between the points `from` and `to` of the original line.
+struct OutputSegment {
+    line: usize,
+    from: usize,
+    to: usize,
+}
+
+fn output_segments<P>(lines: &[Line<P>], kept: &[Vec<bool>]) -> Vec<OutputSegment> {
+    let mut segments = vec![];
+    for (line, kept) in kept.iter().enumerate() {
+        if lines[line].coords.is_empty() {
+            continue;
+        }
+
+        let mut from = 0;
+        for (to, &is_kept) in kept.iter().enumerate().skip(1) {
+            if is_kept {
+                segments.push(OutputSegment { line, from, to });
+                from = to;
+            }
+        }
+    }
+
+    segments
+}
+
+/// Checks if the simplified segment intersects other segments of the simplified geometry, or if any of the vertices
+/// of the simplified geometry lie between the segment and the part of the original line it replaces.
+fn breaks_topology<P>(
+    lines: &[Line<P>],
+    segments: &[OutputSegment],
+    segment: &OutputSegment,
+) -> bool {
+    let line = &lines[segment.line];
+    let len = line.coords.len();
+    let from = line.point(segment.from);
+    let to = line.point(segment.to);
+    let is_end = |other_line: usize, index: usize| {
+        other_line == segment.line
+            && (index % len == segment.from % len || index % len == segment.to % len)
+    };
+
+    let replaced: Vec<[f64; 2]> = (segment.from..=segment.to)
+        .map(|index| line.point(index))
+        .collect();
+    let bbox = bounding_box(&replaced);
+
+    for other in segments {
+        let other_line = &lines[other.line];
+        let other_from = other_line.point(other.from);
+        let other_to = other_line.point(other.to);
+        let shares_from = is_end(other.line, other.from);
+        let shares_to = is_end(other.line, other.to);
+
+        if !shares_from && !shares_to && segments_intersect(from, to, other_from, other_to) {
+            return true;
+        }
+
+        for (vertex, is_shared) in [(other_from, shares_from), (other_to, shares_to)] {
+            if !is_shared && contains(&bbox, vertex) && point_in_ring(vertex, &replaced) {
+                return true;
+            }
+        }
+    }
+
+    false
+}
+
+/// State of a line during Visvalingam-Whyatt simplification.
+struct VwLine {
+    alive: Vec<bool>,
+    prev: Vec<usize>,
+    next: Vec<usize>,
+    area: Vec<f64>,
+    alive_count: usize,
+    closed: bool,
+}
+
+impl VwLine {
+    fn new<P>(line: &Line<P>) -> Self {
+        let len = line.coords.len();
+        Self {
+            alive: vec![true; len],
+            prev: (0..len).map(|i| (i + len - 1) % len).collect(),
+            next: (0..len).map(|i| (i + 1) % len).collect(),
+            area: vec![f64::INFINITY; len],
+            alive_count: len,
+            closed: line.closed,
+        }
+    }
+
+    fn is_removable(&self, index: usize) -> bool {
+        let min_count = if self.closed { 3 } else { 2 };
+        let is_end = !self.closed && (index == 0 || index == self.alive.len() - 1);
+
+        self.alive[index] && !is_end && self.alive_count > min_count
+    }
+
+    fn triangle_area<P>(&self, line: &Line<P>, index: usize) -> f64 {
+        ring_area(&[
+            line.coords[self.prev[index]],
+            line.coords[index],
+            line.coords[self.next[index]],
+        ])
+    }
+
+    fn remove(&mut self, index: usize) -> (usize, usize) {
+        let prev = self.prev[index];
+        let next = self.next[index];
+        self.next[prev] = next;
+        self.prev[next] = prev;
+        self.alive[index] = false;
+        self.alive_count -= 1;
+
+        (prev, next)
+    }
+}
+
+/// Checks if a point can be removed without changing the topology. If the geometry is valid, removing the point
+/// changes the topology only if there are other vertices inside the triangle formed by the point and its neighbours.
+fn can_remove<P>(lines: &[Line<P>], state: &[VwLine], line: usize, index: usize) -> bool {
+    let prev = state[line].prev[index];
+    let next = state[line].next[index];
+    let triangle = [
+        lines[line].coords[prev],
+        lines[line].coords[index],
+        lines[line].coords[next],
+    ];
+    let bbox = bounding_box(&triangle);
+
+    for (other_line, other_state) in state.iter().enumerate() {
+        for (other_index, coords) in lines[other_line].coords.iter().enumerate() {
+            if !other_state.alive[other_index]
+                || (other_line == line && [prev, index, next].contains(&other_index))
+            {
+                continue;
+            }
+
+            if contains(&bbox, *coords) && point_in_triangle(*coords, &triangle) {
+                return false;
+            }
+        }
+    }
+
+    true
+}
+
+struct VwCandidate {
+    area: f64,
+    line: usize,
+    index: usize,
+}
+
+impl PartialEq for VwCandidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for VwCandidate {}
+
+impl PartialOrd for VwCandidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for VwCandidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reversed, so that the binary heap returns the smallest area first.
+        other
+            .area
+            .total_cmp(&self.area)
+            .then_with(|| other.line.cmp(&self.line))
+            .then_with(|| other.index.cmp(&self.index))
+    }
+}
+
+fn cross(o: [f64; 2], a: [f64; 2], b: [f64; 2]) -> f64 {
+    (a[0] - o[0]) * (b[1] - o[1]) - (a[1] - o[1]) * (b[0] - o[0])
+}
+
+fn ring_area(coords: &[[f64; 2]]) -> f64 {
+    let area: f64 = coords
+        .iter()
+        .zip(coords.iter().cycle().skip(1))
+        .map(|(a, b)| a[0] * b[1] - b[0] * a[1])
+        .sum();
+
+    area.abs() / 2.0
+}
+
+fn distance_to_segment(point: [f64; 2], from: [f64; 2], to: [f64; 2]) -> f64 {
+    let dx = to[0] - from[0];
+    let dy = to[1] - from[1];
+    let length_sq = dx * dx + dy * dy;
+    let t = if length_sq > 0.0 {
+        (((point[0] - from[0]) * dx + (point[1] - from[1]) * dy) / length_sq).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+
+    (point[0] - from[0] - t * dx).hypot(point[1] - from[1] - t * dy)
+}
+
+/// Checks if two segments have any common points, including touching ends.
+fn segments_intersect(a: [f64; 2], b: [f64; 2], c: [f64; 2], d: [f64; 2]) -> bool {
+    let d1 = cross(c, d, a);
+    let d2 = cross(c, d, b);
+    let d3 = cross(a, b, c);
+    let d4 = cross(a, b, d);
+
+    if ((d1 > 0.0 && d2 < 0.0) || (d1 < 0.0 && d2 > 0.0))
+        && ((d3 > 0.0 && d4 < 0.0) || (d3 < 0.0 && d4 > 0.0))
+    {
+        return true;
+    }
+
+    let within = |from: [f64; 2], to: [f64; 2], p: [f64; 2]| {
+        p[0] >= from[0].min(to[0])
+            && p[0] <= from[0].max(to[0])
+            && p[1] >= from[1].min(to[1])
+            && p[1] <= from[1].max(to[1])
+    };
+
+    (d1 == 0.0 && within(c, d, a))
+        || (d2 == 0.0 && within(c, d, b))
+        || (d3 == 0.0 && within(a, b, c))
+        || (d4 == 0.0 && within(a, b, d))
+}
+
+fn point_in_triangle(point: [f64; 2], triangle: &[[f64; 2]; 3]) -> bool {
+    let d1 = cross(triangle[0], triangle[1], point);
+    let d2 = cross(triangle[1], triangle[2], point);
+    let d3 = cross(triangle[2], triangle[0], point);
+
+    (d1 >= 0.0 && d2 >= 0.0 && d3 >= 0.0) || (d1 <= 0.0 && d2 <= 0.0 && d3 <= 0.0)
+}
+
+fn point_in_ring(point: [f64; 2], ring: &[[f64; 2]]) -> bool {
+    let mut inside = false;
+    let mut j = ring.len() - 1;
+    for (i, &a) in ring.iter().enumerate() {
+        let b = ring[j];
+        if (a[1] > point[1]) != (b[1] > point[1])
+            && point[0] < (b[0] - a[0]) * (point[1] - a[1]) / (b[1] - a[1]) + a[0]
+        {
+            inside = !inside;
+        }
+        j = i;
+    }
+
+    inside
+}
+
+fn bounding_box(coords: &[[f64; 2]]) -> [f64; 4] {
+    coords.iter().fold(
+        [
+            f64::INFINITY,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            f64::NEG_INFINITY,
+        ],
+        |bbox, c| {
+            [
+                bbox[0].min(c[0]),
+                bbox[1].min(c[1]),
+                bbox[2].max(c[0]),
+                bbox[3].max(c[1]),
+            ]
+        },
+    )
+}
+
+fn contains(bbox: &[f64; 4], point: [f64; 2]) -> bool {
+    point[0] >= bbox[0] && point[0] <= bbox[2] && point[1] >= bbox[1] && point[1] <= bbox[3]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cartesian::impls::point::Point2d;
+    use crate::multi_contour::MultiContour as _;
+
+    fn points(coords: &[(f64, f64)]) -> Vec<Point2d> {
+        coords.iter().map(|&(x, y)| Point2d::new(x, y)).collect()
+    }
+
+    #[test]
+    fn douglas_peucker_line() {
+        let line = Contour::open(points(&[
+            (0.0, 0.0),
+            (1.0, 0.1),
+            (2.0, -0.1),
+            (3.0, 5.0),
+            (4.0, 6.0),
+            (5.0, 7.0),
+            (6.0, 8.1),
+            (7.0, 9.0),
+        ]));
+        let simplified = Simplify::douglas_peucker(0.5).simplify_contour(&line);
+
+        assert!(!simplified.is_closed);
+        assert_eq!(
+            simplified.points,
+            points(&[(0.0, 0.0), (2.0, -0.1), (3.0, 5.0), (7.0, 9.0)])
+        );
+    }
+
+    #[test]
+    fn visvalingam_whyatt_line() {
+        let line = Contour::open(points(&[
+            (0.0, 0.0),
+            (1.0, 0.1),
+            (2.0, 0.0),
+            (3.0, 3.0),
+            (4.0, 0.0),
+        ]));
+        let simplified = Simplify::visvalingam_whyatt(1.0).simplify_contour(&line);
+
+        assert_eq!(
+            simplified.points,
+            points(&[(0.0, 0.0), (2.0, 0.0), (3.0, 3.0), (4.0, 0.0)])
+        );
+    }
+
+    #[test]
+    fn collapsed_rings() {
+        let polygon = Polygon::new(
+            ClosedContour::new(points(&[
+                (0.0, 0.0),
+                (10.0, 0.0),
+                (10.0, 10.0),
+                (5.0, 10.1),
+                (0.0, 10.0),
+                (0.0, 0.0),
+            ])),
+            vec![ClosedContour::new(points(&[
+                (2.0, 2.0),
+                (2.5, 2.0),
+                (2.5, 2.5),
+            ]))],
+        );
+
+        let simplified = Simplify::douglas_peucker(1.0)
+            .simplify_polygon(&polygon)
+            .expect("outer contour is kept");
+        assert_eq!(
+            simplified.outer_contour.points,
+            points(&[(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)])
+        );
+        assert!(simplified.inner_contours.is_empty());
+
+        let simplified = Simplify::visvalingam_whyatt(1.0)
+            .simplify_polygon(&polygon)
+            .expect("outer contour is kept");
+        assert_eq!(simplified.outer_contour.points.len(), 4);
+        assert!(simplified.inner_contours.is_empty());
+
+        assert!(Simplify::douglas_peucker(20.0)
+            .simplify_polygon(&polygon)
+            .is_none());
+        assert!(Simplify::visvalingam_whyatt(200.0)
+            .simplify_polygon(&polygon)
+            .is_none());
+    }
+
+    #[test]
+    fn preserves_topology() {
+        let contours = MultiContour::from(vec![
+            Contour::open(points(&[(0.0, 0.0), (5.0, 2.0), (10.0, 0.0)])),
+            Contour::open(points(&[(4.0, 0.5), (6.0, 0.5)])),
+        ]);
+
+        for simplify in [
+            Simplify::douglas_peucker(3.0),
+            Simplify::visvalingam_whyatt(20.0),
+        ] {
+            let simplified = simplify.simplify_multi_contour(&contours);
+            assert_eq!(simplified.contours().next().unwrap().points.len(), 2);
+
+            let simplified = simplify
+                .preserving_topology()
+                .simplify_multi_contour(&contours);
+            assert_eq!(simplified.contours().next().unwrap().points.len(), 3);
+            assert_eq!(simplified.contours().nth(1).unwrap().points.len(), 2);
+        }
+    }
+
+    #[test]
+    fn empty_contours() {
+        for simplify in [
+            Simplify::douglas_peucker(1.0),
+            Simplify::visvalingam_whyatt(1.0),
+            Simplify::douglas_peucker(1.0).preserving_topology(),
+            Simplify::visvalingam_whyatt(1.0).preserving_topology(),
+        ] {
+            let simplified = simplify.simplify_contour(&Contour::<Point2d>::open(vec![]));
+            assert!(simplified.points.is_empty());
+
+            let simplified = simplify.simplify_contour(&Contour::<Point2d>::closed(vec![]));
+            assert!(simplified.points.is_empty());
+
+            let contours = MultiContour::from(vec![
+                Contour::open(vec![]),
+                Contour::open(points(&[(0.0, 0.0), (10.0, 0.0)])),
+            ]);
+            let simplified = simplify.simplify_multi_contour(&contours);
+            assert_eq!(simplified.contours().nth(1).unwrap().points.len(), 2);
+        }
+    }
+
+    #[test]
+    fn empty_holes() {
+        let polygon = Polygon::new(
+            ClosedContour::new(points(&[
+                (0.0, 0.0),
+                (10.0, 0.0),
+                (10.0, 10.0),
+                (0.0, 10.0),
+            ])),
+            vec![ClosedContour::new(vec![])],
+        );
+
+        for simplify in [
+            Simplify::douglas_peucker(1.0),
+            Simplify::visvalingam_whyatt(1.0),
+            Simplify::douglas_peucker(1.0).preserving_topology(),
+            Simplify::visvalingam_whyatt(1.0).preserving_topology(),
+        ] {
+            let simplified = simplify
+                .simplify_polygon(&polygon)
+                .expect("outer contour is kept");
+            assert_eq!(simplified.outer_contour.points.len(), 4);
+            assert!(simplified.inner_contours.is_empty());
+
+            let empty = Polygon::new(ClosedContour::<Point2d>::new(vec![]), vec![]);
+            assert!(simplify.simplify_polygon(&empty).is_none());
+        }
+    }
+
+    #[test]
+    fn tolerance_for_resolution() {
+        assert_eq!(
+            Simplify::douglas_peucker(0.5)
+                .for_resolution(10.0)
+                .tolerance,
+            5.0
+        );
+        assert_eq!(
+            Simplify::visvalingam_whyatt(0.5)
+                .for_resolution(10.0)
+                .tolerance,
+            50.0
+        );
+    }
+}
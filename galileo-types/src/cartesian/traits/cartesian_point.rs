@@ -1,5 +1,5 @@
 use crate::cartesian::rect::Rect;
-use crate::geo::traits::projection::Projection;
+use crate::geo::traits::projection::{Projection, ProjectionError};
 use crate::geometry::{CartesianGeometry2dSpecialization, Geom, GeometrySpecialization};
 use crate::geometry_type::{CartesianSpace2d, GeometryType, PointGeometryType};
 use crate::point::{CartesianPointType, Point, PointHelper};
@@ -86,11 +86,11 @@ where
 {
     type Point = P;
 
-    fn project_spec<Proj>(&self, projection: &Proj) -> Option<Geom<Proj::OutPoint>>
+    fn project_spec<Proj>(&self, projection: &Proj) -> Result<Geom<Proj::OutPoint>, ProjectionError>
     where
         Proj: Projection<InPoint = Self::Point> + ?Sized,
     {
-        Some(Geom::Point(projection.project(self)?))
+        Ok(Geom::Point(projection.project(self)?))
     }
 }
 
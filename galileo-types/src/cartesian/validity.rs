@@ -0,0 +1,610 @@
+//! Validity checks and repair of polygons.
+//!
+//! A polygon is valid if:
+//! * every contour has at least three distinct points and no repeated consecutive points;
+//! * the outer contour is oriented counterclockwise and the holes clockwise;
+//! * the contours do not intersect themselves and each other, except touching at common vertices;
+//! * the holes lie inside the outer contour.
+//!
+//! Polygons coming from external data often break some of these rules, which makes the algorithms relying on them
+//! (e.g. tessellation or [boolean operations](crate::cartesian::boolean)) produce wrong results. [`validate_polygon`]
+//! lists the problems of a polygon, and [`make_valid_polygon`] builds a valid multipolygon covering the same area.
+
+use crate::cartesian::boolean::{
+    even_odd_area, overlay, to_multi_polygon, union_all, BooleanOp, Ring,
+};
+use crate::cartesian::impls::contour::Contour;
+use crate::cartesian::impls::multipolygon::MultiPolygon;
+use crate::cartesian::traits::cartesian_point::{CartesianPoint2d, NewCartesianPoint2d};
+use crate::cartesian::traits::contour::{CartesianClosedContour, Winding};
+use crate::contour::ClosedContour;
+use crate::geometry::Geom;
+use crate::multi_contour::MultiContour as _;
+use crate::multi_point::MultiPoint as _;
+use crate::polygon::Polygon;
+use crate::segment::Segment;
+use num_traits::Zero;
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use thiserror::Error;
+
+/// Position of a contour in a polygon or a multipolygon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ContourIndex {
+    /// Index of the polygon in the multipolygon. Always 0 for a single polygon.
+    pub polygon: usize,
+    /// Index of the contour in the polygon: 0 for the outer contour, and `i + 1` for the `i`-th hole.
+    pub contour: usize,
+}
+
+impl Display for ContourIndex {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "contour {} of polygon {}", self.contour, self.polygon)
+    }
+}
+
+/// Position of a segment in a polygon or a multipolygon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SegmentIndex {
+    pub contour: ContourIndex,
+    /// Index of the first point of the segment in the contour.
+    pub segment: usize,
+}
+
+impl Display for SegmentIndex {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "segment {} of {}", self.segment, self.contour)
+    }
+}
+
+/// Problem found by the validity check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum ValidityIssue {
+    /// The contour has less than three distinct points. Other checks are not done for such contours.
+    #[error("{contour} has less than three distinct points")]
+    TooFewPoints { contour: ContourIndex },
+    /// The point is the same as the previous point of the contour.
+    #[error("point {point} of {contour} repeats the previous point")]
+    DuplicatePoint { contour: ContourIndex, point: usize },
+    /// All points of the contour lie on one line, so it has no orientation.
+    #[error("{contour} has zero area")]
+    ZeroArea { contour: ContourIndex },
+    /// The contour is oriented in the wrong direction: outer contours must go counterclockwise and holes clockwise.
+    #[error("{contour} is oriented {winding:?}")]
+    WrongWinding {
+        contour: ContourIndex,
+        winding: Winding,
+    },
+    /// Two segments intersect or overlap each other. The segments may belong to one contour or to different contours.
+    #[error("{first} intersects {second}")]
+    SelfIntersection {
+        first: SegmentIndex,
+        second: SegmentIndex,
+    },
+    /// The hole does not lie inside the outer contour of its polygon.
+    #[error("{contour} is outside of the outer contour")]
+    HoleOutsideShell { contour: ContourIndex },
+}
+
+/// Result of the validity check.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValidityReport {
+    /// Found problems, grouped by the kind of the check.
+    pub issues: Vec<ValidityIssue>,
+}
+
+impl ValidityReport {
+    /// Returns true if no problems were found.
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+/// Checks the validity of the geometry. Points and contours are always considered valid.
+pub fn validate<P: CartesianPoint2d>(geom: &Geom<P>) -> ValidityReport {
+    match geom {
+        Geom::Polygon(polygon) => validate_polygon(polygon),
+        Geom::MultiPolygon(polygons) => validate_multi_polygon(polygons),
+        _ => ValidityReport::default(),
+    }
+}
+
+/// Checks the validity of the polygon, see the [module docs](self).
+pub fn validate_polygon<Poly, C, P>(polygon: &Poly) -> ValidityReport
+where
+    Poly: Polygon<Contour = C>,
+    C: ClosedContour<Point = P>,
+    P: CartesianPoint2d,
+{
+    let mut checker = Checker::default();
+    checker.add_polygon(0, polygon);
+    checker.report()
+}
+
+/// Checks the validity of every polygon of the multipolygon. Segments of different polygons must not intersect each
+/// other too.
+pub fn validate_multi_polygon<M, Poly, C, P>(polygons: &M) -> ValidityReport
+where
+    M: crate::multi_polygon::MultiPolygon<Polygon = Poly>,
+    Poly: Polygon<Contour = C>,
+    C: ClosedContour<Point = P>,
+    P: CartesianPoint2d,
+{
+    let mut checker = Checker::default();
+    for (index, polygon) in polygons.polygons().enumerate() {
+        checker.add_polygon(index, polygon);
+    }
+
+    checker.report()
+}
+
+/// Repairs polygons of the geometry with [`make_valid_polygon`]. Other geometries are returned without changes.
+/// Returns `None` if nothing is left of the polygons.
+///
+/// A polygon that has to be split into several parts is returned as a multipolygon.
+pub fn make_valid<P: NewCartesianPoint2d + Clone>(geom: &Geom<P>) -> Option<Geom<P>> {
+    let polygons = match geom {
+        Geom::Point(p) => return Some(Geom::Point(p.clone())),
+        Geom::MultiPoint(points) => {
+            return Some(Geom::MultiPoint(
+                points.iter_points().cloned().collect::<Vec<_>>().into(),
+            ))
+        }
+        Geom::Contour(contour) => return Some(Geom::Contour(clone_contour(contour))),
+        Geom::MultiContour(contours) => {
+            return Some(Geom::MultiContour(
+                contours
+                    .contours()
+                    .map(clone_contour)
+                    .collect::<Vec<_>>()
+                    .into(),
+            ))
+        }
+        Geom::Polygon(polygon) => make_valid_polygon(polygon),
+        Geom::MultiPolygon(polygons) => make_valid_multi_polygon(polygons),
+    };
+
+    match polygons.parts.len() {
+        0 => None,
+        1 => polygons.parts.into_iter().next().map(Geom::Polygon),
+        _ => Some(Geom::MultiPolygon(polygons)),
+    }
+}
+
+/// Builds a valid multipolygon from the polygon.
+///
+/// Every contour is first turned into the area it encloses with the even-odd rule, so the loops of a self-intersecting
+/// contour become separate parts. The result is the area of the outer contour without the areas of the holes.
+/// Orientation of the contours, repeated points and degenerate contours of the polygon do not affect the result.
+pub fn make_valid_polygon<Poly, C, P, Out>(polygon: &Poly) -> MultiPolygon<Out>
+where
+    Poly: Polygon<Contour = C>,
+    C: ClosedContour<Point = P>,
+    P: CartesianPoint2d<Num = f64>,
+    Out: NewCartesianPoint2d,
+{
+    to_multi_polygon(valid_rings(polygon))
+}
+
+/// Builds a valid multipolygon from the polygons of the multipolygon with [`make_valid_polygon`]. Overlapping
+/// polygons are merged.
+pub fn make_valid_multi_polygon<M, Poly, C, P, Out>(polygons: &M) -> MultiPolygon<Out>
+where
+    M: crate::multi_polygon::MultiPolygon<Polygon = Poly>,
+    Poly: Polygon<Contour = C>,
+    C: ClosedContour<Point = P>,
+    P: CartesianPoint2d<Num = f64>,
+    Out: NewCartesianPoint2d,
+{
+    to_multi_polygon(union_all(polygons.polygons().map(valid_rings).collect()))
+}
+
+fn valid_rings<Poly, C, P>(polygon: &Poly) -> Vec<Ring>
+where
+    Poly: Polygon<Contour = C>,
+    C: ClosedContour<Point = P>,
+    P: CartesianPoint2d<Num = f64>,
+{
+    let area = |contour: &C| {
+        let ring: Ring = contour.iter_points().map(|p| [p.x(), p.y()]).collect();
+        even_odd_area(&[ring])
+    };
+
+    let shell = area(polygon.outer_contour());
+    let holes = union_all(polygon.inner_contours().map(area).collect());
+    overlay(&shell, &holes, BooleanOp::Difference)
+}
+
+fn clone_contour<P: Clone>(contour: &Contour<P>) -> Contour<P> {
+    Contour::new(contour.points.clone(), contour.is_closed)
+}
+
+/// Contour prepared for the checks: without repeated points, with the indices of the points in the original contour.
+struct CheckedRing<'a, P> {
+    contour: ContourIndex,
+    points: Vec<(usize, &'a P)>,
+}
+
+struct Checker<'a, P> {
+    issues: Vec<ValidityIssue>,
+    rings: Vec<CheckedRing<'a, P>>,
+}
+
+impl<P> Default for Checker<'_, P> {
+    fn default() -> Self {
+        Self {
+            issues: vec![],
+            rings: vec![],
+        }
+    }
+}
+
+impl<'a, P: CartesianPoint2d> Checker<'a, P> {
+    fn add_polygon<Poly, C>(&mut self, polygon_index: usize, polygon: &'a Poly)
+    where
+        Poly: Polygon<Contour = C>,
+        C: ClosedContour<Point = P> + 'a,
+    {
+        let first_ring = self.rings.len();
+        for (contour_index, contour) in polygon.iter_contours().enumerate() {
+            let index = ContourIndex {
+                polygon: polygon_index,
+                contour: contour_index,
+            };
+
+            let mut points: Vec<(usize, &P)> = vec![];
+            for (i, point) in contour.iter_points().enumerate() {
+                match points.last() {
+                    Some((_, last)) if last.equal(point) => {
+                        self.issues.push(ValidityIssue::DuplicatePoint {
+                            contour: index,
+                            point: i,
+                        })
+                    }
+                    _ => points.push((i, point)),
+                }
+            }
+
+            // The closing point repeating the first one is allowed.
+            if points.len() > 1 && points[0].1.equal(points[points.len() - 1].1) {
+                points.pop();
+            }
+
+            if points.len() < 3 {
+                self.issues
+                    .push(ValidityIssue::TooFewPoints { contour: index });
+                continue;
+            }
+
+            if contour.area_signed() == P::Num::zero() {
+                self.issues.push(ValidityIssue::ZeroArea { contour: index });
+            } else {
+                let expected = if contour_index == 0 {
+                    Winding::CounterClockwise
+                } else {
+                    Winding::Clockwise
+                };
+                let winding = contour.winding();
+                if winding != expected {
+                    self.issues.push(ValidityIssue::WrongWinding {
+                        contour: index,
+                        winding,
+                    });
+                }
+            }
+
+            self.rings.push(CheckedRing {
+                contour: index,
+                points,
+            });
+        }
+
+        let rings = &self.rings[first_ring..];
+        let Some(shell) = rings.first().filter(|ring| ring.contour.contour == 0) else {
+            return;
+        };
+        for hole in &rings[1..] {
+            let outside = hole
+                .points
+                .iter()
+                .find_map(|(_, point)| locate(*point, &shell.points))
+                .is_some_and(|inside| !inside);
+            if outside {
+                self.issues.push(ValidityIssue::HoleOutsideShell {
+                    contour: hole.contour,
+                });
+            }
+        }
+    }
+
+    fn report(mut self) -> ValidityReport {
+        self.add_intersections();
+        ValidityReport {
+            issues: self.issues,
+        }
+    }
+
+    /// Checks all pairs of segments with overlapping x ranges for intersections.
+    fn add_intersections(&mut self) {
+        struct Part<'a, P: CartesianPoint2d> {
+            index: SegmentIndex,
+            from: &'a P,
+            to: &'a P,
+            min_x: P::Num,
+            max_x: P::Num,
+        }
+
+        let mut parts = vec![];
+        for ring in &self.rings {
+            for (position, &(point_index, from)) in ring.points.iter().enumerate() {
+                let to = ring.points[(position + 1) % ring.points.len()].1;
+                let (min_x, max_x) = if from.x() <= to.x() {
+                    (from.x(), to.x())
+                } else {
+                    (to.x(), from.x())
+                };
+                parts.push(Part {
+                    index: SegmentIndex {
+                        contour: ring.contour,
+                        segment: point_index,
+                    },
+                    from,
+                    to,
+                    min_x,
+                    max_x,
+                });
+            }
+        }
+        parts.sort_by(|a, b| a.min_x.partial_cmp(&b.min_x).unwrap_or(Ordering::Equal));
+
+        let mut intersections = vec![];
+        for (k, part) in parts.iter().enumerate() {
+            for other in &parts[k + 1..] {
+                if other.min_x > part.max_x {
+                    break;
+                }
+
+                if Segment(part.from, part.to).intersects(&Segment(other.from, other.to))
+                    && !touch_at_vertex((part.from, part.to), (other.from, other.to))
+                {
+                    intersections.push((part.index.min(other.index), part.index.max(other.index)));
+                }
+            }
+        }
+
+        intersections.sort();
+        self.issues.extend(
+            intersections
+                .into_iter()
+                .map(|(first, second)| ValidityIssue::SelfIntersection { first, second }),
+        );
+    }
+}
+
+/// Checks if the segments have a common end and no other common points. This is how the neighbouring segments of a
+/// contour are connected, and how the contours may touch each other.
+fn touch_at_vertex<P: CartesianPoint2d>(a: (&P, &P), b: (&P, &P)) -> bool {
+    let (shared, a_end, b_end) = if a.0.equal(b.0) {
+        (a.0, a.1, b.1)
+    } else if a.0.equal(b.1) {
+        (a.0, a.1, b.0)
+    } else if a.1.equal(b.0) {
+        (a.1, a.0, b.1)
+    } else if a.1.equal(b.1) {
+        (a.1, a.0, b.0)
+    } else {
+        return false;
+    };
+
+    // Collinear segments going from the common end in the same direction overlap.
+    let da = a_end.sub(shared);
+    let db = b_end.sub(shared);
+    da.x * db.y - da.y * db.x != P::Num::zero() || da.x * db.x + da.y * db.y <= P::Num::zero()
+}
+
+/// Even-odd test of the point against the ring. Returns `None` if the point lies on the ring.
+fn locate<P: CartesianPoint2d>(point: &P, ring: &[(usize, &P)]) -> Option<bool> {
+    let mut inside = false;
+    for (i, (_, a)) in ring.iter().enumerate() {
+        let b = ring[(i + 1) % ring.len()].1;
+        let cross = (b.x() - a.x()) * (point.y() - a.y()) - (point.x() - a.x()) * (b.y() - a.y());
+        let within_x = (point.x() >= a.x() || point.x() >= b.x())
+            && (point.x() <= a.x() || point.x() <= b.x());
+        let within_y = (point.y() >= a.y() || point.y() >= b.y())
+            && (point.y() <= a.y() || point.y() <= b.y());
+        if cross == P::Num::zero() && within_x && within_y {
+            return None;
+        }
+
+        // The ray going from the point to the right crosses the segment if the segment goes up and the point is on the
+        // left of it, or the segment goes down and the point is on the right.
+        if (a.y() > point.y()) != (b.y() > point.y()) && (b.y() > a.y()) == (cross > P::Num::zero())
+        {
+            inside = !inside;
+        }
+    }
+
+    Some(inside)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cartesian::impls::contour::ClosedContour;
+    use crate::cartesian::impls::point::Point2d;
+    use crate::cartesian::impls::polygon::Polygon;
+
+    fn ring(points: &[(f64, f64)]) -> ClosedContour<Point2d> {
+        ClosedContour::new(points.iter().map(|&(x, y)| Point2d::new(x, y)).collect())
+    }
+
+    fn area(polygons: &MultiPolygon<Point2d>) -> f64 {
+        polygons
+            .parts()
+            .iter()
+            .map(|p| {
+                p.outer_contour.area_signed()
+                    + p.inner_contours
+                        .iter()
+                        .map(|c| c.area_signed())
+                        .sum::<f64>()
+            })
+            .sum()
+    }
+
+    fn square() -> ClosedContour<Point2d> {
+        ring(&[(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)])
+    }
+
+    #[test]
+    fn valid_polygon() {
+        let polygon = Polygon::new(
+            square(),
+            vec![ring(&[(2.0, 2.0), (2.0, 8.0), (8.0, 8.0), (8.0, 2.0)])],
+        );
+        assert!(validate_polygon(&polygon).is_valid());
+
+        let fixed: MultiPolygon<Point2d> = make_valid_polygon(&polygon);
+        assert_eq!(fixed.parts().len(), 1);
+        assert!((area(&fixed) - 64.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn duplicate_points_and_closing_point() {
+        let polygon = Polygon::new(
+            ring(&[
+                (0.0, 0.0),
+                (10.0, 0.0),
+                (10.0, 0.0),
+                (10.0, 10.0),
+                (0.0, 10.0),
+                (0.0, 0.0),
+            ]),
+            vec![],
+        );
+        let contour = ContourIndex {
+            polygon: 0,
+            contour: 0,
+        };
+        assert_eq!(
+            validate_polygon(&polygon).issues,
+            vec![ValidityIssue::DuplicatePoint { contour, point: 2 }]
+        );
+    }
+
+    #[test]
+    fn wrong_winding() {
+        let polygon = Polygon::new(
+            ring(&[(0.0, 0.0), (0.0, 10.0), (10.0, 10.0), (10.0, 0.0)]),
+            vec![ring(&[(2.0, 2.0), (8.0, 2.0), (8.0, 8.0), (2.0, 8.0)])],
+        );
+        let issues = validate_polygon(&polygon).issues;
+        assert_eq!(issues.len(), 2);
+        assert!(matches!(
+            issues[0],
+            ValidityIssue::WrongWinding {
+                winding: Winding::Clockwise,
+                ..
+            }
+        ));
+        assert!(matches!(
+            issues[1],
+            ValidityIssue::WrongWinding {
+                winding: Winding::CounterClockwise,
+                ..
+            }
+        ));
+
+        let fixed: MultiPolygon<Point2d> = make_valid_polygon(&polygon);
+        assert!(validate_multi_polygon(&fixed).is_valid());
+        assert!((area(&fixed) - 64.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn bowtie() {
+        let polygon = Polygon::new(
+            ring(&[(0.0, 0.0), (10.0, 10.0), (10.0, 0.0), (0.0, 10.0)]),
+            vec![],
+        );
+        let issues = validate_polygon(&polygon).issues;
+        assert!(issues.contains(&ValidityIssue::SelfIntersection {
+            first: SegmentIndex {
+                contour: ContourIndex {
+                    polygon: 0,
+                    contour: 0
+                },
+                segment: 0,
+            },
+            second: SegmentIndex {
+                contour: ContourIndex {
+                    polygon: 0,
+                    contour: 0
+                },
+                segment: 2,
+            },
+        }));
+
+        let fixed: MultiPolygon<Point2d> = make_valid_polygon(&polygon);
+        assert_eq!(fixed.parts().len(), 2);
+        assert!(validate_multi_polygon(&fixed).is_valid());
+        assert!((area(&fixed) - 50.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn hole_outside_shell() {
+        let polygon = Polygon::new(
+            square(),
+            vec![ring(&[(20.0, 2.0), (20.0, 8.0), (28.0, 8.0), (28.0, 2.0)])],
+        );
+        assert_eq!(
+            validate_polygon(&polygon).issues,
+            vec![ValidityIssue::HoleOutsideShell {
+                contour: ContourIndex {
+                    polygon: 0,
+                    contour: 1
+                }
+            }]
+        );
+
+        let fixed: MultiPolygon<Point2d> = make_valid_polygon(&polygon);
+        assert_eq!(fixed.parts().len(), 1);
+        assert!(fixed.parts()[0].inner_contours.is_empty());
+        assert!((area(&fixed) - 100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn hole_crossing_shell() {
+        let polygon = Polygon::new(
+            square(),
+            vec![ring(&[(5.0, 2.0), (5.0, 8.0), (15.0, 8.0), (15.0, 2.0)])],
+        );
+        let issues = validate_polygon(&polygon).issues;
+        assert_eq!(
+            issues
+                .iter()
+                .filter(|issue| matches!(issue, ValidityIssue::SelfIntersection { .. }))
+                .count(),
+            2
+        );
+
+        let fixed: MultiPolygon<Point2d> = make_valid_polygon(&polygon);
+        assert!(validate_multi_polygon(&fixed).is_valid());
+        assert!((area(&fixed) - 70.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn geometry() {
+        let degenerate = Geom::Polygon(Polygon::new(
+            ring(&[(0.0, 0.0), (10.0, 0.0), (0.0, 0.0)]),
+            vec![],
+        ));
+        assert_eq!(validate(&degenerate).issues.len(), 1);
+        assert!(make_valid(&degenerate).is_none());
+
+        let bowtie = Geom::Polygon(Polygon::new(
+            ring(&[(0.0, 0.0), (10.0, 10.0), (10.0, 0.0), (0.0, 10.0)]),
+            vec![],
+        ));
+        assert!(matches!(make_valid(&bowtie), Some(Geom::MultiPolygon(_))));
+    }
+}
@@ -1,6 +1,6 @@
 use crate::cartesian::rect::Rect;
 use crate::cartesian::traits::cartesian_point::CartesianPoint2d;
-use crate::geo::traits::projection::Projection;
+use crate::geo::traits::projection::{Projection, ProjectionError};
 use crate::geometry::{CartesianGeometry2dSpecialization, Geom, Geometry, GeometrySpecialization};
 use crate::geometry_type::{CartesianSpace2d, ContourGeometryType, GeometryType};
 use crate::segment::Segment;
@@ -29,14 +29,14 @@ pub trait Contour {
     fn project_points<Proj>(
         &self,
         projection: &Proj,
-    ) -> Option<crate::cartesian::impls::contour::Contour<Proj::OutPoint>>
+    ) -> Result<crate::cartesian::impls::contour::Contour<Proj::OutPoint>, ProjectionError>
     where
         Proj: Projection<InPoint = Self::Point>,
     {
-        Some(crate::cartesian::impls::contour::Contour::new(
+        Ok(crate::cartesian::impls::contour::Contour::new(
             self.iter_points()
                 .map(|p| projection.project(p))
-                .collect::<Option<Vec<Proj::OutPoint>>>()?,
+                .collect::<Result<Vec<Proj::OutPoint>, _>>()?,
             self.is_closed(),
         ))
     }
@@ -144,15 +144,15 @@ where
 {
     type Point = C::Point;
 
-    fn project_spec<Proj>(&self, projection: &Proj) -> Option<Geom<Proj::OutPoint>>
+    fn project_spec<Proj>(&self, projection: &Proj) -> Result<Geom<Proj::OutPoint>, ProjectionError>
     where
         Proj: Projection<InPoint = Self::Point> + ?Sized,
     {
         let points = self
             .iter_points()
             .map(|p| projection.project(p))
-            .collect::<Option<Vec<Proj::OutPoint>>>()?;
-        Some(Geom::Contour(crate::cartesian::impls::contour::Contour {
+            .collect::<Result<Vec<Proj::OutPoint>, _>>()?;
+        Ok(Geom::Contour(crate::cartesian::impls::contour::Contour {
             points,
             is_closed: true,
         }))
@@ -0,0 +1,525 @@
+//! Preparation of geographic geometries for projection onto a flat map.
+//!
+//! Most projections have an edge on the meridian opposite to their central meridian (the antimeridian). A line
+//! segment crossing this meridian is projected into a line spanning the whole map, and a polygon around a pole does
+//! not have an edge the projected ring can be closed along. [`AntimeridianCut`] splits such geometries into parts that
+//! lie on one side of the antimeridian and clamps them to the latitude range of the projection.
+
+use crate::cartesian::impls::contour::{ClosedContour, Contour};
+use crate::cartesian::impls::polygon::Polygon;
+use crate::geo::crs::{Crs, ProjectionType};
+use crate::geo::impls::point::GeoPoint2d;
+use crate::geo::impls::projection::identity::IdentityProjection;
+use crate::geo::traits::point::{GeoPoint, NewGeoPoint};
+use crate::geo::traits::projection::{Projection, ProjectionError};
+use crate::geometry::{Geom, Geometry};
+use crate::geometry_type::GeoSpace2d;
+use crate::multi_contour::MultiContour as _;
+use crate::multi_point::MultiPoint as _;
+use crate::multi_polygon::MultiPolygon as _;
+use std::marker::PhantomData;
+
+/// Latitude in degrees at which the Web Mercator map becomes square.
+pub const MERCATOR_MAX_LATITUDE: f64 = 85.05112877980659;
+
+/// Points on the cut line are moved inside by this margin in degrees, so that the projections that normalize the
+/// longitude do not move them to the opposite edge of the map.
+const CUT_MARGIN: f64 = 1e-9;
+
+/// Splits geographic geometries at the antimeridian of a projection and clamps them to its latitude limits.
+///
+/// Lines are split at the points where they cross the antimeridian. Polygons are clipped into parts on each side of
+/// the antimeridian. A polygon ring that goes around a pole is closed along the antimeridian and the pole, the pole
+/// being chosen on the side of the average latitude of the ring.
+///
+/// If the maximum latitude is set, polygons are clipped by it, and the latitudes of points and line vertices are
+/// clamped to it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AntimeridianCut {
+    central_meridian: f64,
+    max_latitude: Option<f64>,
+}
+
+impl AntimeridianCut {
+    /// Cut for the Web Mercator projection.
+    pub const WEB_MERCATOR: Self = Self {
+        central_meridian: 0.0,
+        max_latitude: Some(MERCATOR_MAX_LATITUDE),
+    };
+
+    /// Creates a cut at the meridian opposite to the given central meridian without latitude limits.
+    pub fn new(central_meridian: f64) -> Self {
+        Self {
+            central_meridian,
+            max_latitude: None,
+        }
+    }
+
+    /// Clamps the geometries to the `[-max_latitude, max_latitude]` range.
+    pub fn with_max_latitude(mut self, max_latitude: f64) -> Self {
+        self.max_latitude = Some(max_latitude);
+        self
+    }
+
+    /// Returns the cut suitable for the projection of the CRS, or `None` if the projection does not need cutting
+    /// (e.g. azimuthal projections) or is not known.
+    pub fn for_crs(crs: &Crs) -> Option<Self> {
+        match crs.projection_type() {
+            ProjectionType::WebMercator => Some(Self::WEB_MERCATOR),
+            ProjectionType::TransverseMercator(params) => Some(Self::new(params.central_meridian)),
+            ProjectionType::LambertConformalConic(params) => {
+                Some(Self::new(params.central_meridian))
+            }
+            ProjectionType::AlbersEqualArea(params) => Some(Self::new(params.central_meridian)),
+            ProjectionType::Equirectangular(params) => Some(Self::new(params.central_meridian)),
+            _ => None,
+        }
+    }
+
+    /// Cuts the geometry and projects the result.
+    pub fn project<G, Proj>(
+        &self,
+        geometry: &G,
+        projection: &Proj,
+    ) -> Result<Geom<Proj::OutPoint>, ProjectionError>
+    where
+        G: Geometry + ?Sized,
+        G::Point: NewGeoPoint,
+        Proj: Projection<InPoint = G::Point> + ?Sized,
+    {
+        let geom = geometry.project(&IdentityProjection::<_, GeoPoint2d, GeoSpace2d>::new())?;
+        self.cut(&geom).project(&FromGeoPoint2d {
+            projection,
+            phantom: PhantomData,
+        })
+    }
+
+    /// Cuts the geometry. Lines and polygons that are split become multi-lines and multi-polygons.
+    pub fn cut<P: NewGeoPoint>(&self, geom: &Geom<P>) -> Geom<P> {
+        match geom {
+            Geom::Point(p) => Geom::Point(self.clamp_point(p)),
+            Geom::MultiPoint(points) => Geom::MultiPoint(
+                points
+                    .iter_points()
+                    .map(|p| self.clamp_point(p))
+                    .collect::<Vec<_>>()
+                    .into(),
+            ),
+            Geom::Contour(contour) => {
+                let mut parts = self.cut_contour(contour);
+                if parts.len() == 1 {
+                    Geom::Contour(parts.remove(0))
+                } else {
+                    Geom::MultiContour(parts.into())
+                }
+            }
+            Geom::MultiContour(contours) => Geom::MultiContour(
+                contours
+                    .contours()
+                    .flat_map(|c| self.cut_contour(c))
+                    .collect::<Vec<_>>()
+                    .into(),
+            ),
+            Geom::Polygon(polygon) => {
+                let mut parts = self.cut_polygon(polygon);
+                if parts.len() == 1 {
+                    Geom::Polygon(parts.remove(0))
+                } else {
+                    Geom::MultiPolygon(parts.into())
+                }
+            }
+            Geom::MultiPolygon(polygons) => Geom::MultiPolygon(
+                polygons
+                    .polygons()
+                    .flat_map(|p| self.cut_polygon(p))
+                    .collect::<Vec<_>>()
+                    .into(),
+            ),
+        }
+    }
+
+    /// Splits the contour at the antimeridian. A closed contour that crosses the antimeridian is split into open
+    /// contours.
+    pub fn cut_contour<C, P>(&self, contour: &C) -> Vec<Contour<P>>
+    where
+        C: crate::contour::Contour<Point = P>,
+        P: NewGeoPoint,
+    {
+        let mut points: Vec<[f64; 2]> = contour
+            .iter_points()
+            .map(|p| [self.relative_longitude(p.lon()), p.lat()])
+            .collect();
+        let is_closed = contour.is_closed() && points.len() > 1;
+        let closing_added = is_closed && points.first() != points.last();
+        if closing_added {
+            points.push(points[0]);
+        }
+
+        let mut parts = vec![];
+        let mut current: Vec<[f64; 2]> = vec![];
+        for (i, point) in points.iter().enumerate() {
+            if i > 0 {
+                let prev = points[i - 1];
+                let d_lon = point[0] - prev[0];
+                if d_lon.abs() > 180.0 {
+                    let edge = if d_lon < 0.0 { 180.0 } else { -180.0 };
+                    let unwrapped = point[0] + 2.0 * edge;
+                    let t = (edge - prev[0]) / (unwrapped - prev[0]);
+                    let lat = prev[1] + t * (point[1] - prev[1]);
+                    current.push([edge, lat]);
+                    parts.push(std::mem::take(&mut current));
+                    current.push([-edge, lat]);
+                }
+            }
+            current.push(*point);
+        }
+        parts.push(current);
+
+        if parts.len() == 1 {
+            return vec![Contour::new(
+                self.restore_points(&parts[0][..points.len() - usize::from(closing_added)], 0.0),
+                is_closed,
+            )];
+        }
+
+        if is_closed {
+            // The first and the last parts are connected through the first point of the contour.
+            let first = parts.remove(0);
+            parts.last_mut().unwrap().extend_from_slice(&first[1..]);
+        }
+
+        parts
+            .iter()
+            .filter(|part| part.len() > 1)
+            .map(|part| Contour::open(self.restore_points(part, 0.0)))
+            .collect()
+    }
+
+    /// Clips the polygon into parts on each side of the antimeridian and inside the latitude limits.
+    pub fn cut_polygon<Poly, P>(&self, polygon: &Poly) -> Vec<Polygon<P>>
+    where
+        Poly: crate::polygon::Polygon,
+        Poly::Contour: crate::contour::Contour<Point = P>,
+        P: NewGeoPoint,
+    {
+        let outer = self.unwrap_ring(polygon.outer_contour());
+        if outer.len() < 3 {
+            return vec![];
+        }
+
+        let min = outer.iter().map(|p| p[0]).fold(f64::INFINITY, f64::min);
+        let max = outer.iter().map(|p| p[0]).fold(f64::NEG_INFINITY, f64::max);
+        let inner: Vec<_> = polygon
+            .inner_contours()
+            .map(|c| {
+                let mut ring = self.unwrap_ring(c);
+                if let Some(first) = ring.first() {
+                    // Move the ring to the same copy of the world as the outer contour.
+                    let shift = 360.0 * ((min - first[0]) / 360.0).ceil();
+                    ring.iter_mut().for_each(|p| p[0] += shift);
+                }
+                ring
+            })
+            .collect();
+
+        let first_strip = ((min + 180.0) / 360.0).floor() as i32;
+        let last_strip = (((max - 180.0) / 360.0).ceil() as i32).max(first_strip);
+
+        let mut parts = vec![];
+        for strip in first_strip..=last_strip {
+            let shift = 360.0 * strip as f64;
+            let outer_part = self.clip_ring(&outer, shift);
+            if outer_part.len() < 3 {
+                continue;
+            }
+
+            let inner_parts = inner
+                .iter()
+                .map(|ring| self.clip_ring(ring, shift))
+                .filter(|ring| ring.len() >= 3)
+                .map(|ring| ClosedContour::new(self.restore_points(&ring, shift)))
+                .collect();
+            parts.push(Polygon::new(
+                ClosedContour::new(self.restore_points(&outer_part, shift)),
+                inner_parts,
+            ));
+        }
+
+        parts
+    }
+
+    fn clamp_point<P: NewGeoPoint>(&self, point: &P) -> P {
+        P::latlon(self.clamp_latitude(point.lat()), point.lon())
+    }
+
+    fn clamp_latitude(&self, lat: f64) -> f64 {
+        match self.max_latitude {
+            Some(max) => lat.clamp(-max, max),
+            None => lat,
+        }
+    }
+
+    /// Longitude relative to the central meridian in the `[-180, 180)` range.
+    fn relative_longitude(&self, lon: f64) -> f64 {
+        wrap_longitude(lon - self.central_meridian)
+    }
+
+    /// Converts the relative coordinates shifted by `shift` degrees back into the points.
+    fn restore_points<P: NewGeoPoint>(&self, points: &[[f64; 2]], shift: f64) -> Vec<P> {
+        points
+            .iter()
+            .map(|[lon, lat]| {
+                P::latlon(
+                    self.clamp_latitude(*lat),
+                    self.central_meridian
+                        + (lon - shift).clamp(-180.0 + CUT_MARGIN, 180.0 - CUT_MARGIN),
+                )
+            })
+            .collect()
+    }
+
+    /// Returns the ring with relative longitudes made continuous, so that the segments crossing the antimeridian go
+    /// beyond the `[-180, 180]` range. The ring around a pole is closed through the pole.
+    fn unwrap_ring<C>(&self, contour: &C) -> Vec<[f64; 2]>
+    where
+        C: crate::contour::Contour,
+        C::Point: NewGeoPoint,
+    {
+        let mut ring: Vec<[f64; 2]> = vec![];
+        for point in contour.iter_points() {
+            let lon = self.relative_longitude(point.lon());
+            let lon = match ring.last() {
+                Some(last) => last[0] + wrap_longitude(lon - last[0]),
+                None => lon,
+            };
+            ring.push([lon, point.lat()]);
+        }
+
+        // The closing point repeating the first one is not needed.
+        if ring.len() > 1
+            && wrap_longitude(ring[ring.len() - 1][0] - ring[0][0]) == 0.0
+            && ring[ring.len() - 1][1] == ring[0][1]
+        {
+            ring.pop();
+        }
+
+        let (Some(first), Some(last)) = (ring.first().copied(), ring.last().copied()) else {
+            return ring;
+        };
+        let winding = last[0] + wrap_longitude(first[0] - last[0]) - first[0];
+        if winding.abs() > 180.0 {
+            let mean_lat = ring.iter().map(|p| p[1]).sum::<f64>() / ring.len() as f64;
+            let pole = if mean_lat >= 0.0 { 90.0 } else { -90.0 };
+            ring.push([first[0] + winding, first[1]]);
+            ring.push([first[0] + winding, pole]);
+            ring.push([first[0], pole]);
+        }
+
+        ring
+    }
+
+    /// Clips the unwrapped ring by the copy of the map shifted by `shift` degrees.
+    fn clip_ring(&self, ring: &[[f64; 2]], shift: f64) -> Vec<[f64; 2]> {
+        let ring = clip_ring(ring, 0, shift - 180.0, -1.0);
+        let ring = clip_ring(&ring, 0, shift + 180.0, 1.0);
+        match self.max_latitude {
+            Some(max) => clip_ring(&clip_ring(&ring, 1, -max, -1.0), 1, max, 1.0),
+            None => ring,
+        }
+    }
+}
+
+/// Wraps the longitude into the `[-180, 180)` range.
+fn wrap_longitude(lon: f64) -> f64 {
+    (lon + 180.0).rem_euclid(360.0) - 180.0
+}
+
+/// Clips the ring by the half-plane `sign * (p[axis] - value) <= 0` (Sutherland-Hodgman algorithm).
+fn clip_ring(ring: &[[f64; 2]], axis: usize, value: f64, sign: f64) -> Vec<[f64; 2]> {
+    let inside = |p: &[f64; 2]| sign * (p[axis] - value) <= 0.0;
+    let intersection = |a: &[f64; 2], b: &[f64; 2]| {
+        let t = (value - a[axis]) / (b[axis] - a[axis]);
+        let mut p = [a[0] + t * (b[0] - a[0]), a[1] + t * (b[1] - a[1])];
+        p[axis] = value;
+        p
+    };
+
+    if ring.iter().all(inside) {
+        return ring.to_vec();
+    }
+
+    let mut result = Vec::with_capacity(ring.len());
+    for (i, current) in ring.iter().enumerate() {
+        let prev = &ring[(i + ring.len() - 1) % ring.len()];
+        match (inside(prev), inside(current)) {
+            (true, true) => result.push(*current),
+            (true, false) => result.push(intersection(prev, current)),
+            (false, true) => {
+                result.push(intersection(prev, current));
+                result.push(*current);
+            }
+            (false, false) => {}
+        }
+    }
+
+    result
+}
+
+/// Converts [`GeoPoint2d`] into the input points of the wrapped projection.
+struct FromGeoPoint2d<'a, P, Proj: ?Sized> {
+    projection: &'a Proj,
+    phantom: PhantomData<P>,
+}
+
+impl<P, Proj> Projection for FromGeoPoint2d<'_, P, Proj>
+where
+    P: NewGeoPoint,
+    Proj: Projection<InPoint = P> + ?Sized,
+{
+    type InPoint = GeoPoint2d;
+    type OutPoint = Proj::OutPoint;
+
+    fn project(&self, input: &Self::InPoint) -> Result<Self::OutPoint, ProjectionError> {
+        self.projection
+            .project(&P::latlon(input.lat(), input.lon()))
+    }
+
+    fn unproject(&self, input: &Self::OutPoint) -> Result<Self::InPoint, ProjectionError> {
+        let point = self.projection.unproject(input)?;
+        Ok(GeoPoint2d::latlon(point.lat(), point.lon()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn contour(points: &[(f64, f64)], is_closed: bool) -> Contour<GeoPoint2d> {
+        Contour::new(
+            points
+                .iter()
+                .map(|(lat, lon)| GeoPoint2d::latlon(*lat, *lon))
+                .collect(),
+            is_closed,
+        )
+    }
+
+    fn lon_range(contour: &ClosedContour<GeoPoint2d>) -> (f64, f64) {
+        contour
+            .points
+            .iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), p| {
+                (min.min(p.lon()), max.max(p.lon()))
+            })
+    }
+
+    #[test]
+    fn line_crossing_antimeridian() {
+        let line = contour(&[(0.0, 170.0), (10.0, -170.0), (20.0, -160.0)], false);
+        let parts = AntimeridianCut::WEB_MERCATOR.cut_contour(&line);
+
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts[0].points.len(), 2);
+        assert_eq!(parts[1].points.len(), 3);
+
+        let end = parts[0].points[1];
+        assert!((end.lon() - 180.0).abs() < 1e-6);
+        assert!((end.lat() - 5.0).abs() < 1e-9);
+        let start = parts[1].points[0];
+        assert!((start.lon() + 180.0).abs() < 1e-6);
+        assert!((start.lat() - 5.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn line_not_crossing_antimeridian() {
+        let line = contour(&[(0.0, -10.0), (10.0, 10.0), (20.0, 30.0)], true);
+        let parts = AntimeridianCut::WEB_MERCATOR.cut_contour(&line);
+
+        assert_eq!(parts.len(), 1);
+        assert!(parts[0].is_closed);
+        assert_eq!(parts[0].points, line.points);
+    }
+
+    #[test]
+    fn cut_with_central_meridian() {
+        let line = contour(&[(0.0, -100.0), (0.0, -80.0)], false);
+        assert_eq!(AntimeridianCut::new(0.0).cut_contour(&line).len(), 1);
+        assert_eq!(AntimeridianCut::new(90.0).cut_contour(&line).len(), 2);
+    }
+
+    #[test]
+    fn polygon_crossing_antimeridian() {
+        let polygon = Polygon::new(
+            contour(
+                &[
+                    (-10.0, 170.0),
+                    (-10.0, -170.0),
+                    (10.0, -170.0),
+                    (10.0, 170.0),
+                ],
+                true,
+            )
+            .into_closed()
+            .unwrap(),
+            vec![],
+        );
+
+        let parts = AntimeridianCut::WEB_MERCATOR.cut_polygon(&polygon);
+        assert_eq!(parts.len(), 2);
+
+        let mut ranges: Vec<_> = parts.iter().map(|p| lon_range(&p.outer_contour)).collect();
+        ranges.sort_by(|a, b| a.0.total_cmp(&b.0));
+        assert!((ranges[0].0 + 180.0).abs() < 1e-6);
+        assert!((ranges[0].1 + 170.0).abs() < 1e-9);
+        assert!((ranges[1].0 - 170.0).abs() < 1e-9);
+        assert!((ranges[1].1 - 180.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn polygon_around_pole() {
+        let polygon = Polygon::new(
+            contour(
+                &[(-70.0, -180.0), (-70.0, -90.0), (-70.0, 0.0), (-70.0, 90.0)],
+                true,
+            )
+            .into_closed()
+            .unwrap(),
+            vec![],
+        );
+
+        let parts = AntimeridianCut::WEB_MERCATOR.cut_polygon(&polygon);
+        assert_eq!(parts.len(), 1);
+
+        let points = &parts[0].outer_contour.points;
+        let min_lat = points.iter().map(|p| p.lat()).fold(f64::INFINITY, f64::min);
+        let max_lat = points
+            .iter()
+            .map(|p| p.lat())
+            .fold(f64::NEG_INFINITY, f64::max);
+        assert!((min_lat + MERCATOR_MAX_LATITUDE).abs() < 1e-9);
+        assert!((max_lat + 70.0).abs() < 1e-9);
+
+        let (min_lon, max_lon) = lon_range(&parts[0].outer_contour);
+        assert!((min_lon + 180.0).abs() < 1e-6);
+        assert!((max_lon - 180.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn projects_cut_geometry() {
+        let crs = Crs::EPSG3857;
+        let projection = crs
+            .projection::<GeoPoint2d, crate::cartesian::impls::point::Point2d>()
+            .unwrap();
+        let line = Geom::Contour(contour(&[(0.0, 170.0), (0.0, -170.0)], false));
+
+        let cut = AntimeridianCut::for_crs(&crs).unwrap();
+        let Geom::MultiContour(projected) = cut.project(&line, &*projection).unwrap() else {
+            panic!("line is not split");
+        };
+        let width = projected
+            .contours()
+            .map(|c| (c.points[1].x - c.points[0].x).abs())
+            .sum::<f64>();
+        assert!((width - 2.0 * 10f64.to_radians() * 6_378_137.0).abs() < 1e-3);
+    }
+}
@@ -0,0 +1,272 @@
+//! Embedded registry of commonly used EPSG coordinate reference systems.
+//!
+//! Every CRS is stored as a PROJ string.
+
+use std::borrow::Cow;
+
+const REGISTRY: &[(u32, &str)] = &[
+    // Geographic
+    (4326, "+proj=longlat +datum=WGS84"),
+    (4258, "+proj=longlat +ellps=GRS80"),
+    (4269, "+proj=longlat +datum=NAD83"),
+    (4267, "+proj=longlat +datum=NAD27"),
+    (4277, "+proj=longlat +datum=OSGB36"),
+    (4230, "+proj=longlat +ellps=intl +towgs84=-87,-98,-121"),
+    (
+        4284,
+        "+proj=longlat +ellps=krass +towgs84=23.92,-141.27,-80.9,0,0.35,0.82,-0.12",
+    ),
+    (4283, "+proj=longlat +ellps=GRS80"),
+    (4314, "+proj=longlat +datum=potsdam"),
+    (4167, "+proj=longlat +ellps=GRS80"),
+    // World
+    (3857, "+proj=webmerc +datum=WGS84"),
+    (900913, "+proj=webmerc +datum=WGS84"),
+    (3395, "+proj=merc +datum=WGS84"),
+    (4087, "+proj=eqc +datum=WGS84"),
+    // National grids
+    (
+        27700,
+        "+proj=tmerc +lat_0=49 +lon_0=-2 +k=0.9996012717 +x_0=400000 +y_0=-100000 +datum=OSGB36",
+    ),
+    (
+        2154,
+        "+proj=lcc +lat_0=46.5 +lon_0=3 +lat_1=49 +lat_2=44 +x_0=700000 +y_0=6600000 +ellps=GRS80",
+    ),
+    (
+        3034,
+        "+proj=lcc +lat_0=52 +lon_0=10 +lat_1=35 +lat_2=65 +x_0=4000000 +y_0=2800000 +ellps=GRS80",
+    ),
+    (
+        3035,
+        "+proj=laea +lat_0=52 +lon_0=10 +x_0=4321000 +y_0=3210000 +ellps=GRS80",
+    ),
+    (
+        3006,
+        "+proj=tmerc +lon_0=15 +k=0.9996 +x_0=500000 +ellps=GRS80",
+    ),
+    (
+        3067,
+        "+proj=tmerc +lon_0=27 +k=0.9996 +x_0=500000 +ellps=GRS80",
+    ),
+    (
+        2180,
+        "+proj=tmerc +lon_0=19 +k=0.9993 +x_0=500000 +y_0=-5300000 +ellps=GRS80",
+    ),
+    (
+        2193,
+        "+proj=tmerc +lon_0=173 +k=0.9996 +x_0=1600000 +y_0=10000000 +ellps=GRS80",
+    ),
+    (
+        3577,
+        "+proj=aea +lat_0=0 +lon_0=132 +lat_1=-18 +lat_2=-36 +x_0=0 +y_0=0 +ellps=GRS80",
+    ),
+    (
+        5070,
+        "+proj=aea +lat_0=23 +lon_0=-96 +lat_1=29.5 +lat_2=45.5 +x_0=0 +y_0=0 +datum=NAD83",
+    ),
+    // Polar stereographic
+    (
+        3413,
+        "+proj=stere +lat_0=90 +lat_ts=70 +lon_0=-45 +x_0=0 +y_0=0 +datum=WGS84",
+    ),
+    (
+        3995,
+        "+proj=stere +lat_0=90 +lat_ts=71 +lon_0=0 +x_0=0 +y_0=0 +datum=WGS84",
+    ),
+    (
+        3031,
+        "+proj=stere +lat_0=-90 +lat_ts=-71 +lon_0=0 +x_0=0 +y_0=0 +datum=WGS84",
+    ),
+    (
+        3976,
+        "+proj=stere +lat_0=-90 +lat_ts=-70 +lon_0=0 +x_0=0 +y_0=0 +datum=WGS84",
+    ),
+    (
+        32661,
+        "+proj=stere +lat_0=90 +lon_0=0 +k=0.994 +x_0=2000000 +y_0=2000000 +datum=WGS84",
+    ),
+    (
+        32761,
+        "+proj=stere +lat_0=-90 +lon_0=0 +k=0.994 +x_0=2000000 +y_0=2000000 +datum=WGS84",
+    ),
+];
+
+/// Ranges of codes of UTM zones: first code, first zone, last zone, hemisphere and datum parameter.
+const UTM_ZONES: &[(u32, u32, u32, &str, &str)] = &[
+    // WGS 84
+    (32601, 1, 60, "", "+datum=WGS84"),
+    (32701, 1, 60, " +south", "+datum=WGS84"),
+    // ETRS89
+    (25828, 28, 38, "", "+ellps=GRS80"),
+    // NAD83
+    (26901, 1, 23, "", "+datum=NAD83"),
+    // NAD27
+    (26701, 1, 22, "", "+datum=NAD27"),
+    // ED50
+    (23028, 28, 38, "", "+ellps=intl +towgs84=-87,-98,-121"),
+    // GDA94 / MGA
+    (28348, 48, 58, " +south", "+ellps=GRS80"),
+];
+
+/// Returns the PROJ string of the CRS with the given EPSG code.
+pub(super) fn proj_string(code: u32) -> Option<Cow<'static, str>> {
+    if let Some((_, definition)) = REGISTRY.iter().find(|(c, _)| *c == code) {
+        return Some(Cow::Borrowed(definition));
+    }
+
+    for &(first_code, first_zone, last_zone, hemisphere, datum) in UTM_ZONES {
+        let last_code = first_code + last_zone - first_zone;
+        if (first_code..=last_code).contains(&code) {
+            let zone = code - first_code + first_zone;
+            return Some(Cow::Owned(format!(
+                "+proj=utm +zone={zone}{hemisphere} {datum}"
+            )));
+        }
+    }
+
+    // DHDN / 3-degree Gauss-Kruger zones 2 to 5
+    if (31466..=31469).contains(&code) {
+        let zone = code - 31464;
+        return Some(Cow::Owned(format!(
+            "+proj=tmerc +lon_0={} +k=1 +x_0={} +datum=potsdam",
+            zone * 3,
+            zone * 1_000_000 + 500_000
+        )));
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cartesian::impls::point::Point2d;
+    use crate::geo::crs::{Crs, CrsError, ProjectionType};
+    use crate::geo::datum::Datum;
+    use crate::geo::impls::point::GeoPoint2d;
+    use crate::geo::impls::projection::albers_equal_area::AlbersEqualAreaParams;
+    use crate::geo::impls::projection::polar_stereographic::PolarStereographicParams;
+    use crate::geo::impls::projection::transverse_mercator::TransverseMercatorParams;
+
+    fn projection_type(code: u32) -> ProjectionType {
+        Crs::from_epsg(code).unwrap().projection_type().clone()
+    }
+
+    fn utm(zone: u8, south: bool) -> ProjectionType {
+        ProjectionType::TransverseMercator(TransverseMercatorParams::utm(zone, south))
+    }
+
+    #[test]
+    fn geographic_and_web_mercator() {
+        assert_eq!(Crs::from_epsg(4326).unwrap(), Crs::WGS84);
+        assert_eq!(Crs::from_epsg(3857).unwrap(), Crs::EPSG3857);
+        assert_eq!(Crs::from_epsg(900913).unwrap(), Crs::EPSG3857);
+
+        assert_eq!(Crs::from_epsg(4277).unwrap().datum(), &Datum::OSGB36);
+        assert_eq!(Crs::from_epsg(4284).unwrap().datum(), &Datum::PULKOVO1942);
+        assert_eq!(Crs::from_epsg(4314).unwrap().datum(), &Datum::DHDN);
+
+        let nad27 = Crs::from_epsg(4267).unwrap();
+        assert!(nad27.is_geographic());
+        assert_eq!(nad27.datum(), &Datum::NAD27);
+    }
+
+    #[test]
+    fn utm_zones() {
+        assert_eq!(projection_type(32601), utm(1, false));
+        assert_eq!(projection_type(32660), utm(60, false));
+        assert_eq!(projection_type(32737), utm(37, true));
+        assert_eq!(projection_type(25832), utm(32, false));
+        assert_eq!(Crs::from_epsg(25832).unwrap().datum(), &Datum::GRS80);
+        assert_eq!(projection_type(26918), utm(18, false));
+        assert_eq!(Crs::from_epsg(26718).unwrap().datum(), &Datum::NAD27);
+        assert_eq!(Crs::from_epsg(23032).unwrap().datum(), &Datum::ED50);
+        assert_eq!(projection_type(28356), utm(56, true));
+        assert_eq!(Crs::from_epsg(32700), Err(CrsError::UnknownEpsgCode(32700)));
+    }
+
+    #[test]
+    fn polar_and_conic_projections() {
+        assert_eq!(
+            projection_type(3413),
+            ProjectionType::PolarStereographic(PolarStereographicParams {
+                south: false,
+                latitude_of_true_scale: Some(70.0),
+                central_meridian: -45.0,
+                scale_factor: 1.0,
+                false_easting: 0.0,
+                false_northing: 0.0,
+            })
+        );
+        assert_eq!(
+            projection_type(32761),
+            ProjectionType::PolarStereographic(PolarStereographicParams {
+                south: true,
+                latitude_of_true_scale: None,
+                central_meridian: 0.0,
+                scale_factor: 0.994,
+                false_easting: 2_000_000.0,
+                false_northing: 2_000_000.0,
+            })
+        );
+        assert_eq!(
+            projection_type(5070),
+            ProjectionType::AlbersEqualArea(AlbersEqualAreaParams {
+                standard_parallel_1: 29.5,
+                standard_parallel_2: 45.5,
+                latitude_of_origin: 23.0,
+                central_meridian: -96.0,
+                false_easting: 0.0,
+                false_northing: 0.0,
+            })
+        );
+    }
+
+    #[test]
+    fn national_grids() {
+        assert_eq!(
+            projection_type(27700),
+            ProjectionType::TransverseMercator(TransverseMercatorParams {
+                latitude_of_origin: 49.0,
+                central_meridian: -2.0,
+                scale_factor: 0.9996012717,
+                false_easting: 400_000.0,
+                false_northing: -100_000.0,
+            })
+        );
+        assert_eq!(
+            projection_type(31467),
+            ProjectionType::TransverseMercator(TransverseMercatorParams {
+                central_meridian: 9.0,
+                false_easting: 3_500_000.0,
+                ..Default::default()
+            })
+        );
+        assert_eq!(
+            projection_type(3035),
+            ProjectionType::Other(
+                "laea lat_0=52 lon_0=10 x_0=4321000 y_0=3210000 ellps=GRS80".into()
+            )
+        );
+    }
+
+    #[test]
+    fn all_entries_are_valid() {
+        let codes = super::REGISTRY.iter().map(|(code, _)| *code).chain(
+            super::UTM_ZONES
+                .iter()
+                .flat_map(|(first, from, to, _, _)| *first..=*first + to - from),
+        );
+
+        for code in codes {
+            let crs = Crs::from_epsg(code)
+                .unwrap_or_else(|err| panic!("invalid registry entry {code}: {err}"));
+            if !crs.is_geographic() {
+                assert!(
+                    crs.get_projection::<GeoPoint2d, Point2d>().is_some(),
+                    "no projection for registry entry {code}"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,261 @@
+use crate::cartesian::traits::cartesian_point::NewCartesianPoint2d;
+use crate::geo::datum::Datum;
+use crate::geo::impls::projection::albers_equal_area::{AlbersEqualArea, AlbersEqualAreaParams};
+use crate::geo::impls::projection::equirectangular::{Equirectangular, EquirectangularParams};
+use crate::geo::impls::projection::geodesy::GeodesyProjection;
+use crate::geo::impls::projection::lambert_conformal_conic::{
+    LambertConformalConic, LambertConformalConicParams,
+};
+use crate::geo::impls::projection::orthographic::{Orthographic, OrthographicParams};
+use crate::geo::impls::projection::polar_stereographic::{
+    PolarStereographic, PolarStereographicParams,
+};
+use crate::geo::impls::projection::transverse_mercator::{
+    TransverseMercator, TransverseMercatorParams,
+};
+use crate::geo::impls::projection::web_mercator::WebMercator;
+use crate::geo::traits::point::NewGeoPoint;
+use crate::geo::traits::projection::{Projection, ProjectionError};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use thiserror::Error;
+
+mod epsg;
+mod proj;
+mod wkt;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Crs {
+    datum: Datum,
+    projection_type: ProjectionType,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum ProjectionType {
+    Unknown,
+    None,
+    WebMercator,
+    TransverseMercator(TransverseMercatorParams),
+    LambertConformalConic(LambertConformalConicParams),
+    AlbersEqualArea(AlbersEqualAreaParams),
+    PolarStereographic(PolarStereographicParams),
+    Equirectangular(EquirectangularParams),
+    Orthographic(OrthographicParams),
+    /// Projection given by the definition of the `geodesy` crate operator.
+    Other(String),
+}
+
+/// Error returned when a CRS cannot be created from its definition.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum CrsError {
+    #[error("EPSG code {0} is not in the registry")]
+    UnknownEpsgCode(u32),
+    #[error("invalid CRS definition: {0}")]
+    InvalidDefinition(String),
+    #[error("parameter `{0}` is required but not set")]
+    MissingParameter(String),
+    #[error("invalid value of parameter `{name}`: {value}")]
+    InvalidParameter { name: String, value: String },
+    #[error("projection `{0}` is not supported")]
+    UnsupportedProjection(String),
+    #[error("datum `{0}` is not supported")]
+    UnsupportedDatum(String),
+    #[error("unit `{0}` is not supported, only meters are supported for projected coordinates")]
+    UnsupportedUnit(String),
+}
+
+impl Crs {
+    pub const EPSG3857: Crs = Crs {
+        datum: Datum::WGS84,
+        projection_type: ProjectionType::WebMercator,
+    };
+
+    pub const WGS84: Crs = Crs {
+        datum: Datum::WGS84,
+        projection_type: ProjectionType::None,
+    };
+
+    pub fn new(datum: Datum, projection_type: ProjectionType) -> Self {
+        Self {
+            datum,
+            projection_type,
+        }
+    }
+
+    /// Creates the CRS with the given EPSG code from the embedded registry.
+    ///
+    /// The registry contains geographic CRSs of the common datums, Web Mercator and World Mercator, all the UTM zones
+    /// of WGS84 and UTM zones of ETRS89, NAD83, NAD27, ED50 and GDA94, the widely used national grids and the polar
+    /// stereographic projections of the Arctic and Antarctic.
+    ///
+    /// ```
+    /// use galileo_types::geo::crs::{Crs, ProjectionType};
+    /// use galileo_types::geo::impls::projection::transverse_mercator::TransverseMercatorParams;
+    ///
+    /// assert_eq!(Crs::from_epsg(4326).unwrap(), Crs::WGS84);
+    /// assert_eq!(
+    ///     Crs::from_epsg(32633).unwrap().projection_type(),
+    ///     &ProjectionType::TransverseMercator(TransverseMercatorParams::utm(33, false))
+    /// );
+    /// ```
+    pub fn from_epsg(code: u32) -> Result<Self, CrsError> {
+        let definition = epsg::proj_string(code).ok_or(CrsError::UnknownEpsgCode(code))?;
+        Self::from_proj_string(&definition)
+    }
+
+    /// Creates the CRS from the OGC WKT definition, as found in `.prj` files. Both WKT1 (including the ESRI flavour)
+    /// and WKT2 are supported.
+    ///
+    /// If the definition contains an EPSG code that is in the registry, the CRS is taken from the registry (see
+    /// [`Crs::from_epsg`]). Otherwise, it is built from the projection method and parameters of the definition.
+    pub fn from_wkt(wkt: &str) -> Result<Self, CrsError> {
+        wkt::parse(wkt)
+    }
+
+    /// Creates the CRS from the PROJ string, e.g. `+proj=utm +zone=33 +datum=WGS84 +units=m`.
+    ///
+    /// Geographic coordinates, Web Mercator, Mercator, Transverse Mercator, UTM, Lambert Conformal Conic, Lambert
+    /// Azimuthal Equal Area, Albers Equal Area, Polar Stereographic, Equirectangular and Orthographic projections are
+    /// supported. Only metric projected coordinates are supported.
+    pub fn from_proj_string(definition: &str) -> Result<Self, CrsError> {
+        proj::ProjDefinition::parse(definition)?.into_crs()
+    }
+
+    pub fn datum(&self) -> &Datum {
+        &self.datum
+    }
+
+    pub fn projection_type(&self) -> &ProjectionType {
+        &self.projection_type
+    }
+
+    pub fn is_geographic(&self) -> bool {
+        self.projection_type == ProjectionType::None
+    }
+
+    /// Projection from the geographic coordinates of the datum into the coordinates of this CRS.
+    ///
+    /// Returns [`ProjectionError::InvalidDefinition`] if the CRS is geographic or its projection is not known.
+    pub fn projection<In, Out>(
+        &self,
+    ) -> Result<Box<dyn Projection<InPoint = In, OutPoint = Out>>, ProjectionError>
+    where
+        In: NewGeoPoint + 'static,
+        Out: NewCartesianPoint2d + 'static,
+    {
+        match &self.projection_type {
+            ProjectionType::WebMercator => Ok(Box::new(WebMercator::new(self.datum))),
+            ProjectionType::TransverseMercator(params) => {
+                Ok(Box::new(TransverseMercator::new(self.datum, *params)))
+            }
+            ProjectionType::LambertConformalConic(params) => {
+                Ok(Box::new(LambertConformalConic::new(self.datum, *params)))
+            }
+            ProjectionType::AlbersEqualArea(params) => {
+                Ok(Box::new(AlbersEqualArea::new(self.datum, *params)))
+            }
+            ProjectionType::PolarStereographic(params) => {
+                Ok(Box::new(PolarStereographic::new(self.datum, *params)))
+            }
+            ProjectionType::Equirectangular(params) => {
+                Ok(Box::new(Equirectangular::new(self.datum, *params)))
+            }
+            ProjectionType::Orthographic(params) => {
+                Ok(Box::new(Orthographic::new(self.datum, *params)))
+            }
+            ProjectionType::Other(definition) => Ok(Box::new(GeodesyProjection::new(definition)?)),
+            ProjectionType::None => Err(ProjectionError::InvalidDefinition(
+                "geographic CRS has no projection".into(),
+            )),
+            _ => Err(ProjectionError::InvalidDefinition(format!(
+                "unsupported projection type: {:?}",
+                self.projection_type
+            ))),
+        }
+    }
+
+    /// Same as [`Crs::projection`], but discards the reason why the projection cannot be created.
+    pub fn get_projection<In, Out>(
+        &self,
+    ) -> Option<Box<dyn Projection<InPoint = In, OutPoint = Out>>>
+    where
+        In: NewGeoPoint + 'static,
+        Out: NewCartesianPoint2d + 'static,
+    {
+        self.projection().ok()
+    }
+}
+
+/// Parses the CRS from an `EPSG:<code>` string, a PROJ string or a WKT definition.
+impl FromStr for Crs {
+    type Err = CrsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Some((authority, code)) = s.split_once(':') {
+            if authority.eq_ignore_ascii_case("EPSG") {
+                let code = code.trim().parse().map_err(|_| {
+                    CrsError::InvalidDefinition(format!("invalid EPSG code: {code}"))
+                })?;
+                return Self::from_epsg(code);
+            }
+        }
+
+        if s.starts_with('+') || s.starts_with("proj=") {
+            Self::from_proj_string(s)
+        } else {
+            Self::from_wkt(s)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_from_str() {
+        assert_eq!("EPSG:3857".parse::<Crs>().unwrap(), Crs::EPSG3857);
+        assert_eq!("epsg: 4326".parse::<Crs>().unwrap(), Crs::WGS84);
+        assert_eq!(
+            "+proj=longlat +datum=WGS84 +no_defs"
+                .parse::<Crs>()
+                .unwrap(),
+            Crs::WGS84
+        );
+        assert_eq!(
+            r#"GEOGCS["WGS 84",DATUM["WGS_1984",SPHEROID["WGS 84",6378137,298.257223563]]]"#
+                .parse::<Crs>()
+                .unwrap(),
+            Crs::WGS84
+        );
+
+        assert_eq!("EPSG:1".parse::<Crs>(), Err(CrsError::UnknownEpsgCode(1)));
+        assert!(matches!(
+            "EPSG:abc".parse::<Crs>(),
+            Err(CrsError::InvalidDefinition(_))
+        ));
+    }
+    #[test]
+    fn projection_errors() {
+        use crate::cartesian::impls::point::Point2d;
+        use crate::geo::impls::point::GeoPoint2d;
+
+        assert!(matches!(
+            Crs::WGS84.projection::<GeoPoint2d, Point2d>(),
+            Err(ProjectionError::InvalidDefinition(_))
+        ));
+
+        let projection = Crs::EPSG3857.projection::<GeoPoint2d, Point2d>().unwrap();
+        assert_eq!(
+            projection.project(&GeoPoint2d::latlon(90.0, 0.0)),
+            Err(ProjectionError::OutOfDomain { x: 0.0, y: 90.0 })
+        );
+        assert_eq!(
+            projection.project(&GeoPoint2d::latlon(-90.0, 10.0)),
+            Err(ProjectionError::OutOfDomain { x: 10.0, y: -90.0 })
+        );
+        assert!(projection.project(&GeoPoint2d::latlon(85.0, 0.0)).is_ok());
+    }
+}
@@ -0,0 +1,641 @@
+//! PROJ-string parsing and conversion of PROJ-style definitions into [`Crs`].
+
+use crate::geo::crs::{Crs, CrsError, ProjectionType};
+use crate::geo::datum::Datum;
+use crate::geo::datum_shift::Helmert;
+use crate::geo::impls::projection::albers_equal_area::AlbersEqualAreaParams;
+use crate::geo::impls::projection::equirectangular::EquirectangularParams;
+use crate::geo::impls::projection::lambert_conformal_conic::LambertConformalConicParams;
+use crate::geo::impls::projection::orthographic::OrthographicParams;
+use crate::geo::impls::projection::polar_stereographic::PolarStereographicParams;
+use crate::geo::impls::projection::transverse_mercator::TransverseMercatorParams;
+use std::collections::HashMap;
+
+/// Ellipsoids that can be referenced by name, with the names used by PROJ and geodesy.
+const ELLIPSOIDS: &[(&str, Datum)] = &[
+    ("WGS84", Datum::WGS84),
+    ("GRS80", Datum::GRS80),
+    ("airy", Datum::AIRY1830),
+    ("bessel", Datum::BESSEL1841),
+    ("clrk66", Datum::CLARKE1866),
+    ("intl", Datum::INTERNATIONAL1924),
+    ("krass", Datum::KRASSOVSKY1940),
+];
+
+/// Datums that can be referenced by the `+datum` parameter of PROJ strings.
+const DATUMS: &[(&str, Datum)] = &[
+    ("WGS84", Datum::WGS84),
+    ("NAD83", Datum::GRS80),
+    ("NAD27", Datum::NAD27),
+    ("OSGB36", Datum::OSGB36),
+    ("potsdam", Datum::DHDN),
+    (
+        "hermannskogel",
+        Datum::BESSEL1841.with_to_wgs84(Helmert::new(
+            577.326, 90.129, 463.919, 5.137, 1.474, 5.297, 2.4232,
+        )),
+    ),
+];
+
+/// Numeric parameters of projections. `k` is an alias of `k_0`.
+const NUMERIC_PARAMETERS: &[&str] = &[
+    "lat_0", "lon_0", "lat_1", "lat_2", "lat_ts", "k_0", "x_0", "y_0", "zone",
+];
+
+/// Projection method with its parameters in PROJ terms. Both PROJ strings and WKT definitions are converted into this
+/// form before being turned into [`Crs`].
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct ProjDefinition {
+    pub method: String,
+    pub datum: Datum,
+    pub parameters: HashMap<&'static str, f64>,
+    pub south: bool,
+}
+
+impl ProjDefinition {
+    pub fn new(method: impl Into<String>, datum: Datum) -> Self {
+        Self {
+            method: method.into(),
+            datum,
+            parameters: HashMap::new(),
+            south: false,
+        }
+    }
+
+    /// Sets the value of the parameter, ignoring parameters that are not used by any of the supported projections.
+    pub fn set(&mut self, name: &str, value: f64) {
+        let name = if name == "k" { "k_0" } else { name };
+        if let Some(&name) = NUMERIC_PARAMETERS.iter().find(|p| **p == name) {
+            self.parameters.insert(name, value);
+        }
+    }
+
+    pub fn parse(definition: &str) -> Result<Self, CrsError> {
+        let mut method = None;
+        let mut parameters = HashMap::new();
+        for token in definition.split_whitespace() {
+            let token = token.strip_prefix('+').unwrap_or(token);
+            if token.is_empty() {
+                continue;
+            }
+
+            let (key, value) = match token.split_once('=') {
+                Some((key, value)) => (key, Some(value)),
+                None => (token, None),
+            };
+            if key == "proj" {
+                method = value;
+            } else {
+                parameters.insert(key, value);
+            }
+        }
+
+        let method = method.ok_or_else(|| CrsError::MissingParameter("proj".into()))?;
+        let mut datum = Self::parse_datum(&parameters)?;
+        if let Some(&Some(towgs84)) = parameters.get("towgs84") {
+            let values = towgs84
+                .split(',')
+                .map(|value| parse_number("towgs84", value))
+                .collect::<Result<Vec<_>, _>>()?;
+            datum = with_to_wgs84(datum, &values).ok_or_else(|| CrsError::InvalidParameter {
+                name: "towgs84".into(),
+                value: towgs84.into(),
+            })?;
+        }
+
+        let mut result = Self::new(method, datum);
+        result.south = parameters.contains_key("south");
+
+        for (&key, value) in &parameters {
+            if key == "k" || NUMERIC_PARAMETERS.contains(&key) {
+                let value = value.ok_or_else(|| CrsError::InvalidParameter {
+                    name: key.into(),
+                    value: String::new(),
+                })?;
+                result.set(key, parse_number(key, value)?);
+            }
+        }
+
+        if let Some(&Some(units)) = parameters.get("units") {
+            if units != "m" {
+                return Err(CrsError::UnsupportedUnit(units.into()));
+            }
+        }
+        if let Some(&Some(to_meter)) = parameters.get("to_meter") {
+            if parse_number("to_meter", to_meter)? != 1.0 {
+                return Err(CrsError::UnsupportedUnit(format!("to_meter={to_meter}")));
+            }
+        }
+        if let Some(&Some(pm)) = parameters.get("pm") {
+            if pm != "greenwich" && pm.parse::<f64>() != Ok(0.0) {
+                return Err(CrsError::UnsupportedDatum(format!("prime meridian {pm}")));
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn parse_datum(parameters: &HashMap<&str, Option<&str>>) -> Result<Datum, CrsError> {
+        let get = |key: &str| parameters.get(key).copied().flatten();
+
+        if let Some(name) = get("datum") {
+            return DATUMS
+                .iter()
+                .find(|(datum_name, _)| datum_name.eq_ignore_ascii_case(name))
+                .map(|(_, datum)| *datum)
+                .ok_or_else(|| CrsError::UnsupportedDatum(name.into()));
+        }
+
+        if let Some(name) = get("ellps") {
+            return ELLIPSOIDS
+                .iter()
+                .find(|(ellipsoid_name, _)| ellipsoid_name.eq_ignore_ascii_case(name))
+                .map(|(_, datum)| *datum)
+                .ok_or_else(|| CrsError::UnsupportedDatum(name.into()));
+        }
+
+        if let Some(radius) = get("R") {
+            return Ok(Datum::new(parse_number("R", radius)?, 0.0));
+        }
+
+        if let Some(a) = get("a") {
+            let a = parse_number("a", a)?;
+            let inv_flattening = if let Some(rf) = get("rf") {
+                parse_number("rf", rf)?
+            } else if let Some(f) = get("f") {
+                let f = parse_number("f", f)?;
+                if f == 0.0 {
+                    0.0
+                } else {
+                    1.0 / f
+                }
+            } else if let Some(b) = get("b") {
+                let b = parse_number("b", b)?;
+                if a == b {
+                    0.0
+                } else {
+                    a / (a - b)
+                }
+            } else {
+                0.0
+            };
+
+            return Ok(ellipsoid(a, inv_flattening));
+        }
+
+        // PROJ uses GRS80 ellipsoid if it is not specified.
+        Ok(Datum::GRS80)
+    }
+
+    pub fn into_crs(self) -> Result<Crs, CrsError> {
+        let projection_type = match self.method.as_str() {
+            "longlat" | "latlong" | "lonlat" | "latlon" => ProjectionType::None,
+            "webmerc" => ProjectionType::WebMercator,
+            "merc"
+                if self.datum.is_sphere() && self.datum.semimajor() == Datum::WGS84.semimajor() =>
+            {
+                return Ok(Crs::EPSG3857);
+            }
+            "merc" => self.geodesy("merc", &["lat_ts", "lon_0", "k_0", "x_0", "y_0"])?,
+            "utm" => self.utm()?,
+            "tmerc" | "etmerc" => ProjectionType::TransverseMercator(TransverseMercatorParams {
+                latitude_of_origin: self.get("lat_0", 0.0),
+                central_meridian: self.get("lon_0", 0.0),
+                scale_factor: self.get("k_0", 1.0),
+                false_easting: self.get("x_0", 0.0),
+                false_northing: self.get("y_0", 0.0),
+            }),
+            "lcc" => {
+                let lat_1 = self.require("lat_1")?;
+                // As in PROJ, the projection with one standard parallel has the origin on it by default.
+                let lat_2 = self.parameters.get("lat_2").copied();
+                let lat_0 = self.get("lat_0", if lat_2.is_some() { 0.0 } else { lat_1 });
+                let lat_2 = lat_2.unwrap_or(lat_1);
+                check_cone(lat_1, lat_2)?;
+
+                ProjectionType::LambertConformalConic(LambertConformalConicParams {
+                    standard_parallel_1: lat_1,
+                    standard_parallel_2: lat_2,
+                    latitude_of_origin: lat_0,
+                    central_meridian: self.get("lon_0", 0.0),
+                    scale_factor: self.get("k_0", 1.0),
+                    false_easting: self.get("x_0", 0.0),
+                    false_northing: self.get("y_0", 0.0),
+                })
+            }
+            "aea" => {
+                let lat_1 = self.require("lat_1")?;
+                let lat_2 = self.get("lat_2", 0.0);
+                check_cone(lat_1, lat_2)?;
+
+                ProjectionType::AlbersEqualArea(AlbersEqualAreaParams {
+                    standard_parallel_1: lat_1,
+                    standard_parallel_2: lat_2,
+                    latitude_of_origin: self.get("lat_0", 0.0),
+                    central_meridian: self.get("lon_0", 0.0),
+                    false_easting: self.get("x_0", 0.0),
+                    false_northing: self.get("y_0", 0.0),
+                })
+            }
+            "stere" | "ups" => self.polar_stereographic()?,
+            "eqc" => ProjectionType::Equirectangular(EquirectangularParams {
+                latitude_of_true_scale: self.get("lat_ts", 0.0),
+                latitude_of_origin: self.get("lat_0", 0.0),
+                central_meridian: self.get("lon_0", 0.0),
+                false_easting: self.get("x_0", 0.0),
+                false_northing: self.get("y_0", 0.0),
+            }),
+            "ortho" => ProjectionType::Orthographic(OrthographicParams {
+                latitude_of_origin: self.get("lat_0", 0.0),
+                central_meridian: self.get("lon_0", 0.0),
+                false_easting: self.get("x_0", 0.0),
+                false_northing: self.get("y_0", 0.0),
+            }),
+            "laea" => self.geodesy("laea", &["lat_0", "lon_0", "x_0", "y_0"])?,
+            method => return Err(CrsError::UnsupportedProjection(method.into())),
+        };
+
+        Ok(Crs::new(self.datum, projection_type))
+    }
+
+    fn get(&self, name: &str, default: f64) -> f64 {
+        self.parameters.get(name).copied().unwrap_or(default)
+    }
+
+    fn require(&self, name: &str) -> Result<f64, CrsError> {
+        self.parameters
+            .get(name)
+            .copied()
+            .ok_or_else(|| CrsError::MissingParameter(name.into()))
+    }
+
+    fn utm(&self) -> Result<ProjectionType, CrsError> {
+        let zone = self.require("zone")?;
+        if zone.fract() != 0.0 || !(1.0..=60.0).contains(&zone) {
+            return Err(CrsError::InvalidParameter {
+                name: "zone".into(),
+                value: zone.to_string(),
+            });
+        }
+
+        Ok(ProjectionType::TransverseMercator(
+            TransverseMercatorParams::utm(zone as u8, self.south),
+        ))
+    }
+
+    fn polar_stereographic(&self) -> Result<ProjectionType, CrsError> {
+        if self.method == "ups" {
+            return Ok(ProjectionType::PolarStereographic(
+                PolarStereographicParams {
+                    south: self.south,
+                    latitude_of_true_scale: None,
+                    central_meridian: 0.0,
+                    scale_factor: 0.994,
+                    false_easting: 2_000_000.0,
+                    false_northing: 2_000_000.0,
+                },
+            ));
+        }
+
+        let lat_ts = self.parameters.get("lat_ts").copied();
+        let lat_0 = match (self.parameters.get("lat_0"), lat_ts) {
+            (Some(lat_0), _) => *lat_0,
+            (None, Some(lat_ts)) => 90f64.copysign(lat_ts),
+            (None, None) => return Err(CrsError::MissingParameter("lat_0".into())),
+        };
+        if lat_0.abs() != 90.0 {
+            return Err(CrsError::UnsupportedProjection(format!(
+                "oblique stereographic (lat_0={lat_0})"
+            )));
+        }
+
+        Ok(ProjectionType::PolarStereographic(
+            PolarStereographicParams {
+                south: lat_0 < 0.0,
+                latitude_of_true_scale: lat_ts,
+                central_meridian: self.get("lon_0", 0.0),
+                scale_factor: self.get("k_0", 1.0),
+                false_easting: self.get("x_0", 0.0),
+                false_northing: self.get("y_0", 0.0),
+            },
+        ))
+    }
+
+    /// Definition of the geodesy operator with the given parameters of the definition.
+    fn geodesy(&self, operator: &str, parameters: &[&str]) -> Result<ProjectionType, CrsError> {
+        let mut definition = operator.to_string();
+        for name in parameters {
+            if let Some(value) = self.parameters.get(name) {
+                definition += &format!(" {name}={value}");
+            }
+        }
+
+        definition += &format!(" ellps={}", geodesy_ellipsoid(&self.datum)?);
+        Ok(ProjectionType::Other(definition))
+    }
+}
+
+/// Returns the datum with the given ellipsoid parameters, using the predefined constants for the known ellipsoids.
+pub(super) fn ellipsoid(semimajor: f64, inv_flattening: f64) -> Datum {
+    ELLIPSOIDS
+        .iter()
+        .map(|(_, datum)| *datum)
+        .find(|datum| {
+            (datum.semimajor() - semimajor).abs() < 1e-3
+                && (datum.inv_flattening() - inv_flattening).abs() < 1e-8
+        })
+        .unwrap_or(Datum::new(semimajor, inv_flattening))
+}
+
+/// Sets the transformation to WGS84 given by 3 or 7 parameters of the Helmert transformation. Returns `None` if the
+/// number of the parameters is wrong.
+pub(super) fn with_to_wgs84(datum: Datum, parameters: &[f64]) -> Option<Datum> {
+    let helmert = match *parameters {
+        [tx, ty, tz] => Helmert::translation(tx, ty, tz),
+        [tx, ty, tz, rx, ry, rz, scale] => Helmert::new(tx, ty, tz, rx, ry, rz, scale),
+        _ => return None,
+    };
+
+    // Zero transformation is often given explicitly for the datums that are the same as WGS84, e.g. ETRS89.
+    if parameters.iter().all(|value| *value == 0.0) {
+        Some(datum)
+    } else {
+        Some(datum.with_to_wgs84(helmert))
+    }
+}
+
+/// Checks that the standard parallels of the conic projection do not define a cylinder.
+fn check_cone(lat_1: f64, lat_2: f64) -> Result<(), CrsError> {
+    if (lat_1 + lat_2).abs() < 1e-10 {
+        return Err(CrsError::InvalidParameter {
+            name: "lat_2".into(),
+            value: lat_2.to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+fn geodesy_ellipsoid(datum: &Datum) -> Result<String, CrsError> {
+    if let Some((name, _)) = ELLIPSOIDS.iter().find(|(_, d)| d.same_ellipsoid(datum)) {
+        return Ok(name.to_string());
+    }
+
+    if datum.is_sphere() {
+        return Err(CrsError::UnsupportedDatum(format!(
+            "sphere with radius {}",
+            datum.semimajor()
+        )));
+    }
+
+    Ok(format!("{},{}", datum.semimajor(), datum.inv_flattening()))
+}
+
+fn parse_number(name: &str, value: &str) -> Result<f64, CrsError> {
+    value.parse().map_err(|_| CrsError::InvalidParameter {
+        name: name.into(),
+        value: value.into(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn projection(definition: &str) -> Result<ProjectionType, CrsError> {
+        Crs::from_proj_string(definition).map(|crs| crs.projection_type().clone())
+    }
+
+    #[test]
+    fn geographic() {
+        let crs = Crs::from_proj_string("+proj=longlat +ellps=airy +no_defs").unwrap();
+        assert!(crs.is_geographic());
+        assert_eq!(crs.datum(), &Datum::AIRY1830);
+
+        let crs = Crs::from_proj_string("+proj=longlat +a=6378137 +rf=298.257223563").unwrap();
+        assert_eq!(crs, Crs::WGS84);
+
+        let crs = Crs::from_proj_string("+proj=longlat +datum=OSGB36").unwrap();
+        assert_eq!(crs.datum(), &Datum::OSGB36);
+
+        let crs = Crs::from_proj_string("+proj=longlat +a=6378000 +b=6356000").unwrap();
+        assert_eq!(crs.datum().semimajor(), 6_378_000.0);
+        assert!((crs.datum().inv_flattening() - 6_378_000.0 / 22_000.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn towgs84() {
+        let crs = Crs::from_proj_string("+proj=longlat +ellps=intl +towgs84=-87,-98,-121").unwrap();
+        assert_eq!(crs.datum(), &Datum::ED50);
+
+        let crs = Crs::from_proj_string(
+            "+proj=tmerc +lon_0=9 +ellps=bessel +towgs84=598.1,73.7,418.2,0.202,0.045,-2.455,6.7",
+        )
+        .unwrap();
+        assert_eq!(crs.datum(), &Datum::DHDN);
+        assert_eq!(
+            crs.projection_type(),
+            &ProjectionType::TransverseMercator(TransverseMercatorParams {
+                central_meridian: 9.0,
+                ..Default::default()
+            })
+        );
+
+        let crs =
+            Crs::from_proj_string("+proj=longlat +ellps=GRS80 +towgs84=0,0,0,0,0,0,0").unwrap();
+        assert_eq!(crs.datum(), &Datum::GRS80);
+
+        assert_eq!(
+            projection("+proj=longlat +ellps=intl +towgs84=1,2"),
+            Err(CrsError::InvalidParameter {
+                name: "towgs84".into(),
+                value: "1,2".into()
+            })
+        );
+    }
+
+    #[test]
+    fn web_mercator() {
+        assert_eq!(
+            Crs::from_proj_string(
+                "+proj=merc +a=6378137 +b=6378137 +lat_ts=0 +lon_0=0 +x_0=0 +y_0=0 +k=1 +units=m +nadgrids=@null +wktext +no_defs"
+            )
+            .unwrap(),
+            Crs::EPSG3857
+        );
+        assert_eq!(
+            Crs::from_proj_string("+proj=webmerc +datum=WGS84").unwrap(),
+            Crs::EPSG3857
+        );
+    }
+
+    #[test]
+    fn utm() {
+        assert_eq!(
+            projection("+proj=utm +zone=32 +south +ellps=GRS80 +units=m +no_defs"),
+            Ok(ProjectionType::TransverseMercator(
+                TransverseMercatorParams::utm(32, true)
+            ))
+        );
+
+        assert_eq!(
+            projection("+proj=utm +datum=WGS84"),
+            Err(CrsError::MissingParameter("zone".into()))
+        );
+        assert!(matches!(
+            projection("+proj=utm +zone=61"),
+            Err(CrsError::InvalidParameter { .. })
+        ));
+    }
+
+    #[test]
+    fn projection_parameters() {
+        assert_eq!(
+            projection("+proj=tmerc +lat_0=49 +lon_0=-2 +k=0.9996012717 +x_0=400000 +y_0=-100000 +ellps=airy"),
+            Ok(ProjectionType::TransverseMercator(TransverseMercatorParams {
+                latitude_of_origin: 49.0,
+                central_meridian: -2.0,
+                scale_factor: 0.9996012717,
+                false_easting: 400_000.0,
+                false_northing: -100_000.0,
+            }))
+        );
+        assert_eq!(
+            projection("+proj=lcc +lat_1=49 +lat_2=44 +lat_0=46.5 +lon_0=3 +x_0=700000 +y_0=6600000 +ellps=GRS80"),
+            Ok(ProjectionType::LambertConformalConic(LambertConformalConicParams {
+                standard_parallel_1: 49.0,
+                standard_parallel_2: 44.0,
+                latitude_of_origin: 46.5,
+                central_meridian: 3.0,
+                scale_factor: 1.0,
+                false_easting: 700_000.0,
+                false_northing: 6_600_000.0,
+            }))
+        );
+        assert_eq!(
+            projection("+proj=lcc +lat_1=-40 +lon_0=170 +k_0=0.9999 +ellps=GRS80"),
+            Ok(ProjectionType::LambertConformalConic(
+                LambertConformalConicParams {
+                    standard_parallel_1: -40.0,
+                    standard_parallel_2: -40.0,
+                    latitude_of_origin: -40.0,
+                    central_meridian: 170.0,
+                    scale_factor: 0.9999,
+                    false_easting: 0.0,
+                    false_northing: 0.0,
+                }
+            ))
+        );
+        assert_eq!(
+            projection("+proj=aea +lat_1=29.5 +lat_2=45.5 +lat_0=23 +lon_0=-96 +datum=NAD83"),
+            Ok(ProjectionType::AlbersEqualArea(AlbersEqualAreaParams {
+                standard_parallel_1: 29.5,
+                standard_parallel_2: 45.5,
+                latitude_of_origin: 23.0,
+                central_meridian: -96.0,
+                false_easting: 0.0,
+                false_northing: 0.0,
+            }))
+        );
+        assert_eq!(
+            projection("+proj=eqc +lat_ts=30 +lon_0=10 +R=6371000"),
+            Ok(ProjectionType::Equirectangular(EquirectangularParams {
+                latitude_of_true_scale: 30.0,
+                central_meridian: 10.0,
+                ..Default::default()
+            }))
+        );
+        assert_eq!(
+            projection("+proj=ortho +lat_0=40 +lon_0=-100 +ellps=WGS84"),
+            Ok(ProjectionType::Orthographic(OrthographicParams {
+                latitude_of_origin: 40.0,
+                central_meridian: -100.0,
+                ..Default::default()
+            }))
+        );
+        assert_eq!(
+            projection(
+                "+proj=laea +lat_0=52 +lon_0=10 +x_0=4321000 +y_0=3210000 +a=6378000 +rf=300"
+            ),
+            Ok(ProjectionType::Other(
+                "laea lat_0=52 lon_0=10 x_0=4321000 y_0=3210000 ellps=6378000,300".into()
+            ))
+        );
+    }
+
+    #[test]
+    fn polar_stereographic() {
+        assert_eq!(
+            projection("+proj=stere +lat_0=-90 +lat_ts=-71 +lon_0=0 +datum=WGS84"),
+            Ok(ProjectionType::PolarStereographic(
+                PolarStereographicParams {
+                    south: true,
+                    latitude_of_true_scale: Some(-71.0),
+                    central_meridian: 0.0,
+                    scale_factor: 1.0,
+                    false_easting: 0.0,
+                    false_northing: 0.0,
+                }
+            ))
+        );
+        assert_eq!(
+            projection("+proj=ups +datum=WGS84"),
+            Ok(ProjectionType::PolarStereographic(
+                PolarStereographicParams {
+                    south: false,
+                    latitude_of_true_scale: None,
+                    central_meridian: 0.0,
+                    scale_factor: 0.994,
+                    false_easting: 2_000_000.0,
+                    false_northing: 2_000_000.0,
+                }
+            ))
+        );
+        assert!(matches!(
+            projection("+proj=stere +lat_0=52 +lon_0=5 +datum=WGS84"),
+            Err(CrsError::UnsupportedProjection(_))
+        ));
+        assert_eq!(
+            projection("+proj=stere +lon_0=5 +datum=WGS84"),
+            Err(CrsError::MissingParameter("lat_0".into()))
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            projection("+ellps=GRS80"),
+            Err(CrsError::MissingParameter("proj".into()))
+        );
+        assert_eq!(
+            projection("+proj=robin +datum=WGS84"),
+            Err(CrsError::UnsupportedProjection("robin".into()))
+        );
+        assert_eq!(
+            projection("+proj=longlat +datum=unknown"),
+            Err(CrsError::UnsupportedDatum("unknown".into()))
+        );
+        assert_eq!(
+            projection("+proj=utm +zone=10 +datum=NAD83 +units=us-ft"),
+            Err(CrsError::UnsupportedUnit("us-ft".into()))
+        );
+        assert_eq!(
+            projection("+proj=tmerc +lon_0=abc"),
+            Err(CrsError::InvalidParameter {
+                name: "lon_0".into(),
+                value: "abc".into()
+            })
+        );
+        assert!(matches!(
+            projection("+proj=laea +R=6371000"),
+            Err(CrsError::UnsupportedDatum(_))
+        ));
+        assert_eq!(
+            projection("+proj=aea +lat_1=30 +lat_2=-30 +datum=WGS84"),
+            Err(CrsError::InvalidParameter {
+                name: "lat_2".into(),
+                value: "-30".into()
+            })
+        );
+    }
+}
@@ -0,0 +1,642 @@
+//! Parsing of OGC WKT CRS definitions (WKT1, ESRI WKT and WKT2).
+
+use crate::geo::crs::epsg;
+use crate::geo::crs::proj::{self, ProjDefinition};
+use crate::geo::crs::{Crs, CrsError};
+use crate::geo::datum::Datum;
+
+const GEOGRAPHIC_KEYWORDS: &[&str] = &[
+    "GEOGCS",
+    "GEOGCRS",
+    "GEODCRS",
+    "GEOGRAPHICCRS",
+    "GEODETICCRS",
+];
+const PROJECTED_KEYWORDS: &[&str] = &["PROJCS", "PROJCRS", "PROJECTEDCRS"];
+const COMPOUND_KEYWORDS: &[&str] = &["COMPD_CS", "COMPOUNDCRS"];
+
+/// Projection method names (lowercase, with only letters and digits) and corresponding PROJ projections.
+const METHODS: &[(&str, &str)] = &[
+    ("transversemercator", "tmerc"),
+    ("gausskruger", "tmerc"),
+    ("lambertconformalconic", "lcc"),
+    ("lambertconformalconic1sp", "lcc"),
+    ("lambertconformalconic2sp", "lcc"),
+    ("lambertconicconformal1sp", "lcc"),
+    ("lambertconicconformal2sp", "lcc"),
+    ("lambertazimuthalequalarea", "laea"),
+    ("mercator", "merc"),
+    ("mercator1sp", "merc"),
+    ("mercator2sp", "merc"),
+    ("mercatorvarianta", "merc"),
+    ("mercatorvariantb", "merc"),
+    ("mercatorauxiliarysphere", "webmerc"),
+    ("popularvisualisationpseudomercator", "webmerc"),
+    ("polarstereographic", "stere"),
+    ("polarstereographicvarianta", "stere"),
+    ("polarstereographicvariantb", "stere"),
+    ("stereographicnorthpole", "stere"),
+    ("stereographicsouthpole", "stere"),
+    ("albersconicequalarea", "aea"),
+    ("albersequalarea", "aea"),
+    ("equirectangular", "eqc"),
+    ("equidistantcylindrical", "eqc"),
+    ("platecarree", "eqc"),
+    ("orthographic", "ortho"),
+];
+
+/// Parameter names (lowercase, with only letters and digits) and corresponding PROJ parameters.
+const PARAMETERS: &[(&str, &str)] = &[
+    ("latitudeoforigin", "lat_0"),
+    ("latitudeofnaturalorigin", "lat_0"),
+    ("latitudeoffalseorigin", "lat_0"),
+    ("latitudeofcenter", "lat_0"),
+    ("latitudeofprojectioncentre", "lat_0"),
+    ("centralmeridian", "lon_0"),
+    ("longitudeofnaturalorigin", "lon_0"),
+    ("longitudeoffalseorigin", "lon_0"),
+    ("longitudeoforigin", "lon_0"),
+    ("longitudeofcenter", "lon_0"),
+    ("longitudeofprojectioncentre", "lon_0"),
+    ("scalefactor", "k_0"),
+    ("scalefactoratnaturalorigin", "k_0"),
+    ("falseeasting", "x_0"),
+    ("eastingatfalseorigin", "x_0"),
+    ("falsenorthing", "y_0"),
+    ("northingatfalseorigin", "y_0"),
+    ("standardparallel1", "lat_1"),
+    ("latitudeof1ststandardparallel", "lat_1"),
+    ("standardparallel2", "lat_2"),
+    ("latitudeof2ndstandardparallel", "lat_2"),
+    ("latitudeofstandardparallel", "lat_ts"),
+];
+
+/// Datum names (lowercase, with only letters and digits) of the datums that are often given without `TOWGS84`.
+const DATUMS: &[(&str, Datum)] = &[
+    ("osgb1936", Datum::OSGB36),
+    ("europeandatum1950", Datum::ED50),
+    ("european1950", Datum::ED50),
+    ("northamericandatum1927", Datum::NAD27),
+    ("northamerican1927", Datum::NAD27),
+    ("pulkovo1942", Datum::PULKOVO1942),
+    ("deutscheshauptdreiecksnetz", Datum::DHDN),
+];
+
+pub(super) fn parse(wkt: &str) -> Result<Crs, CrsError> {
+    let root = Parser::new(wkt).parse()?;
+    crs_from_node(&root)
+}
+
+fn crs_from_node(node: &Node) -> Result<Crs, CrsError> {
+    if let Some(code) = node.epsg_code() {
+        if let Some(definition) = epsg::proj_string(code) {
+            return Crs::from_proj_string(&definition);
+        }
+    }
+
+    if let Some(definition) = node
+        .child("EXTENSION")
+        .filter(|extension| extension.text(0) == Some("PROJ4"))
+        .and_then(|extension| extension.text(1))
+    {
+        return Crs::from_proj_string(definition);
+    }
+
+    let keyword = node.keyword.to_ascii_uppercase();
+    if GEOGRAPHIC_KEYWORDS.contains(&keyword.as_str()) {
+        ProjDefinition::new("longlat", datum(node)?).into_crs()
+    } else if PROJECTED_KEYWORDS.contains(&keyword.as_str()) {
+        projected(node)?.into_crs()
+    } else if COMPOUND_KEYWORDS.contains(&keyword.as_str()) {
+        let horizontal = node
+            .children()
+            .find(|child| {
+                let keyword = child.keyword.to_ascii_uppercase();
+                GEOGRAPHIC_KEYWORDS.contains(&keyword.as_str())
+                    || PROJECTED_KEYWORDS.contains(&keyword.as_str())
+            })
+            .ok_or_else(|| {
+                CrsError::InvalidDefinition("compound CRS without horizontal CRS".into())
+            })?;
+        crs_from_node(horizontal)
+    } else {
+        Err(CrsError::UnsupportedProjection(node.keyword.clone()))
+    }
+}
+
+fn datum(node: &Node) -> Result<Datum, CrsError> {
+    let ellipsoid = node
+        .find(&["SPHEROID", "ELLIPSOID"])
+        .ok_or_else(|| CrsError::MissingParameter("ELLIPSOID".into()))?;
+    let semimajor = ellipsoid.number(0).ok_or_else(|| invalid(ellipsoid))?;
+    let inv_flattening = ellipsoid.number(1).ok_or_else(|| invalid(ellipsoid))?;
+    let unit = ellipsoid
+        .child("LENGTHUNIT")
+        .map(unit_factor)
+        .transpose()?
+        .unwrap_or(1.0);
+
+    if let Some(primem) = node.find(&["PRIMEM", "PRIMEMERIDIAN"]) {
+        if primem.number(0).is_some_and(|lon| lon != 0.0) {
+            return Err(CrsError::UnsupportedDatum(format!(
+                "prime meridian {}",
+                primem.text(0).unwrap_or_default()
+            )));
+        }
+    }
+
+    let ellipsoid = proj::ellipsoid(semimajor * unit, inv_flattening);
+    if let Some(towgs84) = node.find(&["TOWGS84"]) {
+        let parameters: Vec<f64> = towgs84
+            .values
+            .iter()
+            .filter_map(|value| match value {
+                Value::Number(number) => Some(*number),
+                _ => None,
+            })
+            .collect();
+        return proj::with_to_wgs84(ellipsoid, &parameters).ok_or_else(|| invalid(towgs84));
+    }
+
+    // ESRI WKT does not have `TOWGS84`, so the transformation is taken from the known datums.
+    let datum_name = node
+        .find(&["DATUM", "ENSEMBLE"])
+        .and_then(|datum| datum.text(0))
+        .map(|name| normalize(name.strip_prefix("D_").unwrap_or(name)));
+    if let Some(name) = datum_name {
+        if let Some((_, datum)) = DATUMS
+            .iter()
+            .find(|(n, d)| *n == name && d.same_ellipsoid(&ellipsoid))
+        {
+            return Ok(*datum);
+        }
+    }
+
+    Ok(ellipsoid)
+}
+
+fn projected(node: &Node) -> Result<ProjDefinition, CrsError> {
+    let method_node = node
+        .find(&["PROJECTION", "METHOD"])
+        .ok_or_else(|| CrsError::MissingParameter("PROJECTION".into()))?;
+    let method_name = method_node.text(0).ok_or_else(|| invalid(method_node))?;
+    let normalized = normalize(method_name);
+    let method = METHODS
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(&normalized))
+        .map(|(_, method)| *method)
+        .ok_or_else(|| CrsError::UnsupportedProjection(method_name.into()))?;
+
+    let unit = node
+        .children()
+        .chain(node.children_named("AXIS").flat_map(|axis| axis.children()))
+        .find(|child| {
+            child.keyword.eq_ignore_ascii_case("UNIT")
+                || child.keyword.eq_ignore_ascii_case("LENGTHUNIT")
+        });
+    if let Some(unit) = unit {
+        if (unit_factor(unit)? - 1.0).abs() > 1e-9 {
+            return Err(CrsError::UnsupportedUnit(
+                unit.text(0).unwrap_or_default().into(),
+            ));
+        }
+    }
+
+    let mut definition = ProjDefinition::new(method, datum(node)?);
+    let conversion = node.find(&["CONVERSION"]).unwrap_or(node);
+    for parameter in conversion.children_named("PARAMETER") {
+        let Some(name) = parameter.text(0).map(normalize) else {
+            continue;
+        };
+        let Some(&(_, proj_name)) = PARAMETERS.iter().find(|(n, _)| *n == name) else {
+            continue;
+        };
+
+        let mut value = parameter.number(0).ok_or_else(|| invalid(parameter))?;
+        if let Some(unit) = parameter.child("ANGLEUNIT") {
+            // Radians per unit. Values in degrees are taken as is to not introduce rounding errors.
+            let factor = unit_factor(unit)?;
+            if (factor - 1f64.to_radians()).abs() > 1e-15 {
+                value = (value * factor).to_degrees();
+            }
+        } else if let Some(unit) = parameter.child("LENGTHUNIT") {
+            value *= unit_factor(unit)?;
+        }
+
+        definition.set(proj_name, value);
+    }
+
+    if method == "stere" {
+        polar_stereographic_parameters(&mut definition);
+    }
+
+    Ok(definition)
+}
+
+/// ESRI WKT gives the latitude of true scale of the Polar Stereographic projection as the standard parallel, and GDAL
+/// WKT1 gives it as the latitude of origin.
+fn polar_stereographic_parameters(definition: &mut ProjDefinition) {
+    let parameters = &mut definition.parameters;
+    if let Some(lat_1) = parameters.remove("lat_1") {
+        parameters.entry("lat_ts").or_insert(lat_1);
+    }
+
+    if let Some(&lat_0) = parameters.get("lat_0") {
+        if lat_0.abs() != 90.0 && !parameters.contains_key("lat_ts") {
+            parameters.insert("lat_ts", lat_0);
+            parameters.insert("lat_0", 90f64.copysign(lat_0));
+        }
+    }
+}
+
+fn unit_factor(unit: &Node) -> Result<f64, CrsError> {
+    unit.number(0).ok_or_else(|| invalid(unit))
+}
+
+fn invalid(node: &Node) -> CrsError {
+    CrsError::InvalidDefinition(format!("invalid {} node", node.keyword))
+}
+
+/// Lowercase name with only letters and digits, e.g. `latitudeofnaturalorigin` for `Latitude of natural origin`.
+fn normalize(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Value {
+    Node(Node),
+    Text(String),
+    Number(f64),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Node {
+    keyword: String,
+    values: Vec<Value>,
+}
+
+impl Node {
+    fn children(&self) -> impl Iterator<Item = &Node> {
+        self.values.iter().filter_map(|value| match value {
+            Value::Node(node) => Some(node),
+            _ => None,
+        })
+    }
+
+    fn children_named<'a>(&'a self, keyword: &'a str) -> impl Iterator<Item = &'a Node> {
+        self.children()
+            .filter(move |child| child.keyword.eq_ignore_ascii_case(keyword))
+    }
+
+    fn child(&self, keyword: &str) -> Option<&Node> {
+        self.children()
+            .find(|child| child.keyword.eq_ignore_ascii_case(keyword))
+    }
+
+    /// Finds the first node with one of the given keywords, searching depth-first.
+    fn find(&self, keywords: &[&str]) -> Option<&Node> {
+        self.children().find_map(|child| {
+            if keywords
+                .iter()
+                .any(|keyword| child.keyword.eq_ignore_ascii_case(keyword))
+            {
+                Some(child)
+            } else {
+                child.find(keywords)
+            }
+        })
+    }
+
+    fn text(&self, index: usize) -> Option<&str> {
+        self.values
+            .iter()
+            .filter_map(|value| match value {
+                Value::Text(text) => Some(text.as_str()),
+                _ => None,
+            })
+            .nth(index)
+    }
+
+    fn number(&self, index: usize) -> Option<f64> {
+        self.values
+            .iter()
+            .filter_map(|value| match value {
+                Value::Number(number) => Some(*number),
+                _ => None,
+            })
+            .nth(index)
+    }
+
+    /// EPSG code given by `AUTHORITY["EPSG","4326"]` (WKT1) or `ID["EPSG",4326]` (WKT2).
+    fn epsg_code(&self) -> Option<u32> {
+        let id = self
+            .children()
+            .filter(|child| {
+                child.keyword.eq_ignore_ascii_case("AUTHORITY")
+                    || child.keyword.eq_ignore_ascii_case("ID")
+            })
+            .find(|id| id.text(0).is_some_and(|a| a.eq_ignore_ascii_case("EPSG")))?;
+
+        match id.text(1) {
+            Some(code) => code.trim().parse().ok(),
+            None => id
+                .number(0)
+                .filter(|code| code.fract() == 0.0 && *code >= 0.0)
+                .map(|code| code as u32),
+        }
+    }
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(input: &'a str) -> Self {
+        Self { input, position: 0 }
+    }
+
+    fn parse(mut self) -> Result<Node, CrsError> {
+        let node = self.node()?;
+        self.skip_whitespace();
+        if self.position < self.input.len() {
+            return Err(self.error("unexpected characters after the end of the definition"));
+        }
+
+        Ok(node)
+    }
+
+    fn node(&mut self) -> Result<Node, CrsError> {
+        self.skip_whitespace();
+        let start = self.position;
+        while self
+            .peek()
+            .is_some_and(|c| c.is_ascii_alphanumeric() || c == '_')
+        {
+            self.position += 1;
+        }
+        if start == self.position {
+            return Err(self.error("keyword expected"));
+        }
+
+        let keyword = self.input[start..self.position].to_string();
+        self.skip_whitespace();
+        let closing = match self.peek() {
+            Some('[') => ']',
+            Some('(') => ')',
+            // Keywords without values, e.g. axis directions.
+            _ => {
+                return Ok(Node {
+                    keyword,
+                    values: vec![],
+                })
+            }
+        };
+        self.position += 1;
+
+        let mut values = vec![];
+        loop {
+            values.push(self.value()?);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(',') => self.position += 1,
+                Some(c) if c == closing => {
+                    self.position += 1;
+                    break;
+                }
+                _ => return Err(self.error(&format!("`,` or `{closing}` expected"))),
+            }
+        }
+
+        Ok(Node { keyword, values })
+    }
+
+    fn value(&mut self) -> Result<Value, CrsError> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some('"') => self.text().map(Value::Text),
+            Some(c) if c.is_ascii_digit() || c == '-' || c == '+' || c == '.' => {
+                self.number().map(Value::Number)
+            }
+            _ => self.node().map(Value::Node),
+        }
+    }
+
+    fn text(&mut self) -> Result<String, CrsError> {
+        // Skip the opening quote.
+        self.position += 1;
+        let mut text = String::new();
+        loop {
+            let c = self
+                .peek()
+                .ok_or_else(|| self.error("unterminated string"))?;
+            self.position += c.len_utf8();
+            if c == '"' {
+                // Quotes inside strings are escaped by doubling them.
+                if self.peek() == Some('"') {
+                    self.position += 1;
+                } else {
+                    return Ok(text);
+                }
+            }
+            text.push(c);
+        }
+    }
+
+    fn number(&mut self) -> Result<f64, CrsError> {
+        let start = self.position;
+        while self
+            .peek()
+            .is_some_and(|c| c.is_ascii_digit() || matches!(c, '-' | '+' | '.' | 'e' | 'E'))
+        {
+            self.position += 1;
+        }
+
+        let number = &self.input[start..self.position];
+        number
+            .parse()
+            .map_err(|_| self.error(&format!("invalid number `{number}`")))
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.input[self.position..].chars().next()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.position += 1;
+        }
+    }
+
+    fn error(&self, message: &str) -> CrsError {
+        CrsError::InvalidDefinition(format!("{message} at position {}", self.position))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geo::crs::ProjectionType;
+    use crate::geo::impls::projection::lambert_conformal_conic::LambertConformalConicParams;
+    use crate::geo::impls::projection::polar_stereographic::PolarStereographicParams;
+    use crate::geo::impls::projection::transverse_mercator::TransverseMercatorParams;
+
+    const UTM_32N_WKT1: &str = r#"PROJCS["WGS 84 / UTM zone 32N",
+        GEOGCS["WGS 84",
+            DATUM["WGS_1984",SPHEROID["WGS 84",6378137,298.257223563,AUTHORITY["EPSG","7030"]]],
+            PRIMEM["Greenwich",0],
+            UNIT["degree",0.0174532925199433]],
+        PROJECTION["Transverse_Mercator"],
+        PARAMETER["latitude_of_origin",0],
+        PARAMETER["central_meridian",9],
+        PARAMETER["scale_factor",0.9996],
+        PARAMETER["false_easting",500000],
+        PARAMETER["false_northing",0],
+        UNIT["metre",1],
+        AXIS["Easting",EAST],
+        AXIS["Northing",NORTH]]"#;
+
+    const LAMBERT_93_WKT2: &str = r#"PROJCRS["RGF93 v1 / Lambert-93",
+        BASEGEOGCRS["RGF93 v1",
+            DATUM["Reseau Geodesique Francais 1993 v1",
+                ELLIPSOID["GRS 1980",6378137,298.257222101,LENGTHUNIT["metre",1]]],
+            PRIMEM["Greenwich",0,ANGLEUNIT["degree",0.0174532925199433]]],
+        CONVERSION["Lambert-93",
+            METHOD["Lambert Conic Conformal (2SP)",ID["EPSG",9802]],
+            PARAMETER["Latitude of false origin",46.5,ANGLEUNIT["degree",0.0174532925199433],ID["EPSG",8821]],
+            PARAMETER["Longitude of false origin",3,ANGLEUNIT["degree",0.0174532925199433],ID["EPSG",8822]],
+            PARAMETER["Latitude of 1st standard parallel",49,ANGLEUNIT["degree",0.0174532925199433],ID["EPSG",8823]],
+            PARAMETER["Latitude of 2nd standard parallel",44,ANGLEUNIT["degree",0.0174532925199433],ID["EPSG",8824]],
+            PARAMETER["Easting at false origin",700000,LENGTHUNIT["metre",1],ID["EPSG",8826]],
+            PARAMETER["Northing at false origin",6600000,LENGTHUNIT["metre",1],ID["EPSG",8827]]],
+        CS[Cartesian,2],
+            AXIS["easting (X)",east,ORDER[1],LENGTHUNIT["metre",1]],
+            AXIS["northing (Y)",north,ORDER[2],LENGTHUNIT["metre",1]]]"#;
+
+    #[test]
+    fn wkt1_projected() {
+        let crs = parse(UTM_32N_WKT1).unwrap();
+        assert_eq!(
+            crs.projection_type(),
+            &ProjectionType::TransverseMercator(TransverseMercatorParams::utm(32, false))
+        );
+
+        // With the authority code the registry definition is used.
+        let with_authority = UTM_32N_WKT1.replace(
+            r#"AXIS["Northing",NORTH]]"#,
+            r#"AXIS["Northing",NORTH],AUTHORITY["EPSG","32632"]]"#,
+        );
+        assert_eq!(
+            parse(&with_authority).unwrap(),
+            Crs::from_epsg(32632).unwrap()
+        );
+    }
+
+    #[test]
+    fn wkt2_projected() {
+        let crs = parse(LAMBERT_93_WKT2).unwrap();
+        assert_eq!(crs.datum(), &Datum::GRS80);
+        assert_eq!(
+            crs.projection_type(),
+            &ProjectionType::LambertConformalConic(LambertConformalConicParams {
+                standard_parallel_1: 49.0,
+                standard_parallel_2: 44.0,
+                latitude_of_origin: 46.5,
+                central_meridian: 3.0,
+                scale_factor: 1.0,
+                false_easting: 700_000.0,
+                false_northing: 6_600_000.0,
+            })
+        );
+
+        let with_id = format!(
+            "{},ID[\"EPSG\",2154]]",
+            LAMBERT_93_WKT2.strip_suffix(']').unwrap()
+        );
+        assert_eq!(parse(&with_id).unwrap(), Crs::from_epsg(2154).unwrap());
+    }
+
+    #[test]
+    fn geographic() {
+        let wkt2 = r#"GEOGCRS["WGS 84",
+            ENSEMBLE["World Geodetic System 1984 ensemble",
+                MEMBER["World Geodetic System 1984 (Transit)"],
+                ELLIPSOID["WGS 84",6378137,298.257223563,LENGTHUNIT["metre",1]],
+                ENSEMBLEACCURACY[2.0]],
+            PRIMEM["Greenwich",0,ANGLEUNIT["degree",0.0174532925199433]],
+            CS[ellipsoidal,2],
+                AXIS["geodetic latitude (Lat)",north,ORDER[1]],
+                AXIS["geodetic longitude (Lon)",east,ORDER[2]]]"#;
+        assert_eq!(parse(wkt2).unwrap(), Crs::WGS84);
+
+        let esri = r#"GEOGCS["GCS_OSGB_1936",DATUM["D_OSGB_1936",SPHEROID["Airy_1830",6377563.396,299.3249646]],PRIMEM["Greenwich",0.0],UNIT["Degree",0.0174532925199433]]"#;
+        let crs = parse(esri).unwrap();
+        assert!(crs.is_geographic());
+        assert_eq!(crs.datum(), &Datum::OSGB36);
+
+        let towgs84 = r#"GEOGCS["ED50",DATUM["European_Datum_1950",SPHEROID["International 1924",6378388,297],TOWGS84[-87,-98,-121,0,0,0,0]],PRIMEM["Greenwich",0],UNIT["degree",0.0174532925199433]]"#;
+        assert_eq!(parse(towgs84).unwrap().datum(), &Datum::ED50);
+
+        let unknown_datum = esri.replace("D_OSGB_1936", "D_Unknown");
+        assert_eq!(parse(&unknown_datum).unwrap().datum(), &Datum::AIRY1830);
+    }
+
+    #[test]
+    fn esri_web_mercator() {
+        let wkt = r#"PROJCS["WGS_1984_Web_Mercator_Auxiliary_Sphere",GEOGCS["GCS_WGS_1984",DATUM["D_WGS_1984",SPHEROID["WGS_1984",6378137.0,298.257223563]],PRIMEM["Greenwich",0.0],UNIT["Degree",0.0174532925199433]],PROJECTION["Mercator_Auxiliary_Sphere"],PARAMETER["False_Easting",0.0],PARAMETER["False_Northing",0.0],PARAMETER["Central_Meridian",0.0],PARAMETER["Standard_Parallel_1",0.0],PARAMETER["Auxiliary_Sphere_Type",0.0],UNIT["Meter",1.0]]"#;
+        assert_eq!(parse(wkt).unwrap(), Crs::EPSG3857);
+    }
+
+    #[test]
+    fn polar_stereographic() {
+        let expected = ProjectionType::PolarStereographic(PolarStereographicParams {
+            south: false,
+            latitude_of_true_scale: Some(70.0),
+            central_meridian: -45.0,
+            scale_factor: 1.0,
+            false_easting: 0.0,
+            false_northing: 0.0,
+        });
+
+        let gdal = r#"PROJCS["Polar stereographic",GEOGCS["WGS 84",DATUM["WGS_1984",SPHEROID["WGS 84",6378137,298.257223563]]],PROJECTION["Polar_Stereographic"],PARAMETER["latitude_of_origin",70],PARAMETER["central_meridian",-45],PARAMETER["false_easting",0],PARAMETER["false_northing",0],UNIT["metre",1]]"#;
+        assert_eq!(parse(gdal).unwrap().projection_type(), &expected);
+
+        let esri = r#"PROJCS["Polar stereographic",GEOGCS["GCS_WGS_1984",DATUM["D_WGS_1984",SPHEROID["WGS_1984",6378137.0,298.257223563]]],PROJECTION["Stereographic_North_Pole"],PARAMETER["False_Easting",0.0],PARAMETER["False_Northing",0.0],PARAMETER["Central_Meridian",-45.0],PARAMETER["Standard_Parallel_1",70.0],UNIT["Meter",1.0]]"#;
+        assert_eq!(parse(esri).unwrap().projection_type(), &expected);
+    }
+
+    #[test]
+    fn errors() {
+        assert!(matches!(
+            parse(r#"GEOGCS["WGS 84",DATUM["WGS_1984""#),
+            Err(CrsError::InvalidDefinition(_))
+        ));
+        assert!(matches!(
+            parse(r#"GEOGCS["WGS 84"] trailing"#),
+            Err(CrsError::InvalidDefinition(_))
+        ));
+        assert_eq!(
+            parse(r#"GEOGCS["WGS 84"]"#),
+            Err(CrsError::MissingParameter("ELLIPSOID".into()))
+        );
+
+        let feet = UTM_32N_WKT1.replace(
+            r#"UNIT["metre",1]"#,
+            r#"UNIT["US survey foot",0.304800609601219]"#,
+        );
+        assert_eq!(
+            parse(&feet),
+            Err(CrsError::UnsupportedUnit("US survey foot".into()))
+        );
+
+        let robinson = UTM_32N_WKT1.replace("Transverse_Mercator", "Robinson");
+        assert_eq!(
+            parse(&robinson),
+            Err(CrsError::UnsupportedProjection("Robinson".into()))
+        );
+    }
+}
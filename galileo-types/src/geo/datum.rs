@@ -1,17 +1,104 @@
+use crate::geo::datum_shift::Helmert;
 use serde::{Deserialize, Serialize};
 
+/// Geodetic datum: the ellipsoid and its position relative to WGS84.
+///
+/// The position is given by the Helmert transformation from the datum to WGS84 (see [`Datum::to_wgs84`]). The
+/// constants named after ellipsoids (e.g. [`Datum::AIRY1830`]) don't have the transformation and are considered to
+/// be positioned as WGS84.
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct Datum {
     semimajor: f64,
     inv_flattening: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    to_wgs84: Option<Helmert>,
 }
 
 impl Datum {
     pub const WGS84: Self = Datum {
         semimajor: 6_378_137.0,
         inv_flattening: 298.257223563,
+        to_wgs84: None,
     };
 
+    /// GRS 1980 ellipsoid used by ETRS89, NAD83, GDA94 and other modern national datums.
+    pub const GRS80: Self = Datum {
+        semimajor: 6_378_137.0,
+        inv_flattening: 298.257222101,
+        to_wgs84: None,
+    };
+
+    /// Airy 1830 ellipsoid of the OSGB36 datum.
+    pub const AIRY1830: Self = Datum {
+        semimajor: 6_377_563.396,
+        inv_flattening: 299.3249646,
+        to_wgs84: None,
+    };
+
+    /// Bessel 1841 ellipsoid of the DHDN, MGI and Tokyo datums.
+    pub const BESSEL1841: Self = Datum {
+        semimajor: 6_377_397.155,
+        inv_flattening: 299.1528128,
+        to_wgs84: None,
+    };
+
+    /// Clarke 1866 ellipsoid of the NAD27 datum.
+    pub const CLARKE1866: Self = Datum {
+        semimajor: 6_378_206.4,
+        inv_flattening: 294.978698214,
+        to_wgs84: None,
+    };
+
+    /// International 1924 (Hayford) ellipsoid of the ED50 datum.
+    pub const INTERNATIONAL1924: Self = Datum {
+        semimajor: 6_378_388.0,
+        inv_flattening: 297.0,
+        to_wgs84: None,
+    };
+
+    /// Krassovsky 1940 ellipsoid of the Pulkovo 1942 datum.
+    pub const KRASSOVSKY1940: Self = Datum {
+        semimajor: 6_378_245.0,
+        inv_flattening: 298.3,
+        to_wgs84: None,
+    };
+
+    /// OSGB 1936 datum of the Ordnance Survey of Great Britain.
+    pub const OSGB36: Self = Self::AIRY1830.with_to_wgs84(Helmert::new(
+        446.448, -125.157, 542.06, 0.15, 0.247, 0.842, -20.489,
+    ));
+
+    /// European Datum 1950.
+    pub const ED50: Self =
+        Self::INTERNATIONAL1924.with_to_wgs84(Helmert::translation(-87.0, -98.0, -121.0));
+
+    /// North American Datum 1927.
+    pub const NAD27: Self =
+        Self::CLARKE1866.with_to_wgs84(Helmert::translation(-8.0, 160.0, 176.0));
+
+    /// Pulkovo 1942 datum.
+    pub const PULKOVO1942: Self = Self::KRASSOVSKY1940
+        .with_to_wgs84(Helmert::new(23.92, -141.27, -80.9, 0.0, 0.35, 0.82, -0.12));
+
+    /// Deutsches Hauptdreiecksnetz (Potsdam datum).
+    pub const DHDN: Self =
+        Self::BESSEL1841.with_to_wgs84(Helmert::new(598.1, 73.7, 418.2, 0.202, 0.045, -2.455, 6.7));
+
+    /// Creates a datum with the given ellipsoid. Inverse flattening of `0` defines a sphere.
+    pub const fn new(semimajor: f64, inv_flattening: f64) -> Self {
+        Self {
+            semimajor,
+            inv_flattening,
+            to_wgs84: None,
+        }
+    }
+
+    /// Sets the transformation from this datum to WGS84.
+    pub const fn with_to_wgs84(mut self, to_wgs84: Helmert) -> Self {
+        self.to_wgs84 = Some(to_wgs84);
+        self
+    }
+
     pub fn semimajor(&self) -> f64 {
         self.semimajor
     }
@@ -19,6 +106,22 @@ impl Datum {
     pub fn inv_flattening(&self) -> f64 {
         self.inv_flattening
     }
+
+    /// Helmert transformation of geocentric coordinates from this datum to WGS84. `None` if the datum is positioned as
+    /// WGS84.
+    pub fn to_wgs84(&self) -> Option<&Helmert> {
+        self.to_wgs84.as_ref()
+    }
+
+    /// Returns true if the datums have the same ellipsoid, regardless of their positions.
+    pub fn same_ellipsoid(&self, other: &Datum) -> bool {
+        self.semimajor == other.semimajor && self.inv_flattening == other.inv_flattening
+    }
+
+    /// Returns true if the ellipsoid of the datum is a sphere.
+    pub fn is_sphere(&self) -> bool {
+        self.inv_flattening == 0.0
+    }
 }
 
 impl Default for Datum {
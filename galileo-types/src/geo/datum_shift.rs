@@ -0,0 +1,651 @@
+//! Transformations of coordinates between datums.
+//!
+//! Datums are transformed through WGS84: the geodetic coordinates are converted into geocentric coordinates of the
+//! source datum, moved into WGS84 with the [`Helmert`] transformation of the source datum, moved into the target datum
+//! with the inverse transformation of the target datum and converted back to geodetic coordinates. This is accurate to
+//! a few meters, which is enough for most maps. For better accuracy, a national [`GridShift`] can be used instead.
+
+use crate::geo::datum::Datum;
+use crate::geo::impls::point::GeoPoint2d;
+use crate::geo::traits::point::{GeoPoint, NewGeoPoint};
+use crate::geo::traits::projection::{Projection, ProjectionError};
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+use std::path::Path;
+use std::sync::Arc;
+use thiserror::Error;
+
+const ARC_SECOND: f64 = std::f64::consts::PI / (180.0 * 3600.0);
+
+/// Seven-parameter Helmert transformation of geocentric coordinates with the position vector rotation convention, as
+/// used by the `+towgs84` parameter of PROJ strings and the `TOWGS84` node of WKT.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Helmert {
+    /// Translation along the X axis in meters.
+    pub tx: f64,
+    /// Translation along the Y axis in meters.
+    pub ty: f64,
+    /// Translation along the Z axis in meters.
+    pub tz: f64,
+    /// Rotation around the X axis in arc-seconds.
+    pub rx: f64,
+    /// Rotation around the Y axis in arc-seconds.
+    pub ry: f64,
+    /// Rotation around the Z axis in arc-seconds.
+    pub rz: f64,
+    /// Scale correction in parts per million.
+    pub scale: f64,
+}
+
+impl Helmert {
+    pub const fn new(tx: f64, ty: f64, tz: f64, rx: f64, ry: f64, rz: f64, scale: f64) -> Self {
+        Self {
+            tx,
+            ty,
+            tz,
+            rx,
+            ry,
+            rz,
+            scale,
+        }
+    }
+
+    /// Three-parameter transformation with only translations.
+    pub const fn translation(tx: f64, ty: f64, tz: f64) -> Self {
+        Self::new(tx, ty, tz, 0.0, 0.0, 0.0, 0.0)
+    }
+
+    /// Transforms the geocentric coordinates.
+    pub fn apply(&self, [x, y, z]: [f64; 3]) -> [f64; 3] {
+        let (rx, ry, rz) = self.rotations();
+        let m = 1.0 + self.scale * 1e-6;
+        [
+            self.tx + m * (x - rz * y + ry * z),
+            self.ty + m * (rz * x + y - rx * z),
+            self.tz + m * (-ry * x + rx * y + z),
+        ]
+    }
+
+    /// Applies the inverse transformation to the geocentric coordinates.
+    pub fn apply_inverse(&self, [x, y, z]: [f64; 3]) -> [f64; 3] {
+        let (rx, ry, rz) = self.rotations();
+        let m = 1.0 + self.scale * 1e-6;
+        let (x, y, z) = ((x - self.tx) / m, (y - self.ty) / m, (z - self.tz) / m);
+
+        // The rotation matrix is orthogonal, so its inverse is the transposed matrix.
+        [
+            x + rz * y - ry * z,
+            -rz * x + y + rx * z,
+            ry * x - rx * y + z,
+        ]
+    }
+
+    fn rotations(&self) -> (f64, f64, f64) {
+        (
+            self.rx * ARC_SECOND,
+            self.ry * ARC_SECOND,
+            self.rz * ARC_SECOND,
+        )
+    }
+}
+
+impl Datum {
+    /// Converts geodetic coordinates (latitude and longitude in degrees, ellipsoidal height in meters) into geocentric
+    /// cartesian coordinates in meters.
+    pub fn geodetic_to_geocentric(&self, lat: f64, lon: f64, height: f64) -> [f64; 3] {
+        let e2 = self.eccentricity_squared();
+        let (sin_lat, cos_lat) = lat.to_radians().sin_cos();
+        let (sin_lon, cos_lon) = lon.to_radians().sin_cos();
+        let n = self.semimajor() / (1.0 - e2 * sin_lat * sin_lat).sqrt();
+
+        [
+            (n + height) * cos_lat * cos_lon,
+            (n + height) * cos_lat * sin_lon,
+            (n * (1.0 - e2) + height) * sin_lat,
+        ]
+    }
+
+    /// Converts geocentric cartesian coordinates in meters into geodetic coordinates: latitude and longitude in degrees
+    /// and ellipsoidal height in meters.
+    pub fn geocentric_to_geodetic(&self, [x, y, z]: [f64; 3]) -> (f64, f64, f64) {
+        let e2 = self.eccentricity_squared();
+        let a = self.semimajor();
+        let p = x.hypot(y);
+        let lon = y.atan2(x);
+
+        if p < 1e-9 {
+            let lat = if z >= 0.0 { 90.0 } else { -90.0 };
+            return (lat, lon.to_degrees(), z.abs() - self.semiminor());
+        }
+
+        let mut lat = z.atan2(p * (1.0 - e2));
+        let mut height = 0.0;
+        for _ in 0..10 {
+            let sin_lat = lat.sin();
+            let n = a / (1.0 - e2 * sin_lat * sin_lat).sqrt();
+            height = p / lat.cos() - n;
+            let next = z.atan2(p * (1.0 - e2 * n / (n + height)));
+            let converged = (next - lat).abs() < 1e-14;
+            lat = next;
+            if converged {
+                break;
+            }
+        }
+
+        (lat.to_degrees(), lon.to_degrees(), height)
+    }
+
+    /// Transforms the point on the surface of this datum into the target datum. See the [module docs](self) for
+    /// details.
+    pub fn transform_point(&self, point: &impl GeoPoint<Num = f64>, target: &Datum) -> GeoPoint2d {
+        if self == target {
+            return GeoPoint2d::latlon(point.lat(), point.lon());
+        }
+
+        let mut geocentric = self.geodetic_to_geocentric(point.lat(), point.lon(), 0.0);
+        if let Some(to_wgs84) = self.to_wgs84() {
+            geocentric = to_wgs84.apply(geocentric);
+        }
+        if let Some(to_wgs84) = target.to_wgs84() {
+            geocentric = to_wgs84.apply_inverse(geocentric);
+        }
+
+        let (lat, lon, _) = target.geocentric_to_geodetic(geocentric);
+        GeoPoint2d::latlon(lat, lon)
+    }
+}
+
+/// Projection of geographic coordinates from one datum into another.
+///
+/// By default the transformation is done through WGS84 with the Helmert transformations of the datums. If a grid
+/// shift is set with [`DatumShift::with_grid`], it is used instead.
+///
+/// This projection is usually put into a [`ChainProjection`](crate::geo::traits::projection::ChainProjection) between
+/// the inverse projection of the source CRS and the projection of the target CRS.
+pub struct DatumShift<In, Out> {
+    source: Datum,
+    target: Datum,
+    grid: Option<Arc<GridShift>>,
+    phantom: PhantomData<(In, Out)>,
+}
+
+impl<In, Out> DatumShift<In, Out> {
+    pub fn new(source: Datum, target: Datum) -> Self {
+        Self {
+            source,
+            target,
+            grid: None,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Uses the grid shift from the source datum into the target datum instead of the Helmert transformations.
+    pub fn with_grid(mut self, grid: Arc<GridShift>) -> Self {
+        self.grid = Some(grid);
+        self
+    }
+
+    /// Returns true if the projection does not change the coordinates.
+    pub fn is_identity(&self) -> bool {
+        self.grid.is_none() && self.source == self.target
+    }
+}
+
+impl<In: NewGeoPoint<f64>, Out: NewGeoPoint<f64>> Projection for DatumShift<In, Out> {
+    type InPoint = In;
+    type OutPoint = Out;
+
+    fn project(&self, input: &Self::InPoint) -> Result<Self::OutPoint, ProjectionError> {
+        let point = match &self.grid {
+            Some(grid) => grid
+                .apply(input)
+                .ok_or_else(|| ProjectionError::OutOfDomain {
+                    x: input.lon(),
+                    y: input.lat(),
+                })?,
+            None => self.source.transform_point(input, &self.target),
+        };
+        Ok(Out::latlon(point.lat(), point.lon()))
+    }
+
+    fn unproject(&self, input: &Self::OutPoint) -> Result<Self::InPoint, ProjectionError> {
+        let point = match &self.grid {
+            Some(grid) => {
+                grid.apply_inverse(input)
+                    .ok_or_else(|| ProjectionError::OutOfDomain {
+                        x: input.lon(),
+                        y: input.lat(),
+                    })?
+            }
+            None => self.target.transform_point(input, &self.source),
+        };
+        Ok(In::latlon(point.lat(), point.lon()))
+    }
+}
+
+/// Error loading a grid shift file.
+#[derive(Debug, Error)]
+pub enum GridShiftError {
+    #[error("failed to read grid shift file: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("invalid NTv2 grid: {0}")]
+    InvalidFormat(String),
+}
+
+/// Datum shift given by a grid of latitude and longitude offsets, loaded from an NTv2 (`.gsb`) file.
+///
+/// NTv2 grids are published by national mapping agencies for the transformations of the old national datums, e.g.
+/// `ntv2_0.gsb` for NAD27 to NAD83 in Canada or `BETA2007.gsb` for DHDN to ETRS89 in Germany.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GridShift {
+    subgrids: Vec<Subgrid>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Subgrid {
+    /// Latitude of the southern edge in arc-seconds.
+    south: f64,
+    /// Latitude of the northern edge in arc-seconds.
+    north: f64,
+    /// Longitude of the eastern edge in arc-seconds, positive to the west.
+    east: f64,
+    /// Longitude of the western edge in arc-seconds, positive to the west.
+    west: f64,
+    lat_step: f64,
+    lon_step: f64,
+    columns: usize,
+    /// Latitude and longitude (positive to the west) offsets in arc-seconds, row by row from the south, every row
+    /// from the east.
+    shifts: Vec<(f32, f32)>,
+}
+
+const RECORD_SIZE: usize = 16;
+const OVERVIEW_RECORDS: usize = 11;
+const SUBGRID_RECORDS: usize = 11;
+
+impl GridShift {
+    /// Loads the grid from the NTv2 file at the given path.
+    pub fn from_ntv2_file(path: impl AsRef<Path>) -> Result<Self, GridShiftError> {
+        Self::from_ntv2(&std::fs::read(path)?)
+    }
+
+    /// Loads the grid from the contents of an NTv2 file.
+    pub fn from_ntv2(data: &[u8]) -> Result<Self, GridShiftError> {
+        let reader = Ntv2Reader::new(data)?;
+        let subgrid_count = reader.int(2 * RECORD_SIZE)?;
+        let units = reader.text(3 * RECORD_SIZE)?;
+        if !units.eq_ignore_ascii_case("SECONDS") {
+            return Err(invalid(format!("unsupported units {units}")));
+        }
+
+        let mut offset = OVERVIEW_RECORDS * RECORD_SIZE;
+        // Sizes read from the file are not trusted for allocations until the data is checked to be long enough.
+        let max_subgrids = data.len().saturating_sub(offset) / (SUBGRID_RECORDS * RECORD_SIZE);
+        let mut subgrids = Vec::with_capacity(subgrid_count.min(max_subgrids));
+        for _ in 0..subgrid_count {
+            let field = |index: usize| offset + index * RECORD_SIZE;
+            let south = reader.float(field(4))?;
+            let north = reader.float(field(5))?;
+            let east = reader.float(field(6))?;
+            let west = reader.float(field(7))?;
+            let lat_step = reader.float(field(8))?;
+            let lon_step = reader.float(field(9))?;
+            let count = reader.int(field(10))?;
+
+            if !(lat_step > 0.0 && lon_step > 0.0 && north > south && west > east) {
+                return Err(invalid("invalid subgrid extent".into()));
+            }
+            let rows = ((north - south) / lat_step).round() as usize + 1;
+            let columns = ((west - east) / lon_step).round() as usize + 1;
+            if rows.checked_mul(columns) != Some(count) {
+                return Err(invalid(format!(
+                    "subgrid has {count} nodes instead of {rows}x{columns}"
+                )));
+            }
+
+            offset += SUBGRID_RECORDS * RECORD_SIZE;
+            let nodes_end = count
+                .checked_mul(RECORD_SIZE)
+                .and_then(|size| size.checked_add(offset));
+            if nodes_end.is_none_or(|end| end > data.len()) {
+                return Err(invalid("unexpected end of file".into()));
+            }
+
+            let mut shifts = Vec::with_capacity(count);
+            for _ in 0..count {
+                shifts.push((reader.f32(offset)?, reader.f32(offset + 4)?));
+                offset += RECORD_SIZE;
+            }
+
+            subgrids.push(Subgrid {
+                south,
+                north,
+                east,
+                west,
+                lat_step,
+                lon_step,
+                columns,
+                shifts,
+            });
+        }
+
+        Ok(Self { subgrids })
+    }
+
+    /// Shifts the point from the source datum of the grid into the target datum. Returns `None` if the point is
+    /// outside the grid.
+    pub fn apply(&self, point: &impl GeoPoint<Num = f64>) -> Option<GeoPoint2d> {
+        let (lat, lon) = (point.lat(), point.lon());
+        let (d_lat, d_lon) = self.shift(lat, lon)?;
+        Some(GeoPoint2d::latlon(lat + d_lat, lon + d_lon))
+    }
+
+    /// Shifts the point from the target datum of the grid back into the source datum. Returns `None` if the point is
+    /// outside the grid.
+    pub fn apply_inverse(&self, point: &impl GeoPoint<Num = f64>) -> Option<GeoPoint2d> {
+        let (lat, lon) = (point.lat(), point.lon());
+
+        // The shifts change slowly, so a few fixed point iterations are enough for sub-millimeter accuracy.
+        let (mut source_lat, mut source_lon) = (lat, lon);
+        for _ in 0..4 {
+            let (d_lat, d_lon) = self.shift(source_lat, source_lon)?;
+            source_lat = lat - d_lat;
+            source_lon = lon - d_lon;
+        }
+
+        Some(GeoPoint2d::latlon(source_lat, source_lon))
+    }
+
+    /// Latitude and longitude shifts in degrees at the given point, interpolated from the finest subgrid containing
+    /// the point.
+    fn shift(&self, lat: f64, lon: f64) -> Option<(f64, f64)> {
+        let lat_seconds = lat * 3600.0;
+        let west_seconds = -lon * 3600.0;
+        let subgrid = self
+            .subgrids
+            .iter()
+            .filter(|grid| {
+                (grid.south..=grid.north).contains(&lat_seconds)
+                    && (grid.east..=grid.west).contains(&west_seconds)
+            })
+            .min_by(|a, b| (a.lat_step * a.lon_step).total_cmp(&(b.lat_step * b.lon_step)))?;
+
+        let (d_lat, d_lon_west) = subgrid.interpolate(lat_seconds, west_seconds);
+        Some((d_lat / 3600.0, -d_lon_west / 3600.0))
+    }
+}
+
+impl Subgrid {
+    fn interpolate(&self, lat: f64, lon_west: f64) -> (f64, f64) {
+        let rows = self.shifts.len() / self.columns;
+        let y = (lat - self.south) / self.lat_step;
+        let x = (lon_west - self.east) / self.lon_step;
+        let row = (y.floor() as usize).min(rows.saturating_sub(2));
+        let column = (x.floor() as usize).min(self.columns.saturating_sub(2));
+        let fy = y - row as f64;
+        let fx = x - column as f64;
+
+        let node = |row: usize, column: usize| {
+            let index = (row * self.columns + column).min(self.shifts.len() - 1);
+            let (d_lat, d_lon) = self.shifts[index];
+            (d_lat as f64, d_lon as f64)
+        };
+        let (lat00, lon00) = node(row, column);
+        let (lat01, lon01) = node(row, column + 1);
+        let (lat10, lon10) = node(row + 1, column);
+        let (lat11, lon11) = node(row + 1, column + 1);
+
+        let bilinear = |v00: f64, v01: f64, v10: f64, v11: f64| {
+            v00 * (1.0 - fx) * (1.0 - fy)
+                + v01 * fx * (1.0 - fy)
+                + v10 * (1.0 - fx) * fy
+                + v11 * fx * fy
+        };
+
+        (
+            bilinear(lat00, lat01, lat10, lat11),
+            bilinear(lon00, lon01, lon10, lon11),
+        )
+    }
+}
+
+/// Reader of the NTv2 records. The byte order of the file is detected by the first record, which must be the number
+/// of the overview records.
+struct Ntv2Reader<'a> {
+    data: &'a [u8],
+    little_endian: bool,
+}
+
+impl<'a> Ntv2Reader<'a> {
+    fn new(data: &'a [u8]) -> Result<Self, GridShiftError> {
+        let mut reader = Self {
+            data,
+            little_endian: true,
+        };
+        if reader.int(0)? != OVERVIEW_RECORDS {
+            reader.little_endian = false;
+            if reader.int(0)? != OVERVIEW_RECORDS {
+                return Err(invalid("unexpected header".into()));
+            }
+        }
+
+        Ok(reader)
+    }
+
+    fn bytes<const N: usize>(&self, offset: usize) -> Result<[u8; N], GridShiftError> {
+        self.data
+            .get(offset..offset + N)
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or_else(|| invalid("unexpected end of file".into()))
+    }
+
+    /// Integer value of the record at the given offset.
+    fn int(&self, offset: usize) -> Result<usize, GridShiftError> {
+        let bytes = self.bytes::<4>(offset + 8)?;
+        let value = if self.little_endian {
+            i32::from_le_bytes(bytes)
+        } else {
+            i32::from_be_bytes(bytes)
+        };
+        usize::try_from(value).map_err(|_| invalid(format!("invalid record value {value}")))
+    }
+
+    /// Floating point value of the record at the given offset.
+    fn float(&self, offset: usize) -> Result<f64, GridShiftError> {
+        let bytes = self.bytes::<8>(offset + 8)?;
+        Ok(if self.little_endian {
+            f64::from_le_bytes(bytes)
+        } else {
+            f64::from_be_bytes(bytes)
+        })
+    }
+
+    /// Text value of the record at the given offset.
+    fn text(&self, offset: usize) -> Result<String, GridShiftError> {
+        let bytes = self.bytes::<8>(offset + 8)?;
+        Ok(String::from_utf8_lossy(&bytes).trim().to_string())
+    }
+
+    fn f32(&self, offset: usize) -> Result<f32, GridShiftError> {
+        let bytes = self.bytes::<4>(offset)?;
+        Ok(if self.little_endian {
+            f32::from_le_bytes(bytes)
+        } else {
+            f32::from_be_bytes(bytes)
+        })
+    }
+}
+
+fn invalid(message: String) -> GridShiftError {
+    GridShiftError::InvalidFormat(message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn geocentric_conversion() {
+        // Example from the EPSG Guidance Note 7-2.
+        let datum = Datum::WGS84;
+        let lat = 53.0 + 48.0 / 60.0 + 33.820 / 3600.0;
+        let lon = 2.0 + 7.0 / 60.0 + 46.380 / 3600.0;
+        let [x, y, z] = datum.geodetic_to_geocentric(lat, lon, 73.0);
+        assert!((x - 3_771_793.968).abs() < 1e-3);
+        assert!((y - 140_253.342).abs() < 1e-3);
+        assert!((z - 5_124_304.349).abs() < 1e-3);
+
+        let (lat2, lon2, height) = datum.geocentric_to_geodetic([x, y, z]);
+        assert!((lat2 - lat).abs() < 1e-11);
+        assert!((lon2 - lon).abs() < 1e-11);
+        assert!((height - 73.0).abs() < 1e-6);
+
+        let (lat, _, height) = datum.geocentric_to_geodetic([0.0, 0.0, -datum.semiminor()]);
+        assert_eq!(lat, -90.0);
+        assert!(height.abs() < 1e-9);
+    }
+
+    #[test]
+    fn helmert_transformation() {
+        // WGS72 to WGS84 example from the EPSG Guidance Note 7-2.
+        let helmert = Helmert::new(0.0, 0.0, 4.5, 0.0, 0.0, 0.554, 0.219);
+        let source = [3_657_660.66, 255_768.55, 5_201_382.11];
+        let [x, y, z] = helmert.apply(source);
+        assert!((x - 3_657_660.78).abs() < 1e-2);
+        assert!((y - 255_778.43).abs() < 1e-2);
+        assert!((z - 5_201_387.75).abs() < 1e-2);
+
+        let [x, y, z] = helmert.apply_inverse([x, y, z]);
+        assert!((x - source[0]).abs() < 1e-3);
+        assert!((y - source[1]).abs() < 1e-3);
+        assert!((z - source[2]).abs() < 1e-3);
+    }
+
+    #[test]
+    fn datum_transformation() {
+        let point = GeoPoint2d::latlon(48.0, 10.0);
+        let wgs84 = Datum::ED50.transform_point(&point, &Datum::WGS84);
+
+        // ED50 coordinates in central Europe differ from WGS84 by about 100 meters.
+        let offset = point.distance(&wgs84, &Datum::WGS84).unwrap();
+        assert!(offset > 50.0 && offset < 200.0, "offset: {offset}");
+
+        // Heights are dropped in the transformation, which gives millimeter errors on the way back.
+        let back = Datum::WGS84.transform_point(&wgs84, &Datum::ED50);
+        assert!((back.lat() - point.lat()).abs() < 1e-7);
+        assert!((back.lon() - point.lon()).abs() < 1e-7);
+
+        assert_eq!(Datum::WGS84.transform_point(&point, &Datum::WGS84), point);
+
+        let shift = DatumShift::<GeoPoint2d, GeoPoint2d>::new(Datum::ED50, Datum::OSGB36);
+        assert!(!shift.is_identity());
+        let projected = shift.project(&point).unwrap();
+        let unprojected = shift.unproject(&projected).unwrap();
+        assert!((unprojected.lat() - point.lat()).abs() < 1e-7);
+        assert!((unprojected.lon() - point.lon()).abs() < 1e-7);
+    }
+
+    /// Builds an NTv2 file with one subgrid covering `[0, 2]` latitude and `[-2, 0]` longitude with 1 degree step.
+    fn test_grid(big_endian: bool) -> Vec<u8> {
+        let mut data = vec![];
+        let int = |data: &mut Vec<u8>, key: &str, value: i32| {
+            data.extend(format!("{key:<8}").bytes());
+            data.extend(if big_endian {
+                value.to_be_bytes()
+            } else {
+                value.to_le_bytes()
+            });
+            data.extend([0; 4]);
+        };
+        let float = |data: &mut Vec<u8>, key: &str, value: f64| {
+            data.extend(format!("{key:<8}").bytes());
+            data.extend(if big_endian {
+                value.to_be_bytes()
+            } else {
+                value.to_le_bytes()
+            });
+        };
+        let text = |data: &mut Vec<u8>, key: &str, value: &str| {
+            data.extend(format!("{key:<8}{value:<8}").bytes());
+        };
+
+        int(&mut data, "NUM_OREC", 11);
+        int(&mut data, "NUM_SREC", 11);
+        int(&mut data, "NUM_FILE", 1);
+        text(&mut data, "GS_TYPE", "SECONDS");
+        text(&mut data, "VERSION", "NTv2.0");
+        text(&mut data, "SYSTEM_F", "TEST");
+        text(&mut data, "SYSTEM_T", "WGS84");
+        float(&mut data, "MAJOR_F", 6_378_137.0);
+        float(&mut data, "MINOR_F", 6_356_752.314);
+        float(&mut data, "MAJOR_T", 6_378_137.0);
+        float(&mut data, "MINOR_T", 6_356_752.314);
+
+        text(&mut data, "SUB_NAME", "TEST");
+        text(&mut data, "PARENT", "NONE");
+        text(&mut data, "CREATED", "");
+        text(&mut data, "UPDATED", "");
+        float(&mut data, "S_LAT", 0.0);
+        float(&mut data, "N_LAT", 7200.0);
+        float(&mut data, "E_LONG", 0.0);
+        float(&mut data, "W_LONG", 7200.0);
+        float(&mut data, "LAT_INC", 3600.0);
+        float(&mut data, "LONG_INC", 3600.0);
+        int(&mut data, "GS_COUNT", 9);
+
+        for row in 0..3 {
+            for column in 0..3 {
+                // Latitude shift grows to the north, longitude shift (positive west) grows to the west.
+                let values = [row as f32, column as f32, 0.0, 0.0];
+                for value in values {
+                    data.extend(if big_endian {
+                        value.to_be_bytes()
+                    } else {
+                        value.to_le_bytes()
+                    });
+                }
+            }
+        }
+
+        data
+    }
+
+    #[test]
+    fn ntv2_grid_shift() {
+        for big_endian in [false, true] {
+            let grid = GridShift::from_ntv2(&test_grid(big_endian)).unwrap();
+
+            let point = GeoPoint2d::latlon(1.5, -0.5);
+            let shifted = grid.apply(&point).unwrap();
+            assert!((shifted.lat() - (1.5 + 1.5 / 3600.0)).abs() < 1e-12);
+            assert!((shifted.lon() - (-0.5 - 0.5 / 3600.0)).abs() < 1e-12);
+
+            let back = grid.apply_inverse(&shifted).unwrap();
+            assert!((back.lat() - point.lat()).abs() < 1e-9);
+            assert!((back.lon() - point.lon()).abs() < 1e-9);
+
+            assert_eq!(grid.apply(&GeoPoint2d::latlon(3.0, -0.5)), None);
+            assert_eq!(grid.apply(&GeoPoint2d::latlon(1.0, 0.5)), None);
+
+            let shift = DatumShift::<GeoPoint2d, GeoPoint2d>::new(Datum::WGS84, Datum::WGS84)
+                .with_grid(Arc::new(grid));
+            assert!(!shift.is_identity());
+            assert_eq!(shift.project(&point), Ok(shifted));
+        }
+    }
+
+    #[test]
+    fn invalid_ntv2() {
+        assert!(matches!(
+            GridShift::from_ntv2(&[0; 10]),
+            Err(GridShiftError::InvalidFormat(_))
+        ));
+
+        let mut data = test_grid(false);
+        data.truncate(data.len() - 8);
+        assert!(matches!(
+            GridShift::from_ntv2(&data),
+            Err(GridShiftError::InvalidFormat(_))
+        ));
+    }
+}
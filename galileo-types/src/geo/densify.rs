@@ -0,0 +1,330 @@
+//! Densification of geographic geometries before projection.
+//!
+//! A segment between two vertices of a geographic geometry is projected as a straight line between the projected
+//! vertices, while the real path between them (e.g. a flight route) is curved on most projected maps. [`Densify`]
+//! adds intermediate points along the geodesic or the rhumb line of every long segment, so that the projected
+//! geometry follows the path.
+
+use crate::cartesian::impls::contour::{ClosedContour, Contour};
+use crate::cartesian::impls::polygon::Polygon;
+use crate::contour::Contour as _;
+use crate::geo::datum::Datum;
+use crate::geo::impls::projection::common::{conformal_t, latitude_from_conformal_t};
+use crate::geo::traits::point::{GeoPoint, NewGeoPoint};
+use crate::geometry::Geom;
+use crate::multi_contour::MultiContour as _;
+use crate::multi_point::MultiPoint as _;
+use crate::multi_polygon::MultiPolygon as _;
+
+/// Maximum number of parts a single segment is divided into.
+const MAX_SEGMENT_PARTS: usize = 10_000;
+
+/// Path between two vertices along which the intermediate points are added.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SegmentPath {
+    /// Shortest path on the surface of the ellipsoid.
+    #[default]
+    Geodesic,
+    /// Path with a constant bearing, which is a straight line in the Mercator projection.
+    Rhumb,
+}
+
+/// Maximum size of a segment after densification.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SegmentLimit {
+    /// Length of the segment in meters.
+    Length(f64),
+    /// Angular length of the segment in degrees, measured on the sphere with the radius of the semi-major axis.
+    Angle(f64),
+}
+
+/// Adds intermediate points to the segments of geographic geometries, see the [module docs](self).
+///
+/// Points and the segments that are shorter than the limit are not changed. The vertices of the geometry are always
+/// kept, so the densified geometry goes through the same points as the original one.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Densify {
+    pub path: SegmentPath,
+    pub limit: SegmentLimit,
+}
+
+impl Densify {
+    /// Densification along the geodesics.
+    pub fn geodesic(limit: SegmentLimit) -> Self {
+        Self {
+            path: SegmentPath::Geodesic,
+            limit,
+        }
+    }
+
+    /// Densification along the rhumb lines.
+    pub fn rhumb(limit: SegmentLimit) -> Self {
+        Self {
+            path: SegmentPath::Rhumb,
+            limit,
+        }
+    }
+
+    /// Densifies the geometry with the coordinates on the given datum.
+    pub fn densify<P: NewGeoPoint>(&self, geom: &Geom<P>, datum: &Datum) -> Geom<P> {
+        match geom {
+            Geom::Point(p) => Geom::Point(P::latlon(p.lat(), p.lon())),
+            Geom::MultiPoint(points) => Geom::MultiPoint(
+                points
+                    .iter_points()
+                    .map(|p| P::latlon(p.lat(), p.lon()))
+                    .collect::<Vec<_>>()
+                    .into(),
+            ),
+            Geom::Contour(contour) => Geom::Contour(self.densify_contour(contour, datum)),
+            Geom::MultiContour(contours) => Geom::MultiContour(
+                contours
+                    .contours()
+                    .map(|c| self.densify_contour(c, datum))
+                    .collect::<Vec<_>>()
+                    .into(),
+            ),
+            Geom::Polygon(polygon) => Geom::Polygon(self.densify_polygon(polygon, datum)),
+            Geom::MultiPolygon(polygons) => Geom::MultiPolygon(
+                polygons
+                    .polygons()
+                    .map(|p| self.densify_polygon(p, datum))
+                    .collect::<Vec<_>>()
+                    .into(),
+            ),
+        }
+    }
+
+    /// Densifies the contour. For a closed contour the closing segment is densified too.
+    pub fn densify_contour<C, P>(&self, contour: &C, datum: &Datum) -> Contour<P>
+    where
+        C: crate::contour::Contour<Point = P>,
+        P: NewGeoPoint,
+    {
+        Contour::new(
+            self.densify_points(contour.iter_points(), contour.is_closed(), datum),
+            contour.is_closed(),
+        )
+    }
+
+    /// Densifies all contours of the polygon.
+    pub fn densify_polygon<Poly, P>(&self, polygon: &Poly, datum: &Datum) -> Polygon<P>
+    where
+        Poly: crate::polygon::Polygon,
+        Poly::Contour: crate::contour::Contour<Point = P>,
+        P: NewGeoPoint,
+    {
+        let densify_ring = |c: &Poly::Contour| {
+            ClosedContour::new(self.densify_points(c.iter_points(), true, datum))
+        };
+        Polygon::new(
+            densify_ring(polygon.outer_contour()),
+            polygon.inner_contours().map(densify_ring).collect(),
+        )
+    }
+
+    fn densify_points<'a, P: NewGeoPoint + 'a>(
+        &self,
+        points: impl Iterator<Item = &'a P>,
+        is_closed: bool,
+        datum: &Datum,
+    ) -> Vec<P> {
+        let points: Vec<&P> = points.collect();
+        let mut result = Vec::with_capacity(points.len());
+        for (i, point) in points.iter().enumerate() {
+            result.push(P::latlon(point.lat(), point.lon()));
+
+            let next = match points.get(i + 1) {
+                Some(next) => *next,
+                None if is_closed && points.len() > 2 => points[0],
+                None => break,
+            };
+            self.add_intermediate_points(*point, next, datum, &mut result);
+        }
+
+        result
+    }
+
+    /// Adds the points between `from` and `to` (not including the ends) into `points`.
+    fn add_intermediate_points<P: NewGeoPoint>(
+        &self,
+        from: &P,
+        to: &P,
+        datum: &Datum,
+        points: &mut Vec<P>,
+    ) {
+        match self.path {
+            SegmentPath::Geodesic => {
+                // Segments with the ends that have no finite coordinates are kept straight.
+                let Some(inverse) = datum.geodesic_inverse(from, to) else {
+                    return;
+                };
+                let parts = self.parts_count(inverse.distance, datum);
+                for i in 1..parts {
+                    let point = datum
+                        .geodesic_direct(
+                            from,
+                            inverse.initial_bearing,
+                            inverse.distance * i as f64 / parts as f64,
+                        )
+                        .point;
+                    points.push(P::latlon(point.lat(), point.lon()));
+                }
+            }
+            SegmentPath::Rhumb => {
+                let rhumb = RhumbLine::new(from, to, datum);
+                let parts = self.parts_count(rhumb.length(), datum);
+                for i in 1..parts {
+                    let (lat, lon) = rhumb.point(i as f64 / parts as f64);
+                    points.push(P::latlon(lat, lon));
+                }
+            }
+        }
+    }
+
+    fn parts_count(&self, length: f64, datum: &Datum) -> usize {
+        let max_length = match self.limit {
+            SegmentLimit::Length(length) => length,
+            SegmentLimit::Angle(angle) => angle.to_radians() * datum.semimajor(),
+        };
+        if !length.is_finite() || max_length <= 0.0 || max_length.is_nan() {
+            return 1;
+        }
+
+        ((length / max_length).ceil() as usize).clamp(1, MAX_SEGMENT_PARTS)
+    }
+}
+
+/// Rhumb line between two points. Longitude along the line changes linearly with the isometric latitude.
+struct RhumbLine {
+    eccentricity: f64,
+    semimajor: f64,
+    lat: f64,
+    lon: f64,
+    d_lat: f64,
+    d_lon: f64,
+    psi: f64,
+    d_psi: f64,
+}
+
+impl RhumbLine {
+    fn new<P: NewGeoPoint>(from: &P, to: &P, datum: &Datum) -> Self {
+        let eccentricity = datum.eccentricity_squared().sqrt();
+        let psi = isometric_latitude(eccentricity, from.lat_rad());
+        Self {
+            eccentricity,
+            semimajor: datum.semimajor(),
+            lat: from.lat(),
+            lon: from.lon(),
+            d_lat: to.lat() - from.lat(),
+            d_lon: (to.lon() - from.lon() + 180.0).rem_euclid(360.0) - 180.0,
+            psi,
+            d_psi: isometric_latitude(eccentricity, to.lat_rad()) - psi,
+        }
+    }
+
+    /// Approximate length of the line in meters, calculated on the sphere with the radius of the semi-major axis.
+    fn length(&self) -> f64 {
+        let d_phi = self.d_lat.to_radians();
+        let q = if self.d_psi.is_finite() && self.d_psi.abs() > 1e-12 {
+            d_phi / self.d_psi
+        } else {
+            self.lat.to_radians().cos()
+        };
+
+        self.semimajor * d_phi.hypot(q * self.d_lon.to_radians())
+    }
+
+    /// Latitude and longitude of the point at the given fraction of the line.
+    fn point(&self, fraction: f64) -> (f64, f64) {
+        let lat = if self.d_psi.is_finite() && self.d_psi.abs() > 1e-12 {
+            let psi = self.psi + fraction * self.d_psi;
+            latitude_from_conformal_t(self.eccentricity, (-psi).exp()).to_degrees()
+        } else {
+            // Parallels, and the lines going to a pole where the isometric latitude is infinite.
+            self.lat + fraction * self.d_lat
+        };
+        let lon = (self.lon + fraction * self.d_lon + 180.0).rem_euclid(360.0) - 180.0;
+
+        (lat, lon)
+    }
+}
+
+fn isometric_latitude(eccentricity: f64, phi: f64) -> f64 {
+    -conformal_t(eccentricity, phi).ln()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geo::impls::point::GeoPoint2d;
+    use crate::geo::traits::point::GeoPoint;
+
+    #[test]
+    fn geodesic_along_equator() {
+        let line = Contour::open(vec![
+            GeoPoint2d::latlon(0.0, 0.0),
+            GeoPoint2d::latlon(0.0, 90.0),
+        ]);
+        let densified = Densify::geodesic(SegmentLimit::Length(1_000_000.0))
+            .densify_contour(&line, &Datum::WGS84);
+
+        // The length of the segment is about 10 019 km.
+        assert_eq!(densified.points.len(), 12);
+        for (i, point) in densified.points.iter().enumerate() {
+            assert!(point.lat().abs() < 1e-9);
+            assert!((point.lon() - 90.0 * i as f64 / 11.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn geodesic_bends_to_pole() {
+        let line = Contour::open(vec![
+            GeoPoint2d::latlon(50.0, -120.0),
+            GeoPoint2d::latlon(50.0, 120.0),
+        ]);
+        let densified = Densify::geodesic(SegmentLimit::Length(1_000_000.0))
+            .densify_contour(&line, &Datum::WGS84);
+
+        // The geodesic is about 7 500 km long and goes through the antimeridian, where it is the closest to the pole.
+        assert_eq!(densified.points.len(), 9);
+        let middle = densified.points[4];
+        assert!((middle.lon().abs() - 180.0).abs() < 1e-6);
+        assert!(middle.lat() > 66.5);
+        assert_eq!(densified.points[0], line.points[0]);
+        assert_eq!(densified.points[densified.points.len() - 1], line.points[1]);
+    }
+
+    #[test]
+    fn rhumb_line() {
+        let sphere = Datum::new(6_371_000.0, 0.0);
+        let line = Contour::open(vec![
+            GeoPoint2d::latlon(0.0, 0.0),
+            GeoPoint2d::latlon(60.0, 90.0),
+        ]);
+        let densified = Densify::rhumb(SegmentLimit::Angle(50.0)).densify_contour(&line, &sphere);
+
+        // The line is about 93 degrees long, so it is divided in two halves. The middle point is at the half of the
+        // isometric latitude of the end.
+        assert_eq!(densified.points.len(), 3);
+        assert!((densified.points[1].lon() - 45.0).abs() < 1e-9);
+        assert!((densified.points[1].lat() - 35.264389682754654).abs() < 1e-9);
+    }
+
+    #[test]
+    fn closed_contour() {
+        let ring = ClosedContour::new(vec![
+            GeoPoint2d::latlon(0.0, 0.0),
+            GeoPoint2d::latlon(0.0, 10.0),
+            GeoPoint2d::latlon(10.0, 10.0),
+        ]);
+        let densified =
+            Densify::rhumb(SegmentLimit::Angle(5.5)).densify_contour(&ring, &Datum::WGS84);
+
+        assert!(densified.is_closed);
+        // Two parts on every side and three parts on the diagonal.
+        assert_eq!(densified.points.len(), 3 + 1 + 1 + 2);
+        assert_eq!(densified.points[0], ring.points[0]);
+        assert_ne!(densified.points.last(), densified.points.first());
+    }
+}
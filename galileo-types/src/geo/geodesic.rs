@@ -0,0 +1,552 @@
+//! Measurements on the surface of the ellipsoid of a [`Datum`].
+
+use crate::geo::datum::Datum;
+use crate::geo::impls::point::GeoPoint2d;
+use crate::geo::traits::point::{GeoPoint, NewGeoPoint};
+use geographiclib_rs::{Geodesic, InverseGeodesic};
+use std::f64::consts::PI;
+
+/// Maximum number of iterations of the Vincenty's formulae. The formulae converge in a few iterations for all points
+/// except nearly antipodal ones.
+const MAX_ITERATIONS: usize = 200;
+const CONVERGENCE_THRESHOLD: f64 = 1e-12;
+
+/// Solution of the inverse geodesic problem, see [`Datum::geodesic_inverse`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct GeodesicInverse {
+    /// Length of the geodesic in meters.
+    pub distance: f64,
+    /// Bearing of the geodesic at the start point in degrees clockwise from the north, in the `[0, 360)` range.
+    pub initial_bearing: f64,
+    /// Bearing of the geodesic at the end point in degrees clockwise from the north, in the `[0, 360)` range.
+    pub final_bearing: f64,
+}
+
+/// Solution of the direct geodesic problem, see [`Datum::geodesic_direct`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct GeodesicDirect {
+    /// End point of the geodesic.
+    pub point: GeoPoint2d,
+    /// Bearing of the geodesic at the end point in degrees clockwise from the north, in the `[0, 360)` range.
+    pub final_bearing: f64,
+}
+
+impl Datum {
+    /// Flattening of the ellipsoid.
+    pub fn flattening(&self) -> f64 {
+        if self.is_sphere() {
+            0.0
+        } else {
+            1.0 / self.inv_flattening()
+        }
+    }
+
+    /// Semi-minor axis of the ellipsoid in meters.
+    pub fn semiminor(&self) -> f64 {
+        self.semimajor() * (1.0 - self.flattening())
+    }
+
+    /// Square of the first eccentricity of the ellipsoid.
+    pub fn eccentricity_squared(&self) -> f64 {
+        let f = self.flattening();
+        f * (2.0 - f)
+    }
+
+    /// Length in meters of the shortest path on the surface of the ellipsoid between two points.
+    ///
+    /// Returns `None` if the distance cannot be calculated, see [`Datum::geodesic_inverse`].
+    pub fn geodesic_distance(
+        &self,
+        from: &impl GeoPoint<Num = f64>,
+        to: &impl GeoPoint<Num = f64>,
+    ) -> Option<f64> {
+        Some(self.geodesic_inverse(from, to)?.distance)
+    }
+
+    /// Solves the inverse geodesic problem: finds the length and the bearings of the shortest path on the surface of
+    /// the ellipsoid between two points.
+    ///
+    /// The problem is solved with the Vincenty's formulae, which are accurate to fractions of a millimeter. The
+    /// iterations of the formulae do not converge for nearly antipodal points, so for them the problem is solved with
+    /// the Karney's algorithm instead, which has the same accuracy for any points. Returns `None` if the coordinates
+    /// of the points are not finite.
+    pub fn geodesic_inverse(
+        &self,
+        from: &impl GeoPoint<Num = f64>,
+        to: &impl GeoPoint<Num = f64>,
+    ) -> Option<GeodesicInverse> {
+        if !(from.lat().is_finite()
+            && from.lon().is_finite()
+            && to.lat().is_finite()
+            && to.lon().is_finite())
+        {
+            return None;
+        }
+
+        let a = self.semimajor();
+        let b = self.semiminor();
+        let f = self.flattening();
+
+        let l = normalize_lon(to.lon_rad() - from.lon_rad());
+        let u1 = ((1.0 - f) * from.lat_rad().tan()).atan();
+        let u2 = ((1.0 - f) * to.lat_rad().tan()).atan();
+        let (sin_u1, cos_u1) = u1.sin_cos();
+        let (sin_u2, cos_u2) = u2.sin_cos();
+
+        let mut lambda = l;
+        let mut converged = false;
+        let (mut sin_sigma, mut cos_sigma, mut sigma) = (0.0, 0.0, 0.0);
+        let (mut cos_sq_alpha, mut cos_2sigma_m) = (0.0, 0.0);
+
+        for _ in 0..MAX_ITERATIONS {
+            let (sin_lambda, cos_lambda) = lambda.sin_cos();
+            sin_sigma = (cos_u2 * sin_lambda).hypot(cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda);
+            if sin_sigma == 0.0 {
+                // Coincident points.
+                return Some(GeodesicInverse {
+                    distance: 0.0,
+                    initial_bearing: 0.0,
+                    final_bearing: 0.0,
+                });
+            }
+
+            cos_sigma = sin_u1 * sin_u2 + cos_u1 * cos_u2 * cos_lambda;
+            sigma = sin_sigma.atan2(cos_sigma);
+            let sin_alpha = cos_u1 * cos_u2 * sin_lambda / sin_sigma;
+            cos_sq_alpha = 1.0 - sin_alpha * sin_alpha;
+            cos_2sigma_m = if cos_sq_alpha != 0.0 {
+                cos_sigma - 2.0 * sin_u1 * sin_u2 / cos_sq_alpha
+            } else {
+                // Both points are on the equator.
+                0.0
+            };
+
+            let c = f / 16.0 * cos_sq_alpha * (4.0 + f * (4.0 - 3.0 * cos_sq_alpha));
+            let prev_lambda = lambda;
+            lambda = l
+                + (1.0 - c)
+                    * f
+                    * sin_alpha
+                    * (sigma
+                        + c * sin_sigma
+                            * (cos_2sigma_m
+                                + c * cos_sigma * (-1.0 + 2.0 * cos_2sigma_m * cos_2sigma_m)));
+
+            if lambda.abs() > PI {
+                // The iterations diverge, which only happens for nearly antipodal points.
+                break;
+            }
+
+            if (lambda - prev_lambda).abs() < CONVERGENCE_THRESHOLD {
+                converged = true;
+                break;
+            }
+        }
+
+        if !converged {
+            return Some(self.karney_inverse(from, to));
+        }
+
+        let u_sq = cos_sq_alpha * (a * a - b * b) / (b * b);
+        let (big_a, big_b) = series_coefficients(u_sq);
+        let delta_sigma = delta_sigma(big_b, sin_sigma, cos_sigma, cos_2sigma_m);
+
+        let (sin_lambda, cos_lambda) = lambda.sin_cos();
+        let initial_bearing =
+            (cos_u2 * sin_lambda).atan2(cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda);
+        let final_bearing =
+            (cos_u1 * sin_lambda).atan2(-sin_u1 * cos_u2 + cos_u1 * sin_u2 * cos_lambda);
+
+        Some(GeodesicInverse {
+            distance: b * big_a * (sigma - delta_sigma),
+            initial_bearing: normalize_bearing(initial_bearing.to_degrees()),
+            final_bearing: normalize_bearing(final_bearing.to_degrees()),
+        })
+    }
+
+    /// Solves the inverse geodesic problem with the Karney's algorithm (C. F. F. Karney, Algorithms for geodesics,
+    /// 2013), which converges for all pairs of points.
+    fn karney_inverse(
+        &self,
+        from: &impl GeoPoint<Num = f64>,
+        to: &impl GeoPoint<Num = f64>,
+    ) -> GeodesicInverse {
+        let geodesic = Geodesic::new(self.semimajor(), self.flattening());
+        let (distance, initial_bearing, final_bearing, _arc_length): (f64, f64, f64, f64) =
+            geodesic.inverse(from.lat(), from.lon(), to.lat(), to.lon());
+
+        GeodesicInverse {
+            distance,
+            initial_bearing: normalize_bearing(initial_bearing),
+            final_bearing: normalize_bearing(final_bearing),
+        }
+    }
+
+    /// Solves the direct geodesic problem: finds the point at the given distance in meters from the start point along
+    /// the geodesic with the given initial bearing in degrees.
+    ///
+    /// The problem is solved with the Vincenty's formulae, which are accurate to fractions of a millimeter for any
+    /// distance up to half of the circumference of the ellipsoid.
+    pub fn geodesic_direct(
+        &self,
+        from: &impl GeoPoint<Num = f64>,
+        bearing: f64,
+        distance: f64,
+    ) -> GeodesicDirect {
+        let a = self.semimajor();
+        let b = self.semiminor();
+        let f = self.flattening();
+
+        let (sin_alpha1, cos_alpha1) = bearing.to_radians().sin_cos();
+        let tan_u1 = (1.0 - f) * from.lat_rad().tan();
+        let cos_u1 = 1.0 / (1.0 + tan_u1 * tan_u1).sqrt();
+        let sin_u1 = tan_u1 * cos_u1;
+
+        let sigma1 = tan_u1.atan2(cos_alpha1);
+        let sin_alpha = cos_u1 * sin_alpha1;
+        let cos_sq_alpha = 1.0 - sin_alpha * sin_alpha;
+        let u_sq = cos_sq_alpha * (a * a - b * b) / (b * b);
+        let (big_a, big_b) = series_coefficients(u_sq);
+
+        let mut sigma = distance / (b * big_a);
+        for _ in 0..MAX_ITERATIONS {
+            let cos_2sigma_m = (2.0 * sigma1 + sigma).cos();
+            let (sin_sigma, cos_sigma) = sigma.sin_cos();
+            let prev_sigma = sigma;
+            sigma = distance / (b * big_a) + delta_sigma(big_b, sin_sigma, cos_sigma, cos_2sigma_m);
+
+            if (sigma - prev_sigma).abs() < CONVERGENCE_THRESHOLD {
+                break;
+            }
+        }
+
+        let cos_2sigma_m = (2.0 * sigma1 + sigma).cos();
+        let (sin_sigma, cos_sigma) = sigma.sin_cos();
+        let x = sin_u1 * sin_sigma - cos_u1 * cos_sigma * cos_alpha1;
+        let lat = (sin_u1 * cos_sigma + cos_u1 * sin_sigma * cos_alpha1)
+            .atan2((1.0 - f) * sin_alpha.hypot(x));
+        let lambda =
+            (sin_sigma * sin_alpha1).atan2(cos_u1 * cos_sigma - sin_u1 * sin_sigma * cos_alpha1);
+        let c = f / 16.0 * cos_sq_alpha * (4.0 + f * (4.0 - 3.0 * cos_sq_alpha));
+        let l = lambda
+            - (1.0 - c)
+                * f
+                * sin_alpha
+                * (sigma
+                    + c * sin_sigma
+                        * (cos_2sigma_m
+                            + c * cos_sigma * (-1.0 + 2.0 * cos_2sigma_m * cos_2sigma_m)));
+        let final_bearing = sin_alpha.atan2(-x);
+
+        GeodesicDirect {
+            point: GeoPoint2d::latlon(
+                lat.to_degrees(),
+                normalize_lon(from.lon_rad() + l).to_degrees(),
+            ),
+            final_bearing: normalize_bearing(final_bearing.to_degrees()),
+        }
+    }
+
+    /// Returns the point on the shortest path between two points at the given fraction of the path length from the
+    /// start point. Returns `None` if the path cannot be calculated, see [`Datum::geodesic_inverse`].
+    pub fn geodesic_interpolate(
+        &self,
+        from: &impl GeoPoint<Num = f64>,
+        to: &impl GeoPoint<Num = f64>,
+        fraction: f64,
+    ) -> Option<GeoPoint2d> {
+        let inverse = self.geodesic_inverse(from, to)?;
+        Some(
+            self.geodesic_direct(from, inverse.initial_bearing, inverse.distance * fraction)
+                .point,
+        )
+    }
+
+    /// Area in square meters of the polygon on the surface of the ellipsoid with the given vertices. The polygon is
+    /// closed automatically, the last point should not repeat the first one.
+    ///
+    /// The area is calculated on the authalic (equal-area) sphere of the ellipsoid, so it is exact for polygons with
+    /// the edges along meridians and the equator, and the error for other polygons is negligible compared to the
+    /// accuracy of the coordinates. The polygon must not contain a pole.
+    pub fn polygon_area<'a, P>(&self, points: impl IntoIterator<Item = &'a P>) -> f64
+    where
+        P: GeoPoint<Num = f64> + 'a,
+    {
+        let points: Vec<(f64, f64)> = points
+            .into_iter()
+            .map(|p| (self.authalic_latitude(p.lat_rad()), p.lon_rad()))
+            .collect();
+        if points.len() < 3 {
+            return 0.0;
+        }
+
+        // Sum of the spherical excesses of the quadrilaterals between every edge and the equator.
+        let mut excess = 0.0;
+        for (index, &(lat1, lon1)) in points.iter().enumerate() {
+            let (lat2, lon2) = points[(index + 1) % points.len()];
+            let t1 = (lat1 / 2.0).tan();
+            let t2 = (lat2 / 2.0).tan();
+            let d_lon = normalize_lon(lon2 - lon1);
+            excess += 2.0 * ((d_lon / 2.0).tan() * (t1 + t2)).atan2(1.0 + t1 * t2);
+        }
+
+        let radius = self.authalic_radius();
+        excess.abs() * radius * radius
+    }
+
+    /// Radius of the sphere with the same surface area as the ellipsoid.
+    pub fn authalic_radius(&self) -> f64 {
+        self.semimajor() * (self.authalic_q(PI / 2.0) / 2.0).sqrt()
+    }
+
+    fn authalic_latitude(&self, lat: f64) -> f64 {
+        (self.authalic_q(lat) / self.authalic_q(PI / 2.0))
+            .clamp(-1.0, 1.0)
+            .asin()
+    }
+
+    fn authalic_q(&self, lat: f64) -> f64 {
+        let e2 = self.eccentricity_squared();
+        let sin = lat.sin();
+        if e2 == 0.0 {
+            return 2.0 * sin;
+        }
+
+        let e = e2.sqrt();
+        (1.0 - e2)
+            * (sin / (1.0 - e2 * sin * sin)
+                - 1.0 / (2.0 * e) * ((1.0 - e * sin) / (1.0 + e * sin)).ln())
+    }
+}
+
+/// Coefficients `A` and `B` of the series expansion of the Vincenty's formulae.
+fn series_coefficients(u_sq: f64) -> (f64, f64) {
+    let a = 1.0 + u_sq / 16384.0 * (4096.0 + u_sq * (-768.0 + u_sq * (320.0 - 175.0 * u_sq)));
+    let b = u_sq / 1024.0 * (256.0 + u_sq * (-128.0 + u_sq * (74.0 - 47.0 * u_sq)));
+    (a, b)
+}
+
+fn delta_sigma(big_b: f64, sin_sigma: f64, cos_sigma: f64, cos_2sigma_m: f64) -> f64 {
+    big_b
+        * sin_sigma
+        * (cos_2sigma_m
+            + big_b / 4.0
+                * (cos_sigma * (-1.0 + 2.0 * cos_2sigma_m * cos_2sigma_m)
+                    - big_b / 6.0
+                        * cos_2sigma_m
+                        * (-3.0 + 4.0 * sin_sigma * sin_sigma)
+                        * (-3.0 + 4.0 * cos_2sigma_m * cos_2sigma_m)))
+}
+
+/// Brings the bearing in degrees into the `[0, 360)` range.
+fn normalize_bearing(bearing: f64) -> f64 {
+    let bearing = bearing.rem_euclid(360.0);
+    if bearing >= 360.0 {
+        0.0
+    } else {
+        bearing
+    }
+}
+
+/// Brings the longitude difference in radians into the `[-PI, PI]` range.
+fn normalize_lon(lon: f64) -> f64 {
+    if lon > PI {
+        lon - 2.0 * PI
+    } else if lon < -PI {
+        lon + 2.0 * PI
+    } else {
+        lon
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dms(degrees: f64, minutes: f64, seconds: f64) -> f64 {
+        degrees.signum() * (degrees.abs() + minutes / 60.0 + seconds / 3600.0)
+    }
+
+    #[test]
+    fn vincenty_reference_distance() {
+        // Flinders Peak to Buninyong, the example from the Vincenty's paper.
+        let flinders_peak =
+            GeoPoint2d::latlon(dms(-37.0, 57.0, 3.72030), dms(144.0, 25.0, 29.52440));
+        let buninyong = GeoPoint2d::latlon(dms(-37.0, 39.0, 10.15610), dms(143.0, 55.0, 35.38390));
+
+        let distance = Datum::WGS84
+            .geodesic_distance(&flinders_peak, &buninyong)
+            .unwrap();
+        assert!((distance - 54_972.271).abs() < 1e-3);
+
+        let distance = flinders_peak.distance(&buninyong, &Datum::WGS84).unwrap();
+        assert!((distance - 54_972.271).abs() < 1e-3);
+    }
+
+    #[test]
+    fn vincenty_reference_bearings() {
+        let flinders_peak =
+            GeoPoint2d::latlon(dms(-37.0, 57.0, 3.72030), dms(144.0, 25.0, 29.52440));
+        let buninyong = GeoPoint2d::latlon(dms(-37.0, 39.0, 10.15610), dms(143.0, 55.0, 35.38390));
+        let datum = Datum::WGS84;
+
+        let inverse = datum.geodesic_inverse(&flinders_peak, &buninyong).unwrap();
+        assert!((inverse.initial_bearing - dms(306.0, 52.0, 5.37)).abs() < 1e-5);
+        assert!((inverse.final_bearing - dms(307.0, 10.0, 25.07)).abs() < 1e-5);
+
+        let initial = flinders_peak.initial_bearing(&buninyong, &datum).unwrap();
+        let final_bearing = flinders_peak.final_bearing(&buninyong, &datum).unwrap();
+        assert_eq!(initial, inverse.initial_bearing);
+        assert_eq!(final_bearing, inverse.final_bearing);
+
+        let direct = datum.geodesic_direct(&flinders_peak, inverse.initial_bearing, 54_972.271);
+        assert!((direct.point.lat() - buninyong.lat()).abs() < 1e-7);
+        assert!((direct.point.lon() - buninyong.lon()).abs() < 1e-7);
+        assert!((direct.final_bearing - inverse.final_bearing).abs() < 1e-5);
+    }
+
+    #[test]
+    fn karney_reference_direct() {
+        // Example from C. F. F. Karney, "Algorithms for geodesics", J. Geodesy 87 (2013).
+        let datum = Datum::WGS84;
+        let from = GeoPoint2d::latlon(40.0, 0.0);
+
+        let direct = datum.geodesic_direct(&from, 30.0, 10_000_000.0);
+        assert!((direct.point.lat() - 41.79331020506).abs() < 1e-8);
+        assert!((direct.point.lon() - 137.84490004377).abs() < 1e-8);
+        assert!((direct.final_bearing - 149.09016931807).abs() < 1e-8);
+
+        let destination = from.destination(30.0, 10_000_000.0, &datum).unwrap();
+        assert_eq!(destination, direct.point);
+
+        let inverse = datum.geodesic_inverse(&from, &direct.point).unwrap();
+        assert!((inverse.distance - 10_000_000.0).abs() < 1e-3);
+        assert!((inverse.initial_bearing - 30.0).abs() < 1e-8);
+        assert!((inverse.final_bearing - 149.09016931807).abs() < 1e-8);
+    }
+
+    #[test]
+    fn interpolation() {
+        let datum = Datum::WGS84;
+        let from = GeoPoint2d::latlon(0.0, 0.0);
+        let to = GeoPoint2d::latlon(0.0, 10.0);
+
+        let middle = datum.geodesic_interpolate(&from, &to, 0.5).unwrap();
+        assert!(middle.lat().abs() < 1e-9);
+        assert!((middle.lon() - 5.0).abs() < 1e-9);
+
+        let from = GeoPoint2d::latlon(51.5, -0.1);
+        let to = GeoPoint2d::latlon(40.7, -74.0);
+        let middle = from.interpolate(&to, 0.5, &datum).unwrap();
+        let total = from.distance(&to, &datum).unwrap();
+        assert!((from.distance(&middle, &datum).unwrap() - total / 2.0).abs() < 1e-3);
+        assert!((middle.distance(&to, &datum).unwrap() - total / 2.0).abs() < 1e-3);
+
+        let start = from.interpolate(&to, 0.0, &datum).unwrap();
+        assert!(start.distance(&from, &datum).unwrap() < 1e-6);
+    }
+
+    #[test]
+    fn coincident_points() {
+        let point = GeoPoint2d::latlon(55.0, 37.0);
+        let inverse = Datum::WGS84.geodesic_inverse(&point, &point).unwrap();
+        assert_eq!(inverse.distance, 0.0);
+        assert_eq!(inverse.initial_bearing, 0.0);
+    }
+
+    #[test]
+    fn distance_along_equator_and_across_antimeridian() {
+        let datum = Datum::WGS84;
+        let one_degree = datum
+            .geodesic_distance(&GeoPoint2d::latlon(0.0, 0.0), &GeoPoint2d::latlon(0.0, 1.0))
+            .unwrap();
+        assert!((one_degree - 111_319.491).abs() < 1e-3);
+
+        let across = datum
+            .geodesic_distance(
+                &GeoPoint2d::latlon(0.0, 179.5),
+                &GeoPoint2d::latlon(0.0, -179.5),
+            )
+            .unwrap();
+        assert!((across - one_degree).abs() < 1e-6);
+
+        let point = GeoPoint2d::latlon(55.0, 37.0);
+        assert_eq!(datum.geodesic_distance(&point, &point), Some(0.0));
+    }
+
+    #[test]
+    fn nearly_antipodal_points() {
+        let datum = Datum::WGS84;
+
+        // The shortest path between antipodal points on the equator goes through the poles, so its length is a half
+        // of the meridian.
+        let distance = datum
+            .geodesic_distance(
+                &GeoPoint2d::latlon(0.0, 0.0),
+                &GeoPoint2d::latlon(0.0, 180.0),
+            )
+            .unwrap();
+        assert!((distance - 20_003_931.458_6).abs() < 1e-3, "{distance}");
+
+        // Example of the points for which the Vincenty's formulae fail to converge, from the GeographicLib
+        // documentation.
+        let from = GeoPoint2d::latlon(0.0, 0.0);
+        let to = GeoPoint2d::latlon(0.5, 179.5);
+        let inverse = datum.geodesic_inverse(&from, &to).unwrap();
+        assert!(
+            (inverse.distance - 19_936_288.579).abs() < 1e-3,
+            "{}",
+            inverse.distance
+        );
+        assert!((0.0..360.0).contains(&inverse.initial_bearing));
+        assert!((0.0..360.0).contains(&inverse.final_bearing));
+
+        let back = datum.geodesic_inverse(&to, &from).unwrap();
+        assert!((back.distance - inverse.distance).abs() < 1e-6);
+
+        // The end point of the path found for nearly antipodal points is the target point.
+        let to = GeoPoint2d::latlon(-0.2, 179.9);
+        let inverse = datum.geodesic_inverse(&from, &to).unwrap();
+        let end = datum.geodesic_direct(&from, inverse.initial_bearing, inverse.distance);
+        assert!((end.point.lat() - to.lat()).abs() < 1e-8, "{:?}", end.point);
+        assert!((end.point.lon() - to.lon()).abs() < 1e-8, "{:?}", end.point);
+
+        assert_eq!(
+            datum.geodesic_inverse(&GeoPoint2d::latlon(f64::NAN, 0.0), &to),
+            None
+        );
+    }
+
+    #[test]
+    fn octant_area() {
+        let datum = Datum::WGS84;
+        let octant = [
+            GeoPoint2d::latlon(0.0, 0.0),
+            GeoPoint2d::latlon(0.0, 90.0),
+            GeoPoint2d::latlon(90.0, 0.0),
+        ];
+
+        // Surface area of the WGS84 ellipsoid is 510 065 621.724 km².
+        let area = datum.polygon_area(&octant);
+        assert!((area * 8.0 - 510_065_621_724_088.75).abs() < 1e3);
+
+        let reversed: Vec<_> = octant.iter().rev().copied().collect();
+        assert!((datum.polygon_area(&reversed) - area).abs() < 1e-3);
+        assert_eq!(datum.polygon_area(&octant[..2]), 0.0);
+    }
+
+    #[test]
+    fn small_polygon_area() {
+        // Approximately 1 km by 1 km square at the equator.
+        let side = 1000.0 / 111_319.491;
+        let square = [
+            GeoPoint2d::latlon(0.0, 0.0),
+            GeoPoint2d::latlon(0.0, side),
+            GeoPoint2d::latlon(side, side),
+            GeoPoint2d::latlon(side, 0.0),
+        ];
+
+        let area = Datum::WGS84.polygon_area(&square);
+        assert!((area - 1e6).abs() / 1e6 < 0.01);
+    }
+}
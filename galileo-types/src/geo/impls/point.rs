@@ -1,6 +1,5 @@
 use crate::geo::traits::point::{GeoPoint, NewGeoPoint};
-use crate::geo::traits::projection::Projection;
-use crate::geometry::{Geom, Geometry};
+use crate::geometry_type::{GeoSpace2d, GeometryType, PointGeometryType};
 use crate::point::{GeoPointType, Point};
 
 #[derive(Debug, Clone, Copy, Default, PartialEq)]
@@ -42,15 +41,9 @@ impl Point for GeoPoint2d {
     const DIMENSIONS: usize = 2;
 }
 
-impl Geometry for GeoPoint2d {
-    type Point = Self;
-
-    fn project<P: Projection<InPoint = Self::Point> + ?Sized>(
-        &self,
-        projection: &P,
-    ) -> Option<Geom<P::OutPoint>> {
-        Some(Geom::Point(projection.project(self)?))
-    }
+impl GeometryType for GeoPoint2d {
+    type Type = PointGeometryType;
+    type Space = GeoSpace2d;
 }
 
 #[macro_export]
@@ -0,0 +1,196 @@
+use crate::cartesian::traits::cartesian_point::NewCartesianPoint2d;
+use crate::geo::datum::Datum;
+use crate::geo::impls::projection::common::{normalize_longitude, parallel_radius};
+use crate::geo::traits::point::NewGeoPoint;
+use crate::geo::traits::projection::{Projection, ProjectionError};
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+
+/// Parameters of the Albers Equal Area conic projection. Angles are in degrees, distances in meters.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct AlbersEqualAreaParams {
+    pub standard_parallel_1: f64,
+    pub standard_parallel_2: f64,
+    pub latitude_of_origin: f64,
+    pub central_meridian: f64,
+    pub false_easting: f64,
+    pub false_northing: f64,
+}
+
+/// Albers Equal Area conic projection on the ellipsoid.
+#[derive(Debug, Copy, Clone)]
+pub struct AlbersEqualArea<In, Out> {
+    params: AlbersEqualAreaParams,
+    semimajor: f64,
+    eccentricity: f64,
+    /// Cone constant.
+    n: f64,
+    c: f64,
+    origin_radius: f64,
+    phantom_in: PhantomData<In>,
+    phantom_out: PhantomData<Out>,
+}
+
+impl<In, Out> AlbersEqualArea<In, Out> {
+    pub fn new(datum: Datum, params: AlbersEqualAreaParams) -> Self {
+        let e2 = datum.eccentricity_squared();
+        let e = e2.sqrt();
+        let phi1 = params.standard_parallel_1.to_radians();
+        let phi2 = params.standard_parallel_2.to_radians();
+
+        let m1 = parallel_radius(e2, phi1);
+        let q1 = authalic_q(e, phi1);
+        let n = if (phi1 - phi2).abs() > 1e-10 {
+            let m2 = parallel_radius(e2, phi2);
+            (m1 * m1 - m2 * m2) / (authalic_q(e, phi2) - q1)
+        } else {
+            phi1.sin()
+        };
+
+        let mut projection = Self {
+            params,
+            semimajor: datum.semimajor(),
+            eccentricity: e,
+            n,
+            c: m1 * m1 + n * q1,
+            origin_radius: 0.0,
+            phantom_in: Default::default(),
+            phantom_out: Default::default(),
+        };
+        projection.origin_radius =
+            projection.parallel_radius(params.latitude_of_origin.to_radians());
+
+        projection
+    }
+
+    fn parallel_radius(&self, phi: f64) -> f64 {
+        self.semimajor * (self.c - self.n * authalic_q(self.eccentricity, phi)).sqrt() / self.n
+    }
+}
+
+/// Function `q` of the equal area projections. Its value at the pole is the ratio of the area of the ellipsoid to the
+/// area of the sphere with the radius of the semi-major axis multiplied by 2.
+fn authalic_q(e: f64, phi: f64) -> f64 {
+    let sin = phi.sin();
+    if e == 0.0 {
+        return 2.0 * sin;
+    }
+
+    let e2 = e * e;
+    (1.0 - e2)
+        * (sin / (1.0 - e2 * sin * sin)
+            - (1.0 / (2.0 * e)) * ((1.0 - e * sin) / (1.0 + e * sin)).ln())
+}
+
+impl<In: NewGeoPoint<f64>, Out: NewCartesianPoint2d<f64>> Projection for AlbersEqualArea<In, Out> {
+    type InPoint = In;
+    type OutPoint = Out;
+
+    fn project(&self, input: &Self::InPoint) -> Result<Self::OutPoint, ProjectionError> {
+        let rho = self.parallel_radius(input.lat_rad());
+        let theta = self.n
+            * normalize_longitude(input.lon_rad() - self.params.central_meridian.to_radians());
+
+        let x = self.params.false_easting + rho * theta.sin();
+        let y = self.params.false_northing + self.origin_radius - rho * theta.cos();
+
+        if x.is_finite() && y.is_finite() {
+            Ok(Out::new(x, y))
+        } else {
+            Err(ProjectionError::NonFinite)
+        }
+    }
+
+    fn unproject(&self, input: &Self::OutPoint) -> Result<Self::InPoint, ProjectionError> {
+        let x = input.x() - self.params.false_easting;
+        let y = self.origin_radius - (input.y() - self.params.false_northing);
+        let sign = self.n.signum();
+
+        let rho = x.hypot(y);
+        let theta = (sign * x).atan2(sign * y);
+        let q = (self.c - (rho * self.n / self.semimajor).powi(2)) / self.n;
+
+        // Newton iterations starting from the latitude on the sphere.
+        let e = self.eccentricity;
+        let e2 = e * e;
+        let mut phi = (q / 2.0).clamp(-1.0, 1.0).asin();
+        if e > 0.0 {
+            for _ in 0..15 {
+                let sin = phi.sin();
+                let one_minus = 1.0 - e2 * sin * sin;
+                let delta = one_minus.powi(2) / (2.0 * phi.cos())
+                    * (q / (1.0 - e2) - sin / one_minus
+                        + (1.0 / (2.0 * e)) * ((1.0 - e * sin) / (1.0 + e * sin)).ln());
+                phi += delta;
+                if delta.abs() < 1e-14 {
+                    break;
+                }
+            }
+        }
+
+        let lat = phi.to_degrees();
+        let lon = self.params.central_meridian + (theta / self.n).to_degrees();
+        if !lat.is_finite() || !lon.is_finite() {
+            return Err(ProjectionError::NonFinite);
+        }
+
+        Ok(In::latlon(lat, lon))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cartesian::impls::point::Point2d;
+    use crate::cartesian::traits::cartesian_point::CartesianPoint2d;
+    use crate::geo::impls::point::GeoPoint2d;
+    use crate::geo::traits::point::GeoPoint;
+
+    #[test]
+    fn conus_albers() {
+        // Example from the Snyder's "Map Projections: A Working Manual"
+        let projection = AlbersEqualArea::<GeoPoint2d, Point2d>::new(
+            Datum::CLARKE1866,
+            AlbersEqualAreaParams {
+                standard_parallel_1: 29.5,
+                standard_parallel_2: 45.5,
+                latitude_of_origin: 23.0,
+                central_meridian: -96.0,
+                false_easting: 0.0,
+                false_northing: 0.0,
+            },
+        );
+
+        let projected = projection
+            .project(&GeoPoint2d::latlon(35.0, -75.0))
+            .unwrap();
+        assert!((projected.x() - 1_885_472.7).abs() < 0.1);
+        assert!((projected.y() - 1_535_925.0).abs() < 0.1);
+
+        let unprojected = projection.unproject(&projected).unwrap();
+        assert!((unprojected.lat() - 35.0).abs() < 1e-9);
+        assert!((unprojected.lon() + 75.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn southern_hemisphere() {
+        let projection = AlbersEqualArea::<GeoPoint2d, Point2d>::new(
+            Datum::GRS80,
+            AlbersEqualAreaParams {
+                standard_parallel_1: -18.0,
+                standard_parallel_2: -36.0,
+                latitude_of_origin: 0.0,
+                central_meridian: 132.0,
+                false_easting: 0.0,
+                false_northing: 0.0,
+            },
+        );
+
+        for (lat, lon) in [(-30.0, 140.0), (-12.0, 120.0), (-43.0, 147.0)] {
+            let projected = projection.project(&GeoPoint2d::latlon(lat, lon)).unwrap();
+            let unprojected = projection.unproject(&projected).unwrap();
+            assert!((unprojected.lat() - lat).abs() < 1e-9);
+            assert!((unprojected.lon() - lon).abs() < 1e-9);
+        }
+    }
+}
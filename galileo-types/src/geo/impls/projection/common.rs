@@ -0,0 +1,38 @@
+//! Functions of the ellipsoid shared by the projections.
+
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+/// Ratio of the radius of the parallel to the semi-major axis at the latitude `phi`.
+pub(crate) fn parallel_radius(e2: f64, phi: f64) -> f64 {
+    phi.cos() / (1.0 - e2 * phi.sin().powi(2)).sqrt()
+}
+
+/// Function `t` of the conformal projections (tangent of the half colatitude of the conformal sphere).
+pub(crate) fn conformal_t(e: f64, phi: f64) -> f64 {
+    let sin = e * phi.sin();
+    (FRAC_PI_4 - phi / 2.0).tan() / ((1.0 - sin) / (1.0 + sin)).powf(e / 2.0)
+}
+
+/// Inverse of [`conformal_t`].
+pub(crate) fn latitude_from_conformal_t(e: f64, t: f64) -> f64 {
+    let mut phi = FRAC_PI_2 - 2.0 * t.atan();
+    for _ in 0..15 {
+        let sin = e * phi.sin();
+        let next = FRAC_PI_2 - 2.0 * (t * ((1.0 - sin) / (1.0 + sin)).powf(e / 2.0)).atan();
+        if (next - phi).abs() < 1e-14 {
+            return next;
+        }
+        phi = next;
+    }
+
+    phi
+}
+
+/// Normalizes the longitude difference in radians into the `[-PI, PI]` range.
+pub(crate) fn normalize_longitude(lambda: f64) -> f64 {
+    if (-PI..=PI).contains(&lambda) {
+        lambda
+    } else {
+        lambda - (2.0 * PI) * ((lambda + PI) / (2.0 * PI)).floor()
+    }
+}
@@ -1,5 +1,5 @@
 use crate::cartesian::traits::cartesian_point::{NewCartesianPoint2d, NewCartesianPoint3d};
-use crate::geo::traits::projection::Projection;
+use crate::geo::traits::projection::{Projection, ProjectionError};
 use std::marker::PhantomData;
 
 pub struct AddDimensionProjection<Num, In, Out> {
@@ -24,11 +24,11 @@ impl<Num: Copy, In: NewCartesianPoint2d<Num>, Out: NewCartesianPoint3d<Num>> Pro
     type InPoint = In;
     type OutPoint = Out;
 
-    fn project(&self, input: &Self::InPoint) -> Option<Self::OutPoint> {
-        Some(Out::new(input.x(), input.y(), self.z))
+    fn project(&self, input: &Self::InPoint) -> Result<Self::OutPoint, ProjectionError> {
+        Ok(Out::new(input.x(), input.y(), self.z))
     }
 
-    fn unproject(&self, input: &Self::OutPoint) -> Option<Self::InPoint> {
-        Some(In::new(input.x(), input.y()))
+    fn unproject(&self, input: &Self::OutPoint) -> Result<Self::InPoint, ProjectionError> {
+        Ok(In::new(input.x(), input.y()))
     }
 }
@@ -0,0 +1,140 @@
+use crate::cartesian::traits::cartesian_point::NewCartesianPoint2d;
+use crate::geo::datum::Datum;
+use crate::geo::traits::point::NewGeoPoint;
+use crate::geo::traits::projection::{Projection, ProjectionError};
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+
+/// Parameters of the Equirectangular projection. Angles are in degrees, distances in meters.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+pub struct EquirectangularParams {
+    /// Parallel along which the scale is true. With `0` the projection is the Plate Carrée.
+    pub latitude_of_true_scale: f64,
+    pub latitude_of_origin: f64,
+    pub central_meridian: f64,
+    pub false_easting: f64,
+    pub false_northing: f64,
+}
+
+/// Equirectangular (Equidistant Cylindrical) projection.
+///
+/// As the `eqc` projection of PROJ, it uses the spherical formulas with the radius of the semi-major axis of the
+/// datum, so the distances along the meridians are not exactly true on the ellipsoid.
+#[derive(Debug, Copy, Clone)]
+pub struct Equirectangular<In, Out> {
+    params: EquirectangularParams,
+    semimajor: f64,
+    phantom_in: PhantomData<In>,
+    phantom_out: PhantomData<Out>,
+}
+
+impl<In, Out> Equirectangular<In, Out> {
+    pub fn new(datum: Datum, params: EquirectangularParams) -> Self {
+        Self {
+            params,
+            semimajor: datum.semimajor(),
+            phantom_in: Default::default(),
+            phantom_out: Default::default(),
+        }
+    }
+}
+
+impl<In: NewGeoPoint<f64>, Out: NewCartesianPoint2d<f64>> Projection for Equirectangular<In, Out> {
+    type InPoint = In;
+    type OutPoint = Out;
+
+    fn project(&self, input: &Self::InPoint) -> Result<Self::OutPoint, ProjectionError> {
+        let x = self.params.false_easting
+            + self.semimajor
+                * self.params.latitude_of_true_scale.to_radians().cos()
+                * (input.lon() - self.params.central_meridian).to_radians();
+        let y = self.params.false_northing
+            + self.semimajor * (input.lat() - self.params.latitude_of_origin).to_radians();
+
+        if x.is_finite() && y.is_finite() {
+            Ok(Out::new(x, y))
+        } else {
+            Err(ProjectionError::NonFinite)
+        }
+    }
+
+    fn unproject(&self, input: &Self::OutPoint) -> Result<Self::InPoint, ProjectionError> {
+        let lat = self.params.latitude_of_origin
+            + ((input.y() - self.params.false_northing) / self.semimajor).to_degrees();
+        let lon = self.params.central_meridian
+            + ((input.x() - self.params.false_easting)
+                / (self.semimajor * self.params.latitude_of_true_scale.to_radians().cos()))
+            .to_degrees();
+
+        if !lat.is_finite() || !lon.is_finite() {
+            return Err(ProjectionError::NonFinite);
+        }
+        if lat.abs() > 90.0 {
+            return Err(ProjectionError::OutOfDomain {
+                x: input.x(),
+                y: input.y(),
+            });
+        }
+
+        Ok(In::latlon(lat, lon))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cartesian::impls::point::Point2d;
+    use crate::cartesian::traits::cartesian_point::CartesianPoint2d;
+    use crate::geo::impls::point::GeoPoint2d;
+    use crate::geo::traits::point::GeoPoint;
+
+    #[test]
+    fn plate_carree() {
+        // EPSG:4087, WGS 84 / World Equidistant Cylindrical: one degree is 111 319.490793 m along both axes.
+        let projection = Equirectangular::<GeoPoint2d, Point2d>::new(
+            Datum::WGS84,
+            EquirectangularParams::default(),
+        );
+
+        let projected = projection.project(&GeoPoint2d::latlon(10.0, 20.0)).unwrap();
+        assert!((projected.x() - 2_226_389.815865).abs() < 1e-6);
+        assert!((projected.y() - 1_113_194.907933).abs() < 1e-6);
+
+        let unprojected = projection.unproject(&projected).unwrap();
+        assert!((unprojected.lat() - 10.0).abs() < 1e-9);
+        assert!((unprojected.lon() - 20.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn true_scale_and_offsets() {
+        let projection = Equirectangular::<GeoPoint2d, Point2d>::new(
+            Datum::WGS84,
+            EquirectangularParams {
+                latitude_of_true_scale: 30.0,
+                latitude_of_origin: 5.0,
+                central_meridian: -10.0,
+                false_easting: 500_000.0,
+                false_northing: 100_000.0,
+            },
+        );
+
+        let projected = projection.project(&GeoPoint2d::latlon(45.0, 20.0)).unwrap();
+        assert!((projected.x() - 3_392_165.208900).abs() < 1e-6);
+        assert!((projected.y() - 4_552_779.631731).abs() < 1e-6);
+
+        for (lat, lon) in [(45.0, 20.0), (-89.5, 179.9), (0.0, -10.0), (60.0, -120.0)] {
+            let point = GeoPoint2d::latlon(lat, lon);
+            let unprojected = projection
+                .unproject(&projection.project(&point).unwrap())
+                .unwrap();
+            assert!((unprojected.lat() - lat).abs() < 1e-9);
+            assert!((unprojected.lon() - lon).abs() < 1e-9);
+        }
+
+        let beyond_pole = Point2d::new(500_000.0, 100_000.0 + 6_378_137.0 * 1.6);
+        assert!(matches!(
+            projection.unproject(&beyond_pole),
+            Err(ProjectionError::OutOfDomain { .. })
+        ));
+    }
+}
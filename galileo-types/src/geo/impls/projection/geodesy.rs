@@ -1,6 +1,6 @@
 use crate::cartesian::traits::cartesian_point::NewCartesianPoint2d;
 use crate::geo::traits::point::NewGeoPoint;
-use crate::geo::traits::projection::Projection;
+use crate::geo::traits::projection::{Projection, ProjectionError};
 use geodesy::prelude::*;
 use std::marker::PhantomData;
 
@@ -12,10 +12,12 @@ pub struct GeodesyProjection<In, Out> {
 }
 
 impl<In, Out> GeodesyProjection<In, Out> {
-    pub fn new(definition: &str) -> Option<Self> {
+    pub fn new(definition: &str) -> Result<Self, ProjectionError> {
         let mut context = Minimal::new();
-        let op = context.op(definition).ok()?;
-        Some(Self {
+        let op = context
+            .op(definition)
+            .map_err(|err| ProjectionError::InvalidDefinition(err.to_string()))?;
+        Ok(Self {
             context,
             op,
             phantom_in: Default::default(),
@@ -30,22 +32,32 @@ impl<In: NewGeoPoint<f64>, Out: NewCartesianPoint2d<f64>> Projection
     type InPoint = In;
     type OutPoint = Out;
 
-    fn project(&self, input: &Self::InPoint) -> Option<Self::OutPoint> {
+    fn project(&self, input: &Self::InPoint) -> Result<Self::OutPoint, ProjectionError> {
         let mut data = [Coor2D::geo(input.lat(), input.lon())];
-        self.context.apply(self.op, Fwd, &mut data).ok()?;
+        self.context
+            .apply(self.op, Fwd, &mut data)
+            .map_err(|_| ProjectionError::OutOfDomain {
+                x: input.lon(),
+                y: input.lat(),
+            })?;
 
         if !data[0].0[0].is_finite() || !data[0].0[1].is_finite() {
-            return None;
+            return Err(ProjectionError::NonFinite);
         }
 
-        Some(Out::new(data[0].0[0], data[0].0[1]))
+        Ok(Out::new(data[0].0[0], data[0].0[1]))
     }
 
-    fn unproject(&self, input: &Self::OutPoint) -> Option<Self::InPoint> {
+    fn unproject(&self, input: &Self::OutPoint) -> Result<Self::InPoint, ProjectionError> {
         let mut data = [Coor2D([input.x(), input.y()])];
-        self.context.apply(self.op, Inv, &mut data).ok()?;
+        self.context
+            .apply(self.op, Inv, &mut data)
+            .map_err(|_| ProjectionError::OutOfDomain {
+                x: input.x(),
+                y: input.y(),
+            })?;
 
-        Some(In::latlon(
+        Ok(In::latlon(
             data[0].0[1].to_degrees(),
             data[0].0[0].to_degrees(),
         ))
@@ -1,6 +1,6 @@
 use crate::cartesian::traits::cartesian_point::{NewCartesianPoint2d, NewCartesianPoint3d};
 use crate::geo::traits::point::NewGeoPoint;
-use crate::geo::traits::projection::Projection;
+use crate::geo::traits::projection::{Projection, ProjectionError};
 use crate::geometry_type::{CartesianSpace2d, CartesianSpace3d, GeoSpace2d};
 use std::marker::PhantomData;
 
@@ -27,12 +27,12 @@ impl<IN: NewCartesianPoint2d, OUT: NewCartesianPoint2d> Projection
     type InPoint = IN;
     type OutPoint = OUT;
 
-    fn project(&self, input: &Self::InPoint) -> Option<Self::OutPoint> {
-        Some(OUT::new(input.x(), input.y()))
+    fn project(&self, input: &Self::InPoint) -> Result<Self::OutPoint, ProjectionError> {
+        Ok(OUT::new(input.x(), input.y()))
     }
 
-    fn unproject(&self, input: &Self::OutPoint) -> Option<Self::InPoint> {
-        Some(IN::new(input.x(), input.y()))
+    fn unproject(&self, input: &Self::OutPoint) -> Result<Self::InPoint, ProjectionError> {
+        Ok(IN::new(input.x(), input.y()))
     }
 }
 
@@ -42,12 +42,12 @@ impl<IN: NewCartesianPoint3d, OUT: NewCartesianPoint3d> Projection
     type InPoint = IN;
     type OutPoint = OUT;
 
-    fn project(&self, input: &Self::InPoint) -> Option<Self::OutPoint> {
-        Some(OUT::new(input.x(), input.y(), input.z()))
+    fn project(&self, input: &Self::InPoint) -> Result<Self::OutPoint, ProjectionError> {
+        Ok(OUT::new(input.x(), input.y(), input.z()))
     }
 
-    fn unproject(&self, input: &Self::OutPoint) -> Option<Self::InPoint> {
-        Some(IN::new(input.x(), input.y(), input.z()))
+    fn unproject(&self, input: &Self::OutPoint) -> Result<Self::InPoint, ProjectionError> {
+        Ok(IN::new(input.x(), input.y(), input.z()))
     }
 }
 
@@ -55,11 +55,11 @@ impl<IN: NewGeoPoint, OUT: NewGeoPoint> Projection for IdentityProjection<IN, OU
     type InPoint = IN;
     type OutPoint = OUT;
 
-    fn project(&self, input: &Self::InPoint) -> Option<Self::OutPoint> {
-        Some(OUT::latlon(input.lat(), input.lon()))
+    fn project(&self, input: &Self::InPoint) -> Result<Self::OutPoint, ProjectionError> {
+        Ok(OUT::latlon(input.lat(), input.lon()))
     }
 
-    fn unproject(&self, input: &Self::OutPoint) -> Option<Self::InPoint> {
-        Some(IN::latlon(input.lat(), input.lon()))
+    fn unproject(&self, input: &Self::OutPoint) -> Result<Self::InPoint, ProjectionError> {
+        Ok(IN::latlon(input.lat(), input.lon()))
     }
 }
@@ -0,0 +1,188 @@
+use crate::cartesian::traits::cartesian_point::NewCartesianPoint2d;
+use crate::geo::datum::Datum;
+use crate::geo::impls::projection::common::{
+    conformal_t, latitude_from_conformal_t, normalize_longitude, parallel_radius,
+};
+use crate::geo::traits::point::NewGeoPoint;
+use crate::geo::traits::projection::{Projection, ProjectionError};
+use serde::{Deserialize, Serialize};
+use std::f64::consts::FRAC_PI_2;
+use std::marker::PhantomData;
+
+/// Parameters of the Lambert Conformal Conic projection. Angles are in degrees, distances in meters.
+///
+/// If both standard parallels are the same, the projection has one standard parallel (the 1SP variant), and the scale
+/// factor is applied along it. For the projections with two standard parallels the scale factor is usually `1`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LambertConformalConicParams {
+    pub standard_parallel_1: f64,
+    pub standard_parallel_2: f64,
+    pub latitude_of_origin: f64,
+    pub central_meridian: f64,
+    pub scale_factor: f64,
+    pub false_easting: f64,
+    pub false_northing: f64,
+}
+
+/// Lambert Conformal Conic projection on the ellipsoid.
+#[derive(Debug, Copy, Clone)]
+pub struct LambertConformalConic<In, Out> {
+    params: LambertConformalConicParams,
+    eccentricity: f64,
+    /// Cone constant.
+    n: f64,
+    /// Radius of the parallel with `t = 1`, including the scale factor.
+    radius: f64,
+    origin_radius: f64,
+    phantom_in: PhantomData<In>,
+    phantom_out: PhantomData<Out>,
+}
+
+impl<In, Out> LambertConformalConic<In, Out> {
+    pub fn new(datum: Datum, params: LambertConformalConicParams) -> Self {
+        let e2 = datum.eccentricity_squared();
+        let e = e2.sqrt();
+        let phi1 = params.standard_parallel_1.to_radians();
+        let phi2 = params.standard_parallel_2.to_radians();
+
+        let m1 = parallel_radius(e2, phi1);
+        let t1 = conformal_t(e, phi1);
+        let n = if (phi1 - phi2).abs() > 1e-10 {
+            (m1 / parallel_radius(e2, phi2)).ln() / (t1 / conformal_t(e, phi2)).ln()
+        } else {
+            phi1.sin()
+        };
+
+        let mut projection = Self {
+            params,
+            eccentricity: e,
+            n,
+            radius: datum.semimajor() * params.scale_factor * m1 / (n * t1.powf(n)),
+            origin_radius: 0.0,
+            phantom_in: Default::default(),
+            phantom_out: Default::default(),
+        };
+        projection.origin_radius =
+            projection.parallel_radius(params.latitude_of_origin.to_radians());
+
+        projection
+    }
+
+    fn parallel_radius(&self, phi: f64) -> f64 {
+        if (phi.abs() - FRAC_PI_2).abs() < 1e-10 {
+            if phi * self.n > 0.0 {
+                0.0
+            } else {
+                f64::INFINITY
+            }
+        } else {
+            self.radius * conformal_t(self.eccentricity, phi).powf(self.n)
+        }
+    }
+}
+
+impl<In: NewGeoPoint<f64>, Out: NewCartesianPoint2d<f64>> Projection
+    for LambertConformalConic<In, Out>
+{
+    type InPoint = In;
+    type OutPoint = Out;
+
+    fn project(&self, input: &Self::InPoint) -> Result<Self::OutPoint, ProjectionError> {
+        let rho = self.parallel_radius(input.lat_rad());
+        let theta = self.n
+            * normalize_longitude(input.lon_rad() - self.params.central_meridian.to_radians());
+
+        let x = self.params.false_easting + rho * theta.sin();
+        let y = self.params.false_northing + self.origin_radius - rho * theta.cos();
+
+        if x.is_finite() && y.is_finite() {
+            Ok(Out::new(x, y))
+        } else {
+            Err(ProjectionError::NonFinite)
+        }
+    }
+
+    fn unproject(&self, input: &Self::OutPoint) -> Result<Self::InPoint, ProjectionError> {
+        let x = input.x() - self.params.false_easting;
+        let y = self.origin_radius - (input.y() - self.params.false_northing);
+        let sign = self.n.signum();
+
+        let rho = sign * x.hypot(y);
+        let theta = (sign * x).atan2(sign * y);
+        let t = (rho / self.radius).powf(1.0 / self.n);
+
+        let lat = latitude_from_conformal_t(self.eccentricity, t).to_degrees();
+        let lon = self.params.central_meridian + (theta / self.n).to_degrees();
+        if !lat.is_finite() || !lon.is_finite() {
+            return Err(ProjectionError::NonFinite);
+        }
+
+        Ok(In::latlon(lat, lon))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cartesian::impls::point::Point2d;
+    use crate::cartesian::traits::cartesian_point::CartesianPoint2d;
+    use crate::geo::impls::point::GeoPoint2d;
+    use crate::geo::traits::point::GeoPoint;
+
+    #[test]
+    fn texas_south_central() {
+        // Example from the EPSG Guidance Note 7-2, converted from US survey feet into meters
+        const US_FOOT: f64 = 1200.0 / 3937.0;
+        let projection = LambertConformalConic::<GeoPoint2d, Point2d>::new(
+            Datum::CLARKE1866,
+            LambertConformalConicParams {
+                standard_parallel_1: 28.0 + 23.0 / 60.0,
+                standard_parallel_2: 30.0 + 17.0 / 60.0,
+                latitude_of_origin: 27.0 + 50.0 / 60.0,
+                central_meridian: -99.0,
+                scale_factor: 1.0,
+                false_easting: 2_000_000.0 * US_FOOT,
+                false_northing: 0.0,
+            },
+        );
+
+        let projected = projection
+            .project(&GeoPoint2d::latlon(28.5, -96.0))
+            .unwrap();
+        assert!((projected.x() / US_FOOT - 2_963_503.91).abs() < 0.01);
+        assert!((projected.y() / US_FOOT - 254_759.80).abs() < 0.01);
+
+        let unprojected = projection.unproject(&projected).unwrap();
+        assert!((unprojected.lat() - 28.5).abs() < 1e-9);
+        assert!((unprojected.lon() + 96.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn one_standard_parallel() {
+        let projection = LambertConformalConic::<GeoPoint2d, Point2d>::new(
+            Datum::GRS80,
+            LambertConformalConicParams {
+                standard_parallel_1: -40.0,
+                standard_parallel_2: -40.0,
+                latitude_of_origin: -40.0,
+                central_meridian: 170.0,
+                scale_factor: 0.9999,
+                false_easting: 1_000_000.0,
+                false_northing: 2_000_000.0,
+            },
+        );
+
+        let origin = projection
+            .project(&GeoPoint2d::latlon(-40.0, 170.0))
+            .unwrap();
+        assert!((origin.x() - 1_000_000.0).abs() < 1e-6);
+        assert!((origin.y() - 2_000_000.0).abs() < 1e-6);
+
+        for (lat, lon) in [(-35.0, 165.0), (-47.0, 178.5), (-40.0, -179.0)] {
+            let projected = projection.project(&GeoPoint2d::latlon(lat, lon)).unwrap();
+            let unprojected = projection.unproject(&projected).unwrap();
+            assert!((unprojected.lat() - lat).abs() < 1e-9);
+            assert!((normalize_longitude((unprojected.lon() - lon).to_radians())).abs() < 1e-9);
+        }
+    }
+}
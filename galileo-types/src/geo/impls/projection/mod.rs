@@ -1,4 +1,11 @@
+pub mod albers_equal_area;
+pub(crate) mod common;
 pub mod dimensions;
+pub mod equirectangular;
 pub mod geodesy;
 pub mod identity;
+pub mod lambert_conformal_conic;
+pub mod orthographic;
+pub mod polar_stereographic;
+pub mod transverse_mercator;
 pub mod web_mercator;
@@ -0,0 +1,231 @@
+use crate::cartesian::traits::cartesian_point::NewCartesianPoint2d;
+use crate::geo::datum::Datum;
+use crate::geo::traits::point::NewGeoPoint;
+use crate::geo::traits::projection::{Projection, ProjectionError};
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+
+/// Parameters of the Orthographic projection. Angles are in degrees, distances in meters.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+pub struct OrthographicParams {
+    /// Latitude of the center of the view.
+    pub latitude_of_origin: f64,
+    /// Longitude of the center of the view.
+    pub central_meridian: f64,
+    pub false_easting: f64,
+    pub false_northing: f64,
+}
+
+/// Orthographic projection: the view of the globe from an infinite distance.
+///
+/// Only the hemisphere facing the viewer can be projected, points of the other hemisphere return
+/// [`ProjectionError::OutOfDomain`]. On the ellipsoid the formulas of the EPSG method 9840 are used.
+#[derive(Debug, Copy, Clone)]
+pub struct Orthographic<In, Out> {
+    params: OrthographicParams,
+    semimajor: f64,
+    e2: f64,
+    sin_phi0: f64,
+    cos_phi0: f64,
+    /// Radius of curvature in the prime vertical at the origin.
+    nu0: f64,
+    phantom_in: PhantomData<In>,
+    phantom_out: PhantomData<Out>,
+}
+
+impl<In, Out> Orthographic<In, Out> {
+    pub fn new(datum: Datum, params: OrthographicParams) -> Self {
+        let phi0 = params.latitude_of_origin.to_radians();
+        let mut projection = Self {
+            params,
+            semimajor: datum.semimajor(),
+            e2: datum.eccentricity_squared(),
+            sin_phi0: phi0.sin(),
+            cos_phi0: phi0.cos(),
+            nu0: 0.0,
+            phantom_in: Default::default(),
+            phantom_out: Default::default(),
+        };
+        projection.nu0 = projection.nu(phi0);
+
+        projection
+    }
+
+    /// Radius of curvature in the prime vertical.
+    fn nu(&self, phi: f64) -> f64 {
+        self.semimajor / (1.0 - self.e2 * phi.sin().powi(2)).sqrt()
+    }
+
+    /// Radius of curvature in the meridian.
+    fn rho(&self, phi: f64) -> f64 {
+        self.semimajor * (1.0 - self.e2) / (1.0 - self.e2 * phi.sin().powi(2)).powf(1.5)
+    }
+
+    /// Projected coordinates without the false origin.
+    fn project_raw(&self, phi: f64, lambda: f64) -> (f64, f64) {
+        let nu = self.nu(phi);
+        let x = nu * phi.cos() * lambda.sin();
+        let y = nu * (phi.sin() * self.cos_phi0 - phi.cos() * self.sin_phi0 * lambda.cos())
+            + self.e2 * (self.nu0 * self.sin_phi0 - nu * phi.sin()) * self.cos_phi0;
+        (x, y)
+    }
+}
+
+impl<In: NewGeoPoint<f64>, Out: NewCartesianPoint2d<f64>> Projection for Orthographic<In, Out> {
+    type InPoint = In;
+    type OutPoint = Out;
+
+    fn project(&self, input: &Self::InPoint) -> Result<Self::OutPoint, ProjectionError> {
+        let phi = input.lat_rad();
+        let lambda = input.lon_rad() - self.params.central_meridian.to_radians();
+
+        let cos_c = self.sin_phi0 * phi.sin() + self.cos_phi0 * phi.cos() * lambda.cos();
+        if cos_c < 0.0 {
+            return Err(ProjectionError::OutOfDomain {
+                x: input.lon(),
+                y: input.lat(),
+            });
+        }
+
+        let (x, y) = self.project_raw(phi, lambda);
+        let x = self.params.false_easting + x;
+        let y = self.params.false_northing + y;
+
+        if x.is_finite() && y.is_finite() {
+            Ok(Out::new(x, y))
+        } else {
+            Err(ProjectionError::NonFinite)
+        }
+    }
+
+    fn unproject(&self, input: &Self::OutPoint) -> Result<Self::InPoint, ProjectionError> {
+        let x = input.x() - self.params.false_easting;
+        let y = input.y() - self.params.false_northing;
+
+        // Spherical solution is used as the first approximation.
+        let rho = x.hypot(y) / self.semimajor;
+        if rho > 1.0 {
+            return Err(ProjectionError::OutOfDomain {
+                x: input.x(),
+                y: input.y(),
+            });
+        }
+
+        let (mut phi, mut lambda) = if rho == 0.0 {
+            (self.params.latitude_of_origin.to_radians(), 0.0)
+        } else {
+            let c = rho.asin();
+            let phi = (c.cos() * self.sin_phi0
+                + y * c.sin() * self.cos_phi0 / rho / self.semimajor)
+                .clamp(-1.0, 1.0)
+                .asin();
+            let lambda = (x * c.sin()).atan2(
+                rho * self.semimajor * c.cos() * self.cos_phi0 - y * c.sin() * self.sin_phi0,
+            );
+            (phi, lambda)
+        };
+
+        // Newton iterations on the ellipsoid.
+        if self.e2 > 0.0 {
+            for _ in 0..20 {
+                let (fx, fy) = self.project_raw(phi, lambda);
+                let (dx, dy) = (fx - x, fy - y);
+
+                let nu = self.nu(phi);
+                let rho = self.rho(phi);
+                let j11 = -rho * phi.sin() * lambda.sin();
+                let j12 = nu * phi.cos() * lambda.cos();
+                let j21 =
+                    rho * (phi.cos() * self.cos_phi0 + phi.sin() * self.sin_phi0 * lambda.cos());
+                let j22 = nu * phi.cos() * self.sin_phi0 * lambda.sin();
+                let det = j11 * j22 - j12 * j21;
+                if det == 0.0 {
+                    break;
+                }
+
+                let d_phi = (j22 * dx - j12 * dy) / det;
+                let d_lambda = (j11 * dy - j21 * dx) / det;
+                phi -= d_phi;
+                lambda -= d_lambda;
+                if d_phi.abs() < 1e-14 && d_lambda.abs() < 1e-14 {
+                    break;
+                }
+            }
+        }
+
+        let lat = phi.to_degrees();
+        let lon = self.params.central_meridian + lambda.to_degrees();
+        if !lat.is_finite() || !lon.is_finite() {
+            return Err(ProjectionError::NonFinite);
+        }
+
+        Ok(In::latlon(lat, lon))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cartesian::impls::point::Point2d;
+    use crate::cartesian::traits::cartesian_point::CartesianPoint2d;
+    use crate::geo::impls::point::GeoPoint2d;
+    use crate::geo::traits::point::GeoPoint;
+
+    #[test]
+    fn sphere() {
+        // Example from the Snyder's "Map Projections: A Working Manual"
+        let projection = Orthographic::<GeoPoint2d, Point2d>::new(
+            Datum::new(1.0, 0.0),
+            OrthographicParams {
+                latitude_of_origin: 40.0,
+                central_meridian: -100.0,
+                ..Default::default()
+            },
+        );
+
+        let projected = projection
+            .project(&GeoPoint2d::latlon(30.0, -110.0))
+            .unwrap();
+        assert!((projected.x() + 0.1503837).abs() < 1e-7);
+        assert!((projected.y() + 0.1651911).abs() < 1e-7);
+
+        let unprojected = projection.unproject(&projected).unwrap();
+        assert!((unprojected.lat() - 30.0).abs() < 1e-9);
+        assert!((unprojected.lon() + 110.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn ellipsoid() {
+        let projection = Orthographic::<GeoPoint2d, Point2d>::new(
+            Datum::WGS84,
+            OrthographicParams {
+                latitude_of_origin: 55.0,
+                central_meridian: 5.0,
+                false_easting: 100.0,
+                false_northing: 200.0,
+            },
+        );
+
+        let origin = projection.project(&GeoPoint2d::latlon(55.0, 5.0)).unwrap();
+        assert!((origin.x() - 100.0).abs() < 1e-6);
+        assert!((origin.y() - 200.0).abs() < 1e-6);
+
+        for (lat, lon) in [(53.0, -3.0), (20.0, 75.0), (89.0, -120.0), (-10.0, 10.0)] {
+            let projected = projection.project(&GeoPoint2d::latlon(lat, lon)).unwrap();
+            let unprojected = projection.unproject(&projected).unwrap();
+            assert!((unprojected.lat() - lat).abs() < 1e-9);
+            assert!((unprojected.lon() - lon).abs() < 1e-9);
+        }
+
+        assert_eq!(
+            projection.project(&GeoPoint2d::latlon(-55.0, -175.0)),
+            Err(ProjectionError::OutOfDomain {
+                x: -175.0,
+                y: -55.0
+            })
+        );
+        assert!(projection
+            .unproject(&Point2d::new(7_000_000.0, 0.0))
+            .is_err());
+    }
+}
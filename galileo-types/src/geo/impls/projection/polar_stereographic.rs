@@ -0,0 +1,174 @@
+use crate::cartesian::traits::cartesian_point::NewCartesianPoint2d;
+use crate::geo::datum::Datum;
+use crate::geo::impls::projection::common::{
+    conformal_t, latitude_from_conformal_t, parallel_radius,
+};
+use crate::geo::traits::point::NewGeoPoint;
+use crate::geo::traits::projection::{Projection, ProjectionError};
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+
+/// Parameters of the Polar Stereographic projection. Angles are in degrees, distances in meters.
+///
+/// The scale of the projection is given either by the latitude of true scale (variant B of the EPSG registry) or by
+/// the scale factor at the pole (variant A). If `latitude_of_true_scale` is set, `scale_factor` is ignored.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PolarStereographicParams {
+    /// If `true`, the projection is centered on the South pole, otherwise on the North pole.
+    pub south: bool,
+    pub latitude_of_true_scale: Option<f64>,
+    /// Meridian that is directed down from the pole (up for the South pole).
+    pub central_meridian: f64,
+    pub scale_factor: f64,
+    pub false_easting: f64,
+    pub false_northing: f64,
+}
+
+/// Polar Stereographic projection on the ellipsoid. Points of the opposite pole cannot be projected.
+#[derive(Debug, Copy, Clone)]
+pub struct PolarStereographic<In, Out> {
+    params: PolarStereographicParams,
+    eccentricity: f64,
+    /// `1` for the North pole and `-1` for the South pole.
+    sign: f64,
+    /// Ratio of the distance from the pole to the `t` function of the latitude.
+    radius: f64,
+    phantom_in: PhantomData<In>,
+    phantom_out: PhantomData<Out>,
+}
+
+impl<In, Out> PolarStereographic<In, Out> {
+    pub fn new(datum: Datum, params: PolarStereographicParams) -> Self {
+        let e2 = datum.eccentricity_squared();
+        let e = e2.sqrt();
+        let sign = if params.south { -1.0 } else { 1.0 };
+
+        let radius = match params.latitude_of_true_scale {
+            Some(lat_ts) if (lat_ts.abs() - 90.0).abs() > 1e-10 => {
+                let phi = sign * lat_ts.to_radians();
+                datum.semimajor() * parallel_radius(e2, phi) / conformal_t(e, phi)
+            }
+            _ => {
+                let scale_factor = if params.latitude_of_true_scale.is_some() {
+                    1.0
+                } else {
+                    params.scale_factor
+                };
+                2.0 * datum.semimajor() * scale_factor
+                    / ((1.0 + e).powf(1.0 + e) * (1.0 - e).powf(1.0 - e)).sqrt()
+            }
+        };
+
+        Self {
+            params,
+            eccentricity: e,
+            sign,
+            radius,
+            phantom_in: Default::default(),
+            phantom_out: Default::default(),
+        }
+    }
+}
+
+impl<In: NewGeoPoint<f64>, Out: NewCartesianPoint2d<f64>> Projection
+    for PolarStereographic<In, Out>
+{
+    type InPoint = In;
+    type OutPoint = Out;
+
+    fn project(&self, input: &Self::InPoint) -> Result<Self::OutPoint, ProjectionError> {
+        if self.sign * input.lat() <= -90.0 {
+            return Err(ProjectionError::OutOfDomain {
+                x: input.lon(),
+                y: input.lat(),
+            });
+        }
+
+        let rho = self.radius * conformal_t(self.eccentricity, self.sign * input.lat_rad());
+        let lambda = input.lon_rad() - self.params.central_meridian.to_radians();
+
+        let x = self.params.false_easting + rho * lambda.sin();
+        let y = self.params.false_northing - self.sign * rho * lambda.cos();
+
+        if x.is_finite() && y.is_finite() {
+            Ok(Out::new(x, y))
+        } else {
+            Err(ProjectionError::NonFinite)
+        }
+    }
+
+    fn unproject(&self, input: &Self::OutPoint) -> Result<Self::InPoint, ProjectionError> {
+        let x = input.x() - self.params.false_easting;
+        let y = input.y() - self.params.false_northing;
+        let t = x.hypot(y) / self.radius;
+
+        let lat = self.sign * latitude_from_conformal_t(self.eccentricity, t).to_degrees();
+        let lon = self.params.central_meridian + x.atan2(-self.sign * y).to_degrees();
+        if !lat.is_finite() || !lon.is_finite() {
+            return Err(ProjectionError::NonFinite);
+        }
+
+        Ok(In::latlon(lat, lon))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cartesian::impls::point::Point2d;
+    use crate::cartesian::traits::cartesian_point::CartesianPoint2d;
+    use crate::geo::impls::point::GeoPoint2d;
+    use crate::geo::traits::point::GeoPoint;
+
+    // Examples from the EPSG Guidance Note 7-2
+
+    #[test]
+    fn variant_a() {
+        let projection = PolarStereographic::<GeoPoint2d, Point2d>::new(
+            Datum::WGS84,
+            PolarStereographicParams {
+                south: false,
+                latitude_of_true_scale: None,
+                central_meridian: 0.0,
+                scale_factor: 0.994,
+                false_easting: 2_000_000.0,
+                false_northing: 2_000_000.0,
+            },
+        );
+
+        let projected = projection.project(&GeoPoint2d::latlon(73.0, 44.0)).unwrap();
+        assert!((projected.x() - 3_320_416.75).abs() < 0.01);
+        assert!((projected.y() - 632_668.43).abs() < 0.01);
+
+        let unprojected = projection.unproject(&projected).unwrap();
+        assert!((unprojected.lat() - 73.0).abs() < 1e-9);
+        assert!((unprojected.lon() - 44.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn variant_b() {
+        let projection = PolarStereographic::<GeoPoint2d, Point2d>::new(
+            Datum::WGS84,
+            PolarStereographicParams {
+                south: true,
+                latitude_of_true_scale: Some(-71.0),
+                central_meridian: 70.0,
+                scale_factor: 1.0,
+                false_easting: 6_000_000.0,
+                false_northing: 6_000_000.0,
+            },
+        );
+
+        let projected = projection
+            .project(&GeoPoint2d::latlon(-75.0, 120.0))
+            .unwrap();
+        assert!((projected.x() - 7_255_380.79).abs() < 0.01);
+        assert!((projected.y() - 7_053_389.56).abs() < 0.01);
+
+        let unprojected = projection.unproject(&projected).unwrap();
+        assert!((unprojected.lat() + 75.0).abs() < 1e-9);
+        assert!((unprojected.lon() - 120.0).abs() < 1e-9);
+
+        assert!(projection.project(&GeoPoint2d::latlon(90.0, 0.0)).is_err());
+    }
+}
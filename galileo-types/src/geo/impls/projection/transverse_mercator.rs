@@ -0,0 +1,239 @@
+use crate::cartesian::traits::cartesian_point::NewCartesianPoint2d;
+use crate::geo::datum::Datum;
+use crate::geo::impls::projection::common::normalize_longitude;
+use crate::geo::traits::point::NewGeoPoint;
+use crate::geo::traits::projection::{Projection, ProjectionError};
+use serde::{Deserialize, Serialize};
+use std::f64::consts::FRAC_PI_2;
+use std::marker::PhantomData;
+
+/// Parameters of the Transverse Mercator projection. Angles are in degrees, distances in meters.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TransverseMercatorParams {
+    pub latitude_of_origin: f64,
+    pub central_meridian: f64,
+    pub scale_factor: f64,
+    pub false_easting: f64,
+    pub false_northing: f64,
+}
+
+impl TransverseMercatorParams {
+    /// Parameters of the given UTM zone (`1..=60`) of the northern or southern hemisphere.
+    pub fn utm(zone: u8, south: bool) -> Self {
+        Self {
+            latitude_of_origin: 0.0,
+            central_meridian: zone as f64 * 6.0 - 183.0,
+            scale_factor: 0.9996,
+            false_easting: 500_000.0,
+            false_northing: if south { 10_000_000.0 } else { 0.0 },
+        }
+    }
+}
+
+impl Default for TransverseMercatorParams {
+    fn default() -> Self {
+        Self {
+            latitude_of_origin: 0.0,
+            central_meridian: 0.0,
+            scale_factor: 1.0,
+            false_easting: 0.0,
+            false_northing: 0.0,
+        }
+    }
+}
+
+/// Transverse Mercator projection on the ellipsoid.
+///
+/// Uses the Krüger series of the 4th order in the third flattening (as in the `etmerc` projection of PROJ), which
+/// is accurate to less than a millimeter within 4000 km from the central meridian. Points more than 90 degrees away
+/// from the central meridian cannot be projected.
+#[derive(Debug, Copy, Clone)]
+pub struct TransverseMercator<In, Out> {
+    params: TransverseMercatorParams,
+    eccentricity: f64,
+    /// Radius of the rectifying sphere multiplied by the scale factor.
+    radius: f64,
+    alpha: [f64; 4],
+    beta: [f64; 4],
+    delta: [f64; 4],
+    /// Northing of the origin latitude on the central meridian before the false northing is applied.
+    origin_northing: f64,
+    phantom_in: PhantomData<In>,
+    phantom_out: PhantomData<Out>,
+}
+
+impl<In, Out> TransverseMercator<In, Out> {
+    pub fn new(datum: Datum, params: TransverseMercatorParams) -> Self {
+        let f = datum.flattening();
+        let n = f / (2.0 - f);
+        let n2 = n * n;
+        let n3 = n2 * n;
+        let n4 = n3 * n;
+
+        let mut projection = Self {
+            params,
+            eccentricity: datum.eccentricity_squared().sqrt(),
+            radius: params.scale_factor * datum.semimajor() / (1.0 + n)
+                * (1.0 + n2 / 4.0 + n4 / 64.0),
+            alpha: [
+                n / 2.0 - 2.0 * n2 / 3.0 + 5.0 * n3 / 16.0 + 41.0 * n4 / 180.0,
+                13.0 * n2 / 48.0 - 3.0 * n3 / 5.0 + 557.0 * n4 / 1440.0,
+                61.0 * n3 / 240.0 - 103.0 * n4 / 140.0,
+                49561.0 * n4 / 161280.0,
+            ],
+            beta: [
+                n / 2.0 - 2.0 * n2 / 3.0 + 37.0 * n3 / 96.0 - n4 / 360.0,
+                n2 / 48.0 + n3 / 15.0 - 437.0 * n4 / 1440.0,
+                17.0 * n3 / 480.0 - 37.0 * n4 / 840.0,
+                4397.0 * n4 / 161280.0,
+            ],
+            delta: [
+                2.0 * n - 2.0 * n2 / 3.0 - 2.0 * n3 + 116.0 * n4 / 45.0,
+                7.0 * n2 / 3.0 - 8.0 * n3 / 5.0 - 227.0 * n4 / 45.0,
+                56.0 * n3 / 15.0 - 136.0 * n4 / 35.0,
+                4279.0 * n4 / 630.0,
+            ],
+            origin_northing: 0.0,
+            phantom_in: Default::default(),
+            phantom_out: Default::default(),
+        };
+
+        let (_, origin_northing) =
+            projection.project_normalized(params.latitude_of_origin.to_radians(), 0.0);
+        projection.origin_northing = origin_northing * projection.radius;
+
+        projection
+    }
+
+    /// Projects the point into the coordinates on the sphere with unit radius, without scale and false origin.
+    fn project_normalized(&self, phi: f64, lambda: f64) -> (f64, f64) {
+        let e = self.eccentricity;
+        let conformal_tan = (phi.tan().asinh() - e * (e * phi.sin()).atanh()).sinh();
+        let xi = conformal_tan.atan2(lambda.cos());
+        let eta = (lambda.sin() / conformal_tan.hypot(lambda.cos())).asinh();
+
+        let mut x = eta;
+        let mut y = xi;
+        for (j, alpha) in self.alpha.iter().enumerate() {
+            let k = 2.0 * (j + 1) as f64;
+            x += alpha * (k * xi).cos() * (k * eta).sinh();
+            y += alpha * (k * xi).sin() * (k * eta).cosh();
+        }
+
+        (x, y)
+    }
+}
+
+impl<In: NewGeoPoint<f64>, Out: NewCartesianPoint2d<f64>> Projection
+    for TransverseMercator<In, Out>
+{
+    type InPoint = In;
+    type OutPoint = Out;
+
+    fn project(&self, input: &Self::InPoint) -> Result<Self::OutPoint, ProjectionError> {
+        let lambda =
+            normalize_longitude(input.lon_rad() - self.params.central_meridian.to_radians());
+        if lambda.abs() >= FRAC_PI_2 || input.lat_rad().abs() > FRAC_PI_2 {
+            return Err(ProjectionError::OutOfDomain {
+                x: input.lon(),
+                y: input.lat(),
+            });
+        }
+
+        let (x, y) = self.project_normalized(input.lat_rad(), lambda);
+        let x = self.params.false_easting + self.radius * x;
+        let y = self.params.false_northing + self.radius * y - self.origin_northing;
+
+        if x.is_finite() && y.is_finite() {
+            Ok(Out::new(x, y))
+        } else {
+            Err(ProjectionError::NonFinite)
+        }
+    }
+
+    fn unproject(&self, input: &Self::OutPoint) -> Result<Self::InPoint, ProjectionError> {
+        let xi = (input.y() - self.params.false_northing + self.origin_northing) / self.radius;
+        let eta = (input.x() - self.params.false_easting) / self.radius;
+
+        let mut xi_prime = xi;
+        let mut eta_prime = eta;
+        for (j, beta) in self.beta.iter().enumerate() {
+            let k = 2.0 * (j + 1) as f64;
+            xi_prime -= beta * (k * xi).sin() * (k * eta).cosh();
+            eta_prime -= beta * (k * xi).cos() * (k * eta).sinh();
+        }
+
+        let chi = (xi_prime.sin() / eta_prime.cosh()).asin();
+        let mut phi = chi;
+        for (j, delta) in self.delta.iter().enumerate() {
+            phi += delta * (2.0 * (j + 1) as f64 * chi).sin();
+        }
+        let lambda = eta_prime.sinh().atan2(xi_prime.cos());
+
+        let lat = phi.to_degrees();
+        let lon = self.params.central_meridian + lambda.to_degrees();
+        if !lat.is_finite() || !lon.is_finite() {
+            return Err(ProjectionError::NonFinite);
+        }
+
+        Ok(In::latlon(lat, lon))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cartesian::impls::point::Point2d;
+    use crate::cartesian::traits::cartesian_point::CartesianPoint2d;
+    use crate::geo::impls::point::GeoPoint2d;
+    use crate::geo::traits::point::GeoPoint;
+
+    #[test]
+    fn british_national_grid() {
+        // Example from the EPSG Guidance Note 7-2
+        let projection = TransverseMercator::<GeoPoint2d, Point2d>::new(
+            Datum::AIRY1830,
+            TransverseMercatorParams {
+                latitude_of_origin: 49.0,
+                central_meridian: -2.0,
+                scale_factor: 0.9996012717,
+                false_easting: 400_000.0,
+                false_northing: -100_000.0,
+            },
+        );
+
+        let projected = projection.project(&GeoPoint2d::latlon(50.5, 0.5)).unwrap();
+        assert!((projected.x() - 577_274.98).abs() < 0.01);
+        assert!((projected.y() - 69_740.49).abs() < 0.01);
+
+        let unprojected = projection.unproject(&projected).unwrap();
+        assert!((unprojected.lat() - 50.5).abs() < 1e-9);
+        assert!((unprojected.lon() - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn utm() {
+        let projection = TransverseMercator::<GeoPoint2d, Point2d>::new(
+            Datum::WGS84,
+            TransverseMercatorParams::utm(32, false),
+        );
+        let projected = projection.project(&GeoPoint2d::latlon(60.0, 9.0)).unwrap();
+        assert!((projected.x() - 500_000.0).abs() < 1e-6);
+        assert!((projected.y() - 6_651_411.19).abs() < 0.01);
+
+        let south = TransverseMercator::<GeoPoint2d, Point2d>::new(
+            Datum::WGS84,
+            TransverseMercatorParams::utm(34, true),
+        );
+        for (lat, lon) in [(-33.0, 20.0), (-1.0, 16.0), (-80.0, 23.5)] {
+            let projected = south.project(&GeoPoint2d::latlon(lat, lon)).unwrap();
+            let unprojected = south.unproject(&projected).unwrap();
+            assert!((unprojected.lat() - lat).abs() < 1e-9);
+            assert!((unprojected.lon() - lon).abs() < 1e-9);
+        }
+
+        assert!(projection
+            .project(&GeoPoint2d::latlon(10.0, -100.0))
+            .is_err());
+    }
+}
@@ -1,7 +1,7 @@
 use crate::cartesian::traits::cartesian_point::NewCartesianPoint2d;
 use crate::geo::datum::Datum;
 use crate::geo::traits::point::NewGeoPoint;
-use crate::geo::traits::projection::Projection;
+use crate::geo::traits::projection::{Projection, ProjectionError};
 use std::marker::PhantomData;
 
 #[derive(Debug, Copy, Clone)]
@@ -35,7 +35,15 @@ impl<In: NewGeoPoint<f64>, Out: NewCartesianPoint2d<f64>> Projection for WebMerc
     type InPoint = In;
     type OutPoint = Out;
 
-    fn project(&self, input: &Self::InPoint) -> Option<Self::OutPoint> {
+    fn project(&self, input: &Self::InPoint) -> Result<Self::OutPoint, ProjectionError> {
+        // Poles are mapped to infinity, but rounding of `tan` near them gives large finite values.
+        if input.lat().abs() >= 90.0 {
+            return Err(ProjectionError::OutOfDomain {
+                x: input.lon(),
+                y: input.lat(),
+            });
+        }
+
         let x = self.datum.semimajor() * input.lon_rad();
         let y = self.datum.semimajor()
             * (std::f64::consts::FRAC_PI_4 + input.lat_rad() / 2.0)
@@ -43,21 +51,21 @@ impl<In: NewGeoPoint<f64>, Out: NewCartesianPoint2d<f64>> Projection for WebMerc
                 .ln();
 
         if x.is_finite() && y.is_finite() {
-            Some(Self::OutPoint::new(x, y))
+            Ok(Self::OutPoint::new(x, y))
         } else {
-            None
+            Err(ProjectionError::NonFinite)
         }
     }
 
-    fn unproject(&self, input: &Self::OutPoint) -> Option<Self::InPoint> {
+    fn unproject(&self, input: &Self::OutPoint) -> Result<Self::InPoint, ProjectionError> {
         let lat = std::f64::consts::FRAC_PI_2
             - 2.0 * (-(*input).y() / self.datum.semimajor()).exp().atan();
         let lon = input.x() / self.datum.semimajor();
 
         if !lat.is_finite() || !lon.is_finite() {
-            return None;
+            return Err(ProjectionError::NonFinite);
         }
 
-        Some(Self::InPoint::latlon(lat.to_degrees(), lon.to_degrees()))
+        Ok(Self::InPoint::latlon(lat.to_degrees(), lon.to_degrees()))
     }
 }
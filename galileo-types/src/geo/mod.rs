@@ -1,4 +1,8 @@
+pub mod antimeridian;
 pub mod crs;
 pub mod datum;
+pub mod datum_shift;
+pub mod densify;
+pub mod geodesic;
 pub mod impls;
 pub mod traits;
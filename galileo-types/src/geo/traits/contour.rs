@@ -0,0 +1,55 @@
+use crate::contour::Contour;
+use crate::geo::datum::Datum;
+use crate::geo::traits::point::GeoPoint;
+
+/// Measurements of contours with geographic points.
+pub trait GeoContour<P: GeoPoint<Num = f64>>: Contour<Point = P> {
+    /// Length of the contour in meters, with every segment being the shortest path on the surface of the ellipsoid of
+    /// the datum. Returns `None` if the length of one of the segments cannot be calculated, see
+    /// [`Datum::geodesic_inverse`].
+    fn geodesic_length(&self, datum: &Datum) -> Option<f64>
+    where
+        Self: Sized,
+    {
+        self.iter_segments()
+            .map(|segment| datum.geodesic_distance(segment.0, segment.1))
+            .sum()
+    }
+}
+
+impl<T: Contour<Point = P>, P: GeoPoint<Num = f64>> GeoContour<P> for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cartesian::impls::contour::Contour;
+    use crate::geo::impls::point::GeoPoint2d;
+    use crate::geo::traits::point::NewGeoPoint;
+
+    #[test]
+    fn closed_contour_length_includes_closing_segment() {
+        let points = vec![
+            GeoPoint2d::latlon(0.0, 0.0),
+            GeoPoint2d::latlon(0.0, 1.0),
+            GeoPoint2d::latlon(1.0, 1.0),
+        ];
+        let datum = Datum::WGS84;
+
+        let open = Contour::open(points.clone())
+            .geodesic_length(&datum)
+            .unwrap();
+        // One degree along the equator plus one degree along the meridian.
+        assert!((open - 111_319.491 - 110_574.389).abs() < 1e-3);
+
+        let closed = Contour::closed(points.clone())
+            .geodesic_length(&datum)
+            .unwrap();
+        let closing = datum.geodesic_distance(&points[2], &points[0]).unwrap();
+        assert!((closed - open - closing).abs() < 1e-6);
+
+        assert_eq!(
+            Contour::<GeoPoint2d>::open(vec![]).geodesic_length(&datum),
+            Some(0.0)
+        );
+    }
+}
@@ -1,9 +1,10 @@
 use crate::geo::datum::Datum;
-use crate::geo::traits::projection::Projection;
+use crate::geo::impls::point::GeoPoint2d;
+use crate::geo::traits::projection::{Projection, ProjectionError};
 use crate::geometry::{Geom, GeometrySpecialization};
 use crate::geometry_type::{GeoSpace2d, GeometryType, PointGeometryType};
 use crate::point::{GeoPointType, Point, PointHelper};
-use num_traits::Float;
+use num_traits::{Float, NumCast, ToPrimitive};
 
 pub trait GeoPoint {
     type Num: Float;
@@ -19,20 +20,82 @@ pub trait GeoPoint {
         self.lon().to_radians()
     }
 
-    fn distance(
+    /// Length of the shortest path on the surface of the ellipsoid of the datum between the points, in meters. See
+    /// [`Datum::geodesic_distance`].
+    fn distance(&self, other: &impl GeoPoint<Num = Self::Num>, datum: &Datum) -> Option<Self::Num> {
+        let distance =
+            datum.geodesic_distance(&to_geo_point_2d(self)?, &to_geo_point_2d(other)?)?;
+        <Self::Num as NumCast>::from(distance)
+    }
+
+    /// Bearing in degrees clockwise from the north at this point of the shortest path to the other point. See
+    /// [`Datum::geodesic_inverse`].
+    fn initial_bearing(
         &self,
-        _other: &impl GeoPoint<Num = Self::Num>,
-        _datum: &Datum,
+        other: &impl GeoPoint<Num = Self::Num>,
+        datum: &Datum,
     ) -> Option<Self::Num> {
-        todo!()
+        let inverse = datum.geodesic_inverse(&to_geo_point_2d(self)?, &to_geo_point_2d(other)?)?;
+        <Self::Num as NumCast>::from(inverse.initial_bearing)
+    }
+
+    /// Bearing in degrees clockwise from the north at the other point of the shortest path from this point to the
+    /// other point. See [`Datum::geodesic_inverse`].
+    fn final_bearing(
+        &self,
+        other: &impl GeoPoint<Num = Self::Num>,
+        datum: &Datum,
+    ) -> Option<Self::Num> {
+        let inverse = datum.geodesic_inverse(&to_geo_point_2d(self)?, &to_geo_point_2d(other)?)?;
+        <Self::Num as NumCast>::from(inverse.final_bearing)
     }
 }
 
-pub trait NewGeoPoint<N = f64>: GeoPoint<Num = N> + Sized {
+fn to_geo_point_2d<P: GeoPoint + ?Sized>(point: &P) -> Option<GeoPoint2d> {
+    Some(GeoPoint2d::latlon(
+        point.lat().to_f64()?,
+        point.lon().to_f64()?,
+    ))
+}
+
+pub trait NewGeoPoint<N: Float = f64>: GeoPoint<Num = N> + Sized {
     fn latlon(lat: N, lon: N) -> Self;
     fn lonlat(lon: N, lat: N) -> Self {
         Self::latlon(lat, lon)
     }
+
+    /// Point at the given distance in meters from this point along the geodesic with the given initial bearing in
+    /// degrees. See [`Datum::geodesic_direct`].
+    fn destination(&self, bearing: N, distance: N, datum: &Datum) -> Option<Self> {
+        let direct = datum.geodesic_direct(
+            &to_geo_point_2d(self)?,
+            bearing.to_f64()?,
+            distance.to_f64()?,
+        );
+        Some(Self::latlon(
+            <N as NumCast>::from(direct.point.lat())?,
+            <N as NumCast>::from(direct.point.lon())?,
+        ))
+    }
+
+    /// Point on the shortest path from this point to the other point at the given fraction of the path length. See
+    /// [`Datum::geodesic_interpolate`].
+    fn interpolate(
+        &self,
+        other: &impl GeoPoint<Num = N>,
+        fraction: N,
+        datum: &Datum,
+    ) -> Option<Self> {
+        let point = datum.geodesic_interpolate(
+            &to_geo_point_2d(self)?,
+            &to_geo_point_2d(other)?,
+            fraction.to_f64()?,
+        )?;
+        Some(Self::latlon(
+            <N as NumCast>::from(point.lat())?,
+            <N as NumCast>::from(point.lon())?,
+        ))
+    }
 }
 
 impl<T> PointHelper<GeoPointType> for T where T: GeoPoint + Point<Type = GeoPointType> {}
@@ -43,10 +106,10 @@ where
 {
     type Point = P;
 
-    fn project_spec<Proj>(&self, projection: &Proj) -> Option<Geom<Proj::OutPoint>>
+    fn project_spec<Proj>(&self, projection: &Proj) -> Result<Geom<Proj::OutPoint>, ProjectionError>
     where
         Proj: Projection<InPoint = Self::Point> + ?Sized,
     {
-        Some(Geom::Point(projection.project(self)?))
+        Ok(Geom::Point(projection.project(self)?))
     }
 }
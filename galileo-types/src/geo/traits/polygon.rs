@@ -0,0 +1,63 @@
+use crate::contour::Contour;
+use crate::geo::datum::Datum;
+use crate::geo::traits::point::GeoPoint;
+use crate::polygon::Polygon;
+
+/// Measurements of polygons with geographic points.
+pub trait GeoPolygon {
+    type Point: GeoPoint<Num = f64>;
+
+    /// Area of the polygon on the surface of the ellipsoid of the datum in square meters, with the areas of the holes
+    /// subtracted. See [`Datum::polygon_area`].
+    fn geodesic_area(&self, datum: &Datum) -> f64;
+}
+
+impl<P, C, T> GeoPolygon for T
+where
+    P: GeoPoint<Num = f64>,
+    C: Contour<Point = P>,
+    T: Polygon<Contour = C>,
+{
+    type Point = P;
+
+    fn geodesic_area(&self, datum: &Datum) -> f64 {
+        let outer = datum.polygon_area(self.outer_contour().iter_points());
+        let holes: f64 = self
+            .inner_contours()
+            .map(|contour| datum.polygon_area(contour.iter_points()))
+            .sum();
+
+        outer - holes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cartesian::impls::contour::ClosedContour;
+    use crate::cartesian::impls::polygon::Polygon;
+    use crate::geo::impls::point::GeoPoint2d;
+    use crate::geo::traits::point::NewGeoPoint;
+
+    fn square(lat: f64, lon: f64, size: f64) -> ClosedContour<GeoPoint2d> {
+        ClosedContour::new(vec![
+            GeoPoint2d::latlon(lat, lon),
+            GeoPoint2d::latlon(lat, lon + size),
+            GeoPoint2d::latlon(lat + size, lon + size),
+            GeoPoint2d::latlon(lat + size, lon),
+        ])
+    }
+
+    #[test]
+    fn holes_are_subtracted() {
+        let datum = Datum::WGS84;
+        let outer = square(10.0, 10.0, 1.0);
+        let hole = square(10.25, 10.25, 0.5);
+        let outer_area = datum.polygon_area(&outer.points);
+        let hole_area = datum.polygon_area(&hole.points);
+
+        let polygon = Polygon::new(outer, vec![hole]);
+        assert!((polygon.geodesic_area(&datum) - (outer_area - hole_area)).abs() < 1e-3);
+        assert!(hole_area > 0.0 && hole_area < outer_area / 3.0);
+    }
+}
@@ -1,9 +1,11 @@
+use thiserror::Error;
+
 pub trait Projection {
     type InPoint;
     type OutPoint;
 
-    fn project(&self, input: &Self::InPoint) -> Option<Self::OutPoint>;
-    fn unproject(&self, input: &Self::OutPoint) -> Option<Self::InPoint>;
+    fn project(&self, input: &Self::InPoint) -> Result<Self::OutPoint, ProjectionError>;
+    fn unproject(&self, input: &Self::OutPoint) -> Result<Self::InPoint, ProjectionError>;
 
     fn inverse(self: Box<Self>) -> InvertedProjection<Self::InPoint, Self::OutPoint>
     where
@@ -27,11 +29,11 @@ impl<IN, OUT> Projection for InvertedProjection<IN, OUT> {
     type InPoint = OUT;
     type OutPoint = IN;
 
-    fn project(&self, input: &Self::InPoint) -> Option<Self::OutPoint> {
+    fn project(&self, input: &Self::InPoint) -> Result<Self::OutPoint, ProjectionError> {
         self.inner.unproject(input)
     }
 
-    fn unproject(&self, input: &Self::OutPoint) -> Option<Self::InPoint> {
+    fn unproject(&self, input: &Self::OutPoint) -> Result<Self::InPoint, ProjectionError> {
         self.inner.project(input)
     }
 }
@@ -54,11 +56,29 @@ impl<IN, MID, OUT> Projection for ChainProjection<IN, MID, OUT> {
     type InPoint = IN;
     type OutPoint = OUT;
 
-    fn project(&self, input: &Self::InPoint) -> Option<Self::OutPoint> {
+    fn project(&self, input: &Self::InPoint) -> Result<Self::OutPoint, ProjectionError> {
         self.second.project(&self.first.project(input)?)
     }
 
-    fn unproject(&self, input: &Self::OutPoint) -> Option<Self::InPoint> {
+    fn unproject(&self, input: &Self::OutPoint) -> Result<Self::InPoint, ProjectionError> {
         self.first.unproject(&self.second.unproject(input)?)
     }
 }
+
+/// Reason why a point or a geometry cannot be projected.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum ProjectionError {
+    /// The point is outside the area where the projection is defined, e.g. on the far side of the globe for the
+    /// orthographic projection. For geographic coordinates `x` is longitude and `y` is latitude.
+    #[error("point ({x}, {y}) is outside of the projection domain")]
+    OutOfDomain { x: f64, y: f64 },
+    /// The projection cannot be created from its definition.
+    #[error("invalid projection definition: {0}")]
+    InvalidDefinition(String),
+    /// The projection produced infinite or NaN coordinates.
+    #[error("projected coordinates are not finite")]
+    NonFinite,
+    /// The geometry cannot be projected because it is malformed.
+    #[error("invalid geometry: {0}")]
+    InvalidGeometry(String),
+}
@@ -1,7 +1,7 @@
 use crate::cartesian::impls::contour::Contour;
 use crate::cartesian::impls::multipolygon::MultiPolygon;
 use crate::cartesian::impls::polygon::Polygon;
-use crate::geo::traits::projection::Projection;
+use crate::geo::traits::projection::{Projection, ProjectionError};
 use crate::geojson::point::GeoJsonPoint;
 use crate::geometry::{Geom, Geometry};
 use crate::impls::multi_contour::MultiContour;
@@ -14,18 +14,33 @@ mod point;
 impl Geometry for geojson::Geometry {
     type Point = GeoJsonPoint;
 
-    fn project<Proj>(&self, projection: &Proj) -> Option<Geom<Proj::OutPoint>>
+    fn project<Proj>(&self, projection: &Proj) -> Result<Geom<Proj::OutPoint>, ProjectionError>
     where
         Proj: Projection<InPoint = Self::Point> + ?Sized,
     {
+        let invalid = || ProjectionError::InvalidGeometry("invalid GeoJSON coordinates".into());
         match &self.value {
-            Value::Point(p) => GeoJsonPoint::try_from(p.clone()).ok()?.project(projection),
-            Value::MultiPoint(points) => convert_multi_point(points)?.project(projection),
-            Value::LineString(points) => convert_contour(points)?.project(projection),
-            Value::MultiLineString(lines) => convert_multi_contour(lines)?.project(projection),
-            Value::Polygon(polygon) => convert_polygon(polygon)?.project(projection),
-            Value::MultiPolygon(mp) => convert_multi_polygon(mp)?.project(projection),
-            Value::GeometryCollection(_) => todo!(),
+            Value::Point(p) => GeoJsonPoint::try_from(p.clone())
+                .map_err(|_| invalid())?
+                .project(projection),
+            Value::MultiPoint(points) => convert_multi_point(points)
+                .ok_or_else(invalid)?
+                .project(projection),
+            Value::LineString(points) => convert_contour(points)
+                .ok_or_else(invalid)?
+                .project(projection),
+            Value::MultiLineString(lines) => convert_multi_contour(lines)
+                .ok_or_else(invalid)?
+                .project(projection),
+            Value::Polygon(polygon) => convert_polygon(polygon)
+                .ok_or_else(invalid)?
+                .project(projection),
+            Value::MultiPolygon(mp) => convert_multi_polygon(mp)
+                .ok_or_else(invalid)?
+                .project(projection),
+            Value::GeometryCollection(_) => Err(ProjectionError::InvalidGeometry(
+                "geometry collections are not supported".into(),
+            )),
         }
     }
 }
@@ -76,3 +91,23 @@ fn convert_multi_polygon(mp: &Vec<PolygonType>) -> Option<MultiPolygon<GeoJsonPo
             .collect::<Option<Vec<_>>>()?,
     ))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cartesian::impls::point::Point2d;
+    use crate::geo::impls::projection::web_mercator::WebMercator;
+
+    #[test]
+    fn geometry_collection_is_rejected() {
+        let point = geojson::Geometry::new(Value::Point(vec![10.0, 20.0]));
+        let collection = geojson::Geometry::new(Value::GeometryCollection(vec![point.clone()]));
+        let projection = WebMercator::<GeoJsonPoint, Point2d>::default();
+
+        assert!(point.project(&projection).is_ok());
+        assert!(matches!(
+            collection.project(&projection),
+            Err(ProjectionError::InvalidGeometry(_))
+        ));
+    }
+}
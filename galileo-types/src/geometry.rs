@@ -3,7 +3,7 @@ use crate::cartesian::impls::multipolygon::MultiPolygon;
 use crate::cartesian::impls::polygon::Polygon;
 use crate::cartesian::rect::Rect;
 use crate::cartesian::traits::cartesian_point::CartesianPoint2d;
-use crate::geo::traits::projection::Projection;
+use crate::geo::traits::projection::{Projection, ProjectionError};
 use crate::geometry_type::{CartesianSpace2d, GeometryType, PointGeometryType};
 use crate::impls::multi_contour::MultiContour;
 use crate::impls::multi_point::MultiPoint;
@@ -20,12 +20,12 @@ pub enum Geom<P> {
 impl<P: GeometryType> Geometry for Geom<P> {
     type Point = P;
 
-    fn project<Proj>(&self, projection: &Proj) -> Option<Geom<Proj::OutPoint>>
+    fn project<Proj>(&self, projection: &Proj) -> Result<Geom<Proj::OutPoint>, ProjectionError>
     where
         Proj: Projection<InPoint = <Self as Geometry>::Point> + ?Sized,
     {
         match &self {
-            Geom::Point(v) => Some(Geom::Point(projection.project(v)?)),
+            Geom::Point(v) => Ok(Geom::Point(projection.project(v)?)),
             Geom::MultiPoint(v) => v.project(projection),
             Geom::Contour(v) => v.project(projection),
             Geom::MultiContour(v) => v.project(projection),
@@ -68,9 +68,17 @@ where
 
 pub trait Geometry {
     type Point;
-    fn project<Proj>(&self, projection: &Proj) -> Option<Geom<Proj::OutPoint>>
+    fn project<Proj>(&self, projection: &Proj) -> Result<Geom<Proj::OutPoint>, ProjectionError>
     where
         Proj: Projection<InPoint = Self::Point> + ?Sized;
+
+    /// Same as [`Geometry::project`], but discards the reason why the geometry cannot be projected.
+    fn project_opt<Proj>(&self, projection: &Proj) -> Option<Geom<Proj::OutPoint>>
+    where
+        Proj: Projection<InPoint = Self::Point> + ?Sized,
+    {
+        self.project(projection).ok()
+    }
 }
 
 pub trait CartesianGeometry2d<P: CartesianPoint2d>: Geometry<Point = P> {
@@ -109,7 +117,10 @@ impl<P> From<MultiPolygon<P>> for Geom<P> {
 pub trait GeometrySpecialization<GT, ST>: GeometryType {
     type Point;
 
-    fn project_spec<Proj>(&self, projection: &Proj) -> Option<Geom<Proj::OutPoint>>
+    fn project_spec<Proj>(
+        &self,
+        projection: &Proj,
+    ) -> Result<Geom<Proj::OutPoint>, ProjectionError>
     where
         Proj: Projection<InPoint = Self::Point> + ?Sized;
 }
@@ -123,7 +134,7 @@ where
         <Self as GeometryType>::Space,
     >>::Point;
 
-    fn project<Proj>(&self, projection: &Proj) -> Option<Geom<Proj::OutPoint>>
+    fn project<Proj>(&self, projection: &Proj) -> Result<Geom<Proj::OutPoint>, ProjectionError>
     where
         Proj: Projection<InPoint = Self::Point> + ?Sized,
     {
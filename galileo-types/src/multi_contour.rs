@@ -1,7 +1,7 @@
 use crate::cartesian::rect::Rect;
 use crate::cartesian::traits::cartesian_point::CartesianPoint2d;
 use crate::contour::Contour;
-use crate::geo::traits::projection::Projection;
+use crate::geo::traits::projection::{Projection, ProjectionError};
 use crate::geometry::{
     CartesianGeometry2d, CartesianGeometry2dSpecialization, Geom, Geometry, GeometrySpecialization,
 };
@@ -20,7 +20,7 @@ where
 {
     type Point = <C::Contour as Geometry>::Point;
 
-    fn project_spec<Proj>(&self, projection: &Proj) -> Option<Geom<Proj::OutPoint>>
+    fn project_spec<Proj>(&self, projection: &Proj) -> Result<Geom<Proj::OutPoint>, ProjectionError>
     where
         Proj: Projection<InPoint = Self::Point> + ?Sized,
     {
@@ -28,12 +28,15 @@ where
             .contours()
             .map(|c| {
                 c.project(projection).and_then(|c| match c {
-                    Geom::Contour(contour) => Some(contour),
-                    _ => None,
+                    Geom::Contour(contour) => Ok(contour),
+                    _ => Err(ProjectionError::InvalidGeometry(
+                        "contour is projected into a non-contour geometry".into(),
+                    )),
                 })
             })
-            .collect::<Option<Vec<crate::cartesian::impls::contour::Contour<Proj::OutPoint>>>>()?;
-        Some(Geom::MultiContour(contours.into()))
+            .collect::<Result<Vec<crate::cartesian::impls::contour::Contour<Proj::OutPoint>>, _>>(
+            )?;
+        Ok(Geom::MultiContour(contours.into()))
     }
 }
 
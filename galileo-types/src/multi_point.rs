@@ -1,6 +1,6 @@
 use crate::cartesian::rect::Rect;
 use crate::cartesian::traits::cartesian_point::CartesianPoint2d;
-use crate::geo::traits::projection::Projection;
+use crate::geo::traits::projection::{Projection, ProjectionError};
 use crate::geometry::{
     CartesianGeometry2d, CartesianGeometry2dSpecialization, Geom, GeometrySpecialization,
 };
@@ -18,15 +18,15 @@ where
 {
     type Point = P::Point;
 
-    fn project_spec<Proj>(&self, projection: &Proj) -> Option<Geom<Proj::OutPoint>>
+    fn project_spec<Proj>(&self, projection: &Proj) -> Result<Geom<Proj::OutPoint>, ProjectionError>
     where
         Proj: Projection<InPoint = Self::Point> + ?Sized,
     {
         let points = self
             .iter_points()
             .map(|p| projection.project(p))
-            .collect::<Option<Vec<Proj::OutPoint>>>()?;
-        Some(Geom::MultiPoint(points.into()))
+            .collect::<Result<Vec<Proj::OutPoint>, _>>()?;
+        Ok(Geom::MultiPoint(points.into()))
     }
 }
 
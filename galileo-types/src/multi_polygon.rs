@@ -2,7 +2,7 @@ use crate::cartesian::impls::polygon::Polygon;
 use crate::cartesian::rect::Rect;
 use crate::cartesian::traits::cartesian_point::CartesianPoint2d;
 use crate::contour::Contour;
-use crate::geo::traits::projection::Projection;
+use crate::geo::traits::projection::{Projection, ProjectionError};
 use crate::geometry::{
     CartesianGeometry2d, CartesianGeometry2dSpecialization, Geom, Geometry, GeometrySpecialization,
 };
@@ -21,7 +21,7 @@ where
 {
     type Point = <Poly::Polygon as Geometry>::Point;
 
-    fn project_spec<Proj>(&self, projection: &Proj) -> Option<Geom<Proj::OutPoint>>
+    fn project_spec<Proj>(&self, projection: &Proj) -> Result<Geom<Proj::OutPoint>, ProjectionError>
     where
         Proj: Projection<InPoint = Self::Point> + ?Sized,
     {
@@ -29,12 +29,14 @@ where
             .polygons()
             .map(|c| {
                 c.project(projection).and_then(|c| match c {
-                    Geom::Polygon(polygon) => Some(polygon),
-                    _ => None,
+                    Geom::Polygon(polygon) => Ok(polygon),
+                    _ => Err(ProjectionError::InvalidGeometry(
+                        "polygon is projected into a non-polygon geometry".into(),
+                    )),
                 })
             })
-            .collect::<Option<Vec<Polygon<Proj::OutPoint>>>>()?;
-        Some(Geom::MultiPolygon(polygons.into()))
+            .collect::<Result<Vec<Polygon<Proj::OutPoint>>, _>>()?;
+        Ok(Geom::MultiPolygon(polygons.into()))
     }
 }
 
@@ -2,7 +2,7 @@ use crate::cartesian::rect::Rect;
 use crate::cartesian::traits::cartesian_point::CartesianPoint2d;
 use crate::cartesian::traits::polygon::CartesianPolygon;
 use crate::contour::Contour;
-use crate::geo::traits::projection::Projection;
+use crate::geo::traits::projection::{Projection, ProjectionError};
 use crate::geometry::{
     CartesianGeometry2d, CartesianGeometry2dSpecialization, Geom, Geometry, GeometrySpecialization,
 };
@@ -33,24 +33,26 @@ where
 {
     type Point = <Poly::Contour as Geometry>::Point;
 
-    fn project_spec<Proj>(&self, projection: &Proj) -> Option<Geom<Proj::OutPoint>>
+    fn project_spec<Proj>(&self, projection: &Proj) -> Result<Geom<Proj::OutPoint>, ProjectionError>
     where
         Proj: Projection<InPoint = Self::Point> + ?Sized,
     {
-        let Geom::Contour(outer_contour) = self.outer_contour().project(projection)? else {
-            return None;
+        let into_closed = |geom: Geom<Proj::OutPoint>| match geom {
+            Geom::Contour(contour) => contour.into_closed().ok_or_else(|| {
+                ProjectionError::InvalidGeometry("polygon contour is not closed".into())
+            }),
+            _ => Err(ProjectionError::InvalidGeometry(
+                "polygon contour is projected into a non-contour geometry".into(),
+            )),
         };
+
+        let outer_contour = into_closed(self.outer_contour().project(projection)?)?;
         let inner_contours = self
             .inner_contours()
-            .map(|c| {
-                c.project(projection).and_then(|c| match c {
-                    Geom::Contour(contour) => contour.into_closed(),
-                    _ => None,
-                })
-            })
-            .collect::<Option<Vec<crate::cartesian::impls::contour::ClosedContour<Proj::OutPoint>>>>()?;
-        Some(Geom::Polygon(crate::cartesian::impls::polygon::Polygon {
-            outer_contour: outer_contour.into_closed()?,
+            .map(|c| c.project(projection).and_then(into_closed))
+            .collect::<Result<Vec<crate::cartesian::impls::contour::ClosedContour<Proj::OutPoint>>, _>>()?;
+        Ok(Geom::Polygon(crate::cartesian::impls::polygon::Polygon {
+            outer_contour,
             inner_contours,
         }))
     }
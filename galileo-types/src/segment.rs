@@ -40,8 +40,8 @@ impl<'a, P: CartesianPoint2d> Segment<'a, P> {
         ) -> bool {
             let x_max = if p.x() >= r.x() { p.x() } else { r.x() };
             let x_min = if p.x() <= r.x() { p.x() } else { r.x() };
-            let y_max = if p.y() >= r.y() { p.x() } else { r.x() };
-            let y_min = if p.y() <= r.y() { p.x() } else { r.x() };
+            let y_max = if p.y() >= r.y() { p.y() } else { r.y() };
+            let y_min = if p.y() <= r.y() { p.y() } else { r.y() };
 
             q.x() <= x_max && q.x() >= x_min && q.y() <= y_max && q.y() >= y_min
         }
@@ -71,3 +71,19 @@ impl<'a, P: CartesianPoint2d> Segment<'a, P> {
         false
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cartesian::impls::point::Point2d;
+
+    #[test]
+    fn intersects_collinear() {
+        let a = (Point2d::new(0.0, 0.0), Point2d::new(0.0, 10.0));
+        let b = (Point2d::new(0.0, 5.0), Point2d::new(0.0, 20.0));
+        let c = (Point2d::new(0.0, 11.0), Point2d::new(0.0, 20.0));
+
+        assert!(Segment(&a.0, &a.1).intersects(&Segment(&b.0, &b.1)));
+        assert!(!Segment(&a.0, &a.1).intersects(&Segment(&c.0, &c.1)));
+    }
+}
@@ -7,7 +7,7 @@ use galileo_types::cartesian::rect::Rect;
 use galileo_types::cartesian::traits::cartesian_point::CartesianPoint2d;
 use galileo_types::geo::impls::point::GeoPoint2d;
 use galileo_types::geo::traits::point::{GeoPoint, NewGeoPoint};
-use galileo_types::geo::traits::projection::Projection;
+use galileo_types::geo::traits::projection::{Projection, ProjectionError};
 use galileo_types::geometry::{CartesianGeometry2d, Geom, Geometry};
 use serde::{Deserialize, Deserializer, Serialize};
 
@@ -45,7 +45,7 @@ impl Geometry for Country {
     fn project<P: Projection<InPoint = Self::Point> + ?Sized>(
         &self,
         projection: &P,
-    ) -> Option<Geom<P::OutPoint>> {
+    ) -> Result<Geom<P::OutPoint>, ProjectionError> {
         self.geometry.project(projection)
     }
 }
@@ -102,7 +102,7 @@ impl Geometry for City {
     fn project<P: Projection<InPoint = Self::Point> + ?Sized>(
         &self,
         projection: &P,
-    ) -> Option<Geom<P::OutPoint>> {
+    ) -> Result<Geom<P::OutPoint>, ProjectionError> {
         GeoPoint2d::latlon(self.lat, self.lng).project(projection)
     }
 }
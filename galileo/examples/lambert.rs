@@ -68,7 +68,7 @@ pub async fn run(builder: MapBuilder) {
                     layer.crs().get_projection::<_, Point2d>().unwrap(),
                 );
 
-                let Some(projected) = projection.project(&position) else {
+                let Ok(projected) = projection.project(&position) else {
                     return EventPropagation::Stop;
                 };
 
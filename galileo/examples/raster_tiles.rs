@@ -1,4 +1,5 @@
 use galileo::galileo_map::MapBuilder;
+use galileo::overlay::{Attribution, Compass, ScaleBar, ZoomButtons};
 use galileo::tile_scheme::TileScheme;
 use galileo_types::latlon;
 
@@ -22,6 +23,10 @@ pub async fn run(builder: MapBuilder) {
             },
             TileScheme::web(18),
         )
+        .with_widget(ZoomButtons::new())
+        .with_widget(Compass::new())
+        .with_widget(ScaleBar::new())
+        .with_widget(Attribution::new("© OpenStreetMap contributors"))
         .build()
         .await
         .run();
@@ -16,7 +16,7 @@ use galileo::messenger::{DummyMessenger, Messenger};
 use galileo::render::wgpu::WgpuRenderer;
 use galileo::symbol::arbitrary::ArbitraryGeometrySymbol;
 use galileo::tile_scheme::TileIndex;
-use galileo::view::MapView;
+use galileo::view::{MapView, ViewConstraints};
 use galileo::TileScheme;
 use galileo_types::cartesian::size::Size;
 use galileo_types::geo::crs::Crs;
@@ -55,15 +55,9 @@ async fn main() -> Result<()> {
     // To calculate the area of the map which we want to draw, we use map's CRS instead of
     // layer CRS.
     let extent = layer.extent_projected(&Crs::EPSG3857).unwrap();
-    let center = extent.center();
 
     let image_size = Size::new(512, 512);
 
-    let width_resolution = extent.width() / image_size.width() as f64;
-    let height_resolution = extent.height() / image_size.height() as f64;
-    let resolution = (width_resolution.max(height_resolution) * 1.1)
-        .max(TileScheme::web(18).lod_resolution(17).unwrap());
-
     // Create OSM layer for background
     let cache_controller = Some(FileCacheController::new(".tile_cache"));
     let tile_provider = UrlImageProvider::new(
@@ -85,7 +79,13 @@ async fn main() -> Result<()> {
     // be transparent.
     osm.set_fade_in_duration(Duration::default());
 
-    let map_view = MapView::new_projected(&center, resolution).with_size(image_size.cast());
+    let map_view = MapView::new_projected(&extent.center(), 1.0)
+        .with_size(image_size.cast())
+        .fit_bounds(extent, 25.0)
+        .unwrap();
+    let map_view = ViewConstraints::new()
+        .with_resolution_range(TileScheme::web(18).lod_resolution(17).unwrap(), f64::MAX)
+        .apply(&map_view);
 
     // Load all tiles required for the given view before we request rendering.
     osm.load_tiles(&map_view).await;
@@ -88,5 +88,6 @@ pub fn tile_scheme() -> TileScheme {
         max_tile_scale: 8.0,
         cycle_x: true,
         crs: Crs::EPSG3857,
+        hidpi: false,
     }
 }
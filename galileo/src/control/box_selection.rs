@@ -0,0 +1,266 @@
+use crate::control::map::MapControllerParameters;
+use crate::control::{EventPropagation, MouseButton, UserEvent, UserEventHandler};
+use crate::map::Map;
+use crate::overlay::{ScreenPainter, Widget};
+use crate::render::Renderer;
+use crate::view::{MapView, ViewConstraints};
+use crate::Color;
+use galileo_types::cartesian::impls::point::Point2d;
+use galileo_types::cartesian::rect::Rect;
+use maybe_sync::{MaybeSend, MaybeSync};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+const ZOOM_DURATION: Duration = Duration::from_millis(200);
+
+/// What is done with the rectangle drawn by the user.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum BoxSelectionMode {
+    /// The map is zoomed to show the rectangle.
+    Zoom,
+    /// The rectangle is given to the selection callback.
+    Select,
+}
+
+#[derive(Debug, Copy, Clone)]
+struct RubberBand {
+    mode: BoxSelectionMode,
+    start: Point2d,
+    end: Point2d,
+}
+
+type SelectionCallback = dyn Fn(Rect, &mut Map) + MaybeSend + MaybeSync;
+
+/// Event handler for drawing a rectangle on the map with the mouse: dragging with `Shift` pressed zooms the map into
+/// the rectangle, dragging with `Ctrl` pressed selects the rectangle.
+///
+/// The selected rectangle is given in the projected coordinates of the map to the callback set with
+/// [`BoxSelection::on_select`]. Without the callback, `Ctrl`-drag is not handled.
+///
+/// The rectangle is shown while dragging by the overlay widget returned from [`BoxSelection::widget`]. The view the
+/// map is zoomed to is limited by the
+/// [`ViewConstraints`] set with [`BoxSelection::with_constraints`], which should be the same as the ones of the
+/// [`MapController`](crate::control::map::MapController).
+///
+/// ```no_run
+/// # use galileo::control::box_selection::BoxSelection;
+/// # use galileo::galileo_map::MapBuilder;
+/// let selection = BoxSelection::new()
+///     .on_select(|rect, _map| println!("selected area: {rect:?}"));
+/// let builder = MapBuilder::new()
+///     .with_widget(selection.widget())
+///     .with_user_event_handler(selection);
+/// ```
+pub struct BoxSelection {
+    rubber_band: Arc<RwLock<Option<RubberBand>>>,
+    on_select: Option<Box<SelectionCallback>>,
+    constraints: ViewConstraints,
+}
+
+impl Default for BoxSelection {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BoxSelection {
+    /// Creates the handler with zooming enabled and no selection callback. The zoom is limited by the default
+    /// constraints of the [`MapController`](crate::control::map::MapController).
+    pub fn new() -> Self {
+        Self {
+            rubber_band: Arc::new(RwLock::new(None)),
+            on_select: None,
+            constraints: MapControllerParameters::default().constraints,
+        }
+    }
+
+    /// Sets the function called with the rectangle selected by `Ctrl`-drag.
+    pub fn on_select(
+        mut self,
+        callback: impl Fn(Rect, &mut Map) + MaybeSend + MaybeSync + 'static,
+    ) -> Self {
+        self.on_select = Some(Box::new(callback));
+        self
+    }
+
+    /// Sets the limits of the view the map is zoomed to.
+    pub fn with_constraints(mut self, constraints: ViewConstraints) -> Self {
+        self.constraints = constraints;
+        self
+    }
+
+    /// Returns the overlay widget that draws the rectangle while the user drags it.
+    pub fn widget(&self) -> BoxSelectionWidget {
+        BoxSelectionWidget {
+            rubber_band: self.rubber_band.clone(),
+            fill: Color::rgba(50, 100, 200, 60),
+            outline: Color::rgba(50, 100, 200, 255),
+        }
+    }
+
+    fn set_rubber_band(&self, rubber_band: Option<RubberBand>) {
+        *self.rubber_band.write().expect("lock is poisoned") = rubber_band;
+    }
+
+    fn rubber_band(&self) -> Option<RubberBand> {
+        *self.rubber_band.read().expect("lock is poisoned")
+    }
+}
+
+impl UserEventHandler for BoxSelection {
+    fn handle(
+        &self,
+        event: &UserEvent,
+        map: &mut Map,
+        _backend: &dyn Renderer,
+    ) -> EventPropagation {
+        match event {
+            UserEvent::DragStarted(MouseButton::Left, e) => {
+                let mode = if e.modifiers.shift {
+                    BoxSelectionMode::Zoom
+                } else if e.modifiers.ctrl && self.on_select.is_some() {
+                    BoxSelectionMode::Select
+                } else {
+                    return EventPropagation::Propagate;
+                };
+
+                self.set_rubber_band(Some(RubberBand {
+                    mode,
+                    start: e.screen_pointer_position,
+                    end: e.screen_pointer_position,
+                }));
+                EventPropagation::Consume
+            }
+            UserEvent::Drag(_, _, e) => {
+                let Some(mut rubber_band) = self.rubber_band() else {
+                    return EventPropagation::Propagate;
+                };
+
+                rubber_band.end = e.screen_pointer_position;
+                self.set_rubber_band(Some(rubber_band));
+                map.redraw();
+                EventPropagation::Stop
+            }
+            UserEvent::DragEnded(_, e) => {
+                let Some(rubber_band) = self.rubber_band() else {
+                    return EventPropagation::Propagate;
+                };
+
+                self.set_rubber_band(None);
+                map.redraw();
+
+                let Some(rect) = map_rect(map.view(), rubber_band.start, e.screen_pointer_position)
+                else {
+                    return EventPropagation::Stop;
+                };
+
+                match rubber_band.mode {
+                    BoxSelectionMode::Zoom => {
+                        if let Some(target) = zoom_target(map.view(), rect, &self.constraints) {
+                            map.animate_to(target, ZOOM_DURATION);
+                        }
+                    }
+                    BoxSelectionMode::Select => {
+                        if let Some(callback) = &self.on_select {
+                            callback(rect, map);
+                        }
+                    }
+                }
+
+                EventPropagation::Stop
+            }
+            _ => EventPropagation::Propagate,
+        }
+    }
+}
+
+/// Overlay widget drawing the rectangle of a [`BoxSelection`] while it is dragged.
+///
+/// The widget does not take part in hit testing, so the drag events over the rectangle reach the handler.
+pub struct BoxSelectionWidget {
+    rubber_band: Arc<RwLock<Option<RubberBand>>>,
+    fill: Color,
+    outline: Color,
+}
+
+impl BoxSelectionWidget {
+    /// Sets the colors of the rectangle.
+    pub fn with_colors(mut self, fill: Color, outline: Color) -> Self {
+        self.fill = fill;
+        self.outline = outline;
+        self
+    }
+}
+
+impl Widget for BoxSelectionWidget {
+    fn bounds(&self, _view: &MapView) -> Option<Rect> {
+        None
+    }
+
+    fn render(&self, _view: &MapView, painter: &mut ScreenPainter) {
+        let Some(rubber_band) = *self.rubber_band.read().expect("lock is poisoned") else {
+            return;
+        };
+        let Some(rect) = Rect::from_points([rubber_band.start, rubber_band.end].iter()) else {
+            return;
+        };
+
+        painter.fill_rect(rect, self.fill);
+        painter.stroke_rect(rect, self.outline, 1.0);
+    }
+}
+
+/// Map coordinates of the corners of the screen rectangle, in the order around the rectangle.
+fn map_corners(view: &MapView, from: Point2d, to: Point2d) -> Option<[Point2d; 4]> {
+    Some([
+        view.screen_to_map(from)?,
+        view.screen_to_map(Point2d::new(from.x, to.y))?,
+        view.screen_to_map(to)?,
+        view.screen_to_map(Point2d::new(to.x, from.y))?,
+    ])
+}
+
+/// View that shows the given map rectangle, limited by the constraints.
+fn zoom_target(view: &MapView, rect: Rect, constraints: &ViewConstraints) -> Option<MapView> {
+    Some(constraints.apply(&view.fit_bounds(rect, 0.0)?))
+}
+
+/// Bounding rectangle in map coordinates of the screen rectangle. Returns `None` for degenerate rectangles.
+fn map_rect(view: &MapView, from: Point2d, to: Point2d) -> Option<Rect> {
+    if (to.x - from.x).abs() < 1.0 || (to.y - from.y).abs() < 1.0 {
+        return None;
+    }
+
+    Rect::from_points(map_corners(view, from, to)?.iter())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use galileo_types::cartesian::size::Size;
+
+    #[test]
+    fn map_rect_of_screen_rectangle() {
+        let view =
+            MapView::new_projected(&Point2d::new(0.0, 0.0), 2.0).with_size(Size::new(100.0, 100.0));
+
+        let rect = map_rect(&view, Point2d::new(60.0, 10.0), Point2d::new(10.0, 60.0)).unwrap();
+        assert_eq!(rect, Rect::new(-80.0, -20.0, 20.0, 80.0));
+
+        assert!(map_rect(&view, Point2d::new(10.0, 10.0), Point2d::new(10.5, 60.0)).is_none());
+    }
+
+    #[test]
+    fn zoom_respects_constraints() {
+        let view =
+            MapView::new_projected(&Point2d::new(0.0, 0.0), 2.0).with_size(Size::new(100.0, 100.0));
+        let rect = Rect::new(0.0, 0.0, 10.0, 10.0);
+
+        let target = zoom_target(&view, rect, &ViewConstraints::new()).unwrap();
+        assert!((target.resolution() - 0.1).abs() < 1e-9);
+
+        let constraints = ViewConstraints::new().with_resolution_range(0.5, 10.0);
+        let target = zoom_target(&view, rect, &constraints).unwrap();
+        assert!((target.resolution() - 0.5).abs() < 1e-9);
+    }
+}
@@ -1,6 +1,6 @@
 use crate::control::{
-    EventPropagation, MouseButton, MouseButtonsState, MouseEvent, RawUserEvent, TouchId, UserEvent,
-    UserEventHandler,
+    EventPropagation, KeyboardModifiers, MouseButton, MouseButtonsState, MouseEvent, RawUserEvent,
+    TouchId, UserEvent, UserEventHandler,
 };
 use crate::map::Map;
 use crate::render::Renderer;
@@ -12,6 +12,29 @@ const DRAG_THRESHOLD: f64 = 3.0;
 const CLICK_TIMEOUT: std::time::Duration = std::time::Duration::from_millis(200);
 const DBL_CLICK_TIMEOUT: std::time::Duration = std::time::Duration::from_millis(500);
 
+/// Relative change of the distance between two fingers after which the gesture is recognized as zoom.
+const PINCH_ZOOM_THRESHOLD: f64 = 0.1;
+/// Change of the angle between two fingers (in radians) after which the gesture is recognized as rotation.
+const PINCH_ROTATION_THRESHOLD: f64 = 0.17;
+/// Vertical movement of two fingers (in pixels) after which the gesture is recognized as tilt.
+const TILT_THRESHOLD: f64 = 10.0;
+/// Maximum angle between the fingers and the horizontal line for the tilt gesture.
+const TILT_MAX_FINGERS_ANGLE: f64 = 0.5;
+
+/// Two-finger gesture in progress. The gesture stays undecided until the movement of the fingers exceeds one of the
+/// thresholds, after which only the events of the recognized gesture are emitted until one of the fingers is lifted.
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum TwoFingerGesture {
+    Pending {
+        start_distance: f64,
+        start_angle: f64,
+        start_center: Point2d,
+    },
+    Zoom,
+    Rotate,
+    Tilt,
+}
+
 struct TouchInfo {
     id: TouchId,
     start_position: Point2d,
@@ -24,8 +47,10 @@ pub struct EventProcessor {
     pointer_position: Point2d,
     pointer_pressed_position: Point2d,
     touches: Vec<TouchInfo>,
+    two_finger_gesture: Option<TwoFingerGesture>,
 
     buttons_state: MouseButtonsState,
+    modifiers: KeyboardModifiers,
 
     last_pressed_time: SystemTime,
     last_click_time: SystemTime,
@@ -40,7 +65,9 @@ impl Default for EventProcessor {
             pointer_position: Default::default(),
             pointer_pressed_position: Default::default(),
             touches: Vec::new(),
+            two_finger_gesture: None,
             buttons_state: Default::default(),
+            modifiers: Default::default(),
             last_pressed_time: SystemTime::UNIX_EPOCH,
             last_click_time: SystemTime::UNIX_EPOCH,
             drag_target: None,
@@ -70,6 +97,10 @@ impl EventProcessor {
                     log::info!("click position: {map_position:?}");
                 }
 
+                if !Self::handle_by_overlay(&user_event, map) {
+                    continue;
+                }
+
                 for (index, handler) in self.handlers.iter_mut().enumerate() {
                     if matches!(user_event, UserEvent::Drag(..) | UserEvent::DragEnded(..)) {
                         if let Some(target) = &self.drag_target {
@@ -105,6 +136,25 @@ impl EventProcessor {
         }
     }
 
+    /// Gives the event to the overlay widget under the pointer. Returns false if the widget stopped the event.
+    fn handle_by_overlay(event: &UserEvent, map: &mut Map) -> bool {
+        let position = match event {
+            UserEvent::ButtonPressed(_, e)
+            | UserEvent::ButtonReleased(_, e)
+            | UserEvent::Click(_, e)
+            | UserEvent::DoubleClick(_, e)
+            | UserEvent::DragStarted(_, e)
+            | UserEvent::Scroll(_, e) => e.screen_pointer_position,
+            _ => return true,
+        };
+
+        let Some(widget) = map.overlay().widget_at(map.view(), position) else {
+            return true;
+        };
+
+        matches!(widget.handle(event, map), EventPropagation::Propagate)
+    }
+
     fn process(&mut self, event: RawUserEvent) -> Option<Vec<UserEvent>> {
         let now = SystemTime::now();
         match event {
@@ -134,10 +184,11 @@ impl EventProcessor {
                     }
 
                     self.last_click_time = now;
+                }
 
-                    if self.drag_target.take().is_some() {
-                        events.push(UserEvent::DragEnded(button, self.get_mouse_event()));
-                    }
+                // The drag target is reset after the event is delivered to it.
+                if self.drag_target.is_some() {
+                    events.push(UserEvent::DragEnded(button, self.get_mouse_event()));
                 }
 
                 Some(events)
@@ -175,6 +226,12 @@ impl EventProcessor {
             RawUserEvent::Scroll(delta) => {
                 Some(vec![UserEvent::Scroll(delta, self.get_mouse_event())])
             }
+            RawUserEvent::KeyPressed(key) => Some(vec![UserEvent::KeyPressed(key)]),
+            RawUserEvent::KeyReleased(key) => Some(vec![UserEvent::KeyReleased(key)]),
+            RawUserEvent::ModifiersChanged(modifiers) => {
+                self.modifiers = modifiers;
+                None
+            }
             RawUserEvent::TouchStart(touch) => {
                 for i in 0..self.touches.len() {
                     if self.touches[i].id == touch.touch_id {
@@ -191,6 +248,15 @@ impl EventProcessor {
                     prev_position: touch.position,
                 });
 
+                self.two_finger_gesture = match &self.touches[..] {
+                    [first, second] => Some(TwoFingerGesture::Pending {
+                        start_distance: (second.prev_position - first.prev_position).magnitude(),
+                        start_angle: fingers_angle(first.prev_position, second.prev_position),
+                        start_center: center(first.prev_position, second.prev_position),
+                    }),
+                    _ => None,
+                };
+
                 None
             }
             RawUserEvent::TouchMove(touch) => {
@@ -223,12 +289,60 @@ impl EventProcessor {
                     }
                 } else if self.touches.len() == 2 {
                     let other_touch = self.touches.iter().find(|t| t.id != touch_info.id).unwrap();
-                    let distance = (other_touch.prev_position - position).magnitude();
-                    let prev_distance =
-                        (other_touch.prev_position - touch_info.prev_position).magnitude();
-                    let zoom = prev_distance / distance;
+                    let other_position = other_touch.prev_position;
+                    let prev_position = touch_info.prev_position;
+
+                    if let Some(TwoFingerGesture::Pending {
+                        start_distance,
+                        start_angle,
+                        start_center,
+                    }) = self.two_finger_gesture
+                    {
+                        // The start angle is measured from the first finger to the second one,
+                        // so the current angle must be too, whichever of them has moved.
+                        let (first, second) = if self.touches[0].id == touch_info.id {
+                            (position, other_position)
+                        } else {
+                            (other_position, position)
+                        };
+                        self.two_finger_gesture = Some(
+                            recognize_gesture(
+                                start_distance,
+                                start_angle,
+                                start_center,
+                                first,
+                                second,
+                            )
+                            .unwrap_or(TwoFingerGesture::Pending {
+                                start_distance,
+                                start_angle,
+                                start_center,
+                            }),
+                        );
+                    }
 
-                    events.push(UserEvent::Zoom(zoom, other_touch.prev_position))
+                    match self.two_finger_gesture {
+                        Some(TwoFingerGesture::Zoom) => {
+                            let distance = (other_position - position).magnitude();
+                            let prev_distance = (other_position - prev_position).magnitude();
+                            let zoom = prev_distance / distance;
+
+                            events.push(UserEvent::Zoom(zoom, other_position))
+                        }
+                        Some(TwoFingerGesture::Rotate) => {
+                            // Screen Y axis points down, so the angle is inverted to be counterclockwise positive.
+                            let angle = fingers_angle(other_position, prev_position)
+                                - fingers_angle(other_position, position);
+                            events.push(UserEvent::Rotate(
+                                normalize_angle(angle),
+                                center(other_position, position),
+                            ))
+                        }
+                        Some(TwoFingerGesture::Tilt) => {
+                            events.push(UserEvent::Tilt((position.y - prev_position.y) / 2.0))
+                        }
+                        _ => {}
+                    }
                 }
 
                 for touch_info in &mut self.touches {
@@ -247,10 +361,13 @@ impl EventProcessor {
                     }
                 }
 
+                if self.touches.len() < 2 {
+                    self.two_finger_gesture = None;
+                }
+
                 let mut events = vec![];
 
                 if self.drag_target.is_some() && self.touches.is_empty() {
-                    self.drag_target = None;
                     events.push(UserEvent::DragEnded(
                         MouseButton::Other,
                         self.get_mouse_event_pos(touch.position),
@@ -270,6 +387,111 @@ impl EventProcessor {
         MouseEvent {
             screen_pointer_position,
             buttons: self.buttons_state,
+            modifiers: self.modifiers,
         }
     }
 }
+
+fn center(a: Point2d, b: Point2d) -> Point2d {
+    Point2d::new((a.x + b.x) / 2.0, (a.y + b.y) / 2.0)
+}
+
+fn fingers_angle(a: Point2d, b: Point2d) -> f64 {
+    (b.y - a.y).atan2(b.x - a.x)
+}
+
+fn normalize_angle(angle: f64) -> f64 {
+    use std::f64::consts::PI;
+    (angle + PI).rem_euclid(2.0 * PI) - PI
+}
+
+/// Decides which gesture the fingers perform based on their movement since the start of the gesture. Returns `None`
+/// if none of the thresholds is exceeded yet.
+fn recognize_gesture(
+    start_distance: f64,
+    start_angle: f64,
+    start_center: Point2d,
+    a: Point2d,
+    b: Point2d,
+) -> Option<TwoFingerGesture> {
+    let distance = (b - a).magnitude();
+    if start_distance > 0.0 && (distance / start_distance).ln().abs() > PINCH_ZOOM_THRESHOLD {
+        return Some(TwoFingerGesture::Zoom);
+    }
+
+    let angle = fingers_angle(a, b);
+    if normalize_angle(angle - start_angle).abs() > PINCH_ROTATION_THRESHOLD {
+        return Some(TwoFingerGesture::Rotate);
+    }
+
+    let is_horizontal = angle.sin().abs() < TILT_MAX_FINGERS_ANGLE.sin();
+    if is_horizontal && (center(a, b).y - start_center.y).abs() > TILT_THRESHOLD {
+        return Some(TwoFingerGesture::Tilt);
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::control::TouchEvent;
+
+    fn touch(processor: &mut EventProcessor, id: TouchId, x: f64, y: f64) -> Vec<UserEvent> {
+        let event = TouchEvent {
+            touch_id: id,
+            position: Point2d::new(x, y),
+        };
+        let raw = if processor.touches.iter().any(|t| t.id == id) {
+            RawUserEvent::TouchMove(event)
+        } else {
+            RawUserEvent::TouchStart(event)
+        };
+
+        processor.process(raw).unwrap_or_default()
+    }
+
+    #[test]
+    fn recognizes_pinch_zoom() {
+        let mut processor = EventProcessor::default();
+        touch(&mut processor, 0, 100.0, 100.0);
+        touch(&mut processor, 1, 200.0, 100.0);
+
+        assert!(touch(&mut processor, 1, 202.0, 100.0).is_empty());
+        let events = touch(&mut processor, 1, 250.0, 100.0);
+        assert!(matches!(events[..], [UserEvent::Zoom(..)]));
+
+        // Once recognized, the gesture does not change until the fingers are lifted.
+        let events = touch(&mut processor, 1, 250.0, 150.0);
+        assert!(matches!(events[..], [UserEvent::Zoom(..)]));
+    }
+
+    #[test]
+    fn recognizes_rotation() {
+        let mut processor = EventProcessor::default();
+        touch(&mut processor, 0, 100.0, 100.0);
+        touch(&mut processor, 1, 200.0, 100.0);
+
+        let events = touch(&mut processor, 1, 198.0, 130.0);
+        let [UserEvent::Rotate(angle, _)] = events[..] else {
+            panic!("rotation is not recognized");
+        };
+        assert!(angle < 0.0);
+    }
+
+    #[test]
+    fn recognizes_tilt() {
+        let mut processor = EventProcessor::default();
+        touch(&mut processor, 0, 100.0, 100.0);
+        touch(&mut processor, 1, 200.0, 100.0);
+
+        assert!(touch(&mut processor, 0, 100.0, 95.0).is_empty());
+        assert!(touch(&mut processor, 1, 200.0, 95.0).is_empty());
+        assert!(touch(&mut processor, 0, 100.0, 88.0).is_empty());
+        let events = touch(&mut processor, 1, 200.0, 88.0);
+        let [UserEvent::Tilt(delta)] = events[..] else {
+            panic!("tilt is not recognized");
+        };
+        assert_eq!(delta, -3.5);
+    }
+}
@@ -1,25 +1,125 @@
-use crate::control::{EventPropagation, MouseButton, UserEvent, UserEventHandler};
+use crate::control::{EventPropagation, Key, MouseButton, UserEvent, UserEventHandler};
+use crate::map::animation::{Animation, Easing};
 use crate::map::Map;
 use crate::render::Renderer;
-use crate::view::MapView;
+use crate::view::{MapView, ViewConstraints};
+use galileo_types::cartesian::impls::point::Point2d;
 use nalgebra::Vector2;
+use std::sync::RwLock;
 use std::time::Duration;
+use web_time::SystemTime;
 
 const DEFAULT_ZOOM_DURATION: Duration = Duration::from_millis(50);
 
+/// Drag movements older than this are not taken into account when calculating the speed of the map on release.
+const VELOCITY_WINDOW: Duration = Duration::from_millis(100);
+/// Speed in pixels per second at which the inertial movement stops.
+const INERTIA_MIN_SPEED: f64 = 20.0;
+
+/// Event handler that navigates the map in response to the user input: pans, zooms, rotates and tilts the map.
+///
+/// What the controller does in response to which input is configured with [`MapControllerParameters`].
 #[derive(Default)]
 pub struct MapController {
     parameters: MapControllerParameters,
+    key_bindings: KeyBindings,
+    drag_samples: RwLock<Vec<(SystemTime, Vector2<f64>)>>,
+    drag_action: RwLock<Option<DragAction>>,
 }
 
+/// Parameters of the [`MapController`].
+#[derive(Debug, Clone, PartialEq)]
 pub struct MapControllerParameters {
-    zoom_duration: Duration,
-    zoom_speed: f64,
-    min_resolution: f64,
-    max_resolution: f64,
+    /// Duration of the animation of the zoom by mouse wheel.
+    pub zoom_duration: Duration,
+    /// Change of the resolution for one step of the mouse wheel. With the value of `0.2` every step changes the
+    /// resolution by 20%.
+    pub zoom_speed: f64,
+    /// Limits of the view the user can navigate to.
+    pub constraints: ViewConstraints,
+
+    /// Rotation angle in radians for one pixel of drag movement.
+    pub rotation_speed: f64,
+    /// Maximum tilt of the map in radians.
+    pub max_rotation_x: f64,
+
+    /// Distance in pixels the map is panned by one key press.
+    pub key_pan_step: f64,
+    /// Rotation angle in radians for one key press.
+    pub key_rotation_step: f64,
+    /// Duration of the animation of discrete navigation steps: key presses and double click zoom.
+    pub step_animation_duration: Duration,
+    /// Resolution multiplier for the double click zoom.
+    pub double_click_zoom: f64,
+
+    /// Movement of the map after the drag is released. `None` disables inertia.
+    pub inertia: Option<InertiaParameters>,
+    /// Which user input the controller responds to.
+    pub input: InputMapping,
+}
 
-    rotation_speed: f64,
-    max_rotation_x: f64,
+/// Navigation action performed by dragging the map.
+///
+/// Zooming into a rectangle drawn with the mouse is done by the
+/// [`BoxSelection`](crate::control::box_selection::BoxSelection) handler.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DragAction {
+    /// Moves the map with the pointer.
+    Pan,
+    /// Rotates the map with horizontal movement and tilts it with vertical movement.
+    Rotate,
+}
+
+/// Mapping of the user gestures to the navigation actions of the [`MapController`]. Every gesture can be disabled
+/// by setting it to `None` or `false`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InputMapping {
+    pub left_drag: Option<DragAction>,
+    pub right_drag: Option<DragAction>,
+    pub middle_drag: Option<DragAction>,
+    /// Action for dragging with one finger.
+    pub touch_drag: Option<DragAction>,
+    pub double_click_zoom: bool,
+    pub scroll_zoom: bool,
+    pub pinch_zoom: bool,
+    pub touch_rotate: bool,
+    pub touch_tilt: bool,
+    pub keyboard: bool,
+}
+
+impl Default for InputMapping {
+    fn default() -> Self {
+        Self {
+            left_drag: Some(DragAction::Pan),
+            right_drag: Some(DragAction::Rotate),
+            middle_drag: None,
+            touch_drag: Some(DragAction::Pan),
+            double_click_zoom: true,
+            scroll_zoom: true,
+            pinch_zoom: true,
+            touch_rotate: true,
+            touch_tilt: true,
+            keyboard: true,
+        }
+    }
+}
+
+/// Parameters of the map movement that continues after the map is dragged and released.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct InertiaParameters {
+    /// Rate of the exponential deceleration, in 1/s. The larger the value, the sooner the map stops.
+    pub friction: f64,
+    /// Maximum speed of the map right after release, in pixels per second.
+    pub max_speed: f64,
+}
+
+impl Default for InertiaParameters {
+    fn default() -> Self {
+        Self {
+            friction: 4.0,
+            max_speed: 4000.0,
+        }
+    }
 }
 
 impl Default for MapControllerParameters {
@@ -27,14 +127,89 @@ impl Default for MapControllerParameters {
         Self {
             zoom_duration: DEFAULT_ZOOM_DURATION,
             zoom_speed: 0.2,
-            max_resolution: 156543.03392800014 / 8.0,
-            min_resolution: 156543.03392800014 / 8.0 / 2.0f64.powi(16),
+            constraints: ViewConstraints::new().with_resolution_range(
+                156543.03392800014 / 8.0 / 2.0f64.powi(16),
+                156543.03392800014 / 8.0,
+            ),
             rotation_speed: 0.005,
             max_rotation_x: 80f64.to_radians(),
+            key_pan_step: 100.0,
+            key_rotation_step: 15f64.to_radians(),
+            step_animation_duration: Duration::from_millis(150),
+            double_click_zoom: 0.5,
+            inertia: Some(InertiaParameters::default()),
+            input: InputMapping::default(),
         }
     }
 }
 
+/// Change of the map view that can be bound to a keyboard key.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MapAction {
+    PanUp,
+    PanDown,
+    PanLeft,
+    PanRight,
+    ZoomIn,
+    ZoomOut,
+    RotateClockwise,
+    RotateCounterClockwise,
+    /// Rotates the map so that the north is on the top of the screen.
+    ResetNorth,
+}
+
+/// Keyboard keys the [`MapController`] responds to.
+///
+/// By default the arrow keys pan the map, `+`/`=` and `-` zoom it, `q` and `e` rotate it and `n` resets the rotation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyBindings {
+    bindings: Vec<(Key, MapAction)>,
+}
+
+impl KeyBindings {
+    /// Creates bindings with no keys bound.
+    pub fn empty() -> Self {
+        Self { bindings: vec![] }
+    }
+
+    /// Binds the key to the action, replacing the previous binding of the key.
+    pub fn bind(mut self, key: Key, action: MapAction) -> Self {
+        self.bindings.retain(|(bound, _)| *bound != key);
+        self.bindings.push((key, action));
+        self
+    }
+
+    /// Removes the binding of the key.
+    pub fn unbind(mut self, key: Key) -> Self {
+        self.bindings.retain(|(bound, _)| *bound != key);
+        self
+    }
+
+    /// Returns the action the key is bound to.
+    pub fn action(&self, key: Key) -> Option<MapAction> {
+        self.bindings
+            .iter()
+            .find(|(bound, _)| *bound == key)
+            .map(|(_, action)| *action)
+    }
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self::empty()
+            .bind(Key::ArrowUp, MapAction::PanUp)
+            .bind(Key::ArrowDown, MapAction::PanDown)
+            .bind(Key::ArrowLeft, MapAction::PanLeft)
+            .bind(Key::ArrowRight, MapAction::PanRight)
+            .bind(Key::Character('+'), MapAction::ZoomIn)
+            .bind(Key::Character('='), MapAction::ZoomIn)
+            .bind(Key::Character('-'), MapAction::ZoomOut)
+            .bind(Key::Character('q'), MapAction::RotateCounterClockwise)
+            .bind(Key::Character('e'), MapAction::RotateClockwise)
+            .bind(Key::Character('n'), MapAction::ResetNorth)
+    }
+}
+
 impl UserEventHandler for MapController {
     fn handle(
         &self,
@@ -42,61 +217,282 @@ impl UserEventHandler for MapController {
         map: &mut Map,
         _backend: &dyn Renderer,
     ) -> EventPropagation {
+        let input = &self.parameters.input;
         match event {
-            UserEvent::DragStarted(button, _)
-                if *button == MouseButton::Left
-                    || *button == MouseButton::Right
-                    || *button == MouseButton::Other =>
-            {
-                EventPropagation::Consume
+            UserEvent::ButtonPressed(..) => {
+                map.stop_animation();
+                EventPropagation::Propagate
             }
-            UserEvent::Drag(button, delta, e) => match button {
-                MouseButton::Left | MouseButton::Other => {
-                    let current_position = e.screen_pointer_position;
-                    let prev_position = current_position - delta;
-
-                    map.set_view(
-                        map.view()
-                            .translate_by_pixels(prev_position, current_position),
-                    );
-                    EventPropagation::Stop
+            UserEvent::DragStarted(button, _) => {
+                let action = match button {
+                    MouseButton::Left => input.left_drag,
+                    MouseButton::Right => input.right_drag,
+                    MouseButton::Middle => input.middle_drag,
+                    MouseButton::Other => input.touch_drag,
+                };
+
+                *self.drag_action.write().expect("lock is poisoned") = action;
+                match action {
+                    Some(_) => {
+                        map.stop_animation();
+                        self.drag_samples.write().expect("lock is poisoned").clear();
+                        EventPropagation::Consume
+                    }
+                    None => EventPropagation::Propagate,
                 }
-                MouseButton::Right => {
-                    map.set_view(self.get_rotation(map.view(), *delta));
-                    EventPropagation::Stop
+            }
+            UserEvent::Drag(_, delta, e) => {
+                let action = *self.drag_action.read().expect("lock is poisoned");
+                match action {
+                    Some(DragAction::Pan) => {
+                        self.add_drag_sample(*delta);
+                        let current_position = e.screen_pointer_position;
+                        let prev_position = current_position - delta;
+
+                        map.set_view(
+                            self.parameters.constraints.apply(
+                                &map.view()
+                                    .translate_by_pixels(prev_position, current_position),
+                            ),
+                        );
+                        EventPropagation::Stop
+                    }
+                    Some(DragAction::Rotate) => {
+                        map.set_view(self.get_rotation(map.view(), *delta));
+                        EventPropagation::Stop
+                    }
+                    None => EventPropagation::Propagate,
                 }
-                _ => EventPropagation::Propagate,
-            },
-            UserEvent::Scroll(delta, mouse_event) => {
-                let zoom = self.get_zoom(*delta, map.view().resolution());
-                let target = map
-                    .target_view()
-                    .zoom(zoom, mouse_event.screen_pointer_position);
+            }
+            UserEvent::DragEnded(..) => {
+                let action = self.drag_action.write().expect("lock is poisoned").take();
+                match action {
+                    Some(DragAction::Pan) => {
+                        if let Some(inertia) = &self.parameters.inertia {
+                            let velocity = self.release_velocity(SystemTime::now());
+                            if let Some((target, duration, easing)) =
+                                Self::inertial_movement(map.view(), velocity, inertia)
+                            {
+                                map.start_animation(
+                                    Animation::new(
+                                        self.parameters.constraints.apply(&target),
+                                        duration,
+                                    )
+                                    .with_easing(easing),
+                                );
+                            }
+                        }
+
+                        EventPropagation::Stop
+                    }
+                    Some(DragAction::Rotate) => EventPropagation::Stop,
+                    None => EventPropagation::Propagate,
+                }
+            }
+            UserEvent::DoubleClick(MouseButton::Left, e) if input.double_click_zoom => {
+                let target_view = map.target_view();
+                let resolution = target_view.resolution();
+                let zoom = self
+                    .parameters
+                    .constraints
+                    .zoom_resolution(resolution, self.parameters.double_click_zoom)
+                    / resolution;
+                let target = self
+                    .parameters
+                    .constraints
+                    .apply(&target_view.zoom(zoom, e.screen_pointer_position));
+                map.animate_to(target, self.parameters.step_animation_duration);
+
+                EventPropagation::Stop
+            }
+            UserEvent::Scroll(delta, mouse_event) if input.scroll_zoom => {
+                let target_view = map.target_view();
+                let zoom = self.get_zoom(*delta, target_view.resolution());
+                let target = self
+                    .parameters
+                    .constraints
+                    .apply(&target_view.zoom(zoom, mouse_event.screen_pointer_position));
                 map.animate_to(target, self.parameters.zoom_duration);
 
                 EventPropagation::Stop
             }
-            UserEvent::Zoom(zoom, center) => {
-                let target = map.view().zoom(*zoom, *center);
-                map.set_view(target);
+            UserEvent::Zoom(zoom, center) if input.pinch_zoom => {
+                let resolution = map.view().resolution();
+                let zoom = self
+                    .parameters
+                    .constraints
+                    .clamp_resolution(resolution * zoom)
+                    / resolution;
+                let target = map.view().zoom(zoom, *center);
+                map.set_view(self.parameters.constraints.apply(&target));
+
+                EventPropagation::Stop
+            }
+            UserEvent::Rotate(angle, center) if input.touch_rotate => {
+                map.set_view(Self::rotate_around(
+                    map.view(),
+                    map.view().rotation_z() + angle,
+                    *center,
+                ));
+
+                EventPropagation::Stop
+            }
+            UserEvent::Tilt(delta) if input.touch_tilt => {
+                map.set_view(self.get_rotation(map.view(), Vector2::new(0.0, *delta)));
 
                 EventPropagation::Stop
             }
+            UserEvent::KeyPressed(key) if input.keyboard => match self.key_bindings.action(*key) {
+                Some(action) => {
+                    let target = self.apply_action(action, map.target_view());
+                    map.animate_to(
+                        self.parameters.constraints.apply(&target),
+                        self.parameters.step_animation_duration,
+                    );
+
+                    EventPropagation::Stop
+                }
+                None => EventPropagation::Propagate,
+            },
             _ => EventPropagation::Propagate,
         }
     }
 }
 
 impl MapController {
+    /// Creates a controller with the given parameters.
+    pub fn new(parameters: MapControllerParameters) -> Self {
+        Self {
+            parameters,
+            ..Default::default()
+        }
+    }
+
+    pub fn parameters(&self) -> &MapControllerParameters {
+        &self.parameters
+    }
+
+    pub fn parameters_mut(&mut self) -> &mut MapControllerParameters {
+        &mut self.parameters
+    }
+
+    /// Sets the keyboard keys the controller responds to.
+    pub fn with_key_bindings(mut self, key_bindings: KeyBindings) -> Self {
+        self.key_bindings = key_bindings;
+        self
+    }
+
+    /// Sets the limits of the view the user can navigate to. By default only the resolution is limited.
+    pub fn with_constraints(mut self, constraints: ViewConstraints) -> Self {
+        self.parameters.constraints = constraints;
+        self
+    }
+
+    /// Sets the parameters of the map movement after the drag is released. `None` disables the inertia, so the map
+    /// stops as soon as the pointer is released.
+    pub fn with_inertia(mut self, inertia: Option<InertiaParameters>) -> Self {
+        self.parameters.inertia = inertia;
+        self
+    }
+
+    fn add_drag_sample(&self, delta: Vector2<f64>) {
+        let now = SystemTime::now();
+        let mut samples = self.drag_samples.write().expect("lock is poisoned");
+        samples.retain(|(time, _)| now.duration_since(*time).unwrap_or_default() < VELOCITY_WINDOW);
+        samples.push((now, delta));
+    }
+
+    /// Speed of the pointer in pixels per second during the last moments of the drag.
+    fn release_velocity(&self, now: SystemTime) -> Vector2<f64> {
+        let mut samples = self.drag_samples.write().expect("lock is poisoned");
+        let recent: Vector2<f64> = samples
+            .iter()
+            .filter(|(time, _)| now.duration_since(*time).unwrap_or_default() < VELOCITY_WINDOW)
+            .map(|(_, delta)| *delta)
+            .sum();
+        samples.clear();
+
+        recent / VELOCITY_WINDOW.as_secs_f64()
+    }
+
+    /// Returns the target view, duration and easing of the movement of the map released with the given velocity.
+    fn inertial_movement(
+        view: &MapView,
+        velocity: Vector2<f64>,
+        inertia: &InertiaParameters,
+    ) -> Option<(MapView, Duration, Easing)> {
+        let mut speed = velocity.magnitude();
+        if speed <= INERTIA_MIN_SPEED || inertia.friction <= 0.0 {
+            return None;
+        }
+
+        let velocity = if speed > inertia.max_speed {
+            let clamped = velocity * (inertia.max_speed / speed);
+            speed = inertia.max_speed;
+            clamped
+        } else {
+            velocity
+        };
+
+        // With the speed v(t) = v0 * e^(-friction * t) the map stops (falls below the minimum speed) at
+        // t = ln(v0 / v_min) / friction, having travelled v0 / friction * (1 - v_min / v0) pixels.
+        let decay = (speed / INERTIA_MIN_SPEED).ln();
+        let duration = decay / inertia.friction;
+        let distance = velocity / inertia.friction * (1.0 - INERTIA_MIN_SPEED / speed);
+
+        let size = view.size();
+        let center = Point2d::new(size.half_width(), size.half_height());
+        let from = view.screen_to_map(center)?;
+        let to = view.screen_to_map(center + distance)?;
+
+        Some((
+            view.translate(to - from),
+            Duration::from_secs_f64(duration),
+            Easing::ExponentialOut { decay },
+        ))
+    }
+
+    fn apply_action(&self, action: MapAction, view: &MapView) -> MapView {
+        let step = self.parameters.key_pan_step;
+        let rotation_step = self.parameters.key_rotation_step;
+        let size = view.size();
+        let center = Point2d::new(size.half_width(), size.half_height());
+
+        match action {
+            MapAction::PanUp => view.translate_by_pixels(center, center + Vector2::new(0.0, step)),
+            MapAction::PanDown => {
+                view.translate_by_pixels(center, center - Vector2::new(0.0, step))
+            }
+            MapAction::PanLeft => {
+                view.translate_by_pixels(center, center + Vector2::new(step, 0.0))
+            }
+            MapAction::PanRight => {
+                view.translate_by_pixels(center, center - Vector2::new(step, 0.0))
+            }
+            MapAction::ZoomIn => view.zoom(self.get_zoom(1.0, view.resolution()), center),
+            MapAction::ZoomOut => view.zoom(self.get_zoom(-1.0, view.resolution()), center),
+            MapAction::RotateClockwise => view.with_rotation_z(view.rotation_z() + rotation_step),
+            MapAction::RotateCounterClockwise => {
+                view.with_rotation_z(view.rotation_z() - rotation_step)
+            }
+            MapAction::ResetNorth => view.with_rotation_z(0.0),
+        }
+    }
+
     fn get_zoom(&self, delta: f64, current_resolution: f64) -> f64 {
         let zoom = (self.parameters.zoom_speed + 1.0).powf(-delta);
-        let target_resolution = current_resolution * zoom;
-        if target_resolution > self.parameters.max_resolution {
-            self.parameters.max_resolution / current_resolution
-        } else if target_resolution < self.parameters.min_resolution {
-            self.parameters.min_resolution / current_resolution
-        } else {
-            zoom
+        self.parameters
+            .constraints
+            .zoom_resolution(current_resolution, zoom)
+            / current_resolution
+    }
+
+    /// Rotates the view keeping the map point under the given screen position in place.
+    fn rotate_around(view: &MapView, rotation_z: f64, center: Point2d) -> MapView {
+        let rotated = view.with_rotation_z(rotation_z);
+        match (view.screen_to_map(center), rotated.screen_to_map(center)) {
+            (Some(before), Some(after)) => rotated.translate(after - before),
+            _ => rotated,
         }
     }
 
@@ -115,3 +511,114 @@ impl MapController {
         curr_view.with_rotation(rotation_x, rotation_z)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use galileo_types::cartesian::size::Size;
+
+    fn test_view() -> MapView {
+        MapView::new_projected(&Point2d::new(0.0, 0.0), 10.0).with_size(Size::new(200.0, 200.0))
+    }
+
+    #[test]
+    fn rotate_around_keeps_point() {
+        let view = test_view();
+        let center = Point2d::new(150.0, 50.0);
+        let expected = view.screen_to_map(center).unwrap();
+
+        let rotated = MapController::rotate_around(&view, 0.5, center);
+        let actual = rotated.screen_to_map(center).unwrap();
+        assert!((actual - expected).magnitude() < 1e-6);
+        assert_eq!(rotated.rotation_z(), 0.5);
+    }
+
+    #[test]
+    fn inertial_movement() {
+        let view = test_view();
+        let inertia = InertiaParameters::default();
+
+        assert!(
+            MapController::inertial_movement(&view, Vector2::new(10.0, 0.0), &inertia).is_none()
+        );
+
+        let (target, duration, _) =
+            MapController::inertial_movement(&view, Vector2::new(1000.0, 0.0), &inertia).unwrap();
+        let center = target.screen_to_map(Point2d::new(100.0, 100.0)).unwrap();
+        // Map moves with the pointer to the right, so the center of the view shifts to the left.
+        assert!(center.x < 0.0);
+        assert!(center.y.abs() < 1e-6);
+        assert!(duration > Duration::ZERO);
+
+        let (fast, _, _) =
+            MapController::inertial_movement(&view, Vector2::new(100_000.0, 0.0), &inertia)
+                .unwrap();
+        let (max, _, _) =
+            MapController::inertial_movement(&view, Vector2::new(inertia.max_speed, 0.0), &inertia)
+                .unwrap();
+        let fast_center = fast.screen_to_map(Point2d::new(100.0, 100.0)).unwrap();
+        let max_center = max.screen_to_map(Point2d::new(100.0, 100.0)).unwrap();
+        assert!((fast_center - max_center).magnitude() < 1e-6);
+    }
+
+    #[test]
+    fn release_velocity() {
+        let controller = MapController::default();
+        controller.add_drag_sample(Vector2::new(5.0, 0.0));
+        controller.add_drag_sample(Vector2::new(5.0, 0.0));
+
+        let velocity = controller.release_velocity(SystemTime::now());
+        assert!((velocity.x - 100.0).abs() < 1e-6);
+        assert_eq!(
+            controller.release_velocity(SystemTime::now()),
+            Vector2::new(0.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn zoom_respects_constraints() {
+        let controller = MapController::default()
+            .with_constraints(ViewConstraints::new().with_resolution_range(9.0, 20.0));
+        let view = test_view();
+
+        let zoomed = controller.apply_action(MapAction::ZoomIn, &view);
+        assert!((zoomed.resolution() - 9.0).abs() < 1e-9);
+        let zoomed = controller.apply_action(MapAction::ZoomOut, &view);
+        assert!((zoomed.resolution() - 12.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn key_bindings() {
+        let bindings = KeyBindings::default()
+            .bind(Key::ArrowUp, MapAction::ZoomIn)
+            .unbind(Key::Character('n'));
+
+        assert_eq!(bindings.action(Key::ArrowUp), Some(MapAction::ZoomIn));
+        assert_eq!(bindings.action(Key::ArrowDown), Some(MapAction::PanDown));
+        assert_eq!(bindings.action(Key::Character('n')), None);
+        assert_eq!(bindings.action(Key::Other), None);
+    }
+
+    #[test]
+    fn keyboard_actions() {
+        let controller = MapController::default();
+        let view = test_view();
+
+        let panned = controller.apply_action(MapAction::PanUp, &view);
+        let center = panned.screen_to_map(Point2d::new(100.0, 100.0)).unwrap();
+        assert!((center.y - 1000.0).abs() < 1e-6);
+        assert!(center.x.abs() < 1e-6);
+
+        let panned = controller.apply_action(MapAction::PanRight, &view);
+        let center = panned.screen_to_map(Point2d::new(100.0, 100.0)).unwrap();
+        assert!((center.x - 1000.0).abs() < 1e-6);
+
+        let zoomed = controller.apply_action(MapAction::ZoomIn, &view);
+        assert!(zoomed.resolution() < view.resolution());
+
+        let rotated = controller.apply_action(MapAction::RotateClockwise, &view);
+        assert!(rotated.rotation_z() > 0.0);
+        let reset = controller.apply_action(MapAction::ResetNorth, &rotated);
+        assert_eq!(reset.rotation_z(), 0.0);
+    }
+}
@@ -0,0 +1,438 @@
+use crate::control::{EventPropagation, Key, MouseButton, UserEvent, UserEventHandler};
+use crate::map::Map;
+use crate::overlay::{ScreenPainter, Widget};
+use crate::render::Renderer;
+use crate::view::MapView;
+use crate::Color;
+use galileo_types::cartesian::impls::point::Point2d;
+use galileo_types::cartesian::rect::Rect;
+use galileo_types::geo::datum::Datum;
+use galileo_types::geo::impls::point::GeoPoint2d;
+use galileo_types::geo::traits::point::NewGeoPoint;
+use maybe_sync::{MaybeSend, MaybeSync};
+use nalgebra::Point3;
+use std::sync::{Arc, RwLock};
+
+/// Clicks closer than this number of pixels to the last point do not add a new point. This also makes the second click
+/// of a double click, which finishes the measurement, not add a point.
+const MIN_POINT_DISTANCE: f64 = 3.0;
+const LABEL_HEIGHT: f64 = 7.0;
+const LABEL_PADDING: f64 = 3.0;
+const LABEL_OFFSET: f64 = 10.0;
+const VERTEX_SIZE: f64 = 5.0;
+
+/// What is measured by the [`MeasureTool`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MeasureMode {
+    /// Length of the line through the points.
+    Distance,
+    /// Area of the polygon with the points as vertices.
+    Area,
+}
+
+/// Current result of the measurement.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Measurement {
+    pub mode: MeasureMode,
+    /// Points set by the user, including the current pointer position while the measurement is in progress.
+    pub points: Vec<GeoPoint2d>,
+    /// Geodesic length of the line through the points in meters. In the [`MeasureMode::Area`] mode this is the
+    /// perimeter of the polygon.
+    pub length: f64,
+    /// Area of the polygon in square meters. `None` in the [`MeasureMode::Distance`] mode.
+    pub area: Option<f64>,
+    /// True if the user finished the measurement with a double click.
+    pub finished: bool,
+}
+
+impl Measurement {
+    fn new(mode: MeasureMode, points: Vec<GeoPoint2d>, finished: bool, datum: &Datum) -> Self {
+        let closing = match mode {
+            MeasureMode::Area if points.len() > 2 => points.first(),
+            _ => None,
+        };
+        let length = points
+            .iter()
+            .zip(points.iter().skip(1).chain(closing))
+            .filter_map(|(a, b)| datum.geodesic_distance(a, b))
+            .sum();
+        let area = match mode {
+            MeasureMode::Distance => None,
+            MeasureMode::Area => Some(datum.polygon_area(&points)),
+        };
+
+        Self {
+            mode,
+            points,
+            length,
+            area,
+            finished,
+        }
+    }
+
+    /// Text describing the result: the length in the distance mode and the area in the area mode.
+    pub fn label(&self) -> String {
+        match self.area {
+            Some(area) => format_area(area),
+            None => format_distance(self.length),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct MeasureState {
+    /// Points in the map coordinates and geographic coordinates.
+    points: Vec<(Point2d, GeoPoint2d)>,
+    cursor: Option<(Point2d, GeoPoint2d)>,
+    finished: bool,
+}
+
+impl MeasureState {
+    fn geo_points(&self) -> Vec<GeoPoint2d> {
+        self.points
+            .iter()
+            .chain(self.cursor.iter())
+            .map(|(_, geo)| *geo)
+            .collect()
+    }
+
+    fn map_points(&self) -> Vec<Point2d> {
+        self.points
+            .iter()
+            .chain(self.cursor.iter())
+            .map(|(point, _)| *point)
+            .collect()
+    }
+}
+
+type ChangeCallback = dyn Fn(&Measurement) + MaybeSend + MaybeSync;
+
+/// Event handler for measuring geodesic distances and areas on the map.
+///
+/// Every click with the left mouse button adds a point, double click finishes the measurement, and `Escape` clears it.
+/// While the measurement is in progress, the current pointer position is used as the last point, so the result is
+/// updated as the pointer moves. A click after the measurement is finished starts a new one.
+///
+/// The points and the result are shown by the overlay widget returned from [`MeasureTool::widget`]. The result can
+/// also be received with the callback set by [`MeasureTool::on_change`].
+///
+/// ```no_run
+/// # use galileo::control::measure::{MeasureMode, MeasureTool};
+/// # use galileo::galileo_map::MapBuilder;
+/// let tool = MeasureTool::new(MeasureMode::Area)
+///     .on_change(|measurement| println!("area: {}", measurement.label()));
+/// let builder = MapBuilder::new()
+///     .with_widget(tool.widget())
+///     .with_user_event_handler(tool);
+/// ```
+pub struct MeasureTool {
+    mode: MeasureMode,
+    datum: Datum,
+    state: Arc<RwLock<MeasureState>>,
+    on_change: Option<Box<ChangeCallback>>,
+}
+
+impl MeasureTool {
+    /// Creates the tool measuring on the WGS84 ellipsoid.
+    pub fn new(mode: MeasureMode) -> Self {
+        Self {
+            mode,
+            datum: Datum::WGS84,
+            state: Arc::new(RwLock::new(MeasureState::default())),
+            on_change: None,
+        }
+    }
+
+    /// Sets the datum of the ellipsoid the distances and areas are measured on.
+    pub fn with_datum(mut self, datum: Datum) -> Self {
+        self.datum = datum;
+        self
+    }
+
+    /// Sets the function called every time the measurement changes.
+    pub fn on_change(
+        mut self,
+        callback: impl Fn(&Measurement) + MaybeSend + MaybeSync + 'static,
+    ) -> Self {
+        self.on_change = Some(Box::new(callback));
+        self
+    }
+
+    /// Returns the overlay widget that draws the measured line or polygon and the result.
+    pub fn widget(&self) -> MeasureWidget {
+        MeasureWidget {
+            mode: self.mode,
+            datum: self.datum,
+            state: self.state.clone(),
+            color: Color::rgba(230, 80, 30, 255),
+        }
+    }
+
+    /// Returns the current measurement, or `None` if no points are set.
+    pub fn measurement(&self) -> Option<Measurement> {
+        let state = self.state.read().expect("lock is poisoned");
+        if state.points.is_empty() {
+            return None;
+        }
+
+        Some(Measurement::new(
+            self.mode,
+            state.geo_points(),
+            state.finished,
+            &self.datum,
+        ))
+    }
+
+    fn changed(&self, map: &Map) {
+        map.redraw();
+        if let (Some(callback), Some(measurement)) = (&self.on_change, self.measurement()) {
+            callback(&measurement);
+        }
+    }
+
+    fn add_point(&self, view: &MapView, position: Point2d) -> bool {
+        let Some((point, geo)) = to_map_and_geo(view, position) else {
+            return false;
+        };
+
+        let mut state = self.state.write().expect("lock is poisoned");
+        if state.finished {
+            *state = MeasureState::default();
+        }
+
+        let is_duplicate = state
+            .points
+            .last()
+            .and_then(|(last, _)| view.map_to_screen(Point3::new(last.x, last.y, 0.0)))
+            .is_some_and(|last| {
+                (last.x - position.x).hypot(last.y - position.y) < MIN_POINT_DISTANCE
+            });
+        if is_duplicate {
+            return false;
+        }
+
+        state.points.push((point, geo));
+        state.cursor = None;
+        true
+    }
+}
+
+impl UserEventHandler for MeasureTool {
+    fn handle(
+        &self,
+        event: &UserEvent,
+        map: &mut Map,
+        _backend: &dyn Renderer,
+    ) -> EventPropagation {
+        match event {
+            UserEvent::Click(MouseButton::Left, e) => {
+                if self.add_point(map.view(), e.screen_pointer_position) {
+                    self.changed(map);
+                }
+                EventPropagation::Stop
+            }
+            UserEvent::DoubleClick(MouseButton::Left, _) => {
+                {
+                    let mut state = self.state.write().expect("lock is poisoned");
+                    if state.points.is_empty() || state.finished {
+                        return EventPropagation::Stop;
+                    }
+                    state.finished = true;
+                    state.cursor = None;
+                }
+
+                self.changed(map);
+                EventPropagation::Stop
+            }
+            UserEvent::PointerMoved(e) => {
+                {
+                    let mut state = self.state.write().expect("lock is poisoned");
+                    if state.points.is_empty() || state.finished {
+                        return EventPropagation::Propagate;
+                    }
+                    state.cursor = to_map_and_geo(map.view(), e.screen_pointer_position);
+                }
+
+                self.changed(map);
+                EventPropagation::Propagate
+            }
+            UserEvent::KeyPressed(Key::Escape) => {
+                *self.state.write().expect("lock is poisoned") = MeasureState::default();
+                map.redraw();
+                EventPropagation::Stop
+            }
+            _ => EventPropagation::Propagate,
+        }
+    }
+}
+
+/// Overlay widget drawing the measurement of a [`MeasureTool`].
+///
+/// The widget does not take part in hit testing, so the clicks over the measured line reach the tool.
+pub struct MeasureWidget {
+    mode: MeasureMode,
+    datum: Datum,
+    state: Arc<RwLock<MeasureState>>,
+    color: Color,
+}
+
+impl MeasureWidget {
+    /// Sets the color of the measured line or polygon.
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+}
+
+impl Widget for MeasureWidget {
+    fn bounds(&self, _view: &MapView) -> Option<Rect> {
+        None
+    }
+
+    fn render(&self, view: &MapView, painter: &mut ScreenPainter) {
+        let (map_points, measurement) = {
+            let state = self.state.read().expect("lock is poisoned");
+            if state.points.is_empty() {
+                return;
+            }
+
+            (
+                state.map_points(),
+                Measurement::new(self.mode, state.geo_points(), state.finished, &self.datum),
+            )
+        };
+
+        let screen_points: Vec<Point2d> = map_points
+            .iter()
+            .filter_map(|p| view.map_to_screen(Point3::new(p.x, p.y, 0.0)))
+            .collect();
+        let Some(last) = screen_points.last().copied() else {
+            return;
+        };
+
+        let is_polygon = self.mode == MeasureMode::Area && screen_points.len() > 2;
+        if is_polygon {
+            let fill = Color::rgba(self.color.r, self.color.g, self.color.b, 60);
+            painter.fill_polygon(&screen_points, fill);
+        }
+        if screen_points.len() > 1 {
+            painter.line(&screen_points, self.color, 2.0, is_polygon);
+        }
+
+        let half = VERTEX_SIZE / 2.0;
+        for point in &screen_points {
+            let vertex = Rect::new(
+                point.x - half,
+                point.y - half,
+                point.x + half,
+                point.y + half,
+            );
+            painter.fill_rect(vertex, Color::WHITE);
+            painter.stroke_rect(vertex, self.color, 1.0);
+        }
+
+        if screen_points.len() > 1 {
+            let label = measurement.label();
+            let x = last.x + LABEL_OFFSET;
+            let y = last.y + LABEL_OFFSET;
+            let width = ScreenPainter::text_width(&label, LABEL_HEIGHT);
+            painter.fill_rect(
+                Rect::new(
+                    x,
+                    y,
+                    x + width + LABEL_PADDING * 2.0,
+                    y + LABEL_HEIGHT + LABEL_PADDING * 2.0,
+                ),
+                Color::rgba(255, 255, 255, 220),
+            );
+            painter.text(
+                &label,
+                Point2d::new(x + LABEL_PADDING, y + LABEL_PADDING),
+                LABEL_HEIGHT,
+                Color::BLACK,
+            );
+        }
+    }
+}
+
+/// Converts the screen point into the map coordinates and the geographic coordinates.
+fn to_map_and_geo(view: &MapView, position: Point2d) -> Option<(Point2d, GeoPoint2d)> {
+    let point = view.screen_to_map(position)?;
+    let geo = if view.crs().is_geographic() {
+        GeoPoint2d::latlon(point.y, point.x)
+    } else {
+        view.crs()
+            .get_projection::<GeoPoint2d, Point2d>()?
+            .unproject(&point)
+            .ok()?
+    };
+
+    Some((point, geo))
+}
+
+fn format_distance(meters: f64) -> String {
+    if meters < 1000.0 {
+        format!("{meters:.1} m")
+    } else if meters < 100_000.0 {
+        format!("{:.2} km", meters / 1000.0)
+    } else {
+        format!("{:.0} km", meters / 1000.0)
+    }
+}
+
+fn format_area(square_meters: f64) -> String {
+    if square_meters < 1_000_000.0 {
+        format!("{square_meters:.0} m²")
+    } else {
+        format!("{:.2} km²", square_meters / 1_000_000.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn measurement_values() {
+        let points = vec![
+            GeoPoint2d::latlon(0.0, 0.0),
+            GeoPoint2d::latlon(0.0, 1.0),
+            GeoPoint2d::latlon(1.0, 1.0),
+        ];
+
+        let distance =
+            Measurement::new(MeasureMode::Distance, points.clone(), false, &Datum::WGS84);
+        assert!((distance.length - 111_319.491 - 110_574.389).abs() < 1.0);
+        assert_eq!(distance.area, None);
+        assert_eq!(distance.label(), "222 km");
+
+        let area = Measurement::new(MeasureMode::Area, points, true, &Datum::WGS84);
+        assert!(area.length > distance.length);
+        let expected = Datum::WGS84.polygon_area(&area.points);
+        assert_eq!(area.area, Some(expected));
+        assert!(area.label().ends_with(" km²"));
+    }
+
+    #[test]
+    fn formatting() {
+        assert_eq!(format_distance(12.34), "12.3 m");
+        assert_eq!(format_distance(12_345.0), "12.35 km");
+        assert_eq!(format_area(1234.4), "1234 m²");
+        assert_eq!(format_area(2_500_000.0), "2.50 km²");
+    }
+
+    #[test]
+    fn points_are_added_by_clicks() {
+        let view = MapView::new_projected(&Point2d::new(0.0, 0.0), 10.0)
+            .with_size(galileo_types::cartesian::size::Size::new(200.0, 100.0));
+        let tool = MeasureTool::new(MeasureMode::Distance);
+
+        assert!(tool.add_point(&view, Point2d::new(10.0, 10.0)));
+        assert!(!tool.add_point(&view, Point2d::new(11.0, 11.0)));
+        assert!(tool.add_point(&view, Point2d::new(50.0, 10.0)));
+
+        let measurement = tool.measurement().unwrap();
+        assert_eq!(measurement.points.len(), 2);
+        assert!(measurement.length > 0.0);
+    }
+}
@@ -3,14 +3,22 @@ use crate::render::Renderer;
 use galileo_types::cartesian::impls::point::Point2d;
 use nalgebra::Vector2;
 
+pub mod box_selection;
 pub mod custom;
 pub mod event_processor;
 pub mod map;
+pub mod measure;
 
 pub trait UserEventHandler {
     fn handle(&self, event: &UserEvent, map: &mut Map, backend: &dyn Renderer) -> EventPropagation;
 }
 
+impl<T: UserEventHandler + ?Sized> UserEventHandler for Box<T> {
+    fn handle(&self, event: &UserEvent, map: &mut Map, backend: &dyn Renderer) -> EventPropagation {
+        (**self).handle(event, map, backend)
+    }
+}
+
 pub enum RawUserEvent {
     ButtonPressed(MouseButton),
     ButtonReleased(MouseButton),
@@ -19,6 +27,9 @@ pub enum RawUserEvent {
     TouchStart(TouchEvent),
     TouchMove(TouchEvent),
     TouchEnd(TouchEvent),
+    KeyPressed(Key),
+    KeyReleased(Key),
+    ModifiersChanged(KeyboardModifiers),
 }
 
 #[derive(Debug, Clone)]
@@ -34,7 +45,15 @@ pub enum UserEvent {
     DragEnded(MouseButton, MouseEvent),
 
     Scroll(f64, MouseEvent),
+    /// Pinch zoom by the given factor of the resolution around the screen point.
     Zoom(f64, Point2d),
+    /// Two-finger rotation by the given angle in radians (counterclockwise is positive) around the screen point.
+    Rotate(f64, Point2d),
+    /// Vertical movement of two fingers in pixels, used to tilt the map.
+    Tilt(f64),
+
+    KeyPressed(Key),
+    KeyReleased(Key),
 }
 
 pub enum EventPropagation {
@@ -51,10 +70,40 @@ pub enum MouseButton {
     Other,
 }
 
+/// Keyboard key, independent of the windowing library the events come from.
+///
+/// Printable keys are given as [`Key::Character`] with the character the key produces in the current keyboard layout.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Key {
+    ArrowUp,
+    ArrowDown,
+    ArrowLeft,
+    ArrowRight,
+    PageUp,
+    PageDown,
+    Home,
+    End,
+    Escape,
+    Enter,
+    Character(char),
+    Other,
+}
+
+/// State of the modifier keys of the keyboard.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct KeyboardModifiers {
+    pub shift: bool,
+    pub ctrl: bool,
+    pub alt: bool,
+    /// Windows key on PC and Command key on Mac.
+    pub meta: bool,
+}
+
 #[derive(Debug, Clone)]
 pub struct MouseEvent {
     pub screen_pointer_position: Point2d,
     pub buttons: MouseButtonsState,
+    pub modifiers: KeyboardModifiers,
 }
 
 pub type TouchId = u64;
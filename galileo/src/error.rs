@@ -21,6 +21,23 @@ pub enum GalileoError {
     Generic(String),
     #[error("failed to read file")]
     FsIo(#[from] std::io::Error),
+    #[cfg(feature = "geojson")]
+    #[error("invalid GeoJSON: {0}")]
+    GeoJson(#[from] Box<geojson::Error>),
+    #[cfg(feature = "flatgeobuf")]
+    #[error("failed to read FlatGeobuf: {0}")]
+    FlatGeobuf(#[from] flatgeobuf::Error),
+    #[cfg(feature = "flatgeobuf")]
+    #[error("failed to convert geometry: {0}")]
+    Geozero(#[from] geozero::error::GeozeroError),
+    #[cfg(feature = "shapefile")]
+    #[error("failed to read shapefile: {0}")]
+    Shapefile(#[from] shapefile::Error),
+    #[cfg(feature = "csv")]
+    #[error("failed to read CSV file: {0}")]
+    Csv(#[from] csv::Error),
+    #[error("failed to import data: {0}")]
+    Import(String),
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -30,6 +47,13 @@ impl From<reqwest::Error> for GalileoError {
     }
 }
 
+#[cfg(feature = "geojson")]
+impl From<geojson::Error> for GalileoError {
+    fn from(value: geojson::Error) -> Self {
+        Self::GeoJson(Box::new(value))
+    }
+}
+
 #[cfg(target_arch = "wasm32")]
 impl From<wasm_bindgen::JsValue> for GalileoError {
     fn from(value: wasm_bindgen::JsValue) -> Self {
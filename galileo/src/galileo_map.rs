@@ -1,6 +1,7 @@
 use crate::control::custom::{CustomEventHandler, EventHandler};
 use crate::control::event_processor::EventProcessor;
 use crate::control::map::MapController;
+use crate::control::UserEventHandler;
 use crate::layer::data_provider::file_cache::FileCacheController;
 use crate::layer::data_provider::url_image_provider::{UrlImageProvider, UrlSource};
 use crate::layer::raster_tile::RasterTileLayer;
@@ -8,6 +9,7 @@ use crate::layer::vector_tile_layer::style::VectorTileStyle;
 use crate::layer::vector_tile_layer::VectorTileLayer;
 use crate::layer::Layer;
 use crate::map::Map;
+use crate::overlay::{Overlay, Widget};
 use crate::render::wgpu::WgpuRenderer;
 use crate::render::Renderer;
 use crate::tile_scheme::{TileIndex, TileScheme};
@@ -75,7 +77,18 @@ impl GalileoMap {
                                     .unwrap()
                                     .resize(Size::new(size.width, size.height));
                                 let mut map = map.write().unwrap();
-                                map.set_size(Size::new(size.width as f64, size.height as f64));
+                                map.set_size(logical_size(size, window.scale_factor()));
+                            }
+                            WindowEvent::ScaleFactorChanged { scale_factor, .. } => {
+                                log::info!("Window scale factor changed to: {scale_factor}");
+                                backend
+                                    .write()
+                                    .unwrap()
+                                    .set_dpi_scale_factor(scale_factor as f32);
+                                let mut map = map.write().unwrap();
+                                map.set_dpi_scale_factor(scale_factor);
+                                map.set_size(logical_size(window.inner_size(), scale_factor));
+                                window.request_redraw();
                             }
                             WindowEvent::RedrawRequested => {
                                 let cast: Arc<RwLock<dyn Renderer>> = backend.clone();
@@ -84,15 +97,9 @@ impl GalileoMap {
                                 backend.read().unwrap().render(&map).unwrap();
                             }
                             other => {
-                                // Phone emulator in browsers works funny with scaling, using this code fixes it.
-                                // But my real phone works fine without it, so it's commented out for now, and probably
-                                // should be deleted later, when we know that it's not needed on any devices.
-
-                                // #[cfg(target_arch = "wasm32")]
-                                // let scale = window.scale_factor();
-                                //
-                                // #[cfg(not(target_arch = "wasm32"))]
-                                let scale = 1.0;
+                                // Input coordinates are given by winit in physical pixels, while the map view
+                                // works in logical ones.
+                                let scale = window.scale_factor();
 
                                 if let Some(raw_event) =
                                     input_handler.process_user_input(&other, scale)
@@ -117,12 +124,20 @@ impl GalileoMap {
     }
 }
 
+fn logical_size(size: PhysicalSize<u32>, scale_factor: f64) -> Size {
+    Size::new(
+        size.width as f64 / scale_factor,
+        size.height as f64 / scale_factor,
+    )
+}
+
 pub struct MapBuilder {
     position: GeoPoint2d,
     resolution: f64,
     view: Option<MapView>,
     layers: Vec<Box<dyn Layer>>,
-    event_handlers: Vec<CustomEventHandler>,
+    overlay: Overlay,
+    event_handlers: Vec<Box<dyn UserEventHandler>>,
     window: Option<Window>,
     event_loop: Option<EventLoop<()>>,
 }
@@ -140,6 +155,7 @@ impl MapBuilder {
             resolution: 156543.03392800014 / 16.0,
             view: None,
             layers: vec![],
+            overlay: Overlay::default(),
             event_handlers: vec![],
             window: None,
             event_loop: None,
@@ -164,11 +180,12 @@ impl MapBuilder {
         let window = Arc::new(window);
         let messenger = WinitMessenger::new(window.clone());
 
-        let backend = WgpuRenderer::create_with_window(
+        let mut backend = WgpuRenderer::create_with_window(
             &window,
             Size::new(window.inner_size().width, window.inner_size().height),
         )
         .await;
+        backend.set_dpi_scale_factor(window.scale_factor() as f32);
         let backend = Arc::new(RwLock::new(backend));
 
         let input_handler = WinitInputHandler::default();
@@ -179,9 +196,11 @@ impl MapBuilder {
         }
         event_processor.add_handler(MapController::default());
 
+        let map = self.build_map(messenger, &window);
+
         GalileoMap {
             window,
-            map: self.build_map(messenger),
+            map,
             backend,
             event_processor,
             input_handler,
@@ -276,20 +295,36 @@ impl MapBuilder {
     pub fn with_event_handler(mut self, handler: impl EventHandler + 'static) -> Self {
         let mut event_handler = CustomEventHandler::default();
         event_handler.set_input_handler(handler);
-        self.event_handlers.push(event_handler);
+        self.event_handlers.push(Box::new(event_handler));
+        self
+    }
+
+    /// Adds the handler of the user input. Handlers are called in the order they are added, before the default
+    /// map controller.
+    pub fn with_user_event_handler(mut self, handler: impl UserEventHandler + 'static) -> Self {
+        self.event_handlers.push(Box::new(handler));
+        self
+    }
+
+    /// Adds the widget to the overlay of the map. Widgets are drawn over all the layers in the order they are added.
+    pub fn with_widget(mut self, widget: impl Widget + 'static) -> Self {
+        self.overlay.add(widget);
         self
     }
 
-    fn build_map(mut self, messenger: WinitMessenger) -> Arc<RwLock<Map>> {
+    fn build_map(mut self, messenger: WinitMessenger, window: &Window) -> Arc<RwLock<Map>> {
         for layer in self.layers.iter_mut() {
             layer.set_messenger(Box::new(messenger.clone()))
         }
 
         let view = self
             .view
-            .unwrap_or_else(|| MapView::new(&self.position, self.resolution));
+            .unwrap_or_else(|| MapView::new(&self.position, self.resolution))
+            .with_dpi_scale_factor(window.scale_factor())
+            .with_size(logical_size(window.inner_size(), window.scale_factor()));
 
-        let map = Map::new(view, self.layers, Some(messenger));
+        let mut map = Map::new(view, self.layers, Some(messenger));
+        *map.overlay_mut() = self.overlay;
 
         Arc::new(RwLock::new(map))
     }
@@ -0,0 +1,263 @@
+use crate::layer::feature_layer::feature::Feature;
+use crate::layer::feature_layer::FeatureLayer;
+use galileo_types::cartesian::impls::point::Point2d;
+use galileo_types::cartesian::traits::cartesian_point::NewCartesianPoint2d;
+use galileo_types::geo::crs::Crs;
+use galileo_types::geo::datum_shift::DatumShift;
+use galileo_types::geo::impls::point::GeoPoint2d;
+use galileo_types::geo::impls::projection::identity::IdentityProjection;
+use galileo_types::geo::traits::point::NewGeoPoint;
+use galileo_types::geo::traits::projection::{
+    ChainProjection, InvertedProjection, Projection, ProjectionError,
+};
+use galileo_types::geometry::{Geom, Geometry};
+use galileo_types::geometry_type::{CartesianSpace2d, GeoSpace2d};
+use galileo_types::multi_contour::MultiContour;
+use galileo_types::multi_point::MultiPoint;
+use geojson::{FeatureCollection, JsonObject, Position, Value};
+use std::marker::PhantomData;
+
+impl<P, F, S> FeatureLayer<P, F, S, GeoSpace2d>
+where
+    P: NewGeoPoint + 'static,
+    F: Feature,
+    F::Geom: Geometry<Point = P>,
+{
+    /// Exports the features of the layer as a GeoJSON feature collection with coordinates in the given CRS.
+    ///
+    /// Properties of every exported feature are returned by the `properties` function. Features which geometry
+    /// cannot be projected into `crs` are skipped. Returns `None` if the given CRS is not supported.
+    pub fn to_geojson(
+        &self,
+        crs: &Crs,
+        properties: impl Fn(&F) -> Option<JsonObject>,
+    ) -> Option<FeatureCollection> {
+        let projection = ChainProjection::new(
+            Box::new(DatumShift::<P, P>::new(*self.crs.datum(), *crs.datum())),
+            target_projection::<P>(crs)?,
+        );
+        Some(export_features(&self.features, &projection, properties))
+    }
+}
+
+impl<P, F, S> FeatureLayer<P, F, S, CartesianSpace2d>
+where
+    P: NewCartesianPoint2d + 'static,
+    F: Feature,
+    F::Geom: Geometry<Point = P>,
+{
+    /// Exports the features of the layer as a GeoJSON feature collection with coordinates in the given CRS.
+    ///
+    /// Properties of every exported feature are returned by the `properties` function. Features which geometry
+    /// cannot be projected into `crs` are skipped. Returns `None` if the given CRS or the CRS of the layer is not
+    /// supported.
+    pub fn to_geojson(
+        &self,
+        crs: &Crs,
+        properties: impl Fn(&F) -> Option<JsonObject>,
+    ) -> Option<FeatureCollection> {
+        let projection: Box<dyn Projection<InPoint = P, OutPoint = Point2d>> = if crs == &self.crs {
+            Box::new(IdentityProjection::<P, Point2d, CartesianSpace2d>::new())
+        } else {
+            let layer_projection = self.crs.get_projection::<GeoPoint2d, P>()?;
+            let datum_shift: Box<dyn Projection<InPoint = GeoPoint2d, OutPoint = GeoPoint2d>> =
+                Box::new(DatumShift::new(*self.crs.datum(), *crs.datum()));
+            Box::new(ChainProjection::new(
+                Box::new(ChainProjection::new(
+                    Box::new(InvertedProjection::new(layer_projection)),
+                    datum_shift,
+                )),
+                target_projection::<GeoPoint2d>(crs)?,
+            ))
+        };
+
+        Some(export_features(&self.features, &*projection, properties))
+    }
+}
+
+/// Returns projection from geographic coordinates into the coordinates of the GeoJSON positions in the given CRS.
+fn target_projection<P: NewGeoPoint + 'static>(
+    crs: &Crs,
+) -> Option<Box<dyn Projection<InPoint = P, OutPoint = Point2d>>> {
+    if crs.is_geographic() {
+        Some(Box::new(LonLatProjection(PhantomData)))
+    } else {
+        crs.get_projection::<P, Point2d>()
+    }
+}
+
+fn export_features<P, F>(
+    features: &[F],
+    projection: &dyn Projection<InPoint = P, OutPoint = Point2d>,
+    properties: impl Fn(&F) -> Option<JsonObject>,
+) -> FeatureCollection
+where
+    F: Feature,
+    F::Geom: Geometry<Point = P>,
+{
+    let features = features
+        .iter()
+        .filter_map(|feature| {
+            let geometry = match feature.geometry().project(projection) {
+                Ok(geometry) => geometry,
+                Err(err) => {
+                    log::warn!("Feature is skipped in GeoJSON export: {err}");
+                    return None;
+                }
+            };
+            Some(geojson::Feature {
+                bbox: None,
+                geometry: Some(geojson::Geometry::new(geom_to_value(&geometry))),
+                id: None,
+                properties: properties(feature),
+                foreign_members: None,
+            })
+        })
+        .collect();
+
+    FeatureCollection {
+        bbox: None,
+        features,
+        foreign_members: None,
+    }
+}
+
+fn geom_to_value(geom: &Geom<Point2d>) -> Value {
+    match geom {
+        Geom::Point(p) => Value::Point(position(p)),
+        Geom::MultiPoint(points) => Value::MultiPoint(points.iter_points().map(position).collect()),
+        Geom::Contour(contour) => Value::LineString(positions(&contour.points, contour.is_closed)),
+        Geom::MultiContour(contours) => Value::MultiLineString(
+            contours
+                .contours()
+                .map(|c| positions(&c.points, c.is_closed))
+                .collect(),
+        ),
+        Geom::Polygon(polygon) => Value::Polygon(
+            polygon
+                .iter_contours()
+                .map(|c| positions(&c.points, true))
+                .collect(),
+        ),
+        Geom::MultiPolygon(polygons) => Value::MultiPolygon(
+            polygons
+                .parts()
+                .iter()
+                .map(|polygon| {
+                    polygon
+                        .iter_contours()
+                        .map(|c| positions(&c.points, true))
+                        .collect()
+                })
+                .collect(),
+        ),
+    }
+}
+
+fn position(point: &Point2d) -> Position {
+    vec![point.x, point.y]
+}
+
+/// GeoJSON requires the rings to have the first point repeated at the end.
+fn positions(points: &[Point2d], is_closed: bool) -> Vec<Position> {
+    let mut positions: Vec<_> = points.iter().map(position).collect();
+    if is_closed && points.len() > 1 && points.first() != points.last() {
+        positions.push(position(&points[0]));
+    }
+
+    positions
+}
+
+/// Converts geographic points into positions with longitude as `x` and latitude as `y`.
+struct LonLatProjection<P>(PhantomData<P>);
+
+impl<P: NewGeoPoint> Projection for LonLatProjection<P> {
+    type InPoint = P;
+    type OutPoint = Point2d;
+
+    fn project(&self, input: &Self::InPoint) -> Result<Self::OutPoint, ProjectionError> {
+        Ok(Point2d::new(input.lon(), input.lat()))
+    }
+
+    fn unproject(&self, input: &Self::OutPoint) -> Result<Self::InPoint, ProjectionError> {
+        Ok(P::latlon(input.y, input.x))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layer::feature_layer::feature::AttributedFeature;
+    use crate::symbol::CirclePointSymbol;
+    use crate::Color;
+    use galileo_types::cartesian::impls::contour::{ClosedContour, Contour};
+    use galileo_types::cartesian::impls::polygon::Polygon;
+
+    #[test]
+    fn closes_polygon_rings() {
+        let polygon = Geom::Polygon(Polygon::new(
+            ClosedContour::new(vec![
+                Point2d::new(0.0, 0.0),
+                Point2d::new(1.0, 0.0),
+                Point2d::new(1.0, 1.0),
+            ]),
+            vec![],
+        ));
+        let Value::Polygon(rings) = geom_to_value(&polygon) else {
+            panic!("invalid geometry type");
+        };
+        assert_eq!(rings[0].len(), 4);
+        assert_eq!(rings[0][0], rings[0][3]);
+
+        let line = Geom::Contour(Contour::open(vec![
+            Point2d::new(0.0, 0.0),
+            Point2d::new(1.0, 0.0),
+        ]));
+        let Value::LineString(points) = geom_to_value(&line) else {
+            panic!("invalid geometry type");
+        };
+        assert_eq!(points.len(), 2);
+    }
+
+    #[test]
+    fn exports_geo_layer() {
+        let layer: FeatureLayer<_, _, _, GeoSpace2d> = FeatureLayer::new(
+            vec![
+                AttributedFeature::new(GeoPoint2d::latlon(10.0, 20.0), Default::default())
+                    .with_property("name", "a"),
+            ],
+            CirclePointSymbol::new(Color::RED, 5.0),
+            Crs::WGS84,
+        );
+
+        let collection = layer
+            .to_geojson(&Crs::WGS84, |f| Some(f.geojson_properties()))
+            .unwrap();
+        assert_eq!(collection.features.len(), 1);
+        let feature = &collection.features[0];
+        assert_eq!(
+            feature.geometry.as_ref().unwrap().value,
+            Value::Point(vec![20.0, 10.0])
+        );
+        assert_eq!(
+            feature.property("name"),
+            Some(&geojson::JsonValue::from("a"))
+        );
+
+        let collection = layer.to_geojson(&Crs::EPSG3857, |_| None).unwrap();
+        let Some(Value::Point(position)) = collection.features[0]
+            .geometry
+            .as_ref()
+            .map(|g| g.value.clone())
+        else {
+            panic!("invalid geometry type");
+        };
+        let projected = Crs::EPSG3857
+            .get_projection::<GeoPoint2d, Point2d>()
+            .unwrap()
+            .project(&GeoPoint2d::latlon(10.0, 20.0))
+            .unwrap();
+        assert!((position[0] - projected.x).abs() < 1e-6);
+        assert!((position[1] - projected.y).abs() < 1e-6);
+    }
+}
@@ -8,6 +8,10 @@ use galileo_types::geometry::Geometry;
 use galileo_types::geometry_type::GeometryType;
 use galileo_types::impls::multi_contour::MultiContour;
 
+pub use attributed::{AttributedFeature, Properties, PropertyValue};
+
+mod attributed;
+
 pub trait Feature {
     type Geom: Geometry;
     fn geometry(&self) -> &Self::Geom;
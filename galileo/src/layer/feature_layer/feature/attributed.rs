@@ -0,0 +1,322 @@
+use crate::layer::feature_layer::feature::Feature;
+use galileo_mvt::{MvtFeature, MvtGeometry, MvtValue};
+use galileo_types::cartesian::impls::multipolygon::MultiPolygon;
+use galileo_types::geometry::{Geom, Geometry};
+use galileo_types::impls::multi_contour::MultiContour;
+use galileo_types::impls::multi_point::MultiPoint;
+use nalgebra::Point2;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Value of a feature property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(untagged))]
+pub enum PropertyValue {
+    Null,
+    String(String),
+    Integer(i64),
+    Float(f64),
+    Bool(bool),
+}
+
+impl PropertyValue {
+    pub fn is_null(&self) -> bool {
+        matches!(self, PropertyValue::Null)
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            PropertyValue::String(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            PropertyValue::Integer(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    /// Returns numeric value of the property. Integer values are converted into `f64`.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            PropertyValue::Integer(v) => Some(*v as f64),
+            PropertyValue::Float(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            PropertyValue::Bool(v) => Some(*v),
+            _ => None,
+        }
+    }
+}
+
+impl Display for PropertyValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PropertyValue::Null => write!(f, "null"),
+            PropertyValue::String(v) => write!(f, "{v}"),
+            PropertyValue::Integer(v) => write!(f, "{v}"),
+            PropertyValue::Float(v) => write!(f, "{v}"),
+            PropertyValue::Bool(v) => write!(f, "{v}"),
+        }
+    }
+}
+
+impl From<&str> for PropertyValue {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_string())
+    }
+}
+
+impl From<String> for PropertyValue {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<i64> for PropertyValue {
+    fn from(value: i64) -> Self {
+        Self::Integer(value)
+    }
+}
+
+impl From<f64> for PropertyValue {
+    fn from(value: f64) -> Self {
+        Self::Float(value)
+    }
+}
+
+impl From<bool> for PropertyValue {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl<T: Into<PropertyValue>> From<Option<T>> for PropertyValue {
+    fn from(value: Option<T>) -> Self {
+        value.map(Into::into).unwrap_or(PropertyValue::Null)
+    }
+}
+
+impl From<MvtValue> for PropertyValue {
+    fn from(value: MvtValue) -> Self {
+        match value {
+            MvtValue::String(v) => Self::String(v),
+            // Converted through the decimal representation, so that `0.1f32` becomes `0.1` and not `0.10000000149`.
+            MvtValue::Float(v) => Self::Float(v.to_string().parse().unwrap_or(v as f64)),
+            MvtValue::Double(v) => Self::Float(v),
+            MvtValue::Int64(v) => Self::Integer(v),
+            MvtValue::Uint64(v) => match i64::try_from(v) {
+                Ok(v) => Self::Integer(v),
+                Err(_) => Self::Float(v as f64),
+            },
+            MvtValue::Bool(v) => Self::Bool(v),
+            MvtValue::Unknown => Self::Null,
+        }
+    }
+}
+
+pub type Properties = HashMap<String, PropertyValue>;
+
+/// A feature with a set of named properties.
+///
+/// This type can be used for any data that comes with attributes: it is produced by the
+/// [importers](crate::layer::feature_layer::import), and can be converted from vector tile and GeoJSON features.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AttributedFeature<G> {
+    pub geometry: G,
+    pub properties: Properties,
+}
+
+impl<G> AttributedFeature<G> {
+    pub fn new(geometry: G, properties: Properties) -> Self {
+        Self {
+            geometry,
+            properties,
+        }
+    }
+
+    pub fn with_property(
+        mut self,
+        name: impl Into<String>,
+        value: impl Into<PropertyValue>,
+    ) -> Self {
+        self.properties.insert(name.into(), value.into());
+        self
+    }
+
+    /// Returns the value of the property. Returns `None` if the feature does not have this property.
+    pub fn property(&self, name: &str) -> Option<&PropertyValue> {
+        self.properties.get(name)
+    }
+}
+
+impl<G: Geometry> Feature for AttributedFeature<G> {
+    type Geom = G;
+
+    fn geometry(&self) -> &Self::Geom {
+        &self.geometry
+    }
+}
+
+/// Converts a vector tile feature. Coordinates of the geometry are kept in the tile coordinate space.
+impl From<MvtFeature> for AttributedFeature<Geom<Point2<f32>>> {
+    fn from(value: MvtFeature) -> Self {
+        let geometry = match value.geometry {
+            MvtGeometry::Point(mut points) => {
+                if points.len() == 1 {
+                    Geom::Point(points.remove(0))
+                } else {
+                    Geom::MultiPoint(MultiPoint::from(points))
+                }
+            }
+            MvtGeometry::LineString(mut contours) => {
+                if contours.len() == 1 {
+                    Geom::Contour(contours.remove(0))
+                } else {
+                    Geom::MultiContour(MultiContour::from(contours))
+                }
+            }
+            MvtGeometry::Polygon(mut polygons) => {
+                if polygons.len() == 1 {
+                    Geom::Polygon(polygons.remove(0))
+                } else {
+                    Geom::MultiPolygon(MultiPolygon::from(polygons))
+                }
+            }
+        };
+
+        Self {
+            geometry,
+            properties: value
+                .properties
+                .into_iter()
+                .map(|(key, value)| (key, value.into()))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(feature = "geojson")]
+impl From<geojson::JsonValue> for PropertyValue {
+    fn from(value: geojson::JsonValue) -> Self {
+        use geojson::JsonValue;
+
+        match value {
+            JsonValue::Null => Self::Null,
+            JsonValue::Bool(v) => Self::Bool(v),
+            JsonValue::Number(v) => match v.as_i64() {
+                Some(v) => Self::Integer(v),
+                None => v.as_f64().map(Self::Float).unwrap_or(Self::Null),
+            },
+            JsonValue::String(v) => Self::String(v),
+            v @ (JsonValue::Array(_) | JsonValue::Object(_)) => Self::String(v.to_string()),
+        }
+    }
+}
+
+#[cfg(feature = "geojson")]
+impl From<PropertyValue> for geojson::JsonValue {
+    fn from(value: PropertyValue) -> Self {
+        match value {
+            PropertyValue::Null => Self::Null,
+            PropertyValue::String(v) => Self::String(v),
+            PropertyValue::Integer(v) => Self::from(v),
+            PropertyValue::Float(v) => Self::from(v),
+            PropertyValue::Bool(v) => Self::Bool(v),
+        }
+    }
+}
+
+#[cfg(feature = "geojson")]
+impl<G> AttributedFeature<G> {
+    /// Returns the properties of the feature as a GeoJSON properties object.
+    pub fn geojson_properties(&self) -> geojson::JsonObject {
+        self.properties
+            .iter()
+            .map(|(key, value)| (key.clone(), value.clone().into()))
+            .collect()
+    }
+}
+
+/// Converts a GeoJSON feature. Features without geometry cannot be converted. The bounding box of the feature, if
+/// set, is moved to the geometry.
+#[cfg(feature = "geojson")]
+impl TryFrom<geojson::Feature> for AttributedFeature<geojson::Geometry> {
+    type Error = crate::error::GalileoError;
+
+    fn try_from(value: geojson::Feature) -> Result<Self, Self::Error> {
+        let mut geometry = value.geometry.ok_or_else(|| {
+            crate::error::GalileoError::Import("GeoJSON feature has no geometry".into())
+        })?;
+        if geometry.bbox.is_none() {
+            geometry.bbox = value.bbox;
+        }
+
+        Ok(Self {
+            geometry,
+            properties: value
+                .properties
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(key, value)| (key, value.into()))
+                .collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use galileo_types::cartesian::impls::contour::Contour;
+
+    #[test]
+    fn property_value_accessors() {
+        assert_eq!(PropertyValue::from(3i64).as_f64(), Some(3.0));
+        assert_eq!(PropertyValue::from(3.5).as_i64(), None);
+        assert_eq!(PropertyValue::from("abc").as_str(), Some("abc"));
+        assert_eq!(PropertyValue::from(None::<bool>), PropertyValue::Null);
+        assert_eq!(PropertyValue::from(Some(true)).as_bool(), Some(true));
+    }
+
+    #[test]
+    fn from_mvt_feature() {
+        let mvt = MvtFeature {
+            id: Some(1),
+            properties: HashMap::from([
+                ("name".to_string(), MvtValue::String("Main st".into())),
+                ("lanes".to_string(), MvtValue::Uint64(2)),
+                ("width".to_string(), MvtValue::Float(7.5)),
+            ]),
+            geometry: MvtGeometry::LineString(vec![Contour::open(vec![
+                Point2::new(0.0, 0.0),
+                Point2::new(1.0, 1.0),
+            ])]),
+        };
+
+        let feature = AttributedFeature::from(mvt);
+        assert!(matches!(feature.geometry, Geom::Contour(_)));
+        assert_eq!(
+            feature.property("name"),
+            Some(&PropertyValue::String("Main st".into()))
+        );
+        assert_eq!(feature.property("lanes"), Some(&PropertyValue::Integer(2)));
+        assert_eq!(feature.property("width"), Some(&PropertyValue::Float(7.5)));
+        assert_eq!(feature.property("other"), None);
+
+        assert_eq!(
+            PropertyValue::from(MvtValue::Float(0.1)),
+            PropertyValue::Float(0.1)
+        );
+    }
+}
@@ -0,0 +1,109 @@
+use crate::error::GalileoError;
+use crate::layer::feature_layer::feature::{AttributedFeature, Properties, PropertyValue};
+use crate::layer::feature_layer::feature_source::geojson::push_if_intersects;
+use crate::layer::feature_layer::feature_source::{crs_from_srs_id, FeatureSource};
+use flatgeobuf::{FallibleStreamingIterator, FgbFeature, FgbReader};
+use galileo_types::cartesian::rect::Rect;
+use galileo_types::geo::crs::Crs;
+use geojson::{Geometry, JsonValue};
+use geozero::{ColumnValue, FeatureProperties, PropertyProcessor, ToJson};
+use std::fs::File;
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+
+/// Reads features from a FlatGeobuf file.
+///
+/// If the file contains a spatial index, bounding box queries read only the parts of the file with the requested
+/// features. Files without index are scanned sequentially.
+#[derive(Debug, Clone)]
+pub struct FlatGeobufSource {
+    path: PathBuf,
+    crs: Crs,
+}
+
+impl FlatGeobufSource {
+    /// Opens the file and reads its header. The CRS of the source is taken from the header, if it is set there,
+    /// otherwise WGS84 is assumed.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, GalileoError> {
+        let path = path.as_ref().to_path_buf();
+        let mut reader = BufReader::new(File::open(&path)?);
+        let fgb = FgbReader::open(&mut reader)?;
+        let crs = match fgb.header().crs() {
+            Some(crs) if crs.code() != 0 => crs_from_srs_id(crs.code()),
+            _ => Crs::WGS84,
+        };
+
+        Ok(Self { path, crs })
+    }
+}
+
+impl FeatureSource for FlatGeobufSource {
+    type Feature = AttributedFeature<Geometry>;
+
+    fn crs(&self) -> &Crs {
+        &self.crs
+    }
+
+    fn query(&self, bbox: Option<Rect>) -> Result<Vec<Self::Feature>, GalileoError> {
+        let mut reader = BufReader::new(File::open(&self.path)?);
+        let fgb = FgbReader::open(&mut reader)?;
+        let mut iter = match bbox {
+            Some(bbox) => fgb.select_bbox(bbox.x_min, bbox.y_min, bbox.x_max, bbox.y_max)?,
+            None => fgb.select_all()?,
+        };
+
+        let mut features = vec![];
+        while let Some(feature) = iter.next()? {
+            // The index only contains bounding boxes of the features, so they still have to be checked, but
+            // for files without index this is the only filtering there is.
+            push_if_intersects(convert_feature(feature)?, bbox, &mut features);
+        }
+
+        Ok(features)
+    }
+}
+
+fn convert_feature(feature: &FgbFeature) -> Result<AttributedFeature<Geometry>, GalileoError> {
+    let geometry = feature.to_json()?.parse()?;
+    let mut properties = PropertiesCollector::default();
+    feature.process_properties(&mut properties)?;
+
+    Ok(AttributedFeature::new(geometry, properties.0))
+}
+
+#[derive(Default)]
+struct PropertiesCollector(Properties);
+
+impl PropertyProcessor for PropertiesCollector {
+    fn property(
+        &mut self,
+        _idx: usize,
+        name: &str,
+        value: &ColumnValue,
+    ) -> geozero::error::Result<bool> {
+        let value = match value {
+            ColumnValue::Byte(v) => PropertyValue::Integer(*v as i64),
+            ColumnValue::UByte(v) => PropertyValue::Integer(*v as i64),
+            ColumnValue::Bool(v) => PropertyValue::Bool(*v),
+            ColumnValue::Short(v) => PropertyValue::Integer(*v as i64),
+            ColumnValue::UShort(v) => PropertyValue::Integer(*v as i64),
+            ColumnValue::Int(v) => PropertyValue::Integer(*v as i64),
+            ColumnValue::UInt(v) => PropertyValue::Integer(*v as i64),
+            ColumnValue::Long(v) => PropertyValue::Integer(*v),
+            ColumnValue::ULong(v) => i64::try_from(*v)
+                .map(PropertyValue::Integer)
+                .unwrap_or(PropertyValue::Float(*v as f64)),
+            ColumnValue::Float(v) => PropertyValue::Float(*v as f64),
+            ColumnValue::Double(v) => PropertyValue::Float(*v),
+            ColumnValue::String(v) | ColumnValue::DateTime(v) => PropertyValue::from(*v),
+            ColumnValue::Json(v) => v
+                .parse::<JsonValue>()
+                .map(PropertyValue::from)
+                .unwrap_or_else(|_| PropertyValue::from(*v)),
+            ColumnValue::Binary(_) => PropertyValue::Null,
+        };
+
+        self.0.insert(name.to_string(), value);
+        Ok(false)
+    }
+}
@@ -0,0 +1,258 @@
+use crate::error::GalileoError;
+use crate::layer::feature_layer::feature::AttributedFeature;
+use crate::layer::feature_layer::feature_source::FeatureSource;
+use galileo_types::cartesian::rect::Rect;
+use galileo_types::geo::crs::Crs;
+use geojson::{Feature, FeatureReader, Geometry, Position, Value};
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+
+/// Record separator character used by GeoJSON text sequences (RFC 8142).
+const RECORD_SEPARATOR: char = '\u{1e}';
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum GeoJsonFormat {
+    /// A single GeoJSON `FeatureCollection` object.
+    FeatureCollection,
+    /// One GeoJSON `Feature` per line, optionally prefixed with the record separator (GeoJSONSeq, newline delimited
+    /// GeoJSON).
+    Sequence,
+}
+
+/// Reads features from a GeoJSON file.
+///
+/// The file is parsed feature by feature, and only the features intersecting the requested bounding box are kept
+/// in memory.
+#[derive(Debug, Clone)]
+pub struct GeoJsonSource {
+    path: PathBuf,
+    format: GeoJsonFormat,
+    crs: Crs,
+}
+
+impl GeoJsonSource {
+    /// Creates a new source for the given file. The format of the file is guessed from the file extension:
+    /// `.geojsons`, `.geojsonl`, `.geojsonseq`, `.jsonl` and `.ndjson` files are treated as sequences, all others as
+    /// feature collections.
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        let path = path.as_ref().to_path_buf();
+        let format = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("geojsons" | "geojsonl" | "geojsonseq" | "jsonl" | "ndjson") => {
+                GeoJsonFormat::Sequence
+            }
+            _ => GeoJsonFormat::FeatureCollection,
+        };
+
+        Self {
+            path,
+            format,
+            crs: Crs::WGS84,
+        }
+    }
+
+    pub fn with_format(mut self, format: GeoJsonFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Sets the CRS of the file coordinates. By GeoJSON specification it is always WGS84, but some legacy files
+    /// use other coordinate systems.
+    pub fn with_crs(mut self, crs: Crs) -> Self {
+        self.crs = crs;
+        self
+    }
+
+    pub fn format(&self) -> GeoJsonFormat {
+        self.format
+    }
+}
+
+impl FeatureSource for GeoJsonSource {
+    type Feature = AttributedFeature<Geometry>;
+
+    fn crs(&self) -> &Crs {
+        &self.crs
+    }
+
+    fn query(&self, bbox: Option<Rect>) -> Result<Vec<Self::Feature>, GalileoError> {
+        let reader = BufReader::new(File::open(&self.path)?);
+        let mut features = vec![];
+
+        match self.format {
+            GeoJsonFormat::FeatureCollection => {
+                for feature in FeatureReader::from_reader(reader).features() {
+                    push_geojson_feature(feature?, bbox, &mut features);
+                }
+            }
+            GeoJsonFormat::Sequence => {
+                for line in reader.lines() {
+                    let line = line?;
+                    let line = line.trim_start_matches(RECORD_SEPARATOR).trim();
+                    if line.is_empty() {
+                        continue;
+                    }
+
+                    push_geojson_feature(line.parse::<Feature>()?, bbox, &mut features);
+                }
+            }
+        }
+
+        Ok(features)
+    }
+}
+
+/// Converts a GeoJSON feature and adds it to the list if it intersects `bbox`. Features without geometry are skipped.
+fn push_geojson_feature(
+    feature: Feature,
+    bbox: Option<Rect>,
+    features: &mut Vec<AttributedFeature<Geometry>>,
+) {
+    if let Ok(feature) = AttributedFeature::try_from(feature) {
+        push_if_intersects(feature, bbox, features);
+    }
+}
+
+/// Adds the feature to the list if its geometry can be rendered and intersects `bbox`.
+pub(crate) fn push_if_intersects(
+    feature: AttributedFeature<Geometry>,
+    bbox: Option<Rect>,
+    features: &mut Vec<AttributedFeature<Geometry>>,
+) {
+    let geometry = &feature.geometry;
+    if matches!(geometry.value, Value::GeometryCollection(_)) {
+        log::debug!("Skipping feature: geometry collections are not supported");
+        return;
+    }
+
+    if let Some(bbox) = bbox {
+        let feature_bbox = match &geometry.bbox {
+            Some(b) if b.len() >= 4 => Some(Rect::new(b[0], b[1], b[2], b[3])),
+            _ => value_bbox(&geometry.value),
+        };
+
+        match feature_bbox {
+            Some(feature_bbox) if feature_bbox.intersects(bbox) => {}
+            _ => return,
+        }
+    }
+
+    features.push(feature);
+}
+
+/// Calculates the bounding box of a GeoJSON geometry.
+pub(crate) fn value_bbox(value: &Value) -> Option<Rect> {
+    match value {
+        Value::Point(p) => positions_bbox([p]),
+        Value::MultiPoint(points) | Value::LineString(points) => positions_bbox(points),
+        Value::MultiLineString(lines) | Value::Polygon(lines) => {
+            positions_bbox(lines.iter().flatten())
+        }
+        Value::MultiPolygon(polygons) => positions_bbox(polygons.iter().flatten().flatten()),
+        Value::GeometryCollection(geometries) => geometries
+            .iter()
+            .filter_map(|g| value_bbox(&g.value))
+            .collect(),
+    }
+}
+
+fn positions_bbox<'a>(positions: impl IntoIterator<Item = &'a Position>) -> Option<Rect> {
+    let mut bbox: Option<Rect> = None;
+    for position in positions {
+        if position.len() < 2 {
+            continue;
+        }
+
+        let point_bbox = Rect::new(position[0], position[1], position[0], position[1]);
+        bbox = Some(match bbox {
+            Some(bbox) => bbox.merge(point_bbox),
+            None => point_bbox,
+        });
+    }
+
+    bbox
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layer::feature_layer::feature::{Properties, PropertyValue};
+
+    fn point_feature(x: f64, y: f64) -> AttributedFeature<Geometry> {
+        AttributedFeature::new(Geometry::new(Value::Point(vec![x, y])), Properties::new())
+    }
+
+    #[test]
+    fn value_bbox_polygon() {
+        let value = Value::Polygon(vec![vec![
+            vec![0.0, 0.0],
+            vec![10.0, 0.0],
+            vec![10.0, 5.0],
+            vec![0.0, 0.0],
+        ]]);
+        assert_eq!(value_bbox(&value), Some(Rect::new(0.0, 0.0, 10.0, 5.0)));
+    }
+
+    #[test]
+    fn push_filters_by_bbox() {
+        let bbox = Some(Rect::new(0.0, 0.0, 10.0, 10.0));
+        let mut features = vec![];
+        push_if_intersects(point_feature(5.0, 5.0), bbox, &mut features);
+        push_if_intersects(point_feature(15.0, 5.0), bbox, &mut features);
+        push_if_intersects(point_feature(5.0, -1.0), bbox, &mut features);
+        assert_eq!(features.len(), 1);
+
+        push_if_intersects(point_feature(15.0, 5.0), None, &mut features);
+        assert_eq!(features.len(), 2);
+    }
+
+    #[test]
+    fn geojson_features_are_converted() {
+        let feature: Feature =
+            r#"{"type": "Feature", "bbox": [20, 0, 30, 1], "geometry": {"type": "Point", "coordinates": [5, 5]}, "properties": {"name": "a", "population": 10}}"#
+                .parse()
+                .unwrap();
+        let no_geometry: Feature = r#"{"type": "Feature", "geometry": null, "properties": null}"#
+            .parse()
+            .unwrap();
+
+        let mut features = vec![];
+        push_geojson_feature(no_geometry, None, &mut features);
+        assert!(features.is_empty());
+
+        // The bounding box of the feature is used instead of the geometry one.
+        push_geojson_feature(
+            feature.clone(),
+            Some(Rect::new(0.0, 0.0, 10.0, 10.0)),
+            &mut features,
+        );
+        assert!(features.is_empty());
+
+        push_geojson_feature(
+            feature,
+            Some(Rect::new(25.0, 0.0, 26.0, 10.0)),
+            &mut features,
+        );
+        assert_eq!(features.len(), 1);
+        assert_eq!(
+            features[0].property("name"),
+            Some(&PropertyValue::from("a"))
+        );
+        assert_eq!(
+            features[0].property("population"),
+            Some(&PropertyValue::Integer(10))
+        );
+    }
+
+    #[test]
+    fn format_from_extension() {
+        assert_eq!(
+            GeoJsonSource::new("data/points.geojsons").format(),
+            GeoJsonFormat::Sequence
+        );
+        assert_eq!(
+            GeoJsonSource::new("data/points.geojson").format(),
+            GeoJsonFormat::FeatureCollection
+        );
+    }
+}
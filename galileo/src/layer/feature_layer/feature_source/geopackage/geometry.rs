@@ -0,0 +1,316 @@
+//! Decoding of GeoPackage binary geometry blobs (GeoPackage header followed by ISO WKB) into GeoJSON geometries.
+
+use crate::error::GalileoError;
+use galileo_types::cartesian::rect::Rect;
+use geojson::{Position, Value};
+
+const MAGIC: &[u8; 2] = b"GP";
+
+const FLAG_LITTLE_ENDIAN: u8 = 0b0000_0001;
+const FLAG_EMPTY: u8 = 0b0001_0000;
+
+// EWKB flags, some writers use them instead of ISO type codes
+const EWKB_Z: u32 = 0x8000_0000;
+const EWKB_M: u32 = 0x4000_0000;
+const EWKB_SRID: u32 = 0x2000_0000;
+
+/// Maximum nesting depth of geometry collections. Real data never comes close to it, so deeper geometries can only be
+/// found in malformed files.
+const MAX_NESTING_DEPTH: usize = 32;
+
+/// Header of a GeoPackage geometry blob.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct GpkgHeader {
+    pub envelope: Option<Rect>,
+    pub is_empty: bool,
+    /// Offset of the WKB geometry in the blob.
+    pub wkb_offset: usize,
+}
+
+pub(crate) fn read_header(blob: &[u8]) -> Result<GpkgHeader, GalileoError> {
+    if blob.len() < 8 || &blob[0..2] != MAGIC {
+        return Err(format_error("invalid geometry header"));
+    }
+
+    let flags = blob[3];
+    let little_endian = flags & FLAG_LITTLE_ENDIAN != 0;
+    let mut reader = ByteReader::new(blob, 4, little_endian);
+    // srs id is the same for all geometries in the column and is read from the geometry columns table
+    reader.u32()?;
+
+    let envelope_values = match (flags >> 1) & 0b111 {
+        0 => 0,
+        1 => 4,
+        2 | 3 => 6,
+        4 => 8,
+        _ => return Err(format_error("invalid envelope type")),
+    };
+
+    let envelope = if envelope_values > 0 {
+        let x_min = reader.f64()?;
+        let x_max = reader.f64()?;
+        let y_min = reader.f64()?;
+        let y_max = reader.f64()?;
+        for _ in 4..envelope_values {
+            reader.f64()?;
+        }
+
+        Some(Rect::new(x_min, y_min, x_max, y_max))
+    } else {
+        None
+    };
+
+    Ok(GpkgHeader {
+        envelope,
+        is_empty: flags & FLAG_EMPTY != 0,
+        wkb_offset: reader.offset,
+    })
+}
+
+/// Decodes a WKB geometry. Z coordinates are kept, M coordinates are dropped.
+pub(crate) fn read_wkb(wkb: &[u8]) -> Result<Value, GalileoError> {
+    let mut reader = ByteReader::new(wkb, 0, true);
+    read_geometry(&mut reader, 0)
+}
+
+fn read_geometry(reader: &mut ByteReader, depth: usize) -> Result<Value, GalileoError> {
+    if depth > MAX_NESTING_DEPTH {
+        return Err(format_error("geometry nesting is too deep"));
+    }
+
+    reader.little_endian = match reader.u8()? {
+        0 => false,
+        1 => true,
+        _ => return Err(format_error("invalid WKB byte order")),
+    };
+
+    let type_code = reader.u32()?;
+    let mut has_z = type_code & EWKB_Z != 0;
+    let mut has_m = type_code & EWKB_M != 0;
+    if type_code & EWKB_SRID != 0 {
+        reader.u32()?;
+    }
+
+    let iso_code = type_code & 0x0fff_ffff;
+    match iso_code / 1000 {
+        0 => {}
+        1 => has_z = true,
+        2 => has_m = true,
+        3 => {
+            has_z = true;
+            has_m = true;
+        }
+        _ => return Err(format_error("invalid WKB geometry type")),
+    }
+
+    let dims = Dimensions { has_z, has_m };
+    let value = match iso_code % 1000 {
+        1 => Value::Point(read_position(reader, dims)?),
+        2 => Value::LineString(read_positions(reader, dims)?),
+        3 => Value::Polygon(read_rings(reader, dims)?),
+        4 => Value::MultiPoint(
+            read_parts(reader, depth)?
+                .into_iter()
+                .filter_map(|v| match v {
+                    Value::Point(p) => Some(p),
+                    _ => None,
+                })
+                .collect(),
+        ),
+        5 => Value::MultiLineString(
+            read_parts(reader, depth)?
+                .into_iter()
+                .filter_map(|v| match v {
+                    Value::LineString(l) => Some(l),
+                    _ => None,
+                })
+                .collect(),
+        ),
+        6 => Value::MultiPolygon(
+            read_parts(reader, depth)?
+                .into_iter()
+                .filter_map(|v| match v {
+                    Value::Polygon(p) => Some(p),
+                    _ => None,
+                })
+                .collect(),
+        ),
+        7 => Value::GeometryCollection(
+            read_parts(reader, depth)?
+                .into_iter()
+                .map(geojson::Geometry::new)
+                .collect(),
+        ),
+        _ => return Err(format_error("unsupported WKB geometry type")),
+    };
+
+    Ok(value)
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Dimensions {
+    has_z: bool,
+    has_m: bool,
+}
+
+fn read_parts(reader: &mut ByteReader, depth: usize) -> Result<Vec<Value>, GalileoError> {
+    let count = reader.u32()?;
+    (0..count)
+        .map(|_| read_geometry(reader, depth + 1))
+        .collect()
+}
+
+fn read_rings(
+    reader: &mut ByteReader,
+    dims: Dimensions,
+) -> Result<Vec<Vec<Position>>, GalileoError> {
+    let count = reader.u32()?;
+    (0..count).map(|_| read_positions(reader, dims)).collect()
+}
+
+fn read_positions(
+    reader: &mut ByteReader,
+    dims: Dimensions,
+) -> Result<Vec<Position>, GalileoError> {
+    let count = reader.u32()?;
+    (0..count).map(|_| read_position(reader, dims)).collect()
+}
+
+fn read_position(reader: &mut ByteReader, dims: Dimensions) -> Result<Position, GalileoError> {
+    let mut position = vec![reader.f64()?, reader.f64()?];
+    if dims.has_z {
+        position.push(reader.f64()?);
+    }
+    if dims.has_m {
+        reader.f64()?;
+    }
+
+    Ok(position)
+}
+
+struct ByteReader<'a> {
+    data: &'a [u8],
+    offset: usize,
+    little_endian: bool,
+}
+
+impl<'a> ByteReader<'a> {
+    fn new(data: &'a [u8], offset: usize, little_endian: bool) -> Self {
+        Self {
+            data,
+            offset,
+            little_endian,
+        }
+    }
+
+    fn take<const N: usize>(&mut self) -> Result<[u8; N], GalileoError> {
+        let bytes = self
+            .data
+            .get(self.offset..self.offset + N)
+            .ok_or_else(|| format_error("unexpected end of geometry"))?;
+        self.offset += N;
+        Ok(bytes.try_into().unwrap())
+    }
+
+    fn u8(&mut self) -> Result<u8, GalileoError> {
+        Ok(self.take::<1>()?[0])
+    }
+
+    fn u32(&mut self) -> Result<u32, GalileoError> {
+        let bytes = self.take()?;
+        Ok(if self.little_endian {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        })
+    }
+
+    fn f64(&mut self) -> Result<f64, GalileoError> {
+        let bytes = self.take()?;
+        Ok(if self.little_endian {
+            f64::from_le_bytes(bytes)
+        } else {
+            f64::from_be_bytes(bytes)
+        })
+    }
+}
+
+fn format_error(message: &str) -> GalileoError {
+    GalileoError::Generic(format!("invalid GeoPackage geometry: {message}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gpkg_point(x: f64, y: f64) -> Vec<u8> {
+        // header with little endian flag and XY envelope
+        let mut blob = vec![b'G', b'P', 0, 0b0000_0011];
+        blob.extend_from_slice(&4326i32.to_le_bytes());
+        for v in [x, x, y, y] {
+            blob.extend_from_slice(&v.to_le_bytes());
+        }
+
+        blob.push(1);
+        blob.extend_from_slice(&1u32.to_le_bytes());
+        blob.extend_from_slice(&x.to_le_bytes());
+        blob.extend_from_slice(&y.to_le_bytes());
+        blob
+    }
+
+    #[test]
+    fn point_with_envelope() {
+        let blob = gpkg_point(10.0, 20.0);
+        let header = read_header(&blob).unwrap();
+        assert_eq!(header.envelope, Some(Rect::new(10.0, 20.0, 10.0, 20.0)));
+        assert!(!header.is_empty);
+        assert_eq!(header.wkb_offset, 40);
+
+        let value = read_wkb(&blob[header.wkb_offset..]).unwrap();
+        assert_eq!(value, Value::Point(vec![10.0, 20.0]));
+    }
+
+    #[test]
+    fn big_endian_line_string_z() {
+        let mut wkb = vec![0];
+        wkb.extend_from_slice(&1002u32.to_be_bytes());
+        wkb.extend_from_slice(&2u32.to_be_bytes());
+        for v in [1.0f64, 2.0, 3.0, 4.0, 5.0, 6.0] {
+            wkb.extend_from_slice(&v.to_be_bytes());
+        }
+
+        let value = read_wkb(&wkb).unwrap();
+        assert_eq!(
+            value,
+            Value::LineString(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]])
+        );
+    }
+
+    #[test]
+    fn truncated_geometry() {
+        let blob = gpkg_point(10.0, 20.0);
+        assert!(read_wkb(&blob[40..blob.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn nested_collections() {
+        fn nested_collection(depth: usize) -> Vec<u8> {
+            let mut wkb = vec![];
+            for _ in 0..depth {
+                wkb.push(1);
+                wkb.extend_from_slice(&7u32.to_le_bytes());
+                wkb.extend_from_slice(&1u32.to_le_bytes());
+            }
+
+            wkb.push(1);
+            wkb.extend_from_slice(&1u32.to_le_bytes());
+            wkb.extend_from_slice(&1.0f64.to_le_bytes());
+            wkb.extend_from_slice(&2.0f64.to_le_bytes());
+            wkb
+        }
+
+        assert!(read_wkb(&nested_collection(MAX_NESTING_DEPTH)).is_ok());
+        assert!(read_wkb(&nested_collection(MAX_NESTING_DEPTH + 1)).is_err());
+        assert!(read_wkb(&nested_collection(100_000)).is_err());
+    }
+}
@@ -0,0 +1,215 @@
+use crate::error::GalileoError;
+use crate::layer::feature_layer::feature::{AttributedFeature, Properties, PropertyValue};
+use crate::layer::feature_layer::feature_source::geojson::push_if_intersects;
+use crate::layer::feature_layer::feature_source::{crs_from_srs_id, FeatureSource};
+use galileo_types::cartesian::rect::Rect;
+use galileo_types::geo::crs::Crs;
+use geojson::Geometry;
+use rtree::RTreeIndex;
+use sqlite::{SqlValue, SqliteReader, TableInfo};
+use std::fs::File;
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+
+mod geometry;
+mod rtree;
+mod sqlite;
+
+const GEOMETRY_COLUMNS_TABLE: &str = "gpkg_geometry_columns";
+
+/// Reads features from a feature table of a GeoPackage file.
+///
+/// The database is read directly from the file without linking to the SQLite library. If the geometry column has a
+/// spatial index, bounding box queries read only the rows found in the index. Otherwise the rows are read one by one.
+/// In both cases they are filtered by the envelopes stored in the geometry headers, so only the requested features
+/// are decoded and kept in memory.
+#[derive(Debug, Clone)]
+pub struct GeoPackageSource {
+    path: PathBuf,
+    table_name: String,
+    table: TableInfo,
+    geometry_column: usize,
+    rtree: Option<RTreeIndex>,
+    crs: Crs,
+}
+
+impl GeoPackageSource {
+    /// Opens the first feature table of the GeoPackage.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, GalileoError> {
+        Self::open_internal(path.as_ref(), None)
+    }
+
+    /// Opens the feature table with the given name.
+    pub fn open_table(path: impl AsRef<Path>, table_name: &str) -> Result<Self, GalileoError> {
+        Self::open_internal(path.as_ref(), Some(table_name))
+    }
+
+    pub fn table_name(&self) -> &str {
+        &self.table_name
+    }
+
+    fn open_internal(path: &Path, table_name: Option<&str>) -> Result<Self, GalileoError> {
+        let mut reader = SqliteReader::new(BufReader::new(File::open(path)?))?;
+        let geometry_columns = reader.table(GEOMETRY_COLUMNS_TABLE).map_err(|_| {
+            GalileoError::Generic(format!("{path:?} is not a GeoPackage feature file"))
+        })?;
+        let column = |name: &str| {
+            geometry_columns.column_index(name).ok_or_else(|| {
+                GalileoError::Generic(format!(
+                    "{GEOMETRY_COLUMNS_TABLE} table has no column {name}"
+                ))
+            })
+        };
+        let table_name_idx = column("table_name")?;
+        let column_name_idx = column("column_name")?;
+        let srs_id_idx = column("srs_id")?;
+
+        let mut found = None;
+        reader.scan(geometry_columns.root_page, |_, values| {
+            if found.is_some() {
+                return Ok(());
+            }
+
+            let (Some(name), Some(column)) = (
+                values.get(table_name_idx).and_then(SqlValue::as_str),
+                values.get(column_name_idx).and_then(SqlValue::as_str),
+            ) else {
+                return Ok(());
+            };
+
+            if table_name.is_none_or(|t| t.eq_ignore_ascii_case(name)) {
+                let srs_id = values.get(srs_id_idx).and_then(SqlValue::as_i64);
+                found = Some((name.to_string(), column.to_string(), srs_id));
+            }
+
+            Ok(())
+        })?;
+
+        let Some((table_name, geometry_column_name, srs_id)) = found else {
+            return Err(GalileoError::NotFound);
+        };
+
+        let table = reader.table(&table_name)?;
+        let geometry_column = table.column_index(&geometry_column_name).ok_or_else(|| {
+            GalileoError::Generic(format!(
+                "table {table_name} has no column {geometry_column_name}"
+            ))
+        })?;
+        let rtree = RTreeIndex::open(&mut reader, &table_name, &geometry_column_name)?;
+
+        let crs = match srs_id {
+            Some(srs_id) if srs_id > 0 => crs_from_srs_id(srs_id as i32),
+            _ => Crs::WGS84,
+        };
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            table_name,
+            table,
+            geometry_column,
+            rtree,
+            crs,
+        })
+    }
+
+    fn convert_row(
+        &self,
+        rowid: i64,
+        values: Vec<SqlValue>,
+        bbox: Option<Rect>,
+    ) -> Option<AttributedFeature<Geometry>> {
+        let Some(SqlValue::Blob(blob)) = values.get(self.geometry_column) else {
+            return None;
+        };
+
+        let header = match geometry::read_header(blob) {
+            Ok(header) => header,
+            Err(err) => {
+                log::warn!("Failed to read geometry of feature {rowid}: {err}");
+                return None;
+            }
+        };
+
+        if header.is_empty {
+            return None;
+        }
+
+        if let (Some(envelope), Some(bbox)) = (header.envelope, bbox) {
+            if !envelope.intersects(bbox) {
+                return None;
+            }
+        }
+
+        let value = match geometry::read_wkb(&blob[header.wkb_offset..]) {
+            Ok(value) => value,
+            Err(err) => {
+                log::warn!("Failed to read geometry of feature {rowid}: {err}");
+                return None;
+            }
+        };
+
+        let mut properties = Properties::new();
+        for (index, name) in self.table.columns.iter().enumerate() {
+            if index == self.geometry_column {
+                continue;
+            }
+
+            let value = if Some(index) == self.table.rowid_column {
+                PropertyValue::Integer(rowid)
+            } else {
+                match values.get(index) {
+                    None | Some(SqlValue::Null) => PropertyValue::Null,
+                    Some(SqlValue::Integer(v)) => PropertyValue::Integer(*v),
+                    Some(SqlValue::Real(v)) => PropertyValue::Float(*v),
+                    Some(SqlValue::Text(v)) => PropertyValue::from(v.as_str()),
+                    Some(SqlValue::Blob(_)) => continue,
+                }
+            };
+
+            properties.insert(name.clone(), value);
+        }
+
+        let mut geometry = Geometry::new(value);
+        geometry.bbox = header
+            .envelope
+            .map(|e| vec![e.x_min, e.y_min, e.x_max, e.y_max]);
+
+        Some(AttributedFeature::new(geometry, properties))
+    }
+}
+
+impl FeatureSource for GeoPackageSource {
+    type Feature = AttributedFeature<Geometry>;
+
+    fn crs(&self) -> &Crs {
+        &self.crs
+    }
+
+    fn query(&self, bbox: Option<Rect>) -> Result<Vec<Self::Feature>, GalileoError> {
+        let mut reader = SqliteReader::new(BufReader::new(File::open(&self.path)?))?;
+        let mut features = vec![];
+        match (bbox, &self.rtree) {
+            (Some(query_bbox), Some(rtree)) => {
+                for rowid in rtree.query(&mut reader, query_bbox)? {
+                    let Some(values) = reader.find_row(self.table.root_page, rowid)? else {
+                        log::warn!("Feature {rowid} from the spatial index is not in the table");
+                        continue;
+                    };
+
+                    if let Some(feature) = self.convert_row(rowid, values, bbox) {
+                        push_if_intersects(feature, bbox, &mut features);
+                    }
+                }
+            }
+            _ => reader.scan(self.table.root_page, |rowid, values| {
+                if let Some(feature) = self.convert_row(rowid, values, bbox) {
+                    push_if_intersects(feature, bbox, &mut features);
+                }
+
+                Ok(())
+            })?,
+        }
+
+        Ok(features)
+    }
+}
@@ -0,0 +1,166 @@
+//! Reader of the spatial index of GeoPackage feature tables.
+//!
+//! The index is an SQLite R*Tree virtual table named `rtree_<table>_<column>`. The virtual table itself has no data,
+//! the nodes of the tree are stored as blobs in the `rtree_<table>_<column>_node` table, with the root node having
+//! number 1.
+
+use super::sqlite::{SqlValue, SqliteReader};
+use crate::error::GalileoError;
+use galileo_types::cartesian::rect::Rect;
+use std::collections::HashSet;
+use std::io::{Read, Seek};
+
+const ROOT_NODE: i64 = 1;
+/// Size of a node entry of a 2d tree: 64 bit id and 4 32 bit float coordinates.
+const ENTRY_SIZE: usize = 24;
+/// SQLite never creates R*Trees deeper than this, so deeper trees can only be found in malformed files.
+const MAX_TREE_DEPTH: u16 = 40;
+
+/// Spatial index of a feature table.
+#[derive(Debug, Clone)]
+pub(crate) struct RTreeIndex {
+    node_table_root_page: u32,
+}
+
+impl RTreeIndex {
+    /// Opens the index of the given geometry column. Returns `None` if the column is not indexed.
+    pub fn open<R: Read + Seek>(
+        reader: &mut SqliteReader<R>,
+        table_name: &str,
+        column_name: &str,
+    ) -> Result<Option<Self>, GalileoError> {
+        match reader.table(&format!("rtree_{table_name}_{column_name}_node")) {
+            Ok(table) => Ok(Some(Self {
+                node_table_root_page: table.root_page,
+            })),
+            Err(GalileoError::NotFound) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Returns rowids of the features with the bounding boxes intersecting `bbox`, in ascending order.
+    ///
+    /// Coordinates in the index are stored as 32 bit floats rounded outwards, so the envelopes of the returned features
+    /// may not actually intersect `bbox`.
+    pub fn query<R: Read + Seek>(
+        &self,
+        reader: &mut SqliteReader<R>,
+        bbox: Rect,
+    ) -> Result<Vec<i64>, GalileoError> {
+        let root = self.read_node(reader, ROOT_NODE)?;
+        let depth = read_u16(&root, 0)?;
+        if depth > MAX_TREE_DEPTH {
+            return Err(format_error("invalid tree depth"));
+        }
+
+        let mut rowids = vec![];
+        let mut visited = HashSet::new();
+        let mut stack = vec![(ROOT_NODE, root, depth)];
+        while let Some((node_number, node, height)) = stack.pop() {
+            if !visited.insert(node_number) {
+                return Err(format_error("invalid tree structure"));
+            }
+
+            for (id, entry_bbox) in node_entries(&node)? {
+                if !entry_bbox.intersects(bbox) {
+                    continue;
+                }
+
+                if height == 0 {
+                    rowids.push(id);
+                } else {
+                    stack.push((id, self.read_node(reader, id)?, height - 1));
+                }
+            }
+        }
+
+        rowids.sort_unstable();
+        rowids.dedup();
+
+        Ok(rowids)
+    }
+
+    fn read_node<R: Read + Seek>(
+        &self,
+        reader: &mut SqliteReader<R>,
+        node_number: i64,
+    ) -> Result<Vec<u8>, GalileoError> {
+        // Node table columns: nodeno, data
+        let values = reader
+            .find_row(self.node_table_root_page, node_number)?
+            .ok_or_else(|| format_error("node not found"))?;
+        match values.into_iter().nth(1) {
+            Some(SqlValue::Blob(data)) => Ok(data),
+            _ => Err(format_error("invalid node")),
+        }
+    }
+}
+
+/// Reads ids and bounding boxes of the entries of a node. The node starts with the depth of the tree (only used in the
+/// root node) and the number of entries, followed by the entries.
+fn node_entries(node: &[u8]) -> Result<Vec<(i64, Rect)>, GalileoError> {
+    let count = read_u16(node, 2)? as usize;
+    let entries = node
+        .get(4..4 + count * ENTRY_SIZE)
+        .ok_or_else(|| format_error("invalid node"))?;
+
+    Ok(entries
+        .chunks_exact(ENTRY_SIZE)
+        .map(|entry| {
+            let id = i64::from_be_bytes(entry[0..8].try_into().expect("slice size is checked"));
+            let coord = |index: usize| {
+                let offset = 8 + index * 4;
+                f32::from_be_bytes(
+                    entry[offset..offset + 4]
+                        .try_into()
+                        .expect("slice size is checked"),
+                ) as f64
+            };
+
+            // Coordinates are stored as min x, max x, min y, max y.
+            (id, Rect::new(coord(0), coord(2), coord(1), coord(3)))
+        })
+        .collect())
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Result<u16, GalileoError> {
+    data.get(offset..offset + 2)
+        .map(|b| u16::from_be_bytes([b[0], b[1]]))
+        .ok_or_else(|| format_error("invalid node"))
+}
+
+fn format_error(message: &str) -> GalileoError {
+    GalileoError::Generic(format!("invalid GeoPackage spatial index: {message}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entries() {
+        let mut node = vec![0, 1, 0, 2];
+        for (id, coords) in [
+            (7i64, [0.0f32, 1.0, 2.0, 3.0]),
+            (-1, [-5.0, 5.0, -5.0, 5.0]),
+        ] {
+            node.extend_from_slice(&id.to_be_bytes());
+            for coord in coords {
+                node.extend_from_slice(&coord.to_be_bytes());
+            }
+        }
+
+        let entries = node_entries(&node).unwrap();
+        assert_eq!(
+            entries,
+            vec![
+                (7, Rect::new(0.0, 2.0, 1.0, 3.0)),
+                (-1, Rect::new(-5.0, -5.0, 5.0, 5.0))
+            ]
+        );
+        assert_eq!(read_u16(&node, 0).unwrap(), 1);
+
+        node[3] = 3;
+        assert!(node_entries(&node).is_err());
+    }
+}
@@ -0,0 +1,623 @@
+//! Minimal read-only reader of SQLite database files.
+//!
+//! Only the parts of the file format needed to read rowid tables are supported: the database header, table b-tree
+//! pages, overflow pages and the record format. Indices, `WITHOUT ROWID` tables, WAL files and databases with text
+//! encodings other than UTF-8 are not supported.
+
+use crate::error::GalileoError;
+use std::collections::HashSet;
+use std::io::{Read, Seek, SeekFrom};
+
+const HEADER_SIZE: usize = 100;
+const MAGIC: &[u8; 16] = b"SQLite format 3\0";
+const SCHEMA_ROOT_PAGE: u32 = 1;
+
+const INTERIOR_TABLE_PAGE: u8 = 0x05;
+const LEAF_TABLE_PAGE: u8 = 0x0d;
+
+/// SQLite never creates b-trees deeper than this, so deeper trees can only be found in malformed files.
+const MAX_TREE_DEPTH: usize = 20;
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum SqlValue {
+    Null,
+    Integer(i64),
+    Real(f64),
+    Text(String),
+    Blob(Vec<u8>),
+}
+
+impl SqlValue {
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            SqlValue::Text(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            SqlValue::Integer(v) => Some(*v),
+            _ => None,
+        }
+    }
+}
+
+/// Table description read from the database schema.
+#[derive(Debug, Clone)]
+pub(crate) struct TableInfo {
+    pub root_page: u32,
+    pub columns: Vec<String>,
+    /// Index of the `INTEGER PRIMARY KEY` column. The value of such column is not stored in the record, as it is the
+    /// same as the rowid.
+    pub rowid_column: Option<usize>,
+}
+
+impl TableInfo {
+    pub fn column_index(&self, name: &str) -> Option<usize> {
+        self.columns
+            .iter()
+            .position(|c| c.eq_ignore_ascii_case(name))
+    }
+}
+
+pub(crate) struct SqliteReader<R> {
+    reader: R,
+    page_size: usize,
+    usable_size: usize,
+    page_count: u64,
+}
+
+impl<R: Read + Seek> SqliteReader<R> {
+    pub fn new(mut reader: R) -> Result<Self, GalileoError> {
+        let mut header = [0; HEADER_SIZE];
+        reader.seek(SeekFrom::Start(0))?;
+        reader.read_exact(&mut header)?;
+
+        if &header[0..16] != MAGIC {
+            return Err(format_error("not an SQLite database"));
+        }
+
+        let page_size = match u16::from_be_bytes([header[16], header[17]]) {
+            1 => 65536,
+            v => v as usize,
+        };
+        if page_size < 512 || !page_size.is_power_of_two() {
+            return Err(format_error("invalid page size"));
+        }
+
+        let page_count = reader.seek(SeekFrom::End(0))? / page_size as u64;
+        let reserved = header[20] as usize;
+        let encoding = u32::from_be_bytes([header[56], header[57], header[58], header[59]]);
+        if encoding > 1 {
+            return Err(format_error("only UTF-8 databases are supported"));
+        }
+
+        Ok(Self {
+            reader,
+            page_size,
+            usable_size: page_size - reserved,
+            page_count,
+        })
+    }
+
+    /// Reads the description of the table with the given name from the schema table.
+    pub fn table(&mut self, name: &str) -> Result<TableInfo, GalileoError> {
+        let mut table = None;
+        self.scan(SCHEMA_ROOT_PAGE, |_, values| {
+            if table.is_some() {
+                return Ok(());
+            }
+
+            // Schema table columns: type, name, tbl_name, rootpage, sql
+            let is_table = values.first().and_then(SqlValue::as_str) == Some("table");
+            let is_name = values
+                .get(1)
+                .and_then(SqlValue::as_str)
+                .is_some_and(|v| v.eq_ignore_ascii_case(name));
+            if !is_table || !is_name {
+                return Ok(());
+            }
+
+            let root_page = values
+                .get(3)
+                .and_then(SqlValue::as_i64)
+                .ok_or_else(|| format_error("invalid schema table"))?;
+            let sql = values
+                .get(4)
+                .and_then(SqlValue::as_str)
+                .ok_or_else(|| format_error("invalid schema table"))?;
+            let (columns, rowid_column) = parse_columns(sql);
+            table = Some(TableInfo {
+                root_page: root_page as u32,
+                columns,
+                rowid_column,
+            });
+
+            Ok(())
+        })?;
+
+        table.ok_or(GalileoError::NotFound)
+    }
+
+    /// Calls `visitor` with the rowid and column values of every row of the table with the given root page.
+    ///
+    /// Rows are read one at a time, so the whole table is never loaded into memory.
+    pub fn scan(
+        &mut self,
+        root_page: u32,
+        mut visitor: impl FnMut(i64, Vec<SqlValue>) -> Result<(), GalileoError>,
+    ) -> Result<(), GalileoError> {
+        // Pages of a malformed file can refer to each other in a cycle, which would make the scan endless.
+        let mut visited = HashSet::new();
+        let mut stack = vec![(root_page, 0)];
+        while let Some((page_number, depth)) = stack.pop() {
+            if depth > MAX_TREE_DEPTH || !visited.insert(page_number) {
+                return Err(format_error("invalid b-tree structure"));
+            }
+
+            let page = self.read_page(page_number)?;
+            let header_offset = page_header_offset(page_number);
+            let page_type = page[header_offset];
+            let cell_count = read_u16(&page, header_offset + 3)? as usize;
+
+            match page_type {
+                INTERIOR_TABLE_PAGE => {
+                    stack.push((read_u32(&page, header_offset + 8)?, depth + 1));
+                    for i in (0..cell_count).rev() {
+                        let cell_offset = read_u16(&page, header_offset + 12 + i * 2)? as usize;
+                        stack.push((read_u32(&page, cell_offset)?, depth + 1));
+                    }
+                }
+                LEAF_TABLE_PAGE => {
+                    for i in 0..cell_count {
+                        let cell_offset = read_u16(&page, header_offset + 8 + i * 2)? as usize;
+                        let (rowid, payload) = self.read_leaf_cell(&page, cell_offset)?;
+                        visitor(rowid, decode_record(&payload)?)?;
+                    }
+                }
+                _ => return Err(format_error("unexpected b-tree page type")),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the column values of the row with the given rowid from the table with the given root page, or `None` if
+    /// there is no such row.
+    pub fn find_row(
+        &mut self,
+        root_page: u32,
+        rowid: i64,
+    ) -> Result<Option<Vec<SqlValue>>, GalileoError> {
+        let mut page_number = root_page;
+        for _ in 0..=MAX_TREE_DEPTH {
+            let page = self.read_page(page_number)?;
+            let header_offset = page_header_offset(page_number);
+            let page_type = page[header_offset];
+            let cell_count = read_u16(&page, header_offset + 3)? as usize;
+
+            match page_type {
+                INTERIOR_TABLE_PAGE => {
+                    // Cells are ordered by their keys, which are the largest rowids in their left children.
+                    page_number = read_u32(&page, header_offset + 8)?;
+                    for i in 0..cell_count {
+                        let cell_offset = read_u16(&page, header_offset + 12 + i * 2)? as usize;
+                        let (key, _) = read_varint(&page, cell_offset + 4)?;
+                        if rowid <= key as i64 {
+                            page_number = read_u32(&page, cell_offset)?;
+                            break;
+                        }
+                    }
+                }
+                LEAF_TABLE_PAGE => {
+                    for i in 0..cell_count {
+                        let cell_offset = read_u16(&page, header_offset + 8 + i * 2)? as usize;
+                        let (_, rowid_offset) = read_varint(&page, cell_offset)?;
+                        if read_varint(&page, rowid_offset)?.0 as i64 == rowid {
+                            let (_, payload) = self.read_leaf_cell(&page, cell_offset)?;
+                            return Ok(Some(decode_record(&payload)?));
+                        }
+                    }
+
+                    return Ok(None);
+                }
+                _ => return Err(format_error("unexpected b-tree page type")),
+            }
+        }
+
+        Err(format_error("invalid b-tree structure"))
+    }
+
+    fn read_page(&mut self, page_number: u32) -> Result<Vec<u8>, GalileoError> {
+        if page_number == 0 {
+            return Err(format_error("invalid page number"));
+        }
+
+        let mut page = vec![0; self.page_size];
+        self.reader.seek(SeekFrom::Start(
+            (page_number as u64 - 1) * self.page_size as u64,
+        ))?;
+        self.reader.read_exact(&mut page)?;
+
+        Ok(page)
+    }
+
+    fn read_leaf_cell(
+        &mut self,
+        page: &[u8],
+        offset: usize,
+    ) -> Result<(i64, Vec<u8>), GalileoError> {
+        let (payload_size, offset) = read_varint(page, offset)?;
+        let (rowid, offset) = read_varint(page, offset)?;
+        if payload_size > self.page_count * self.usable_size as u64 {
+            return Err(format_error("cell payload is larger than the file"));
+        }
+        let payload_size = payload_size as usize;
+
+        let local_size = local_payload_size(payload_size, self.usable_size);
+        let mut payload = page
+            .get(offset..offset + local_size)
+            .ok_or_else(|| format_error("cell out of page bounds"))?
+            .to_vec();
+
+        if local_size < payload_size {
+            let mut overflow_page = read_u32(page, offset + local_size)?;
+            while payload.len() < payload_size {
+                let page = self.read_page(overflow_page)?;
+                let chunk_size = (payload_size - payload.len()).min(self.usable_size - 4);
+                payload.extend_from_slice(&page[4..4 + chunk_size]);
+                overflow_page = read_u32(&page, 0)?;
+            }
+        }
+
+        Ok((rowid as i64, payload))
+    }
+}
+
+/// Offset of the b-tree page header in the page. The first page also contains the database header.
+fn page_header_offset(page_number: u32) -> usize {
+    if page_number == SCHEMA_ROOT_PAGE {
+        HEADER_SIZE
+    } else {
+        0
+    }
+}
+
+/// Number of payload bytes stored in the b-tree leaf page itself, as defined by the file format.
+fn local_payload_size(payload_size: usize, usable_size: usize) -> usize {
+    let max_local = usable_size - 35;
+    if payload_size <= max_local {
+        return payload_size;
+    }
+
+    let min_local = (usable_size - 12) * 32 / 255 - 23;
+    let local = min_local + (payload_size - min_local) % (usable_size - 4);
+    if local <= max_local {
+        local
+    } else {
+        min_local
+    }
+}
+
+fn decode_record(payload: &[u8]) -> Result<Vec<SqlValue>, GalileoError> {
+    let (header_size, mut offset) = read_varint(payload, 0)?;
+    let header_size = header_size as usize;
+    let mut serial_types = vec![];
+    while offset < header_size {
+        let (serial_type, next) = read_varint(payload, offset)?;
+        serial_types.push(serial_type);
+        offset = next;
+    }
+
+    let mut data = payload
+        .get(header_size..)
+        .ok_or_else(|| format_error("invalid record"))?;
+    let mut values = Vec::with_capacity(serial_types.len());
+    for serial_type in serial_types {
+        let size = match serial_type {
+            0 | 8 | 9 => 0,
+            1..=4 => serial_type as usize,
+            5 => 6,
+            6 | 7 => 8,
+            10 | 11 => return Err(format_error("invalid record")),
+            n => ((n - 12) / 2) as usize,
+        };
+
+        if data.len() < size {
+            return Err(format_error("invalid record"));
+        }
+        let (bytes, rest) = data.split_at(size);
+        data = rest;
+
+        values.push(match serial_type {
+            0 => SqlValue::Null,
+            8 => SqlValue::Integer(0),
+            9 => SqlValue::Integer(1),
+            1..=6 => SqlValue::Integer(read_be_int(bytes)),
+            7 => SqlValue::Real(f64::from_be_bytes(bytes.try_into().unwrap())),
+            n if n % 2 == 0 => SqlValue::Blob(bytes.to_vec()),
+            _ => SqlValue::Text(String::from_utf8_lossy(bytes).into_owned()),
+        });
+    }
+
+    Ok(values)
+}
+
+/// Reads big-endian two's complement integer of 1 to 8 bytes.
+fn read_be_int(bytes: &[u8]) -> i64 {
+    let mut value = if bytes[0] & 0x80 != 0 { -1i64 } else { 0 };
+    for byte in bytes {
+        value = (value << 8) | *byte as i64;
+    }
+
+    value
+}
+
+fn read_varint(data: &[u8], offset: usize) -> Result<(u64, usize), GalileoError> {
+    let mut value = 0u64;
+    for i in 0..9 {
+        let byte = *data
+            .get(offset + i)
+            .ok_or_else(|| format_error("unexpected end of data"))?;
+        if i == 8 {
+            return Ok(((value << 8) | byte as u64, offset + 9));
+        }
+
+        value = (value << 7) | (byte & 0x7f) as u64;
+        if byte & 0x80 == 0 {
+            return Ok((value, offset + i + 1));
+        }
+    }
+
+    unreachable!()
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Result<u16, GalileoError> {
+    data.get(offset..offset + 2)
+        .map(|b| u16::from_be_bytes([b[0], b[1]]))
+        .ok_or_else(|| format_error("unexpected end of page"))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Result<u32, GalileoError> {
+    data.get(offset..offset + 4)
+        .map(|b| u32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+        .ok_or_else(|| format_error("unexpected end of page"))
+}
+
+/// Extracts column names from the `CREATE TABLE` statement. Also returns the index of the column that is an alias
+/// for the rowid, if there is one.
+fn parse_columns(sql: &str) -> (Vec<String>, Option<usize>) {
+    let (Some(start), Some(end)) = (sql.find('('), sql.rfind(')')) else {
+        return (vec![], None);
+    };
+
+    let mut columns = vec![];
+    let mut rowid_column = None;
+    for definition in split_top_level(&sql[start + 1..end]) {
+        let definition = definition.trim();
+        let (name, rest) = split_identifier(definition);
+        if matches!(
+            name.to_ascii_uppercase().as_str(),
+            "CONSTRAINT" | "PRIMARY" | "UNIQUE" | "CHECK" | "FOREIGN"
+        ) && !is_quoted(definition)
+        {
+            continue;
+        }
+
+        let tokens: Vec<String> = rest
+            .split_whitespace()
+            .map(|t| t.to_ascii_uppercase())
+            .collect();
+        let is_primary_key = tokens
+            .windows(2)
+            .any(|w| w[0] == "PRIMARY" && w[1] == "KEY");
+        if is_primary_key && tokens.first().map(String::as_str) == Some("INTEGER") {
+            rowid_column = Some(columns.len());
+        }
+
+        columns.push(name);
+    }
+
+    (columns, rowid_column)
+}
+
+fn split_top_level(s: &str) -> Vec<&str> {
+    let mut parts = vec![];
+    let mut depth = 0;
+    let mut quote = None;
+    let mut start = 0;
+    for (i, c) in s.char_indices() {
+        match (quote, c) {
+            (Some(q), _) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'' | '`') => quote = Some(c),
+            (None, '[') => quote = Some(']'),
+            (None, '(') => depth += 1,
+            (None, ')') => depth -= 1,
+            (None, ',') if depth == 0 => {
+                parts.push(&s[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&s[start..]);
+
+    parts
+}
+
+fn is_quoted(definition: &str) -> bool {
+    definition.starts_with(['"', '`', '[', '\''])
+}
+
+fn split_identifier(definition: &str) -> (String, &str) {
+    let closing = match definition.chars().next() {
+        Some('"') => Some('"'),
+        Some('`') => Some('`'),
+        Some('\'') => Some('\''),
+        Some('[') => Some(']'),
+        _ => None,
+    };
+
+    match closing {
+        Some(closing) => match definition[1..].find(closing) {
+            Some(end) => (definition[1..end + 1].to_string(), &definition[end + 2..]),
+            None => (definition[1..].to_string(), ""),
+        },
+        None => {
+            let end = definition
+                .find(char::is_whitespace)
+                .unwrap_or(definition.len());
+            (definition[..end].to_string(), &definition[end..])
+        }
+    }
+}
+
+fn format_error(message: &str) -> GalileoError {
+    GalileoError::Generic(format!("invalid SQLite file: {message}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn varint() {
+        assert_eq!(read_varint(&[0x05], 0).unwrap(), (5, 1));
+        assert_eq!(read_varint(&[0x81, 0x00], 0).unwrap(), (128, 2));
+        assert_eq!(read_varint(&[0x00, 0x82, 0x2c], 1).unwrap(), (300, 3));
+        assert_eq!(read_varint(&[0xff; 9], 0).unwrap(), (u64::MAX, 9));
+        assert!(read_varint(&[0x81], 0).is_err());
+    }
+
+    #[test]
+    fn be_int() {
+        assert_eq!(read_be_int(&[0x7f]), 127);
+        assert_eq!(read_be_int(&[0xff]), -1);
+        assert_eq!(read_be_int(&[0xff, 0xfe]), -2);
+        assert_eq!(read_be_int(&[0x01, 0x00, 0x00]), 65536);
+    }
+
+    #[test]
+    fn record() {
+        // header size 6, types: NULL, int8, float, text(3), integer 1
+        let mut payload = vec![6, 0, 1, 7, 19, 9, 42];
+        payload.extend_from_slice(&1.5f64.to_be_bytes());
+        payload.extend_from_slice(b"abc");
+
+        let values = decode_record(&payload).unwrap();
+        assert_eq!(
+            values,
+            vec![
+                SqlValue::Null,
+                SqlValue::Integer(42),
+                SqlValue::Real(1.5),
+                SqlValue::Text("abc".into()),
+                SqlValue::Integer(1),
+            ]
+        );
+    }
+
+    #[test]
+    fn local_payload() {
+        assert_eq!(local_payload_size(100, 4096), 100);
+        assert_eq!(local_payload_size(4061, 4096), 4061);
+        assert!(local_payload_size(10_000, 4096) < 4061);
+    }
+
+    /// Builds a database of 512 byte pages with a table of one integer column, that has an interior root page 1 with
+    /// the rows 3 and 5 in the leaf page 2 and the row 9 in the leaf page 3.
+    fn test_database() -> Vec<u8> {
+        const PAGE_SIZE: usize = 512;
+        let mut data = vec![0; PAGE_SIZE * 3];
+        data[..16].copy_from_slice(MAGIC);
+        data[16..18].copy_from_slice(&(PAGE_SIZE as u16).to_be_bytes());
+        data[59] = 1;
+
+        let root = HEADER_SIZE;
+        data[root] = INTERIOR_TABLE_PAGE;
+        data[root + 4] = 1;
+        data[root + 8..root + 12].copy_from_slice(&3u32.to_be_bytes());
+        data[root + 12..root + 14].copy_from_slice(&200u16.to_be_bytes());
+        data[200..205].copy_from_slice(&[0, 0, 0, 2, 5]);
+
+        for (page, rowids) in [(2, &[3u8, 5][..]), (3, &[9][..])] {
+            let start = (page - 1) * PAGE_SIZE;
+            data[start] = LEAF_TABLE_PAGE;
+            data[start + 4] = rowids.len() as u8;
+            for (i, &rowid) in rowids.iter().enumerate() {
+                let cell_offset = 100 + i * 10;
+                data[start + 8 + i * 2..start + 10 + i * 2]
+                    .copy_from_slice(&(cell_offset as u16).to_be_bytes());
+                // Payload size, rowid, record with one 8 bit integer.
+                data[start + cell_offset..start + cell_offset + 5].copy_from_slice(&[
+                    3,
+                    rowid,
+                    2,
+                    1,
+                    rowid * 10,
+                ]);
+            }
+        }
+
+        data
+    }
+
+    #[test]
+    fn scan_and_find_rows() {
+        let mut reader = SqliteReader::new(std::io::Cursor::new(test_database())).unwrap();
+
+        let mut rows = vec![];
+        reader
+            .scan(1, |rowid, values| {
+                rows.push((rowid, values));
+                Ok(())
+            })
+            .unwrap();
+        assert_eq!(
+            rows,
+            vec![
+                (3, vec![SqlValue::Integer(30)]),
+                (5, vec![SqlValue::Integer(50)]),
+                (9, vec![SqlValue::Integer(90)]),
+            ]
+        );
+
+        for (rowid, _) in rows {
+            assert_eq!(
+                reader.find_row(1, rowid).unwrap(),
+                Some(vec![SqlValue::Integer(rowid * 10)])
+            );
+        }
+        assert_eq!(reader.find_row(1, 4).unwrap(), None);
+        assert_eq!(reader.find_row(1, 10).unwrap(), None);
+    }
+
+    #[test]
+    fn page_cycle() {
+        // Page 3 refers back to the root page.
+        let mut data = test_database();
+        data[1024] = INTERIOR_TABLE_PAGE;
+        data[1024 + 4] = 0;
+        data[1024 + 8..1024 + 12].copy_from_slice(&1u32.to_be_bytes());
+
+        let mut reader = SqliteReader::new(std::io::Cursor::new(data)).unwrap();
+        assert!(reader.scan(1, |_, _| Ok(())).is_err());
+        assert!(reader.find_row(1, 9).is_err());
+        assert!(reader.find_row(1, 5).unwrap().is_some());
+    }
+
+    #[test]
+    fn columns() {
+        let (columns, rowid) = parse_columns(
+            r#"CREATE TABLE "roads" (fid INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL, "geom" LINESTRING, [name] TEXT(80), speed NUMERIC(5, 2), CONSTRAINT u UNIQUE (name))"#,
+        );
+        assert_eq!(columns, vec!["fid", "geom", "name", "speed"]);
+        assert_eq!(rowid, Some(0));
+
+        let (columns, rowid) = parse_columns("CREATE TABLE t (id INT PRIMARY KEY, v BLOB)");
+        assert_eq!(columns, vec!["id", "v"]);
+        assert_eq!(rowid, None);
+    }
+}
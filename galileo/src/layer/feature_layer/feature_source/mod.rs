@@ -0,0 +1,191 @@
+//! Sources of features stored in local vector files.
+//!
+//! A [`FeatureSource`] does not keep the data in memory. Instead, every query reads the file and returns only the
+//! features that intersect the requested bounding box, so the size of the file that can be displayed is not limited
+//! by the available memory. Use [`FeatureSource::query_view`] together with
+//! [`FeatureLayer::set_features`](super::FeatureLayer::set_features) to load only the features visible in the
+//! current map view.
+//!
+//! Available sources (each one is behind the cargo feature with the same name):
+//! * [`geojson::GeoJsonSource`] - GeoJSON feature collections and GeoJSON text sequences
+//! * [`flatgeobuf::FlatGeobufSource`] - FlatGeobuf files, using their built-in spatial index
+//! * [`geopackage::GeoPackageSource`] - GeoPackage feature tables, read with a pure Rust SQLite reader, using their
+//!   spatial index
+
+use crate::error::GalileoError;
+use crate::layer::feature_layer::feature::Feature;
+use crate::view::MapView;
+use galileo_types::cartesian::impls::point::Point2d;
+use galileo_types::cartesian::rect::Rect;
+use galileo_types::geo::crs::Crs;
+use galileo_types::geo::datum_shift::DatumShift;
+use galileo_types::geo::impls::point::GeoPoint2d;
+use galileo_types::geo::traits::point::{GeoPoint, NewGeoPoint};
+use galileo_types::geo::traits::projection::Projection;
+use maybe_sync::{MaybeSend, MaybeSync};
+
+#[cfg(feature = "flatgeobuf")]
+pub mod flatgeobuf;
+#[cfg(feature = "geojson")]
+pub mod geojson;
+#[cfg(feature = "geopackage")]
+pub mod geopackage;
+
+/// Number of points taken on each side of a bounding box when it is transformed into another CRS.
+const BBOX_EDGE_SAMPLES: usize = 8;
+
+/// Storage of features that can be queried by a bounding box.
+///
+/// Implementations read the data on every query and return only the features intersecting the requested bounding
+/// box, without caching them. The returned features are in the [`crs`](FeatureSource::crs) of the source, so the
+/// layer they are added to must use the same CRS or project them.
+pub trait FeatureSource: MaybeSend + MaybeSync {
+    /// Type of the features returned by the source.
+    type Feature: Feature;
+
+    /// Coordinate system of the features returned by the source.
+    fn crs(&self) -> &Crs;
+
+    /// Loads the features which bounding box intersects the given `bbox`. The `bbox` is given in the coordinates of
+    /// the source [`crs`](FeatureSource::crs). If `bbox` is `None`, all features are loaded.
+    fn query(&self, bbox: Option<Rect>) -> Result<Vec<Self::Feature>, GalileoError>;
+
+    /// Loads the features that are visible in the given map view.
+    ///
+    /// If the view bounding box cannot be converted into the source CRS, all the features of the source are loaded.
+    fn query_view(&self, view: &MapView) -> Result<Vec<Self::Feature>, GalileoError> {
+        let Some(bbox) = view.get_bbox() else {
+            return Ok(vec![]);
+        };
+
+        self.query(transform_bbox(&bbox, view.crs(), self.crs()))
+    }
+}
+
+/// Converts a bounding box from one coordinate system into another.
+///
+/// Since projections do not keep straight lines straight, the edges of the box are sampled and the result is the
+/// bounding box of the transformed samples.
+pub fn transform_bbox(bbox: &Rect, from: &Crs, to: &Crs) -> Option<Rect> {
+    if from == to {
+        return Some(*bbox);
+    }
+
+    let points = bbox_edge_points(bbox);
+    let geo_points = if from.is_geographic() {
+        points
+            .iter()
+            .map(|p| GeoPoint2d::latlon(p.y, p.x))
+            .collect::<Vec<_>>()
+    } else {
+        let projection = from.get_projection::<GeoPoint2d, Point2d>()?;
+        points
+            .iter()
+            .map(|p| projection.unproject(p))
+            .collect::<Result<Vec<_>, _>>()
+            .ok()?
+    };
+
+    let datum_shift = DatumShift::<GeoPoint2d, GeoPoint2d>::new(*from.datum(), *to.datum());
+    let geo_points = if datum_shift.is_identity() {
+        geo_points
+    } else {
+        geo_points
+            .iter()
+            .map(|p| datum_shift.project(p))
+            .collect::<Result<Vec<_>, _>>()
+            .ok()?
+    };
+
+    let target_points = if to.is_geographic() {
+        geo_points
+            .iter()
+            .map(|p| Point2d::new(p.lon(), p.lat()))
+            .collect::<Vec<_>>()
+    } else {
+        let projection = to.get_projection::<GeoPoint2d, Point2d>()?;
+        geo_points
+            .iter()
+            .map(|p| projection.project(p))
+            .collect::<Result<Vec<_>, _>>()
+            .ok()?
+    };
+
+    Rect::from_points(target_points.iter())
+}
+
+/// Returns the CRS for the given EPSG code used as spatial reference id in a data file.
+#[cfg(any(feature = "flatgeobuf", feature = "geopackage"))]
+pub(crate) fn crs_from_srs_id(srs_id: i32) -> Crs {
+    use galileo_types::geo::crs::ProjectionType;
+    use galileo_types::geo::datum::Datum;
+
+    match u32::try_from(srs_id).map(Crs::from_epsg) {
+        Ok(Ok(crs)) => crs,
+        Ok(Err(err)) => {
+            log::warn!("Unsupported spatial reference system id {srs_id}: {err}");
+            Crs::new(Datum::WGS84, ProjectionType::Unknown)
+        }
+        Err(_) => {
+            log::warn!("Invalid spatial reference system id: {srs_id}");
+            Crs::new(Datum::WGS84, ProjectionType::Unknown)
+        }
+    }
+}
+
+fn bbox_edge_points(bbox: &Rect) -> Vec<Point2d> {
+    let mut points = Vec::with_capacity(BBOX_EDGE_SAMPLES * 4);
+    for i in 0..BBOX_EDGE_SAMPLES {
+        let k = i as f64 / BBOX_EDGE_SAMPLES as f64;
+        let x = bbox.x_min + bbox.width() * k;
+        let y = bbox.y_min + bbox.height() * k;
+        points.push(Point2d::new(x, bbox.y_min));
+        points.push(Point2d::new(bbox.x_max, y));
+        points.push(Point2d::new(bbox.x_max - bbox.width() * k, bbox.y_max));
+        points.push(Point2d::new(bbox.x_min, bbox.y_max - bbox.height() * k));
+    }
+
+    points
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn transform_bbox_same_crs() {
+        let bbox = Rect::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(
+            transform_bbox(&bbox, &Crs::EPSG3857, &Crs::EPSG3857),
+            Some(bbox)
+        );
+    }
+
+    #[test]
+    fn transform_bbox_geographic_to_mercator_and_back() {
+        let bbox = Rect::new(-10.0, -20.0, 30.0, 40.0);
+        let projected = transform_bbox(&bbox, &Crs::WGS84, &Crs::EPSG3857).unwrap();
+        assert!(projected.x_min < -1_000_000.0);
+        assert!(projected.y_max > 4_000_000.0);
+
+        let back = transform_bbox(&projected, &Crs::EPSG3857, &Crs::WGS84).unwrap();
+        assert_abs_diff_eq!(back.x_min, bbox.x_min, epsilon = 1e-6);
+        assert_abs_diff_eq!(back.y_min, bbox.y_min, epsilon = 1e-6);
+        assert_abs_diff_eq!(back.x_max, bbox.x_max, epsilon = 1e-6);
+        assert_abs_diff_eq!(back.y_max, bbox.y_max, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn transform_bbox_between_datums() {
+        let osgb36 = Crs::from_epsg(4277).unwrap();
+        let bbox = Rect::new(-1.0, 51.0, 0.0, 52.0);
+        let shifted = transform_bbox(&bbox, &osgb36, &Crs::WGS84).unwrap();
+
+        // OSGB36 is displaced from WGS84 by about 100 m in the south of England
+        let dx = (shifted.x_min - bbox.x_min).abs();
+        let dy = (shifted.y_min - bbox.y_min).abs();
+        assert!(dx > 1e-4 && dx < 1e-2, "dx = {dx}");
+        assert!(dy > 1e-5 && dy < 1e-2, "dy = {dy}");
+    }
+}
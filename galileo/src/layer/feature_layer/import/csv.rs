@@ -0,0 +1,277 @@
+use crate::error::GalileoError;
+use crate::layer::feature_layer::feature::{AttributedFeature, Properties, PropertyValue};
+use crate::layer::feature_layer::import::ImportedData;
+use galileo_types::cartesian::impls::point::Point2d;
+use galileo_types::geo::crs::Crs;
+use std::io::Read;
+use std::path::Path;
+
+const X_COLUMN_NAMES: [&str; 5] = ["lon", "lng", "long", "longitude", "x"];
+const Y_COLUMN_NAMES: [&str; 3] = ["lat", "latitude", "y"];
+
+/// Reads points from CSV files.
+///
+/// The first line of the file must contain column names. Coordinates of the points are read from two columns, and
+/// all other columns are stored as feature properties. Numeric and boolean values of the properties are recognized,
+/// empty values are imported as [`PropertyValue::Null`]. Values that would change if written back as numbers, such as
+/// codes with leading zeros (`007`), and non-finite values (`nan`, `inf`) are kept as strings.
+///
+/// Rows with empty coordinate columns are skipped.
+#[derive(Debug, Clone)]
+pub struct CsvPointImporter {
+    x_column: Option<String>,
+    y_column: Option<String>,
+    delimiter: u8,
+    crs: Crs,
+}
+
+impl Default for CsvPointImporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CsvPointImporter {
+    /// Creates a new importer that reads WGS84 coordinates from comma separated files. Coordinate columns are
+    /// detected by their names: `lon`, `lng`, `long`, `longitude` or `x` for longitude and `lat`, `latitude` or
+    /// `y` for latitude (case insensitive).
+    pub fn new() -> Self {
+        Self {
+            x_column: None,
+            y_column: None,
+            delimiter: b',',
+            crs: Crs::WGS84,
+        }
+    }
+
+    /// Sets the names of the columns with the coordinates of the points. For geographic coordinates `x` is longitude
+    /// and `y` is latitude.
+    pub fn with_columns(
+        mut self,
+        x_column: impl Into<String>,
+        y_column: impl Into<String>,
+    ) -> Self {
+        self.x_column = Some(x_column.into());
+        self.y_column = Some(y_column.into());
+        self
+    }
+
+    pub fn with_lat_lon_columns(
+        self,
+        lat_column: impl Into<String>,
+        lon_column: impl Into<String>,
+    ) -> Self {
+        self.with_columns(lon_column, lat_column)
+    }
+
+    pub fn with_delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Sets the CRS of the coordinates in the file.
+    pub fn with_crs(mut self, crs: Crs) -> Self {
+        self.crs = crs;
+        self
+    }
+
+    pub fn read(&self, path: impl AsRef<Path>) -> Result<ImportedData<Point2d>, GalileoError> {
+        self.read_from(std::fs::File::open(path)?)
+    }
+
+    pub fn read_from(&self, reader: impl Read) -> Result<ImportedData<Point2d>, GalileoError> {
+        let mut reader = csv::ReaderBuilder::new()
+            .delimiter(self.delimiter)
+            .from_reader(reader);
+
+        let headers = reader.headers()?.clone();
+        let x_index = find_column(&headers, self.x_column.as_deref(), &X_COLUMN_NAMES)?;
+        let y_index = find_column(&headers, self.y_column.as_deref(), &Y_COLUMN_NAMES)?;
+
+        let mut features = vec![];
+        for (line, record) in reader.records().enumerate() {
+            let record = record?;
+            let (Some(x), Some(y)) = (
+                parse_coordinate(&record, x_index, line)?,
+                parse_coordinate(&record, y_index, line)?,
+            ) else {
+                log::debug!("Skipping CSV record {line} without coordinates");
+                continue;
+            };
+
+            let properties = headers
+                .iter()
+                .zip(record.iter())
+                .enumerate()
+                .filter(|(index, _)| *index != x_index && *index != y_index)
+                .map(|(_, (name, value))| (name.to_string(), parse_value(value)))
+                .collect::<Properties>();
+
+            features.push(AttributedFeature {
+                geometry: Point2d::new(x, y),
+                properties,
+            });
+        }
+
+        Ok(ImportedData {
+            features,
+            crs: self.crs.clone(),
+        })
+    }
+}
+
+fn find_column(
+    headers: &csv::StringRecord,
+    name: Option<&str>,
+    default_names: &[&str],
+) -> Result<usize, GalileoError> {
+    let position = match name {
+        Some(name) => headers.iter().position(|h| h.trim() == name),
+        None => headers.iter().position(|h| {
+            default_names
+                .iter()
+                .any(|n| h.trim().eq_ignore_ascii_case(n))
+        }),
+    };
+
+    position.ok_or_else(|| {
+        GalileoError::Import(match name {
+            Some(name) => format!("column {name} not found"),
+            None => format!("none of the columns {default_names:?} found"),
+        })
+    })
+}
+
+fn parse_coordinate(
+    record: &csv::StringRecord,
+    index: usize,
+    line: usize,
+) -> Result<Option<f64>, GalileoError> {
+    let value = record.get(index).unwrap_or_default().trim();
+    if value.is_empty() {
+        return Ok(None);
+    }
+
+    value.parse().map(Some).map_err(|_| {
+        GalileoError::Import(format!(
+            "invalid coordinate value '{value}' in record {line}"
+        ))
+    })
+}
+
+fn parse_value(value: &str) -> PropertyValue {
+    let trimmed = value.trim();
+    if trimmed.is_empty() {
+        PropertyValue::Null
+    } else if let Some(v) = parse_integer(trimmed) {
+        PropertyValue::Integer(v)
+    } else if let Some(v) = parse_float(trimmed) {
+        PropertyValue::Float(v)
+    } else if trimmed.eq_ignore_ascii_case("true") {
+        PropertyValue::Bool(true)
+    } else if trimmed.eq_ignore_ascii_case("false") {
+        PropertyValue::Bool(false)
+    } else {
+        PropertyValue::String(value.to_string())
+    }
+}
+
+/// Parses the integer only if it is written in the canonical form, so it is not changed when written back.
+fn parse_integer(value: &str) -> Option<i64> {
+    let v = value.parse::<i64>().ok()?;
+    (v.to_string() == value).then_some(v)
+}
+
+/// Parses the finite number without an explicit plus sign and leading zeros.
+fn parse_float(value: &str) -> Option<f64> {
+    let mut chars = value.strip_prefix('-').unwrap_or(value).chars();
+    match chars.next() {
+        Some('+') => return None,
+        Some('0') if chars.next().is_some_and(|c| c.is_ascii_digit()) => return None,
+        _ => {}
+    }
+
+    value.parse::<f64>().ok().filter(|v| v.is_finite())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CSV: &str = "name,Latitude,Longitude,visitors,open\n\
+                       Louvre,48.8606,2.3376,8700000,true\n\
+                       Unknown,,,,\n\
+                       Prado,40.4138,-3.6921,3200000,false\n";
+
+    #[test]
+    fn detects_columns() {
+        let data = CsvPointImporter::new().read_from(CSV.as_bytes()).unwrap();
+        assert_eq!(data.crs, Crs::WGS84);
+        assert_eq!(data.features.len(), 2);
+
+        let louvre = &data.features[0];
+        assert_eq!(louvre.geometry, Point2d::new(2.3376, 48.8606));
+        assert_eq!(
+            louvre.properties["name"],
+            PropertyValue::String("Louvre".into())
+        );
+        assert_eq!(
+            louvre.properties["visitors"],
+            PropertyValue::Integer(8_700_000)
+        );
+        assert_eq!(louvre.properties["open"], PropertyValue::Bool(true));
+        assert!(!louvre.properties.contains_key("Latitude"));
+    }
+
+    #[test]
+    fn value_inference() {
+        assert_eq!(parse_value("42"), PropertyValue::Integer(42));
+        assert_eq!(parse_value("-42"), PropertyValue::Integer(-42));
+        assert_eq!(parse_value("0"), PropertyValue::Integer(0));
+        assert_eq!(parse_value("0.5"), PropertyValue::Float(0.5));
+        assert_eq!(parse_value("-0.5"), PropertyValue::Float(-0.5));
+        assert_eq!(parse_value("1e3"), PropertyValue::Float(1000.0));
+        assert_eq!(parse_value(" TRUE "), PropertyValue::Bool(true));
+        assert_eq!(parse_value(" "), PropertyValue::Null);
+
+        for value in [
+            "007",
+            "01234",
+            "-01",
+            "+5",
+            "00.5",
+            "nan",
+            "inf",
+            "-Infinity",
+        ] {
+            assert_eq!(parse_value(value), PropertyValue::from(value), "{value}");
+        }
+    }
+
+    #[test]
+    fn configured_columns() {
+        let csv = "a;b;c\n1;2;3\n";
+        let data = CsvPointImporter::new()
+            .with_delimiter(b';')
+            .with_columns("c", "a")
+            .read_from(csv.as_bytes())
+            .unwrap();
+        assert_eq!(data.features[0].geometry, Point2d::new(3.0, 1.0));
+        assert_eq!(data.features[0].properties["b"], PropertyValue::Integer(2));
+    }
+
+    #[test]
+    fn missing_column() {
+        let result = CsvPointImporter::new()
+            .with_columns("x", "y")
+            .read_from(CSV.as_bytes());
+        assert!(matches!(result, Err(GalileoError::Import(_))));
+    }
+
+    #[test]
+    fn invalid_coordinate() {
+        let csv = "lat,lon\nabc,1\n";
+        let result = CsvPointImporter::new().read_from(csv.as_bytes());
+        assert!(matches!(result, Err(GalileoError::Import(_))));
+    }
+}
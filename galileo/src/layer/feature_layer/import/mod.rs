@@ -0,0 +1,128 @@
+//! Importers of vector data from files that are commonly used to exchange geo-data.
+//!
+//! Importers read the whole file and return [`ImportedData`] - [`AttributedFeature`]s with their properties and the CRS of the
+//! coordinates. Since the coordinates can be either geographic or projected, geometries are read as cartesian
+//! [`Point2d`] based geometries. Use [`ImportedData::into_geo`] to get features with geographic coordinates, that can
+//! be displayed by a [`FeatureLayer`](super::FeatureLayer) in any map CRS.
+//!
+//! Available importers (each one is behind the cargo feature with the same name):
+//! * [`shapefile::read_shapefile`] - ESRI Shapefiles (`.shp` + `.dbf` + `.prj`)
+//! * [`csv::CsvPointImporter`] - points from CSV files with coordinate columns
+
+use crate::layer::feature_layer::feature::AttributedFeature;
+use galileo_types::cartesian::impls::point::Point2d;
+use galileo_types::geo::crs::Crs;
+use galileo_types::geo::impls::point::GeoPoint2d;
+use galileo_types::geo::traits::point::{GeoPoint, NewGeoPoint};
+use galileo_types::geo::traits::projection::{InvertedProjection, Projection, ProjectionError};
+use galileo_types::geometry::{Geom, Geometry};
+
+#[cfg(feature = "csv")]
+pub mod csv;
+#[cfg(feature = "shapefile")]
+pub mod shapefile;
+
+/// Result of importing a file.
+#[derive(Debug, Clone)]
+pub struct ImportedData<G> {
+    pub features: Vec<AttributedFeature<G>>,
+    /// CRS of the feature coordinates. If the file does not specify it, WGS84 is assumed.
+    pub crs: Crs,
+}
+
+impl<G: Geometry<Point = Point2d>> ImportedData<G> {
+    /// Converts geometries of all features into geographic coordinates.
+    ///
+    /// Features which geometry cannot be converted are skipped. If the CRS of the data is not supported, `None` is
+    /// returned.
+    pub fn into_geo(self) -> Option<Vec<AttributedFeature<Geom<GeoPoint2d>>>> {
+        let projection: Box<dyn Projection<InPoint = Point2d, OutPoint = GeoPoint2d>> =
+            if self.crs.is_geographic() {
+                Box::new(LonLatProjection)
+            } else {
+                Box::new(InvertedProjection::new(
+                    self.crs.get_projection::<GeoPoint2d, Point2d>()?,
+                ))
+            };
+
+        Some(
+            self.features
+                .into_iter()
+                .filter_map(|feature| match feature.geometry.project(&*projection) {
+                    Ok(geometry) => Some(AttributedFeature {
+                        geometry,
+                        properties: feature.properties,
+                    }),
+                    Err(err) => {
+                        log::warn!("Imported feature is skipped: {err}");
+                        None
+                    }
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Interprets x coordinate as longitude and y as latitude.
+struct LonLatProjection;
+
+impl Projection for LonLatProjection {
+    type InPoint = Point2d;
+    type OutPoint = GeoPoint2d;
+
+    fn project(&self, input: &Self::InPoint) -> Result<Self::OutPoint, ProjectionError> {
+        Ok(GeoPoint2d::latlon(input.y, input.x))
+    }
+
+    fn unproject(&self, input: &Self::OutPoint) -> Result<Self::InPoint, ProjectionError> {
+        Ok(Point2d::new(input.lon(), input.lat()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layer::feature_layer::feature::{Properties, PropertyValue};
+    use galileo_types::cartesian::impls::contour::Contour;
+
+    #[test]
+    fn into_geo_geographic() {
+        let data = ImportedData {
+            features: vec![AttributedFeature {
+                geometry: Geom::Contour(Contour::open(vec![
+                    Point2d::new(10.0, 20.0),
+                    Point2d::new(11.0, 21.0),
+                ])),
+                properties: Properties::from([("a".into(), PropertyValue::Integer(1))]),
+            }],
+            crs: Crs::WGS84,
+        };
+
+        let features = data.into_geo().unwrap();
+        assert_eq!(features.len(), 1);
+        assert_eq!(features[0].properties["a"], PropertyValue::Integer(1));
+        let Geom::Contour(contour) = &features[0].geometry else {
+            panic!("invalid geometry type");
+        };
+        assert_eq!(contour.points[0], GeoPoint2d::latlon(20.0, 10.0));
+        assert_eq!(contour.points[1], GeoPoint2d::latlon(21.0, 11.0));
+    }
+
+    #[test]
+    fn into_geo_projected() {
+        let data = ImportedData {
+            features: vec![AttributedFeature {
+                geometry: Point2d::new(0.0, 0.0),
+                properties: Properties::new(),
+            }],
+            crs: Crs::EPSG3857,
+        };
+
+        let features = data.into_geo().unwrap();
+        let Geom::Point(point) = &features[0].geometry else {
+            panic!("invalid geometry type");
+        };
+        assert!(point.lat().abs() < 1e-9);
+        assert!(point.lon().abs() < 1e-9);
+    }
+}
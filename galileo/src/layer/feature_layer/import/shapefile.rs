@@ -0,0 +1,347 @@
+use crate::error::GalileoError;
+use crate::layer::feature_layer::feature::{AttributedFeature, Properties, PropertyValue};
+use crate::layer::feature_layer::import::ImportedData;
+use galileo_types::cartesian::impls::contour::{ClosedContour, Contour};
+use galileo_types::cartesian::impls::multipolygon::MultiPolygon;
+use galileo_types::cartesian::impls::point::Point2d;
+use galileo_types::cartesian::impls::polygon::Polygon;
+use galileo_types::cartesian::traits::contour::CartesianClosedContour;
+use galileo_types::cartesian::traits::polygon::CartesianPolygon;
+use galileo_types::geo::crs::{Crs, ProjectionType};
+use galileo_types::geo::datum::Datum;
+use galileo_types::geometry::Geom;
+use galileo_types::impls::multi_contour::MultiContour;
+use galileo_types::impls::multi_point::MultiPoint;
+use shapefile::dbase::FieldValue;
+use shapefile::{PolygonRing, Shape};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Reads all the features from the shapefile at the given path.
+///
+/// Properties are read from the `.dbf` file next to the `.shp` file, and the CRS from the `.prj` file. If there is no
+/// `.prj` file, the coordinates are assumed to be in WGS84. Z and M coordinates are ignored. Null shapes and
+/// multipatches are skipped.
+pub fn read_shapefile(path: impl AsRef<Path>) -> Result<ImportedData<Geom<Point2d>>, GalileoError> {
+    let path = path.as_ref();
+    let crs = match std::fs::read_to_string(path.with_extension("prj")) {
+        Ok(wkt) => crs_from_prj(&wkt),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Crs::WGS84,
+        Err(err) => return Err(err.into()),
+    };
+
+    let mut reader = shapefile::Reader::from_path(path)?;
+    let mut features = vec![];
+    for result in reader.iter_shapes_and_records() {
+        let (shape, record) = result?;
+        let Some(geometry) = convert_shape(shape) else {
+            continue;
+        };
+
+        let properties = HashMap::<String, FieldValue>::from(record)
+            .into_iter()
+            .map(|(name, value)| (name, convert_value(value)))
+            .collect::<Properties>();
+
+        features.push(AttributedFeature {
+            geometry,
+            properties,
+        });
+    }
+
+    Ok(ImportedData { features, crs })
+}
+
+/// Determines the CRS from the contents of a `.prj` file.
+///
+/// If the WKT cannot be parsed (see [`Crs::from_wkt`]), geographic coordinates and Web Mercator are still recognized by
+/// the name of the coordinate system. For other coordinate systems CRS with unknown projection is returned.
+fn crs_from_prj(wkt: &str) -> Crs {
+    let err = match Crs::from_wkt(wkt) {
+        Ok(crs) => return crs,
+        Err(err) => err,
+    };
+
+    let wkt = wkt.trim_start().to_ascii_uppercase();
+    if wkt.starts_with("GEOGCS") {
+        return Crs::WGS84;
+    }
+
+    if wkt.contains("MERCATOR_AUXILIARY_SPHERE")
+        || wkt.contains("PSEUDO-MERCATOR")
+        || wkt.contains("POPULAR VISUALISATION")
+    {
+        return Crs::EPSG3857;
+    }
+
+    log::warn!("Unsupported shapefile projection ({err}): {wkt}");
+    Crs::new(Datum::WGS84, ProjectionType::Unknown)
+}
+
+fn convert_shape(shape: Shape) -> Option<Geom<Point2d>> {
+    let geom = match shape {
+        Shape::NullShape | Shape::Multipatch(_) => return None,
+        Shape::Point(p) => Geom::Point(Point2d::new(p.x, p.y)),
+        Shape::PointM(p) => Geom::Point(Point2d::new(p.x, p.y)),
+        Shape::PointZ(p) => Geom::Point(Point2d::new(p.x, p.y)),
+        Shape::Multipoint(mp) => multi_point(mp.points().iter().map(|p| (p.x, p.y))),
+        Shape::MultipointM(mp) => multi_point(mp.points().iter().map(|p| (p.x, p.y))),
+        Shape::MultipointZ(mp) => multi_point(mp.points().iter().map(|p| (p.x, p.y))),
+        Shape::Polyline(line) => multi_contour(
+            line.parts()
+                .iter()
+                .map(|part| part.iter().map(|p| (p.x, p.y)).collect()),
+        ),
+        Shape::PolylineM(line) => multi_contour(
+            line.parts()
+                .iter()
+                .map(|part| part.iter().map(|p| (p.x, p.y)).collect()),
+        ),
+        Shape::PolylineZ(line) => multi_contour(
+            line.parts()
+                .iter()
+                .map(|part| part.iter().map(|p| (p.x, p.y)).collect()),
+        ),
+        Shape::Polygon(polygon) => multi_polygon(polygon.rings().iter().map(|ring| {
+            (
+                matches!(ring, PolygonRing::Outer(_)),
+                ring.points().iter().map(|p| (p.x, p.y)).collect(),
+            )
+        })),
+        Shape::PolygonM(polygon) => multi_polygon(polygon.rings().iter().map(|ring| {
+            (
+                matches!(ring, PolygonRing::Outer(_)),
+                ring.points().iter().map(|p| (p.x, p.y)).collect(),
+            )
+        })),
+        Shape::PolygonZ(polygon) => multi_polygon(polygon.rings().iter().map(|ring| {
+            (
+                matches!(ring, PolygonRing::Outer(_)),
+                ring.points().iter().map(|p| (p.x, p.y)).collect(),
+            )
+        })),
+    };
+
+    Some(geom)
+}
+
+fn multi_point(points: impl Iterator<Item = (f64, f64)>) -> Geom<Point2d> {
+    Geom::MultiPoint(MultiPoint::from(
+        points.map(|(x, y)| Point2d::new(x, y)).collect::<Vec<_>>(),
+    ))
+}
+
+fn multi_contour(parts: impl Iterator<Item = Vec<(f64, f64)>>) -> Geom<Point2d> {
+    let mut contours: Vec<_> = parts.map(|part| Contour::open(to_points(part))).collect();
+    if contours.len() == 1 {
+        Geom::Contour(contours.remove(0))
+    } else {
+        Geom::MultiContour(MultiContour::from(contours))
+    }
+}
+
+/// Builds polygons from a list of rings. Every outer ring starts a new polygon.
+///
+/// The format does not specify the order of the rings, so every inner ring is added to the smallest outer ring
+/// containing it. Inner rings that are not inside any outer ring are treated as outer rings.
+fn multi_polygon(rings: impl Iterator<Item = (bool, Vec<(f64, f64)>)>) -> Geom<Point2d> {
+    let mut polygons: Vec<Polygon<Point2d>> = vec![];
+    let mut holes = vec![];
+    for (is_outer, ring) in rings {
+        let contour = ClosedContour::new(to_ring_points(ring));
+        if is_outer {
+            polygons.push(Polygon::new(contour, vec![]));
+        } else {
+            holes.push(contour);
+        }
+    }
+
+    let areas: Vec<f64> = polygons
+        .iter()
+        .map(|polygon| polygon.outer_contour.area_signed().abs())
+        .collect();
+    let shells: Vec<Option<usize>> = holes
+        .iter()
+        .map(|hole| {
+            polygons
+                .iter()
+                .enumerate()
+                .filter(|(_, polygon)| hole.points.iter().any(|p| polygon.contains_point(p)))
+                .min_by(|(a, _), (b, _)| areas[*a].total_cmp(&areas[*b]))
+                .map(|(index, _)| index)
+        })
+        .collect();
+
+    for (hole, shell) in holes.into_iter().zip(shells) {
+        match shell {
+            Some(index) => polygons[index].inner_contours.push(hole),
+            None => polygons.push(Polygon::new(hole, vec![])),
+        }
+    }
+
+    if polygons.len() == 1 {
+        Geom::Polygon(polygons.remove(0))
+    } else {
+        Geom::MultiPolygon(MultiPolygon::from(polygons))
+    }
+}
+
+fn to_points(points: Vec<(f64, f64)>) -> Vec<Point2d> {
+    points
+        .into_iter()
+        .map(|(x, y)| Point2d::new(x, y))
+        .collect()
+}
+
+/// Rings in shapefiles repeat the first point at the end, while closed contours do not need it.
+fn to_ring_points(points: Vec<(f64, f64)>) -> Vec<Point2d> {
+    let mut points = to_points(points);
+    if points.len() > 1 && points.first() == points.last() {
+        points.pop();
+    }
+
+    points
+}
+
+fn convert_value(value: FieldValue) -> PropertyValue {
+    match value {
+        FieldValue::Character(Some(v)) | FieldValue::Memo(v) => PropertyValue::String(v),
+        FieldValue::Numeric(Some(v)) | FieldValue::Double(v) | FieldValue::Currency(v) => {
+            PropertyValue::Float(v)
+        }
+        FieldValue::Float(Some(v)) => PropertyValue::Float(v as f64),
+        FieldValue::Integer(v) => PropertyValue::Integer(v as i64),
+        FieldValue::Logical(Some(v)) => PropertyValue::Bool(v),
+        FieldValue::Date(Some(date)) => PropertyValue::String(format!(
+            "{:04}-{:02}-{:02}",
+            date.year(),
+            date.month(),
+            date.day()
+        )),
+        FieldValue::DateTime(date_time) => {
+            let date = date_time.date();
+            let time = date_time.time();
+            PropertyValue::String(format!(
+                "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+                date.year(),
+                date.month(),
+                date.day(),
+                time.hours(),
+                time.minutes(),
+                time.seconds()
+            ))
+        }
+        FieldValue::Character(None)
+        | FieldValue::Numeric(None)
+        | FieldValue::Float(None)
+        | FieldValue::Logical(None)
+        | FieldValue::Date(None) => PropertyValue::Null,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use galileo_types::geo::impls::projection::transverse_mercator::TransverseMercatorParams;
+
+    #[test]
+    fn prj_crs() {
+        assert_eq!(
+            crs_from_prj(
+                r#"GEOGCS["GCS_WGS_1984",DATUM["D_WGS_1984",SPHEROID["WGS_1984",6378137.0,298.257223563]],PRIMEM["Greenwich",0.0],UNIT["Degree",0.0174532925199433]]"#
+            ),
+            Crs::WGS84
+        );
+        assert_eq!(
+            crs_from_prj(
+                r#"PROJCS["WGS_1984_Web_Mercator_Auxiliary_Sphere",GEOGCS["GCS_WGS_1984"]]"#
+            ),
+            Crs::EPSG3857
+        );
+        assert_eq!(
+            crs_from_prj(
+                r#"PROJCS["ETRS89_UTM_zone_32N",GEOGCS["GCS_ETRS_1989",DATUM["D_ETRS_1989",SPHEROID["GRS_1980",6378137.0,298.257222101]],PRIMEM["Greenwich",0.0],UNIT["Degree",0.0174532925199433]],PROJECTION["Transverse_Mercator"],PARAMETER["False_Easting",500000.0],PARAMETER["False_Northing",0.0],PARAMETER["Central_Meridian",9.0],PARAMETER["Scale_Factor",0.9996],PARAMETER["Latitude_Of_Origin",0.0],UNIT["Meter",1.0]]"#
+            ),
+            Crs::new(
+                Datum::GRS80,
+                ProjectionType::TransverseMercator(TransverseMercatorParams::utm(32, false))
+            )
+        );
+    }
+
+    #[test]
+    fn polygon_rings() {
+        let geom = multi_polygon(
+            vec![
+                (
+                    true,
+                    vec![(0.0, 0.0), (0.0, 10.0), (10.0, 10.0), (0.0, 0.0)],
+                ),
+                (false, vec![(1.0, 1.0), (2.0, 1.0), (2.0, 2.0), (1.0, 1.0)]),
+                (
+                    true,
+                    vec![(20.0, 0.0), (20.0, 10.0), (30.0, 10.0), (20.0, 0.0)],
+                ),
+            ]
+            .into_iter(),
+        );
+
+        let Geom::MultiPolygon(mp) = geom else {
+            panic!("invalid geometry type");
+        };
+        assert_eq!(mp.parts().len(), 2);
+        assert_eq!(mp.parts()[0].inner_contours.len(), 1);
+        assert_eq!(mp.parts()[0].outer_contour.points.len(), 3);
+        assert!(mp.parts()[1].inner_contours.is_empty());
+    }
+
+    #[test]
+    fn holes_are_added_to_containing_rings() {
+        let square = |x: f64, y: f64, size: f64| {
+            vec![
+                (x, y),
+                (x, y + size),
+                (x + size, y + size),
+                (x + size, y),
+                (x, y),
+            ]
+        };
+
+        // The hole of the first shell is listed after an unrelated shell, and the third shell is inside the hole of
+        // the second one, with its own hole.
+        let geom = multi_polygon(
+            vec![
+                (true, square(0.0, 0.0, 10.0)),
+                (true, square(20.0, 0.0, 10.0)),
+                (false, square(1.0, 1.0, 2.0)),
+                (false, square(21.0, 1.0, 8.0)),
+                (true, square(22.0, 2.0, 6.0)),
+                (false, square(23.0, 3.0, 1.0)),
+                (false, square(50.0, 50.0, 1.0)),
+            ]
+            .into_iter(),
+        );
+
+        let Geom::MultiPolygon(mp) = geom else {
+            panic!("invalid geometry type");
+        };
+        let parts = mp.parts();
+        assert_eq!(parts.len(), 4);
+        assert_eq!(parts[0].inner_contours.len(), 1);
+        assert_eq!(parts[0].inner_contours[0].points[0], Point2d::new(1.0, 1.0));
+        assert_eq!(parts[1].inner_contours.len(), 1);
+        assert_eq!(
+            parts[1].inner_contours[0].points[0],
+            Point2d::new(21.0, 1.0)
+        );
+        assert_eq!(parts[2].inner_contours.len(), 1);
+        assert_eq!(
+            parts[2].inner_contours[0].points[0],
+            Point2d::new(23.0, 3.0)
+        );
+
+        // A hole outside all the shells is kept as a polygon.
+        assert_eq!(parts[3].outer_contour.points[0], Point2d::new(50.0, 50.0));
+        assert!(parts[3].inner_contours.is_empty());
+    }
+}
@@ -7,15 +7,22 @@ use crate::render::{Canvas, PackedBundle, PrimitiveId, RenderOptions, Renderer};
 use crate::view::MapView;
 use galileo_types::cartesian::impls::point::{Point2d, Point3d};
 use galileo_types::cartesian::rect::Rect;
+use galileo_types::cartesian::simplify::Simplify;
 use galileo_types::cartesian::traits::cartesian_point::{
     CartesianPoint2d, NewCartesianPoint2d, NewCartesianPoint3d,
 };
+use galileo_types::cartesian::validity::make_valid;
+use galileo_types::geo::antimeridian::AntimeridianCut;
 use galileo_types::geo::crs::Crs;
+use galileo_types::geo::datum_shift::DatumShift;
+use galileo_types::geo::densify::Densify;
 use galileo_types::geo::impls::point::GeoPoint2d;
 use galileo_types::geo::impls::projection::dimensions::AddDimensionProjection;
 use galileo_types::geo::impls::projection::identity::IdentityProjection;
 use galileo_types::geo::traits::point::NewGeoPoint;
-use galileo_types::geo::traits::projection::{ChainProjection, InvertedProjection, Projection};
+use galileo_types::geo::traits::projection::{
+    ChainProjection, InvertedProjection, Projection, ProjectionError,
+};
 use galileo_types::geometry::{CartesianGeometry2d, Geom, Geometry};
 use galileo_types::geometry_type::{CartesianSpace2d, CartesianSpace3d, GeoSpace2d};
 use maybe_sync::{MaybeSend, MaybeSync};
@@ -23,7 +30,11 @@ use num_traits::AsPrimitive;
 use std::marker::PhantomData;
 use std::sync::{Arc, RwLock};
 
+#[cfg(feature = "geojson")]
+mod export;
 pub mod feature;
+pub mod feature_source;
+pub mod import;
 pub mod symbol;
 
 pub struct FeatureLayer<P, F, S, Space>
@@ -60,6 +71,31 @@ pub struct FeatureLayerOptions {
     /// slightly improve performance when rendering, bun drastically improve performance when updating just a
     /// few features from the set.
     pub buffer_size_limit: usize,
+
+    /// If set, intermediate points are added to the long segments of geographic features before they are projected,
+    /// so that the segments are drawn as geodesics or rhumb lines instead of straight lines in the view projection.
+    ///
+    /// Applies only to the layers with [`GeoSpace2d`] features.
+    pub densify: Option<Densify>,
+
+    /// Simplification of the geometries rendered for every level of detail (see
+    /// [`FeatureLayer::with_lods`]). The tolerance is set in pixels at the LOD resolution, so with the tolerance below
+    /// one pixel simplification does not change the look of the layer, but reduces the number of vertices to draw.
+    ///
+    /// Geometries are simplified in the view coordinates. Applies only to the layers with 2d features created with
+    /// [`FeatureLayer::with_lods`], as the single LOD of the layers created with [`FeatureLayer::new`] is drawn at
+    /// any resolution.
+    ///
+    /// Simplification may make contours of the geometries intersect. Use [`Simplify::preserving_topology`] to avoid
+    /// it, if it is worth the much slower preparation of the geometries.
+    pub simplify: Option<Simplify>,
+
+    /// If set to true, invalid polygons (with self-intersecting contours, wrong orientation of the contours or holes
+    /// outside of the outer contour) are repaired before being rendered. Without repair such polygons may be filled
+    /// incorrectly.
+    ///
+    /// Repair is done in the view coordinates, before simplification. Applies only to the layers with 2d features.
+    pub make_valid: bool,
 }
 
 impl Default for FeatureLayerOptions {
@@ -67,17 +103,37 @@ impl Default for FeatureLayerOptions {
         Self {
             sort_by_depth: false,
             buffer_size_limit: 10_000_000,
+            densify: None,
+            simplify: Some(Simplify::douglas_peucker(0.5)),
+            make_valid: false,
         }
     }
 }
 
 struct Lod {
     min_resolution: f64,
+    simplify: bool,
     render_bundles: RwLock<Vec<RenderBundle>>,
     packed_bundles: RwLock<Vec<Option<Box<dyn PackedBundle>>>>,
     feature_render_map: RwLock<Vec<RenderMapEntry>>,
 }
 
+impl Lod {
+    /// Drops the cached bundles if they were created by a canvas of another type (e.g. when a layer that was drawn on
+    /// the screen is exported into an SVG document).
+    fn reset_if_incompatible(&self, canvas: &dyn Canvas) {
+        let mut bundles = self.render_bundles.write().unwrap();
+        if bundles
+            .first()
+            .is_some_and(|bundle| bundle.kind() != canvas.bundle_kind())
+        {
+            bundles.clear();
+            self.packed_bundles.write().unwrap().clear();
+            self.feature_render_map.write().unwrap().clear();
+        }
+    }
+}
+
 struct RenderMapEntry {
     bundle_index: usize,
     primitive_ids: Vec<PrimitiveId>,
@@ -97,6 +153,7 @@ where
             messenger: RwLock::new(None),
             lods: vec![Lod {
                 min_resolution: 1.0,
+                simplify: false,
                 render_bundles: RwLock::new(vec![]),
                 packed_bundles: RwLock::new(vec![]),
                 feature_render_map: RwLock::new(Vec::new()),
@@ -111,6 +168,7 @@ where
             .iter()
             .map(|&min_resolution| Lod {
                 min_resolution,
+                simplify: true,
                 render_bundles: RwLock::new(vec![]),
                 packed_bundles: RwLock::new(vec![]),
                 feature_render_map: RwLock::new(Vec::new()),
@@ -161,6 +219,40 @@ where
             },
         );
     }
+
+    /// Repairs and simplifies the geometry in the 2d view coordinates for the LOD and converts it into the 3d view
+    /// coordinates. Returns `None` if the geometry collapses at the LOD resolution.
+    fn lod_geometry(
+        &self,
+        geometry: Geom<Point2d>,
+        lod: &Lod,
+    ) -> Result<Option<Geom<Point3d>>, ProjectionError> {
+        let geometry = if self.options.make_valid {
+            match make_valid(&geometry) {
+                Some(valid) => valid,
+                None => return Ok(None),
+            }
+        } else {
+            geometry
+        };
+
+        let geometry = match &self.options.simplify {
+            Some(simplify) if lod.simplify => {
+                match simplify
+                    .for_resolution(lod.min_resolution)
+                    .simplify(&geometry)
+                {
+                    Some(simplified) => simplified,
+                    None => return Ok(None),
+                }
+            }
+            _ => geometry,
+        };
+
+        geometry
+            .project(&AddDimensionProjection::new(0.0))
+            .map(Some)
+    }
 }
 
 impl<P, F, S> FeatureLayer<P, F, S, GeoSpace2d>
@@ -170,13 +262,48 @@ where
     F::Geom: Geometry<Point = P>,
 {
     pub fn extent_projected(&self, crs: &Crs) -> Option<Rect> {
-        let projection = crs.get_projection::<P, Point2d>()?;
+        let projection = ChainProjection::new(
+            Box::new(DatumShift::<P, P>::new(*self.crs.datum(), *crs.datum())),
+            crs.get_projection::<P, Point2d>()?,
+        );
         self.features
             .iter()
-            .filter_map(|f| f.geometry().project(&*projection))
+            .filter_map(|f| f.geometry().project_opt(&projection))
             .map(|g| g.bounding_rectangle())
             .collect()
     }
+
+    /// Projection from the coordinates of the layer into the 2d coordinates of the view.
+    fn view_projection<In: NewGeoPoint + 'static>(
+        &self,
+        view: &MapView,
+    ) -> impl Projection<InPoint = In, OutPoint = Point2d> {
+        ChainProjection::new(
+            Box::new(DatumShift::<In, In>::new(
+                *self.crs.datum(),
+                *view.crs().datum(),
+            )),
+            view.crs().get_projection::<In, Point2d>().unwrap(),
+        )
+    }
+
+    /// Converts the geometry into geographic coordinates, densifying it if the layer is configured to, and cutting it
+    /// at the edges of the view projection.
+    fn prepare_geometry(
+        &self,
+        geometry: &F::Geom,
+        cut: Option<&AntimeridianCut>,
+    ) -> Result<Geom<GeoPoint2d>, ProjectionError> {
+        let mut geom = geometry.project(&IdentityProjection::<P, GeoPoint2d, GeoSpace2d>::new())?;
+        if let Some(densify) = &self.options.densify {
+            geom = densify.densify(&geom, self.crs.datum());
+        }
+        if let Some(cut) = cut {
+            geom = cut.cut(&geom);
+        }
+
+        Ok(geom)
+    }
 }
 
 impl<P, F, S> FeatureLayer<P, F, S, CartesianSpace2d>
@@ -234,6 +361,24 @@ where
     F::Geom: Geometry<Point = P>,
     S: Symbol<F>,
 {
+    /// Replaces all the features of the layer with the given ones. Render cache of the layer is dropped, so the new
+    /// features will be drawn on the next frame.
+    ///
+    /// This is used to update the layer with the data loaded from a
+    /// [`FeatureSource`](feature_source::FeatureSource) when the map view changes.
+    pub fn set_features(&mut self, features: Vec<F>) {
+        self.features = features;
+        for lod in &self.lods {
+            lod.render_bundles.write().unwrap().clear();
+            lod.packed_bundles.write().unwrap().clear();
+            lod.feature_render_map.write().unwrap().clear();
+        }
+
+        if let Some(messenger) = &(*self.messenger.read().unwrap()) {
+            messenger.request_redraw();
+        }
+    }
+
     pub fn update_features(&mut self, indices: &[usize]) {
         if indices.is_empty() {
             return;
@@ -293,25 +438,38 @@ where
         }
 
         let lod = self.select_lod(view.resolution());
+        lod.reset_if_incompatible(canvas);
         if lod.render_bundles.read().unwrap().is_empty() {
             let mut render_bundles = lod.render_bundles.write().unwrap();
 
             let mut bundle = canvas.create_bundle();
             let mut render_map = lod.feature_render_map.write().unwrap();
-            let projection = ChainProjection::new(
-                view.crs().get_projection::<P, Point2d>().unwrap(),
-                Box::new(AddDimensionProjection::new(0.0)),
-            );
+            let projection = self.view_projection::<P>(view);
+
+            // Geometries crossing the edges of the view projection are cut, so that they are not stretched over the
+            // whole map.
+            let cut = AntimeridianCut::for_crs(view.crs());
+            let prepare = cut.is_some() || self.options.densify.is_some();
+            let geo_projection = self.view_projection::<GeoPoint2d>(view);
 
             for feature in &self.features {
-                let Some(projected): Option<Geom<Point3d>> =
+                let projected = if prepare {
+                    self.prepare_geometry(feature.geometry(), cut.as_ref())
+                        .and_then(|geom| geom.project(&geo_projection))
+                } else {
                     feature.geometry().project(&projection)
-                else {
-                    continue;
                 };
-                let ids = self
-                    .symbol
-                    .render(feature, &projected, &mut bundle, lod.min_resolution);
+                let ids = match projected.and_then(|geom| self.lod_geometry(geom, lod)) {
+                    Ok(Some(projected)) => {
+                        self.symbol
+                            .render(feature, &projected, &mut bundle, lod.min_resolution)
+                    }
+                    Ok(None) => vec![],
+                    Err(err) => {
+                        log::warn!("Feature cannot be projected into the view CRS: {err}");
+                        vec![]
+                    }
+                };
                 render_map.push(RenderMapEntry {
                     bundle_index: render_bundles.len(),
                     primitive_ids: ids,
@@ -347,6 +505,7 @@ where
 {
     fn render(&self, view: &MapView, canvas: &mut dyn Canvas) {
         let lod = self.select_lod(view.resolution());
+        lod.reset_if_incompatible(canvas);
 
         if lod.render_bundles.read().unwrap().is_empty() {
             let mut render_bundles = lod.render_bundles.write().unwrap();
@@ -354,29 +513,41 @@ where
             let mut bundle = canvas.create_bundle();
             let mut render_map = lod.feature_render_map.write().unwrap();
 
-            let projection: Box<dyn Projection<InPoint = _, OutPoint = Point3d>> =
-                if view.crs() == &self.crs {
-                    Box::new(AddDimensionProjection::new(0.0))
-                } else {
-                    let self_proj = self.crs.get_projection::<GeoPoint2d, P>().unwrap();
-                    let view_proj: Box<dyn Projection<InPoint = _, OutPoint = Point2d>> =
-                        view.crs().get_projection().unwrap();
+            let projection: Box<dyn Projection<InPoint = _, OutPoint = Point2d>> = if view.crs()
+                == &self.crs
+            {
+                Box::new(IdentityProjection::<P, Point2d, CartesianSpace2d>::new())
+            } else {
+                let self_proj = self.crs.get_projection::<GeoPoint2d, P>().unwrap();
+                let view_proj: Box<dyn Projection<InPoint = _, OutPoint = Point2d>> =
+                    view.crs().get_projection().unwrap();
+                let datum_shift: Box<dyn Projection<InPoint = GeoPoint2d, OutPoint = GeoPoint2d>> =
+                    Box::new(DatumShift::new(*self.crs.datum(), *view.crs().datum()));
+                Box::new(ChainProjection::new(
                     Box::new(ChainProjection::new(
-                        Box::new(ChainProjection::new(
-                            Box::new(InvertedProjection::new(self_proj)),
-                            view_proj,
-                        )),
-                        Box::new(AddDimensionProjection::new(0.0)),
-                    ))
-                };
+                        Box::new(InvertedProjection::new(self_proj)),
+                        datum_shift,
+                    )),
+                    view_proj,
+                ))
+            };
 
             for feature in &self.features {
-                let Some(geom) = feature.geometry().project(&*projection) else {
-                    continue;
+                let geom = feature
+                    .geometry()
+                    .project(&*projection)
+                    .and_then(|geom| self.lod_geometry(geom, lod));
+                let ids = match geom {
+                    Ok(Some(geom)) => {
+                        self.symbol
+                            .render(feature, &geom, &mut bundle, lod.min_resolution)
+                    }
+                    Ok(None) => vec![],
+                    Err(err) => {
+                        log::warn!("Feature cannot be projected into the view CRS: {err}");
+                        vec![]
+                    }
                 };
-                let ids = self
-                    .symbol
-                    .render(feature, &geom, &mut bundle, lod.min_resolution);
                 render_map.push(RenderMapEntry {
                     bundle_index: render_bundles.len(),
                     primitive_ids: ids,
@@ -418,6 +589,7 @@ where
         }
 
         let lod = self.select_lod(view.resolution());
+        lod.reset_if_incompatible(canvas);
 
         if lod.render_bundles.read().unwrap().is_empty() {
             let mut render_bundles = lod.render_bundles.write().unwrap();
@@ -427,7 +599,7 @@ where
 
             for feature in &self.features {
                 let projection = IdentityProjection::<_, Point3d, _>::new();
-                if let Some(geometry) = feature.geometry().project(&projection) {
+                if let Ok(geometry) = feature.geometry().project(&projection) {
                     let ids =
                         self.symbol
                             .render(feature, &geometry, &mut bundle, lod.min_resolution);
@@ -1,5 +1,6 @@
+use crate::layer::feature_layer::feature::{Properties, PropertyValue};
 use crate::Color;
-use galileo_mvt::MvtFeature;
+use galileo_mvt::{MvtFeature, MvtValue};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -11,18 +12,51 @@ pub struct VectorTileStyle {
 }
 
 impl VectorTileStyle {
+    /// Returns the first rule for the layer with the given name, which property values are equal to the ones of the
+    /// vector tile feature. Property values of the feature are compared by their string representation.
     pub fn get_style_rule(&self, layer_name: &str, feature: &MvtFeature) -> Option<&StyleRule> {
+        self.find_rule(layer_name, |key, value| {
+            feature
+                .properties
+                .get(key)
+                .is_some_and(|v| mvt_value_matches(v, value))
+        })
+    }
+
+    /// Same as [`get_style_rule`](Self::get_style_rule), but for a feature with typed properties.
+    pub fn get_style_rule_by_properties(
+        &self,
+        layer_name: &str,
+        properties: &Properties,
+    ) -> Option<&StyleRule> {
+        self.find_rule(layer_name, |key, value| {
+            properties.get(key).is_some_and(|v| v.to_string() == value)
+        })
+    }
+
+    fn find_rule(
+        &self,
+        layer_name: &str,
+        property_matches: impl Fn(&str, &str) -> bool,
+    ) -> Option<&StyleRule> {
         self.rules.iter().find(|&rule| {
             (rule.layer_name.is_none() || rule.layer_name.as_ref().unwrap() == layer_name)
-                && (rule.properties.is_empty()
-                    || rule.properties.iter().all(|(key, value)| {
-                        feature.properties.get(key).map(|v| v.to_string())
-                            == Some(value.to_string())
-                    }))
+                && rule
+                    .properties
+                    .iter()
+                    .all(|(key, value)| property_matches(key, value))
         })
     }
 }
 
+/// Compares the value with the same representation as the one of [`PropertyValue`], but without copying strings.
+fn mvt_value_matches(value: &MvtValue, expected: &str) -> bool {
+    match value {
+        MvtValue::String(v) => v == expected,
+        v => PropertyValue::from(v.clone()).to_string() == expected,
+    }
+}
+
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct StyleRule {
     pub layer_name: Option<String>,
@@ -64,3 +98,67 @@ pub struct VectorTileLineSymbol {
 pub struct VectorTilePolygonSymbol {
     pub fill_color: Color,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use galileo_mvt::MvtGeometry;
+
+    #[test]
+    fn rule_selection() {
+        let rule =
+            |layer_name: Option<&str>, properties: &[(&str, &str)], color: Color| StyleRule {
+                layer_name: layer_name.map(String::from),
+                properties: properties
+                    .iter()
+                    .map(|(key, value)| (key.to_string(), value.to_string()))
+                    .collect(),
+                symbol: VectorTileSymbol::polygon(color),
+            };
+        let style = VectorTileStyle {
+            rules: vec![
+                rule(
+                    Some("roads"),
+                    &[("lanes", "2"), ("width", "7.5")],
+                    Color::RED,
+                ),
+                rule(None, &[("kind", "park")], Color::GREEN),
+            ],
+            ..Default::default()
+        };
+
+        let properties = Properties::from([
+            ("lanes".to_string(), PropertyValue::Integer(2)),
+            ("width".to_string(), PropertyValue::Float(7.5)),
+            ("kind".to_string(), PropertyValue::from("park")),
+        ]);
+        let color = |layer_name: &str, properties: &Properties| {
+            style
+                .get_style_rule_by_properties(layer_name, properties)
+                .and_then(|rule| rule.symbol.polygon.as_ref())
+                .map(|symbol| symbol.fill_color)
+        };
+
+        assert_eq!(color("roads", &properties), Some(Color::RED));
+        assert_eq!(color("landuse", &properties), Some(Color::GREEN));
+        assert_eq!(color("landuse", &Properties::new()), None);
+
+        let feature = MvtFeature {
+            id: None,
+            properties: HashMap::from([
+                ("lanes".to_string(), MvtValue::Uint64(2)),
+                ("width".to_string(), MvtValue::Float(7.5)),
+            ]),
+            geometry: MvtGeometry::Point(vec![]),
+        };
+        let mvt_color = |layer_name: &str, feature: &MvtFeature| {
+            style
+                .get_style_rule(layer_name, feature)
+                .and_then(|rule| rule.symbol.polygon.as_ref())
+                .map(|symbol| symbol.fill_color)
+        };
+
+        assert_eq!(mvt_color("roads", &feature), Some(Color::RED));
+        assert_eq!(mvt_color("landuse", &feature), None);
+    }
+}
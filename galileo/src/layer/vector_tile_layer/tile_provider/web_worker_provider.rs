@@ -337,7 +337,11 @@ async fn try_load_tile(payload: LoadTilePayload) -> Result<DecodedVectorTile, Ga
 
     let bytes = data_provider.load_raw(&payload.url).await?;
     let (bundle, _) = data_provider.decode(bytes.clone(), context)?;
-    let RenderBundle::Tessellating(bundle) = bundle;
+    let RenderBundle::Tessellating(bundle) = bundle else {
+        return Err(GalileoError::Generic(
+            "vector tiles must be decoded into tessellating bundles".into(),
+        ));
+    };
 
     let serialized = bincode::serialize(&bundle.into_bytes()).unwrap();
 
@@ -77,6 +77,7 @@ pub mod layer;
 pub mod lod;
 pub mod map;
 pub mod messenger;
+pub mod overlay;
 mod platform;
 pub mod primitives;
 pub mod render;
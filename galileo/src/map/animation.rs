@@ -0,0 +1,390 @@
+use crate::view::MapView;
+use maybe_sync::{MaybeSend, MaybeSync};
+use std::fmt::{Debug, Formatter};
+use std::time::Duration;
+
+/// Curvature of the fly-to path. The value of `sqrt(2)` is the one found optimal by van Wijk and Nuij.
+const FLY_TO_RHO: f64 = std::f64::consts::SQRT_2;
+
+/// Dependency of the animation progress on the elapsed share of the animation time.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Easing {
+    Linear,
+    /// Starts slowly and accelerates (cubic).
+    EaseIn,
+    /// Starts fast and decelerates (cubic).
+    EaseOut,
+    /// Accelerates in the first half and decelerates in the second one (cubic).
+    EaseInOut,
+    /// Movement with the speed decreasing exponentially, that stops when the speed falls `e^decay` times.
+    ExponentialOut {
+        decay: f64,
+    },
+}
+
+impl Easing {
+    /// Returns the progress of the animation for the elapsed share of the animation time `k` in `[0; 1]`.
+    pub fn apply(&self, k: f64) -> f64 {
+        let k = k.clamp(0.0, 1.0);
+        match *self {
+            Easing::Linear => k,
+            Easing::EaseIn => k.powi(3),
+            Easing::EaseOut => 1.0 - (1.0 - k).powi(3),
+            Easing::EaseInOut => {
+                if k < 0.5 {
+                    4.0 * k.powi(3)
+                } else {
+                    1.0 - (-2.0 * k + 2.0).powi(3) / 2.0
+                }
+            }
+            Easing::ExponentialOut { decay } if decay > 0.0 => {
+                (1.0 - (-decay * k).exp()) / (1.0 - (-decay).exp())
+            }
+            Easing::ExponentialOut { .. } => k,
+        }
+    }
+}
+
+/// The way the animation reached its end.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AnimationOutcome {
+    /// The view reached the target of the animation.
+    Completed,
+    /// The animation was stopped or replaced by another animation before it reached the target.
+    Cancelled,
+}
+
+/// Trajectory of the view from the start to the target.
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum AnimationPath {
+    /// Position and resolution change together.
+    Direct,
+    /// The map zooms out and back in while moving, so that both start and end positions stay in sight.
+    FlyTo,
+}
+
+/// Animated change of the map view.
+///
+/// Animations are started with [`Map::start_animation`](super::Map::start_animation), which cancels the animations
+/// in progress, or chained with [`Map::queue_animation`](super::Map::queue_animation).
+///
+/// ```no_run
+/// # use galileo::map::Map;
+/// # use galileo::map::animation::{Animation, Easing};
+/// # use std::time::Duration;
+/// # fn animate(map: &mut Map, target: galileo::view::MapView) {
+/// map.start_animation(
+///     Animation::fly_to(target, Duration::from_secs(3))
+///         .with_easing(Easing::EaseInOut)
+///         .on_finish(|outcome| println!("animation finished: {outcome:?}")),
+/// );
+/// # }
+/// ```
+pub struct Animation {
+    target: MapView,
+    duration: Duration,
+    easing: Easing,
+    path: AnimationPath,
+    on_finish: Option<Box<dyn FnOnce(AnimationOutcome) + MaybeSend + MaybeSync>>,
+}
+
+impl Debug for Animation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Animation")
+            .field("target", &self.target)
+            .field("duration", &self.duration)
+            .field("easing", &self.easing)
+            .field("path", &self.path)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Animation {
+    /// Creates an animation that changes all the parameters of the view linearly.
+    pub fn new(target: MapView, duration: Duration) -> Self {
+        Self {
+            target,
+            duration,
+            easing: Easing::Linear,
+            path: AnimationPath::Direct,
+            on_finish: None,
+        }
+    }
+
+    /// Creates an animation that moves the view along the smooth and efficient path described by van Wijk and Nuij:
+    /// the map is zoomed out while moving over long distances and then zoomed back in to the target.
+    pub fn fly_to(target: MapView, duration: Duration) -> Self {
+        Self {
+            path: AnimationPath::FlyTo,
+            ..Self::new(target, duration)
+        }
+    }
+
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Sets the function that is called when the animation is completed or cancelled.
+    pub fn on_finish(
+        mut self,
+        callback: impl FnOnce(AnimationOutcome) + MaybeSend + MaybeSync + 'static,
+    ) -> Self {
+        self.on_finish = Some(Box::new(callback));
+        self
+    }
+
+    pub fn target(&self) -> &MapView {
+        &self.target
+    }
+
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    pub(crate) fn target_mut(&mut self) -> &mut MapView {
+        &mut self.target
+    }
+
+    pub(crate) fn finish(&mut self, outcome: AnimationOutcome) {
+        if let Some(callback) = self.on_finish.take() {
+            callback(outcome);
+        }
+    }
+
+    /// Returns the view at the elapsed share `k` of the animation time, for the animation that started from `start`.
+    pub(crate) fn view_at(&self, start: &MapView, k: f64) -> MapView {
+        if k >= 1.0 {
+            // The animation must end exactly at the target, without the rounding errors of the path.
+            return self
+                .target
+                .with_size(start.size())
+                .with_dpi_scale_factor(start.dpi_scale_factor());
+        }
+
+        let progress = self.easing.apply(k);
+        match self.path {
+            AnimationPath::Direct => start.interpolate(&self.target, progress),
+            AnimationPath::FlyTo => match FlyToPath::new(start, &self.target) {
+                Some(path) => {
+                    let (position_k, resolution) = path.at(progress);
+                    start
+                        .interpolate_with(&self.target, position_k, progress)
+                        .with_resolution(resolution)
+                }
+                None => start.interpolate(&self.target, progress),
+            },
+        }
+    }
+}
+
+/// Optimal path of the camera from "Smooth and efficient zooming and panning" by J. van Wijk and W. Nuij.
+///
+/// `u` is the distance travelled along the straight line between the view centers, `w` is the size of the visible
+/// area, both in map units.
+struct FlyToPath {
+    u1: f64,
+    w0: f64,
+    r0: f64,
+    length: f64,
+    /// Width of the screen in pixels, used to convert `w` into the resolution.
+    screen_width: f64,
+    /// Direction of zoom when the views have the same center: `1` for zooming out, `-1` for zooming in.
+    zoom_direction: Option<f64>,
+}
+
+impl FlyToPath {
+    fn new(start: &MapView, target: &MapView) -> Option<Self> {
+        let size = start.size();
+        let screen_width = size.width().max(size.height());
+        if screen_width <= 0.0 {
+            return None;
+        }
+
+        let from = start.position()?;
+        let to = target.position()?;
+        let u1 = ((to.x - from.x).powi(2) + (to.y - from.y).powi(2)).sqrt();
+        let w0 = start.resolution() * screen_width;
+        let w1 = target.resolution() * screen_width;
+        let rho = FLY_TO_RHO;
+
+        if u1 < w0.min(w1) * 1e-6 {
+            let ratio = (w1 / w0).ln();
+            return Some(Self {
+                u1,
+                w0,
+                r0: 0.0,
+                length: ratio.abs() / rho,
+                screen_width,
+                zoom_direction: Some(ratio.signum()),
+            });
+        }
+
+        let rho2u1 = rho * rho * u1;
+        let b0 = (w1 * w1 - w0 * w0 + rho2u1 * rho2u1) / (2.0 * w0 * rho2u1);
+        let b1 = (w1 * w1 - w0 * w0 - rho2u1 * rho2u1) / (2.0 * w1 * rho2u1);
+        // `ln(-b + sqrt(b² + 1))` loses all precision for large `b` due to cancellation.
+        let r = |b: f64| -b.asinh();
+        let r0 = r(b0);
+        let r1 = r(b1);
+
+        Some(Self {
+            u1,
+            w0,
+            r0,
+            length: (r1 - r0) / rho,
+            screen_width,
+            zoom_direction: None,
+        })
+    }
+
+    /// Returns the share of the distance between the centers passed and the resolution at the given progress.
+    fn at(&self, progress: f64) -> (f64, f64) {
+        let rho = FLY_TO_RHO;
+        let s = self.length * progress;
+
+        let (u, w) = match self.zoom_direction {
+            Some(direction) => (self.u1 * progress, self.w0 * (direction * rho * s).exp()),
+            None => {
+                let u = self.w0 / (rho * rho)
+                    * (self.r0.cosh() * (rho * s + self.r0).tanh() - self.r0.sinh());
+                let w = self.w0 * self.r0.cosh() / (rho * s + self.r0).cosh();
+                (u, w)
+            }
+        };
+
+        let position_k = if self.u1 > 0.0 { u / self.u1 } else { progress };
+        (position_k, w / self.screen_width)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use galileo_types::cartesian::impls::point::Point2d;
+    use galileo_types::cartesian::size::Size;
+    use std::sync::Arc;
+
+    fn view(x: f64, resolution: f64) -> MapView {
+        MapView::new_projected(&Point2d::new(x, 0.0), resolution).with_size(Size::new(100.0, 100.0))
+    }
+
+    #[test]
+    fn easing_ends() {
+        for easing in [
+            Easing::Linear,
+            Easing::EaseIn,
+            Easing::EaseOut,
+            Easing::EaseInOut,
+            Easing::ExponentialOut { decay: 3.0 },
+        ] {
+            assert_eq!(easing.apply(0.0), 0.0, "{easing:?}");
+            assert!((easing.apply(1.0) - 1.0).abs() < 1e-12, "{easing:?}");
+        }
+
+        assert!(Easing::EaseIn.apply(0.5) < 0.5);
+        assert!(Easing::EaseOut.apply(0.5) > 0.5);
+        assert_eq!(Easing::EaseInOut.apply(0.5), 0.5);
+
+        let exponential = Easing::ExponentialOut { decay: 3.0 };
+        assert!(exponential.apply(0.5) > 0.5);
+        assert!(
+            exponential.apply(0.2) - exponential.apply(0.1)
+                > exponential.apply(0.9) - exponential.apply(0.8)
+        );
+    }
+
+    #[test]
+    fn fly_to_zooms_out_over_long_distance() {
+        let start = view(0.0, 1.0);
+        let target = view(100_000.0, 1.0);
+        let animation = Animation::fly_to(target.clone(), Duration::from_secs(1));
+
+        let middle = animation.view_at(&start, 0.5);
+        assert!(middle.resolution() > 10.0);
+        assert!((middle.position().unwrap().x - 50_000.0).abs() < 1.0);
+
+        let end = animation.view_at(&start, 1.0);
+        assert_eq!(end.resolution(), target.resolution());
+        assert_eq!(end.position(), target.position());
+
+        let almost_end = animation.view_at(&start, 0.999);
+        assert!((almost_end.position().unwrap().x - 100_000.0).abs() < 100.0);
+        assert!((almost_end.resolution() - 1.0).abs() < 0.1);
+
+        let begin = animation.view_at(&start, 0.0);
+        assert!((begin.resolution() - 1.0).abs() < 1e-9);
+        assert!(begin.position().unwrap().x.abs() < 1e-9);
+    }
+
+    #[test]
+    fn fly_to_zoom_only() {
+        let start = view(0.0, 1.0);
+        let target = view(0.0, 16.0);
+        let animation = Animation::fly_to(target, Duration::from_secs(1));
+
+        let middle = animation.view_at(&start, 0.5);
+        assert!((middle.resolution() - 4.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn map_chains_and_cancels_animations() {
+        use crate::map::Map;
+        use crate::messenger::Messenger;
+        use std::sync::Mutex;
+
+        #[derive(Clone)]
+        struct NoMessenger;
+        impl Messenger for NoMessenger {
+            fn request_redraw(&self) {}
+        }
+
+        let outcomes = Arc::new(Mutex::new(Vec::<(&str, AnimationOutcome)>::new()));
+        let record = |name: &'static str| {
+            let outcomes = outcomes.clone();
+            move |outcome: AnimationOutcome| outcomes.lock().unwrap().push((name, outcome))
+        };
+
+        let mut map = Map::new(view(0.0, 1.0), vec![], None::<NoMessenger>);
+        map.start_animation(Animation::new(view(10.0, 1.0), Duration::ZERO).on_finish(record("a")));
+        map.queue_animation(Animation::new(view(20.0, 1.0), Duration::ZERO).on_finish(record("b")));
+        assert_eq!(map.target_view().position().unwrap().x, 20.0);
+
+        map.animate();
+        assert_eq!(map.view().position().unwrap().x, 10.0);
+        map.animate();
+        assert_eq!(map.view().position().unwrap().x, 20.0);
+        assert!(!map.is_animating());
+
+        map.start_animation(
+            Animation::new(view(30.0, 1.0), Duration::from_secs(100)).on_finish(record("c")),
+        );
+        map.start_animation(Animation::new(view(40.0, 1.0), Duration::from_secs(100)));
+
+        assert_eq!(
+            *outcomes.lock().unwrap(),
+            vec![
+                ("a", AnimationOutcome::Completed),
+                ("b", AnimationOutcome::Completed),
+                ("c", AnimationOutcome::Cancelled),
+            ]
+        );
+    }
+
+    #[test]
+    fn finish_callback_is_called_once() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = calls.clone();
+        let mut animation =
+            Animation::new(view(0.0, 1.0), Duration::ZERO).on_finish(move |outcome| {
+                assert_eq!(outcome, AnimationOutcome::Cancelled);
+                counter.fetch_add(1, Ordering::SeqCst);
+            });
+
+        animation.finish(AnimationOutcome::Cancelled);
+        animation.finish(AnimationOutcome::Completed);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+}
@@ -1,26 +1,32 @@
 use crate::layer::Layer;
+use crate::map::animation::{Animation, AnimationOutcome};
 use crate::messenger::Messenger;
+use crate::overlay::Overlay;
 use crate::render::Renderer;
 use crate::view::MapView;
 use galileo_types::cartesian::size::Size;
+use std::collections::VecDeque;
 use std::sync::{Arc, RwLock};
 use std::time::Duration;
 use web_time::SystemTime;
 
+pub mod animation;
+
 const FRAME_DURATION: Duration = Duration::from_millis(16);
 
 pub struct Map {
     view: MapView,
     layers: Vec<Box<dyn Layer>>,
+    overlay: Overlay,
     messenger: Option<Box<dyn Messenger>>,
-    animation: Option<AnimationParameters>,
+    animation: Option<AnimationState>,
+    animation_queue: VecDeque<Animation>,
 }
 
-struct AnimationParameters {
+struct AnimationState {
+    animation: Animation,
     start_view: MapView,
-    end_view: MapView,
     start_time: SystemTime,
-    duration: Duration,
 }
 
 impl Map {
@@ -37,8 +43,10 @@ impl Map {
         Self {
             view,
             layers,
+            overlay: Overlay::default(),
             messenger,
             animation: None,
+            animation_queue: VecDeque::new(),
         }
     }
 
@@ -54,6 +62,16 @@ impl Map {
         self.layers.get_mut(index)
     }
 
+    /// Widgets drawn over the layers of the map.
+    pub fn overlay(&self) -> &Overlay {
+        &self.overlay
+    }
+
+    /// Gives access to the widgets of the map. Call [`Map::redraw`] after changing them to show the changes.
+    pub fn overlay_mut(&mut self) -> &mut Overlay {
+        &mut self.overlay
+    }
+
     pub(crate) fn set_view(&mut self, view: MapView) {
         self.view = view;
         if let Some(messenger) = &self.messenger {
@@ -73,45 +91,118 @@ impl Map {
         }
     }
 
+    /// Advances the current animation to the current time. Should be called before every frame is drawn.
     pub fn animate(&mut self) {
-        let Some(animation) = &self.animation else {
+        if self.animation.is_none() && !self.start_next_animation() {
+            return;
+        }
+
+        let Some(state) = &self.animation else {
             return;
         };
 
         let now = SystemTime::now();
-        let k = now
-            .duration_since(animation.start_time)
-            .unwrap_or_default()
-            .as_millis() as f64
-            / animation.duration.as_millis() as f64;
+        let elapsed = now.duration_since(state.start_time).unwrap_or_default();
+        let duration = state.animation.duration();
+        let k = if duration.is_zero() {
+            1.0
+        } else {
+            elapsed.as_secs_f64() / duration.as_secs_f64()
+        };
 
         if k >= 1.0 {
-            let animation = self.animation.take().unwrap();
-            self.view = animation.end_view;
+            let mut state = self.animation.take().expect("animation is checked above");
+            self.view = state.animation.target().clone();
+            state.animation.finish(AnimationOutcome::Completed);
+            self.start_next_animation();
         } else {
-            self.view = animation.start_view.interpolate(&animation.end_view, k);
+            self.view = state.animation.view_at(&state.start_view, k);
         }
 
         self.redraw();
     }
 
+    /// Returns the view the map will have after all the animations are finished.
     pub fn target_view(&self) -> &MapView {
-        self.animation
-            .as_ref()
-            .map(|v| &v.end_view)
+        self.animation_queue
+            .back()
+            .or(self.animation.as_ref().map(|state| &state.animation))
+            .map(|animation| animation.target())
             .unwrap_or(&self.view)
     }
 
+    /// Animates the view of the map to the target linearly. Cancels all the animations in progress.
     pub fn animate_to(&mut self, target: MapView, duration: Duration) {
-        self.animation = Some(AnimationParameters {
+        self.start_animation(Animation::new(target, duration));
+    }
+
+    /// Moves the view to the target along the fly-to path, see [`Animation::fly_to`]. Cancels all the animations in
+    /// progress.
+    pub fn fly_to(&mut self, target: MapView, duration: Duration) {
+        self.start_animation(Animation::fly_to(target, duration));
+    }
+
+    /// Starts the animation from the current view, cancelling all the animations in progress.
+    pub fn start_animation(&mut self, animation: Animation) {
+        self.stop_animation();
+        self.animation_queue.push_back(animation);
+        self.start_next_animation();
+    }
+
+    /// Adds the animation to be started after all the current animations are finished.
+    pub fn queue_animation(&mut self, animation: Animation) {
+        self.animation_queue.push_back(animation);
+        if self.animation.is_none() {
+            self.start_next_animation();
+        }
+    }
+
+    /// Returns true if there are animations in progress or in the queue.
+    pub fn is_animating(&self) -> bool {
+        self.animation.is_some() || !self.animation_queue.is_empty()
+    }
+
+    /// Stops the current animation leaving the view where the animation got to, and clears the animation queue.
+    pub fn stop_animation(&mut self) {
+        if let Some(mut state) = self.animation.take() {
+            state.animation.finish(AnimationOutcome::Cancelled);
+        }
+
+        for mut animation in self.animation_queue.drain(..) {
+            animation.finish(AnimationOutcome::Cancelled);
+        }
+    }
+
+    fn start_next_animation(&mut self) -> bool {
+        let Some(animation) = self.animation_queue.pop_front() else {
+            return false;
+        };
+
+        self.animation = Some(AnimationState {
+            animation,
             start_view: self.view.clone(),
-            end_view: target,
             start_time: SystemTime::now() - FRAME_DURATION,
-            duration,
         });
+        self.redraw();
+
+        true
     }
 
     pub fn set_size(&mut self, new_size: Size) {
         self.view = self.view.with_size(new_size);
     }
+
+    /// Sets the number of physical pixels per logical pixel of the screen the map is displayed on.
+    pub fn set_dpi_scale_factor(&mut self, dpi_scale_factor: f64) {
+        self.view = self.view.with_dpi_scale_factor(dpi_scale_factor);
+        let animations = self
+            .animation
+            .iter_mut()
+            .map(|state| &mut state.animation)
+            .chain(self.animation_queue.iter_mut());
+        for animation in animations {
+            let target = animation.target_mut();
+            *target = target.with_dpi_scale_factor(dpi_scale_factor);
+        }
+    }
 }
@@ -0,0 +1,103 @@
+use crate::overlay::{ScreenPainter, Widget, WidgetPosition};
+use crate::render::print::Placement;
+use crate::view::MapView;
+use crate::Color;
+use galileo_types::cartesian::impls::point::Point2d;
+use galileo_types::cartesian::rect::Rect;
+use galileo_types::cartesian::size::Size;
+
+const TEXT_HEIGHT: f64 = 7.0;
+const PADDING: f64 = 4.0;
+const SEPARATOR: &str = " | ";
+
+/// Text crediting the sources of the data shown on the map, such as `© OpenStreetMap contributors`.
+///
+/// Several sources are joined into one line. By default the attribution is shown in the bottom right corner of the
+/// map.
+#[derive(Debug, Clone)]
+pub struct Attribution {
+    position: WidgetPosition,
+    sources: Vec<String>,
+    text_color: Color,
+    background: Color,
+}
+
+impl Attribution {
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            position: WidgetPosition::new(Placement::BottomRight, 0.0, 0.0),
+            sources: vec![text.into()],
+            text_color: Color::rgba(50, 50, 50, 255),
+            background: Color::rgba(255, 255, 255, 180),
+        }
+    }
+
+    /// Adds one more data source to the attribution.
+    pub fn with_source(mut self, text: impl Into<String>) -> Self {
+        self.sources.push(text.into());
+        self
+    }
+
+    pub fn with_position(mut self, position: WidgetPosition) -> Self {
+        self.position = position;
+        self
+    }
+
+    pub fn with_colors(mut self, text: Color, background: Color) -> Self {
+        self.text_color = text;
+        self.background = background;
+        self
+    }
+
+    /// The text shown by the widget.
+    pub fn text(&self) -> String {
+        self.sources.join(SEPARATOR)
+    }
+}
+
+impl Widget for Attribution {
+    fn bounds(&self, view: &MapView) -> Option<Rect> {
+        let text = self.text();
+        if text.is_empty() {
+            return None;
+        }
+
+        let size = Size::new(
+            ScreenPainter::text_width(&text, TEXT_HEIGHT) + PADDING * 2.0,
+            TEXT_HEIGHT + PADDING * 2.0,
+        );
+        Some(self.position.rect(view.size(), size))
+    }
+
+    fn render(&self, view: &MapView, painter: &mut ScreenPainter) {
+        let Some(bounds) = self.bounds(view) else {
+            return;
+        };
+
+        painter.fill_rect(bounds, self.background);
+        painter.text(
+            &self.text(),
+            Point2d::new(bounds.x_min() + PADDING, bounds.y_min() + PADDING),
+            TEXT_HEIGHT,
+            self.text_color,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sources_are_joined() {
+        let attribution = Attribution::new("© OpenStreetMap contributors").with_source("Galileo");
+        assert_eq!(attribution.text(), "© OpenStreetMap contributors | Galileo");
+
+        let view =
+            MapView::new_projected(&Point2d::new(0.0, 0.0), 1.0).with_size(Size::new(800.0, 600.0));
+        let bounds = attribution.bounds(&view).unwrap();
+        assert_eq!(bounds.x_max(), 800.0);
+        assert_eq!(bounds.y_max(), 600.0);
+        assert_eq!(bounds.height(), TEXT_HEIGHT + PADDING * 2.0);
+    }
+}
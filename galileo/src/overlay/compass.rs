@@ -0,0 +1,138 @@
+use crate::control::{EventPropagation, MouseButton, UserEvent};
+use crate::map::Map;
+use crate::overlay::{ScreenPainter, Widget, WidgetPosition};
+use crate::render::print::Placement;
+use crate::view::MapView;
+use crate::Color;
+use galileo_types::cartesian::impls::point::Point2d;
+use galileo_types::cartesian::rect::Rect;
+use galileo_types::cartesian::size::Size;
+use nalgebra::{Point3, Vector2};
+use std::f64::consts::PI;
+use std::time::Duration;
+
+const RESET_DURATION: Duration = Duration::from_millis(300);
+const CIRCLE_SEGMENTS: usize = 24;
+
+/// North arrow showing the direction to the north on the screen. Clicking the compass rotates the map so that the
+/// north is on the top of the screen.
+#[derive(Debug, Clone)]
+pub struct Compass {
+    position: WidgetPosition,
+    size: f64,
+}
+
+impl Default for Compass {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Compass {
+    /// Creates a compass with the diameter of 36 pixels in the top right corner of the map.
+    pub fn new() -> Self {
+        Self {
+            position: WidgetPosition::new(Placement::TopRight, 10.0, 10.0),
+            size: 36.0,
+        }
+    }
+
+    pub fn with_position(mut self, position: WidgetPosition) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Sets the diameter of the compass in logical pixels.
+    pub fn with_size(mut self, size: f64) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Unit vector pointing to the north on the screen at the center of the map, with the `y` axis going down.
+    fn north_direction(view: &MapView) -> Vector2<f64> {
+        let up = Vector2::new(0.0, -1.0);
+        let size = view.size();
+        let center = Point2d::new(size.half_width(), size.half_height());
+        let Some(map_center) = view.screen_to_map(center) else {
+            return up;
+        };
+
+        let north = Point3::new(map_center.x, map_center.y + view.resolution(), 0.0);
+        let Some(screen_north) = view.map_to_screen(north) else {
+            return up;
+        };
+
+        let direction = Vector2::new(screen_north.x - center.x, screen_north.y - center.y);
+        if direction.norm() > f64::EPSILON {
+            direction.normalize()
+        } else {
+            up
+        }
+    }
+}
+
+impl Widget for Compass {
+    fn bounds(&self, view: &MapView) -> Option<Rect> {
+        Some(
+            self.position
+                .rect(view.size(), Size::new(self.size, self.size)),
+        )
+    }
+
+    fn render(&self, view: &MapView, painter: &mut ScreenPainter) {
+        let bounds = self
+            .position
+            .rect(view.size(), Size::new(self.size, self.size));
+        let center = bounds.center();
+        let radius = self.size / 2.0;
+
+        let circle: Vec<_> = (0..CIRCLE_SEGMENTS)
+            .map(|i| {
+                let angle = 2.0 * PI * i as f64 / CIRCLE_SEGMENTS as f64;
+                Point2d::new(
+                    center.x + radius * angle.cos(),
+                    center.y + radius * angle.sin(),
+                )
+            })
+            .collect();
+        painter.fill_polygon(&circle, Color::rgba(255, 255, 255, 220));
+        painter.line(&circle, Color::rgba(100, 100, 100, 255), 1.0, true);
+
+        let north = Self::north_direction(view) * radius * 0.8;
+        let side = Vector2::new(-north.y, north.x) * 0.3;
+        let tip = Point2d::new(center.x + north.x, center.y + north.y);
+        let tail = Point2d::new(center.x - north.x, center.y - north.y);
+        let left = Point2d::new(center.x + side.x, center.y + side.y);
+        let right = Point2d::new(center.x - side.x, center.y - side.y);
+
+        painter.fill_polygon(&[tip, left, right], Color::rgba(220, 40, 40, 255));
+        painter.fill_polygon(&[tail, right, left], Color::rgba(120, 120, 120, 255));
+    }
+
+    fn handle(&self, event: &UserEvent, map: &mut Map) -> EventPropagation {
+        if let UserEvent::Click(MouseButton::Left, _) = event {
+            let target = map.target_view().with_rotation_z(0.0);
+            map.animate_to(target, RESET_DURATION);
+        }
+
+        EventPropagation::Stop
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn north_follows_rotation() {
+        let view = MapView::new_projected(&Point2d::new(0.0, 0.0), 10.0)
+            .with_size(Size::new(200.0, 100.0));
+
+        let north = Compass::north_direction(&view);
+        assert!((north - Vector2::new(0.0, -1.0)).norm() < 1e-6);
+
+        let rotated = Compass::north_direction(&view.with_rotation_z(PI / 2.0));
+        assert!(rotated.x.abs() > 0.999);
+        assert!(rotated.y.abs() < 1e-6);
+    }
+}
@@ -0,0 +1,294 @@
+//! Screen-space overlay of the map: widgets such as the scale bar, compass, attribution and zoom buttons that are
+//! drawn on top of all the layers in screen pixels.
+//!
+//! Widgets are added to the [`Overlay`] of the map (see [`Map::overlay_mut`]). They are drawn with the current view of
+//! the map every frame, so they follow the changes of the view without any additional setup. Before the user events
+//! are given to the event handlers, the [`EventProcessor`](crate::control::event_processor::EventProcessor) checks if
+//! the pointer is over a widget, and if so, gives the event to the widget first.
+
+use crate::control::{EventPropagation, UserEvent};
+use crate::map::Map;
+use crate::render::print::Placement;
+use crate::render::render_bundle::RenderBundle;
+use crate::render::{Canvas, LineCap, LinePaint, PolygonPaint, RenderOptions};
+use crate::view::MapView;
+use crate::Color;
+use galileo_types::cartesian::impls::contour::{ClosedContour, Contour};
+use galileo_types::cartesian::impls::point::{Point2d, Point3d};
+use galileo_types::cartesian::impls::polygon::Polygon;
+use galileo_types::cartesian::rect::Rect;
+use galileo_types::cartesian::size::Size;
+use maybe_sync::{MaybeSend, MaybeSync};
+use std::sync::Arc;
+
+mod attribution;
+mod compass;
+pub(crate) mod font;
+mod scale_bar;
+mod zoom_buttons;
+
+pub use attribution::Attribution;
+pub use compass::Compass;
+pub use scale_bar::ScaleBar;
+pub use zoom_buttons::ZoomButtons;
+
+/// Element of the map overlay.
+///
+/// All coordinates a widget deals with are logical screen pixels with the origin in the top left corner of the map and
+/// the `y` axis going down.
+pub trait Widget: MaybeSend + MaybeSync {
+    /// Rectangle the widget occupies on the screen with the given view of the map. Returns `None` if the widget is not
+    /// shown or does not react to the user input.
+    fn bounds(&self, view: &MapView) -> Option<Rect>;
+
+    /// Draws the widget for the given view of the map.
+    fn render(&self, view: &MapView, painter: &mut ScreenPainter);
+
+    /// Handles the user event that happened over the widget.
+    ///
+    /// Only the events with the pointer position inside [`Widget::bounds`] are given to the widget: button presses and
+    /// releases, clicks, start of dragging and scrolling. If [`EventPropagation::Propagate`] is returned, the event is
+    /// then given to the event handlers of the map. By default, all events over the widget are stopped.
+    fn handle(&self, _event: &UserEvent, _map: &mut Map) -> EventPropagation {
+        EventPropagation::Stop
+    }
+}
+
+/// Position of a widget relative to a corner of the map.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct WidgetPosition {
+    /// Corner of the map the widget is attached to.
+    pub placement: Placement,
+    /// Horizontal distance in logical pixels between the widget and the edge of the map.
+    pub margin_x: f64,
+    /// Vertical distance in logical pixels between the widget and the edge of the map.
+    pub margin_y: f64,
+}
+
+impl WidgetPosition {
+    pub fn new(placement: Placement, margin_x: f64, margin_y: f64) -> Self {
+        Self {
+            placement,
+            margin_x,
+            margin_y,
+        }
+    }
+
+    /// Rectangle of the widget of the given size on the map of the given size.
+    pub fn rect(&self, map_size: Size, widget_size: Size) -> Rect {
+        let x_min = match self.placement {
+            Placement::TopLeft | Placement::BottomLeft => self.margin_x,
+            Placement::TopRight | Placement::BottomRight => {
+                map_size.width() - self.margin_x - widget_size.width()
+            }
+        };
+        let y_min = match self.placement {
+            Placement::TopLeft | Placement::TopRight => self.margin_y,
+            Placement::BottomLeft | Placement::BottomRight => {
+                map_size.height() - self.margin_y - widget_size.height()
+            }
+        };
+
+        Rect::new(
+            x_min,
+            y_min,
+            x_min + widget_size.width(),
+            y_min + widget_size.height(),
+        )
+    }
+}
+
+/// Set of widgets drawn over the map. Widgets added later are drawn on top of the earlier ones.
+#[derive(Default, Clone)]
+pub struct Overlay {
+    widgets: Vec<Arc<dyn Widget>>,
+}
+
+impl Overlay {
+    /// Adds the widget on top of the other widgets.
+    pub fn add(&mut self, widget: impl Widget + 'static) {
+        self.widgets.push(Arc::new(widget));
+    }
+
+    pub fn widgets(&self) -> &[Arc<dyn Widget>] {
+        &self.widgets
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.widgets.is_empty()
+    }
+
+    /// Removes all the widgets.
+    pub fn clear(&mut self) {
+        self.widgets.clear();
+    }
+
+    /// Returns the topmost widget containing the given screen point.
+    pub fn widget_at(&self, view: &MapView, position: Point2d) -> Option<Arc<dyn Widget>> {
+        self.widgets
+            .iter()
+            .rev()
+            .find(|widget| {
+                widget
+                    .bounds(view)
+                    .is_some_and(|bounds| bounds.contains(&position))
+            })
+            .cloned()
+    }
+
+    /// Draws the widgets for the given view of the map. The canvas must be set up with the
+    /// [`screen_view`] of the map view.
+    pub fn render(&self, view: &MapView, canvas: &mut dyn Canvas) {
+        if self.widgets.is_empty() {
+            return;
+        }
+
+        let mut painter = ScreenPainter::new(canvas.create_bundle());
+        for widget in &self.widgets {
+            widget.render(view, &mut painter);
+        }
+
+        let packed = canvas.pack_bundle(&painter.bundle);
+        canvas.draw_bundles(&[&*packed], RenderOptions::default());
+    }
+}
+
+/// View with the map coordinates corresponding to the logical screen pixels of the given view, with the `y` axis
+/// inverted. Overlay widgets are rendered with this view.
+pub fn screen_view(view: &MapView) -> MapView {
+    let size = view.size();
+    MapView::new_projected(&Point2d::new(size.half_width(), -size.half_height()), 1.0)
+        .with_size(size)
+        .with_dpi_scale_factor(view.dpi_scale_factor())
+}
+
+/// Draws shapes given in screen pixels into a render bundle.
+pub struct ScreenPainter {
+    bundle: RenderBundle,
+}
+
+impl ScreenPainter {
+    fn new(bundle: RenderBundle) -> Self {
+        Self { bundle }
+    }
+
+    /// Fills the polygon with the given vertices.
+    pub fn fill_polygon(&mut self, points: &[Point2d], color: Color) {
+        let polygon = Polygon::new(ClosedContour::new(Self::to_scene(points)), vec![]);
+        self.bundle
+            .add_polygon(&polygon, PolygonPaint { color }, 1.0);
+    }
+
+    /// Draws the line through the given points. The width of the line is in logical pixels.
+    pub fn line(&mut self, points: &[Point2d], color: Color, width: f64, closed: bool) {
+        let contour = Contour::new(Self::to_scene(points), closed);
+        self.bundle.add_line(
+            &contour,
+            LinePaint {
+                color,
+                width,
+                offset: 0.0,
+                line_cap: LineCap::Butt,
+            },
+            1.0,
+        );
+    }
+
+    pub fn fill_rect(&mut self, rect: Rect, color: Color) {
+        self.fill_polygon(&Self::rect_points(rect), color);
+    }
+
+    pub fn stroke_rect(&mut self, rect: Rect, color: Color, width: f64) {
+        self.line(&Self::rect_points(rect), color, width, true);
+    }
+
+    /// Draws the text with the top left corner at the given point. `height` is the height of the capital letters.
+    ///
+    /// The text is drawn with a simple built-in bitmap font that only has the printable ASCII characters, `©`, `°`
+    /// and `²`.
+    pub fn text(&mut self, text: &str, position: Point2d, height: f64, color: Color) {
+        for rect in font::text_rects(text, position.x, position.y, height) {
+            self.fill_rect(rect, color);
+        }
+    }
+
+    /// Width of the text drawn by [`ScreenPainter::text`] with the given height.
+    pub fn text_width(text: &str, height: f64) -> f64 {
+        font::text_width(text, height)
+    }
+
+    fn rect_points(rect: Rect) -> [Point2d; 4] {
+        [
+            Point2d::new(rect.x_min(), rect.y_min()),
+            Point2d::new(rect.x_max(), rect.y_min()),
+            Point2d::new(rect.x_max(), rect.y_max()),
+            Point2d::new(rect.x_min(), rect.y_max()),
+        ]
+    }
+
+    fn to_scene(points: &[Point2d]) -> Vec<Point3d> {
+        points
+            .iter()
+            .map(|p| Point3d::new(p.x, -p.y, 0.0))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestWidget(Rect);
+
+    impl Widget for TestWidget {
+        fn bounds(&self, _view: &MapView) -> Option<Rect> {
+            Some(self.0)
+        }
+
+        fn render(&self, _view: &MapView, _painter: &mut ScreenPainter) {}
+    }
+
+    fn view() -> MapView {
+        MapView::new_projected(&Point2d::new(0.0, 0.0), 10.0).with_size(Size::new(200.0, 100.0))
+    }
+
+    #[test]
+    fn widget_position() {
+        let map_size = Size::new(200.0, 100.0);
+        let widget_size = Size::new(30.0, 20.0);
+
+        let rect = WidgetPosition::new(Placement::TopLeft, 5.0, 10.0).rect(map_size, widget_size);
+        assert_eq!(rect, Rect::new(5.0, 10.0, 35.0, 30.0));
+
+        let rect =
+            WidgetPosition::new(Placement::BottomRight, 5.0, 10.0).rect(map_size, widget_size);
+        assert_eq!(rect, Rect::new(165.0, 70.0, 195.0, 90.0));
+    }
+
+    #[test]
+    fn topmost_widget_is_hit() {
+        let mut overlay = Overlay::default();
+        overlay.add(TestWidget(Rect::new(0.0, 0.0, 50.0, 50.0)));
+        overlay.add(TestWidget(Rect::new(40.0, 40.0, 60.0, 60.0)));
+
+        let view = view();
+        let hit = overlay.widget_at(&view, Point2d::new(45.0, 45.0)).unwrap();
+        assert_eq!(hit.bounds(&view), Some(Rect::new(40.0, 40.0, 60.0, 60.0)));
+
+        assert!(overlay.widget_at(&view, Point2d::new(10.0, 10.0)).is_some());
+        assert!(overlay
+            .widget_at(&view, Point2d::new(100.0, 10.0))
+            .is_none());
+    }
+
+    #[test]
+    fn screen_view_maps_pixels() {
+        let view = view().with_dpi_scale_factor(2.0);
+        let screen = screen_view(&view);
+        assert_eq!(screen.dpi_scale_factor(), 2.0);
+
+        let point = screen.screen_to_map(Point2d::new(30.0, 20.0)).unwrap();
+        assert!((point.x - 30.0).abs() < 1e-9);
+        assert!((point.y + 20.0).abs() < 1e-9);
+    }
+}
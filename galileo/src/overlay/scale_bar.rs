@@ -0,0 +1,130 @@
+use crate::overlay::{ScreenPainter, Widget, WidgetPosition};
+use crate::render::print::{format_length, meters_per_pixel, nice_length, Placement};
+use crate::view::MapView;
+use crate::Color;
+use galileo_types::cartesian::impls::point::Point2d;
+use galileo_types::cartesian::rect::Rect;
+use galileo_types::cartesian::size::Size;
+
+const BAR_HEIGHT: f64 = 5.0;
+const LABEL_HEIGHT: f64 = 7.0;
+const LABEL_SPACING: f64 = 3.0;
+
+/// Scale bar showing the ground distance of a length of the screen at the center of the map.
+///
+/// The length of the bar is chosen as a round number of meters or kilometers that fits into the maximum width of the
+/// bar, so the bar changes its width when the map is zoomed.
+#[derive(Debug, Clone)]
+pub struct ScaleBar {
+    position: WidgetPosition,
+    max_width: f64,
+    color: Color,
+}
+
+impl Default for ScaleBar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ScaleBar {
+    /// Creates a scale bar in the bottom left corner of the map with the maximum width of 100 pixels.
+    pub fn new() -> Self {
+        Self {
+            position: WidgetPosition::new(Placement::BottomLeft, 10.0, 10.0),
+            max_width: 100.0,
+            color: Color::BLACK,
+        }
+    }
+
+    pub fn with_position(mut self, position: WidgetPosition) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Sets the maximum width of the bar in logical pixels.
+    pub fn with_max_width(mut self, max_width: f64) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Sets the color of the bar outline and the label.
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Returns the length of the bar in meters and its width in pixels for the given view.
+    fn bar(&self, view: &MapView) -> Option<(f64, f64)> {
+        let meters_per_pixel = meters_per_pixel(view)?;
+        let length = nice_length(self.max_width * meters_per_pixel);
+        Some((length, length / meters_per_pixel))
+    }
+
+    fn layout(&self, view: &MapView) -> Option<(Rect, f64, String)> {
+        let (length, width) = self.bar(view)?;
+        let label = format_length(length);
+        let label_width = ScreenPainter::text_width(&label, LABEL_HEIGHT);
+        let size = Size::new(
+            width.max(label_width),
+            LABEL_HEIGHT + LABEL_SPACING + BAR_HEIGHT,
+        );
+
+        Some((self.position.rect(view.size(), size), width, label))
+    }
+}
+
+impl Widget for ScaleBar {
+    fn bounds(&self, view: &MapView) -> Option<Rect> {
+        self.layout(view).map(|(rect, _, _)| rect)
+    }
+
+    fn render(&self, view: &MapView, painter: &mut ScreenPainter) {
+        let Some((rect, width, label)) = self.layout(view) else {
+            return;
+        };
+
+        let x = rect.x_min();
+        let bar_y = rect.y_max() - BAR_HEIGHT;
+        let half = width / 2.0;
+
+        painter.fill_rect(Rect::new(x, bar_y, x + half, rect.y_max()), self.color);
+        painter.fill_rect(
+            Rect::new(x + half, bar_y, x + width, rect.y_max()),
+            Color::WHITE,
+        );
+        painter.stroke_rect(
+            Rect::new(x, bar_y, x + width, rect.y_max()),
+            self.color,
+            1.0,
+        );
+        painter.text(
+            &label,
+            Point2d::new(x, rect.y_min()),
+            LABEL_HEIGHT,
+            self.color,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use galileo_types::geo::impls::point::GeoPoint2d;
+    use galileo_types::geo::traits::point::NewGeoPoint;
+
+    #[test]
+    fn bar_length_is_round() {
+        let view =
+            MapView::new(&GeoPoint2d::latlon(0.0, 0.0), 10.0).with_size(Size::new(400.0, 300.0));
+        let scale_bar = ScaleBar::new();
+
+        let (length, width) = scale_bar.bar(&view).unwrap();
+        assert_eq!(length, 500.0);
+        assert!(width > 49.0 && width <= 100.0);
+
+        let bounds = scale_bar.bounds(&view).unwrap();
+        assert_eq!(bounds.x_min(), 10.0);
+        assert_eq!(bounds.y_max(), 290.0);
+    }
+}
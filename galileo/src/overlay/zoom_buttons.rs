@@ -0,0 +1,159 @@
+use crate::control::{EventPropagation, MouseButton, UserEvent};
+use crate::map::Map;
+use crate::overlay::{ScreenPainter, Widget, WidgetPosition};
+use crate::render::print::Placement;
+use crate::view::{MapView, ViewConstraints};
+use crate::Color;
+use galileo_types::cartesian::impls::point::Point2d;
+use galileo_types::cartesian::rect::Rect;
+use galileo_types::cartesian::size::Size;
+use std::time::Duration;
+
+const ZOOM_DURATION: Duration = Duration::from_millis(250);
+const ZOOM_FACTOR: f64 = 2.0;
+
+/// Pair of buttons zooming the map in and out around the center of the map.
+#[derive(Debug, Clone)]
+pub struct ZoomButtons {
+    position: WidgetPosition,
+    button_size: f64,
+    constraints: ViewConstraints,
+}
+
+impl Default for ZoomButtons {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ZoomButtons {
+    /// Creates the buttons of 28 pixels in the top left corner of the map.
+    pub fn new() -> Self {
+        Self {
+            position: WidgetPosition::new(Placement::TopLeft, 10.0, 10.0),
+            button_size: 28.0,
+            constraints: ViewConstraints::default(),
+        }
+    }
+
+    pub fn with_position(mut self, position: WidgetPosition) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Sets the size of each button in logical pixels.
+    pub fn with_button_size(mut self, size: f64) -> Self {
+        self.button_size = size;
+        self
+    }
+
+    /// Sets the constraints applied to the view zoomed by the buttons. These should usually be the same as the
+    /// constraints of the [`MapController`](crate::control::map::MapController).
+    pub fn with_constraints(mut self, constraints: ViewConstraints) -> Self {
+        self.constraints = constraints;
+        self
+    }
+
+    fn rects(&self, view: &MapView) -> (Rect, Rect) {
+        let bounds = self.position.rect(
+            view.size(),
+            Size::new(self.button_size, self.button_size * 2.0),
+        );
+        let middle = bounds.y_min() + self.button_size;
+        (
+            Rect::new(bounds.x_min(), bounds.y_min(), bounds.x_max(), middle),
+            Rect::new(bounds.x_min(), middle, bounds.x_max(), bounds.y_max()),
+        )
+    }
+
+    fn zoomed_view(&self, view: &MapView, zoom_in: bool) -> MapView {
+        let zoom = if zoom_in {
+            1.0 / ZOOM_FACTOR
+        } else {
+            ZOOM_FACTOR
+        };
+        let resolution = self.constraints.zoom_resolution(view.resolution(), zoom);
+        self.constraints.apply(&view.with_resolution(resolution))
+    }
+
+    fn render_button(painter: &mut ScreenPainter, rect: Rect, with_vertical_stroke: bool) {
+        painter.fill_rect(rect, Color::rgba(255, 255, 255, 230));
+        painter.stroke_rect(rect, Color::rgba(100, 100, 100, 255), 1.0);
+
+        let center = rect.center();
+        let half = rect.width() * 0.25;
+        let color = Color::rgba(50, 50, 50, 255);
+        painter.line(
+            &[
+                Point2d::new(center.x - half, center.y),
+                Point2d::new(center.x + half, center.y),
+            ],
+            color,
+            2.0,
+            false,
+        );
+        if with_vertical_stroke {
+            painter.line(
+                &[
+                    Point2d::new(center.x, center.y - half),
+                    Point2d::new(center.x, center.y + half),
+                ],
+                color,
+                2.0,
+                false,
+            );
+        }
+    }
+}
+
+impl Widget for ZoomButtons {
+    fn bounds(&self, view: &MapView) -> Option<Rect> {
+        let (zoom_in, zoom_out) = self.rects(view);
+        Some(zoom_in.merge(zoom_out))
+    }
+
+    fn render(&self, view: &MapView, painter: &mut ScreenPainter) {
+        let (zoom_in, zoom_out) = self.rects(view);
+        Self::render_button(painter, zoom_in, true);
+        Self::render_button(painter, zoom_out, false);
+    }
+
+    fn handle(&self, event: &UserEvent, map: &mut Map) -> EventPropagation {
+        if let UserEvent::Click(MouseButton::Left, e) = event {
+            let (zoom_in, _) = self.rects(map.view());
+            let target = self.zoomed_view(
+                map.target_view(),
+                zoom_in.contains(&e.screen_pointer_position),
+            );
+            map.animate_to(target, ZOOM_DURATION);
+        }
+
+        EventPropagation::Stop
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn buttons_zoom_within_constraints() {
+        let view = MapView::new_projected(&Point2d::new(0.0, 0.0), 10.0)
+            .with_size(Size::new(200.0, 100.0));
+        let buttons = ZoomButtons::new()
+            .with_constraints(ViewConstraints::new().with_resolution_range(4.0, 100.0));
+
+        assert_eq!(buttons.zoomed_view(&view, true).resolution(), 5.0);
+        assert_eq!(buttons.zoomed_view(&view, false).resolution(), 20.0);
+        assert_eq!(
+            buttons
+                .zoomed_view(&view.with_resolution(5.0), true)
+                .resolution(),
+            4.0
+        );
+
+        let (zoom_in, zoom_out) = buttons.rects(&view);
+        assert_eq!(zoom_in, Rect::new(10.0, 10.0, 38.0, 38.0));
+        assert_eq!(zoom_out, Rect::new(10.0, 38.0, 38.0, 66.0));
+    }
+}
@@ -1,14 +1,16 @@
 use crate::Color;
 use galileo_types::cartesian::size::Size;
 use maybe_sync::{MaybeSend, MaybeSync};
-use render_bundle::RenderBundle;
+use render_bundle::{RenderBundle, RenderBundleKind};
 use std::any::Any;
 
 #[cfg(feature = "wgpu")]
 pub mod wgpu;
 
 pub mod point_paint;
+pub mod print;
 pub mod render_bundle;
+pub mod svg;
 
 #[derive(Debug, Copy, Clone, PartialEq, Hash)]
 pub struct PrimitiveId(usize);
@@ -23,6 +25,13 @@ pub trait Renderer: MaybeSend + MaybeSync {
 pub trait Canvas {
     fn size(&self) -> Size;
     fn create_bundle(&self) -> RenderBundle;
+
+    /// Kind of the bundles created by [`Canvas::create_bundle`]. Implementations should override it if creating a
+    /// bundle is not cheap.
+    fn bundle_kind(&self) -> RenderBundleKind {
+        self.create_bundle().kind()
+    }
+
     fn pack_bundle(&self, bundle: &RenderBundle) -> Box<dyn PackedBundle>;
     fn draw_bundles(&mut self, bundles: &[&dyn PackedBundle], options: RenderOptions);
 }
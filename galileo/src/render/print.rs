@@ -0,0 +1,883 @@
+//! Print layouts: a page with the map frame, title, scale bar and legend exported as an SVG document or as a raster
+//! image.
+
+#[cfg(all(feature = "wgpu", not(target_arch = "wasm32")))]
+use crate::error::GalileoError;
+use crate::map::Map;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::overlay::font;
+use crate::primitives::DecodedImage;
+use crate::render::svg::{
+    encode_image, escape_text, fill_attributes, stroke_attributes, SvgCanvas,
+};
+use crate::render::{LineCap, LinePaint};
+use crate::view::MapView;
+use crate::Color;
+use galileo_types::cartesian::impls::point::Point2d;
+use galileo_types::cartesian::rect::Rect;
+use galileo_types::cartesian::size::Size;
+use galileo_types::geo::impls::point::GeoPoint2d;
+use galileo_types::geo::traits::point::NewGeoPoint;
+use std::fmt::Write;
+
+/// Resolution of the logical pixels of the map view, as defined by CSS.
+const LOGICAL_DPI: f64 = 96.0;
+const MM_PER_INCH: f64 = 25.4;
+
+const TITLE_HEIGHT_MM: f64 = 12.0;
+const TITLE_FONT_MM: f64 = 6.0;
+const LABEL_FONT_MM: f64 = 3.0;
+const DECORATION_PADDING_MM: f64 = 3.0;
+const LEGEND_ROW_MM: f64 = 6.0;
+const LEGEND_SWATCH_MM: f64 = 6.0;
+const SCALE_BAR_HEIGHT_MM: f64 = 2.0;
+const SCALE_BAR_MAX_SHARE: f64 = 0.3;
+
+/// Size of a paper sheet in millimeters.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PaperSize {
+    pub width_mm: f64,
+    pub height_mm: f64,
+}
+
+impl PaperSize {
+    pub const A5: Self = Self::new(148.0, 210.0);
+    pub const A4: Self = Self::new(210.0, 297.0);
+    pub const A3: Self = Self::new(297.0, 420.0);
+    pub const LETTER: Self = Self::new(215.9, 279.4);
+
+    pub const fn new(width_mm: f64, height_mm: f64) -> Self {
+        Self {
+            width_mm,
+            height_mm,
+        }
+    }
+
+    /// Returns the same paper size with the longer side horizontal.
+    pub fn landscape(self) -> Self {
+        if self.width_mm >= self.height_mm {
+            self
+        } else {
+            Self::new(self.height_mm, self.width_mm)
+        }
+    }
+}
+
+/// Corner of the map frame a decoration is placed at.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Placement {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+/// Symbol drawn next to the label of a legend entry.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum LegendSymbol {
+    /// Rectangle filled with the color.
+    Fill(Color),
+    /// Horizontal line with the width in logical pixels.
+    Line { color: Color, width: f64 },
+    /// Circle with the diameter in logical pixels.
+    Point { color: Color, size: f64 },
+}
+
+/// One line of the map legend.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LegendEntry {
+    pub label: String,
+    pub symbol: LegendSymbol,
+}
+
+impl LegendEntry {
+    pub fn new(label: impl Into<String>, symbol: LegendSymbol) -> Self {
+        Self {
+            label: label.into(),
+            symbol,
+        }
+    }
+}
+
+/// Layout of a printed map page.
+///
+/// The page is exported as an SVG document with the dimensions in millimeters, that can be printed or converted into
+/// PDF by any vector graphics tool. The map is placed into a frame inside the page margins and can be either drawn as
+/// vector graphics with [`PrintLayout::render_svg`], or embedded as a raster image rendered at the layout resolution
+/// with [`PrintLayout::render_svg_with_image`]. The whole page can also be exported as a raster image with
+/// [`PrintLayout::render_png`] and [`PrintLayout::render_page_image`].
+///
+/// ```no_run
+/// # use galileo::map::Map;
+/// # use galileo::render::print::{PaperSize, PrintLayout};
+/// # fn print(map: &Map) {
+/// let layout = PrintLayout::new(PaperSize::A4.landscape(), 300.0).with_title("My map");
+/// std::fs::write("map.svg", layout.render_svg(map)).unwrap();
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct PrintLayout {
+    paper: PaperSize,
+    dpi: f64,
+    margin_mm: f64,
+    title: Option<String>,
+    background: Option<Color>,
+    scale_bar: Option<Placement>,
+    legend: Vec<LegendEntry>,
+    legend_placement: Placement,
+}
+
+impl PrintLayout {
+    /// Creates a layout for the given paper with 10 mm margins and a scale bar in the bottom left corner of the map.
+    pub fn new(paper: PaperSize, dpi: f64) -> Self {
+        Self {
+            paper,
+            dpi,
+            margin_mm: 10.0,
+            title: None,
+            background: None,
+            scale_bar: Some(Placement::BottomLeft),
+            legend: vec![],
+            legend_placement: Placement::TopRight,
+        }
+    }
+
+    /// Sets the distance between the edge of the page and the map frame.
+    pub fn with_margin(mut self, margin_mm: f64) -> Self {
+        self.margin_mm = margin_mm;
+        self
+    }
+
+    /// Sets the title printed above the map frame.
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Sets the color the map frame is filled with before the map is drawn.
+    pub fn with_background(mut self, color: Color) -> Self {
+        self.background = Some(color);
+        self
+    }
+
+    /// Sets the position of the scale bar. `None` removes the scale bar from the layout.
+    pub fn with_scale_bar(mut self, placement: Option<Placement>) -> Self {
+        self.scale_bar = placement;
+        self
+    }
+
+    /// Adds a legend with the given entries to the layout.
+    pub fn with_legend(mut self, placement: Placement, entries: Vec<LegendEntry>) -> Self {
+        self.legend_placement = placement;
+        self.legend = entries;
+        self
+    }
+
+    /// Number of page pixels per logical pixel of the map view.
+    pub fn dpi_scale_factor(&self) -> f64 {
+        self.dpi / LOGICAL_DPI
+    }
+
+    /// Size of the page in pixels at the layout resolution.
+    pub fn page_size(&self) -> Size {
+        Size::new(
+            self.mm_to_px(self.paper.width_mm).round(),
+            self.mm_to_px(self.paper.height_mm).round(),
+        )
+    }
+
+    /// Area of the page the map is drawn into, in pixels at the layout resolution.
+    pub fn map_frame(&self) -> Rect {
+        let page = self.page_size();
+        let margin = self.mm_to_px(self.margin_mm).round();
+        let top = match self.title {
+            Some(_) => margin + self.mm_to_px(TITLE_HEIGHT_MM).round(),
+            None => margin,
+        };
+
+        Rect::new(
+            margin,
+            top,
+            (page.width() - margin).max(margin),
+            (page.height() - margin).max(top),
+        )
+    }
+
+    /// Returns the view to render the map frame with.
+    ///
+    /// The view has the center and the orientation of the given one, the size of the map frame and the resolution
+    /// adjusted for the layout DPI, so the map is printed at the same scale it is displayed on the screen. When
+    /// rendering the map into a raster image for [`PrintLayout::render_svg_with_image`], set the DPI scale factor
+    /// of the renderer to [`PrintLayout::dpi_scale_factor`] to keep the symbols of the same physical size.
+    pub fn map_view(&self, view: &MapView) -> MapView {
+        let frame = self.map_frame();
+        view.with_size(Size::new(frame.width(), frame.height()))
+            .with_resolution(view.resolution() / self.dpi_scale_factor())
+    }
+
+    /// Draws the map with the current view of the map as vector graphics and returns the SVG page document.
+    pub fn render_svg(&self, map: &Map) -> String {
+        let view = self.map_view(map.view());
+        let mut canvas = SvgCanvas::new(&view).with_dpi_scale_factor(self.dpi_scale_factor());
+        if let Some(background) = self.background {
+            canvas = canvas.with_background(background);
+        }
+        canvas.draw_layers(map);
+
+        self.compose(&view, &canvas.finish())
+    }
+
+    /// Returns the SVG page document with the given image as the content of the map frame.
+    ///
+    /// The image is expected to be rendered with the view returned by [`PrintLayout::map_view`] for the given `view`.
+    /// The image is left out on the platforms that cannot encode it.
+    pub fn render_svg_with_image(&self, view: &MapView, image: &DecodedImage) -> String {
+        let view = self.map_view(view);
+        let frame = self.map_frame();
+        let mut content = String::new();
+        if let Some(data) = encode_image(image) {
+            let _ = write!(
+                content,
+                r#"<image width="{}" height="{}" preserveAspectRatio="none" href="data:image/png;base64,{data}"/>"#,
+                frame.width(),
+                frame.height(),
+            );
+        }
+
+        self.compose(&view, &content)
+    }
+
+    /// Renders the page with the current view of the map into a PNG image with the layout DPI.
+    ///
+    /// The map is drawn by an offscreen GPU renderer with the DPI scale factor of the layout, so lines and symbols
+    /// have the same physical size as in the SVG export. Layers only draw the data they have already loaded, so the
+    /// data for the [`PrintLayout::map_view`] must be loaded before the export. The page is then composed with
+    /// [`PrintLayout::render_page_image`].
+    #[cfg(all(feature = "wgpu", not(target_arch = "wasm32")))]
+    pub async fn render_png(&self, map: &Map) -> Result<Vec<u8>, GalileoError> {
+        use crate::render::svg::encode_png;
+        use crate::render::wgpu::WgpuRenderer;
+
+        let view = self.map_view(map.view());
+        let size = Size::new(
+            view.size().width().round() as u32,
+            view.size().height().round() as u32,
+        );
+
+        let mut renderer = WgpuRenderer::create(size).await;
+        renderer.set_dpi_scale_factor(self.dpi_scale_factor() as f32);
+        if let Some(background) = self.background {
+            renderer.set_background(background);
+        }
+
+        let render_error = |err: wgpu::SurfaceError| {
+            GalileoError::Generic(format!("failed to render the map: {err}"))
+        };
+        renderer.render_view(map, &view).map_err(render_error)?;
+        let bytes = renderer.get_image().await.map_err(render_error)?;
+
+        let page = self.render_page_image(
+            map.view(),
+            &DecodedImage {
+                bytes,
+                dimensions: (size.width(), size.height()),
+            },
+        );
+        encode_png(&page)
+            .ok_or_else(|| GalileoError::Generic("failed to encode the page image".into()))
+    }
+
+    /// Returns the page with the given image as the content of the map frame as an RGBA image with the layout
+    /// resolution.
+    ///
+    /// The image is expected to be rendered with the view returned by [`PrintLayout::map_view`] for the given `view`.
+    /// The text of the title, scale bar and legend is drawn with the simple built-in bitmap font of the
+    /// [overlay](crate::overlay) widgets.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn render_page_image(&self, view: &MapView, image: &DecodedImage) -> DecodedImage {
+        let view = self.map_view(view);
+        let frame = self.map_frame();
+        let mut page = RasterPage::new(self.page_size());
+        page.draw_image(image, frame.x_min() as u32, frame.y_min() as u32);
+        self.draw_decorations(&view, &mut page);
+
+        page.finish()
+    }
+
+    fn compose(&self, view: &MapView, map_content: &str) -> String {
+        let page = self.page_size();
+        let frame = self.map_frame();
+        let mut document = String::new();
+
+        let _ = write!(
+            document,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}mm" height="{}mm" viewBox="0 0 {} {}">"#,
+            self.paper.width_mm,
+            self.paper.height_mm,
+            page.width(),
+            page.height(),
+        );
+        document.push_str(r#"<rect width="100%" height="100%" fill="white"/>"#);
+
+        let _ = write!(
+            document,
+            r#"<g transform="translate({},{})">{map_content}</g>"#,
+            frame.x_min(),
+            frame.y_min(),
+        );
+
+        let mut page = SvgPage { document };
+        self.draw_decorations(view, &mut page);
+
+        let mut document = page.document;
+        document.push_str("</svg>");
+        document
+    }
+
+    /// Draws the title, scale bar, legend and the border of the map frame.
+    fn draw_decorations(&self, view: &MapView, painter: &mut impl PagePainter) {
+        let page = self.page_size();
+        let frame = self.map_frame();
+
+        if let Some(title) = &self.title {
+            painter.text(
+                title,
+                Point2d::new(
+                    page.half_width(),
+                    frame.y_min() - self.mm_to_px(DECORATION_PADDING_MM).round(),
+                ),
+                self.mm_to_px(TITLE_FONT_MM).round(),
+                TextAnchor::Middle,
+            );
+        }
+
+        if let Some(placement) = self.scale_bar {
+            if let Some(meters_per_pixel) = meters_per_pixel(view) {
+                self.draw_scale_bar(painter, placement, meters_per_pixel);
+            }
+        }
+
+        if !self.legend.is_empty() {
+            self.draw_legend(painter);
+        }
+
+        painter.rect(frame, None, Some((Color::BLACK, self.dpi_scale_factor())));
+    }
+
+    fn draw_scale_bar(
+        &self,
+        painter: &mut impl PagePainter,
+        placement: Placement,
+        meters_per_pixel: f64,
+    ) {
+        let frame = self.map_frame();
+        let length_m = nice_length(frame.width() * SCALE_BAR_MAX_SHARE * meters_per_pixel);
+        let width = length_m / meters_per_pixel;
+        let bar_height = self.mm_to_px(SCALE_BAR_HEIGHT_MM);
+        let font_size = self.mm_to_px(LABEL_FONT_MM);
+        let height = bar_height + font_size * 1.5;
+
+        let (x, y) = self.decoration_position(placement, Size::new(width, height));
+        let bar_y = y + height - bar_height;
+        let half = width / 2.0;
+
+        painter.rect(
+            Rect::new(x, bar_y, x + half, bar_y + bar_height),
+            Some(Color::BLACK),
+            None,
+        );
+        painter.rect(
+            Rect::new(x + half, bar_y, x + width, bar_y + bar_height),
+            Some(Color::WHITE),
+            None,
+        );
+        painter.rect(
+            Rect::new(x, bar_y, x + width, bar_y + bar_height),
+            None,
+            Some((Color::BLACK, self.dpi_scale_factor())),
+        );
+        painter.text(
+            &format_length(length_m),
+            Point2d::new(x, bar_y - font_size * 0.5),
+            font_size,
+            TextAnchor::Start,
+        );
+    }
+
+    fn draw_legend(&self, painter: &mut impl PagePainter) {
+        let font_size = self.mm_to_px(LABEL_FONT_MM);
+        let row = self.mm_to_px(LEGEND_ROW_MM);
+        let swatch = self.mm_to_px(LEGEND_SWATCH_MM);
+        let padding = self.mm_to_px(DECORATION_PADDING_MM) / 2.0;
+
+        // Text metrics are not available without a font, so the width of the labels is estimated.
+        let max_chars = self
+            .legend
+            .iter()
+            .map(|entry| entry.label.chars().count())
+            .max()
+            .unwrap_or(0);
+        let width = padding * 3.0 + swatch + max_chars as f64 * font_size * 0.6;
+        let height = padding * 2.0 + row * self.legend.len() as f64;
+
+        let (x, y) = self.decoration_position(self.legend_placement, Size::new(width, height));
+        let scale = self.dpi_scale_factor();
+
+        painter.rect(
+            Rect::new(x, y, x + width, y + height),
+            Some(Color::rgba(255, 255, 255, 204)),
+            Some((Color::BLACK, scale)),
+        );
+
+        for (index, entry) in self.legend.iter().enumerate() {
+            let row_center = y + padding + row * (index as f64 + 0.5);
+            let swatch_x = x + padding;
+
+            match entry.symbol {
+                LegendSymbol::Fill(color) => painter.rect(
+                    Rect::new(
+                        swatch_x,
+                        row_center - swatch / 3.0,
+                        swatch_x + swatch,
+                        row_center + swatch / 3.0,
+                    ),
+                    Some(color),
+                    None,
+                ),
+                LegendSymbol::Line { color, width } => painter.line(
+                    Point2d::new(swatch_x, row_center),
+                    Point2d::new(swatch_x + swatch, row_center),
+                    color,
+                    width * scale,
+                ),
+                LegendSymbol::Point { color, size } => painter.circle(
+                    Point2d::new(swatch_x + swatch / 2.0, row_center),
+                    size * scale / 2.0,
+                    color,
+                ),
+            }
+
+            painter.text(
+                &entry.label,
+                Point2d::new(swatch_x + swatch + padding, row_center + font_size / 3.0),
+                font_size,
+                TextAnchor::Start,
+            );
+        }
+    }
+
+    /// Top left corner of a decoration of the given size placed at the corner of the map frame.
+    fn decoration_position(&self, placement: Placement, size: Size) -> (f64, f64) {
+        let frame = self.map_frame();
+        let padding = self.mm_to_px(DECORATION_PADDING_MM);
+
+        let x = match placement {
+            Placement::TopLeft | Placement::BottomLeft => frame.x_min() + padding,
+            Placement::TopRight | Placement::BottomRight => frame.x_max() - padding - size.width(),
+        };
+        let y = match placement {
+            Placement::TopLeft | Placement::TopRight => frame.y_min() + padding,
+            Placement::BottomLeft | Placement::BottomRight => {
+                frame.y_max() - padding - size.height()
+            }
+        };
+
+        (x, y)
+    }
+
+    fn mm_to_px(&self, mm: f64) -> f64 {
+        mm / MM_PER_INCH * self.dpi
+    }
+}
+
+/// Horizontal alignment of a text relative to its position.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum TextAnchor {
+    Start,
+    Middle,
+}
+
+/// Drawing primitives the page decorations are made of. All coordinates are in pixels of the page.
+trait PagePainter {
+    fn rect(&mut self, rect: Rect, fill: Option<Color>, stroke: Option<(Color, f64)>);
+    fn line(&mut self, from: Point2d, to: Point2d, color: Color, width: f64);
+    fn circle(&mut self, center: Point2d, radius: f64, color: Color);
+    /// Draws black text with the baseline going through the `position`.
+    fn text(&mut self, text: &str, position: Point2d, font_size: f64, anchor: TextAnchor);
+}
+
+/// Writes the page decorations as SVG elements.
+struct SvgPage {
+    document: String,
+}
+
+impl SvgPage {
+    fn stroke(color: Color, width: f64) -> String {
+        stroke_attributes(
+            &LinePaint {
+                color,
+                width,
+                offset: 0.0,
+                line_cap: LineCap::Butt,
+            },
+            1.0,
+        )
+    }
+}
+
+impl PagePainter for SvgPage {
+    fn rect(&mut self, rect: Rect, fill: Option<Color>, stroke: Option<(Color, f64)>) {
+        let _ = write!(
+            self.document,
+            r#"<rect x="{:.2}" y="{:.2}" width="{:.2}" height="{:.2}" {} {}/>"#,
+            rect.x_min(),
+            rect.y_min(),
+            rect.width(),
+            rect.height(),
+            fill.map(fill_attributes)
+                .unwrap_or_else(|| r#"fill="none""#.to_string()),
+            stroke
+                .map(|(color, width)| Self::stroke(color, width))
+                .unwrap_or_default(),
+        );
+    }
+
+    fn line(&mut self, from: Point2d, to: Point2d, color: Color, width: f64) {
+        let _ = write!(
+            self.document,
+            r#"<line x1="{:.2}" y1="{:.2}" x2="{:.2}" y2="{:.2}" {}/>"#,
+            from.x,
+            from.y,
+            to.x,
+            to.y,
+            Self::stroke(color, width),
+        );
+    }
+
+    fn circle(&mut self, center: Point2d, radius: f64, color: Color) {
+        let _ = write!(
+            self.document,
+            r#"<circle cx="{:.2}" cy="{:.2}" r="{radius:.2}" {}/>"#,
+            center.x,
+            center.y,
+            fill_attributes(color),
+        );
+    }
+
+    fn text(&mut self, text: &str, position: Point2d, font_size: f64, anchor: TextAnchor) {
+        let anchor = match anchor {
+            TextAnchor::Start => "start",
+            TextAnchor::Middle => "middle",
+        };
+        let _ = write!(
+            self.document,
+            r#"<text x="{:.2}" y="{:.2}" font-family="sans-serif" font-size="{font_size:.2}" text-anchor="{anchor}">{}</text>"#,
+            position.x,
+            position.y,
+            escape_text(text),
+        );
+    }
+}
+
+/// Draws the page into an RGBA image. Shapes are drawn without anti-aliasing.
+#[cfg(not(target_arch = "wasm32"))]
+struct RasterPage {
+    image: image::RgbaImage,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl RasterPage {
+    fn new(size: Size) -> Self {
+        Self {
+            image: image::RgbaImage::from_pixel(
+                size.width() as u32,
+                size.height() as u32,
+                image::Rgba([255, 255, 255, 255]),
+            ),
+        }
+    }
+
+    /// Draws the image with the top left corner at the given pixel.
+    fn draw_image(&mut self, image: &DecodedImage, x: u32, y: u32) {
+        let width = image.dimensions.0.max(1);
+        for (index, pixel) in image.bytes.chunks_exact(4).enumerate() {
+            let index = index as u32;
+            self.blend(
+                x + index % width,
+                y + index / width,
+                Color::rgba(pixel[0], pixel[1], pixel[2], pixel[3]),
+            );
+        }
+    }
+
+    /// Fills the pixels inside the bounds, which centers satisfy the predicate.
+    fn fill(&mut self, bounds: Rect, color: Color, contains: impl Fn(Point2d) -> bool) {
+        let to_pixel = |value: f64, max: u32| (value.round().max(0.0) as u32).min(max);
+        let (width, height) = self.image.dimensions();
+
+        for y in to_pixel(bounds.y_min(), height)..to_pixel(bounds.y_max(), height) {
+            for x in to_pixel(bounds.x_min(), width)..to_pixel(bounds.x_max(), width) {
+                if contains(Point2d::new(x as f64 + 0.5, y as f64 + 0.5)) {
+                    self.blend(x, y, color);
+                }
+            }
+        }
+    }
+
+    fn blend(&mut self, x: u32, y: u32, color: Color) {
+        if x >= self.image.width() || y >= self.image.height() {
+            return;
+        }
+
+        let pixel = self.image.get_pixel_mut(x, y);
+        let alpha = color.a as f64 / 255.0;
+        for (channel, value) in pixel.0.iter_mut().zip([color.r, color.g, color.b]) {
+            *channel = (value as f64 * alpha + *channel as f64 * (1.0 - alpha)).round() as u8;
+        }
+    }
+
+    fn finish(self) -> DecodedImage {
+        DecodedImage {
+            dimensions: self.image.dimensions(),
+            bytes: self.image.into_raw(),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl PagePainter for RasterPage {
+    fn rect(&mut self, rect: Rect, fill: Option<Color>, stroke: Option<(Color, f64)>) {
+        if let Some(fill) = fill {
+            self.fill(rect, fill, |_| true);
+        }
+
+        if let Some((color, width)) = stroke {
+            let half = width / 2.0;
+            let (x_min, y_min) = (rect.x_min(), rect.y_min());
+            let (x_max, y_max) = (rect.x_max(), rect.y_max());
+            for side in [
+                Rect::new(x_min - half, y_min - half, x_max + half, y_min + half),
+                Rect::new(x_min - half, y_max - half, x_max + half, y_max + half),
+                Rect::new(x_min - half, y_min + half, x_min + half, y_max - half),
+                Rect::new(x_max - half, y_min + half, x_max + half, y_max - half),
+            ] {
+                self.fill(side, color, |_| true);
+            }
+        }
+    }
+
+    fn line(&mut self, from: Point2d, to: Point2d, color: Color, width: f64) {
+        let half = width / 2.0;
+        let bounds = Rect::new(
+            from.x.min(to.x) - half,
+            from.y.min(to.y) - half,
+            from.x.max(to.x) + half,
+            from.y.max(to.y) + half,
+        );
+        let direction = to - from;
+        let length_sq = direction.norm_squared();
+
+        self.fill(bounds, color, |p| {
+            let t = if length_sq > 0.0 {
+                ((p - from).dot(&direction) / length_sq).clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+            (p - (from + direction * t)).norm() <= half
+        });
+    }
+
+    fn circle(&mut self, center: Point2d, radius: f64, color: Color) {
+        let bounds = Rect::new(
+            center.x - radius,
+            center.y - radius,
+            center.x + radius,
+            center.y + radius,
+        );
+        self.fill(bounds, color, |p| (p - center).norm() <= radius);
+    }
+
+    fn text(&mut self, text: &str, position: Point2d, font_size: f64, anchor: TextAnchor) {
+        // Capital letters of sans-serif fonts are about 0.7 of the font size high.
+        let height = font_size * 0.7;
+        let x = match anchor {
+            TextAnchor::Start => position.x,
+            TextAnchor::Middle => position.x - font::text_width(text, height) / 2.0,
+        };
+
+        for rect in font::text_rects(text, x, position.y - height, height) {
+            self.fill(rect, Color::BLACK, |_| true);
+        }
+    }
+}
+
+/// Ground distance in meters that one pixel of the view covers at the center of the view.
+pub(crate) fn meters_per_pixel(view: &MapView) -> Option<f64> {
+    let size = view.size();
+    let center = Point2d::new(size.half_width(), size.half_height());
+    let offset = Point2d::new(center.x + 1.0, center.y);
+
+    let a = view.screen_to_map(center)?;
+    let b = view.screen_to_map(offset)?;
+    let (a, b) = if view.crs().is_geographic() {
+        (GeoPoint2d::latlon(a.y, a.x), GeoPoint2d::latlon(b.y, b.x))
+    } else {
+        let projection = view.crs().get_projection::<GeoPoint2d, Point2d>()?;
+        (
+            projection.unproject(&a).ok()?,
+            projection.unproject(&b).ok()?,
+        )
+    };
+
+    let distance = view.crs().datum().geodesic_distance(&a, &b)?;
+    (distance > 0.0 && distance.is_finite()).then_some(distance)
+}
+
+/// Largest length of 1, 2 or 5 times a power of ten that is not greater than `max_length`.
+pub(crate) fn nice_length(max_length: f64) -> f64 {
+    let magnitude = 10f64.powf(max_length.log10().floor());
+    [5.0, 2.0, 1.0]
+        .into_iter()
+        .map(|k| k * magnitude)
+        .find(|&length| length <= max_length)
+        .unwrap_or(magnitude)
+}
+
+pub(crate) fn format_length(meters: f64) -> String {
+    if meters >= 1000.0 {
+        format!("{} km", meters / 1000.0)
+    } else {
+        format!("{meters} m")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nice_lengths() {
+        assert_eq!(nice_length(7.0), 5.0);
+        assert_eq!(nice_length(1999.0), 1000.0);
+        assert_eq!(nice_length(0.35), 0.2);
+        assert_eq!(format_length(5000.0), "5 km");
+        assert_eq!(format_length(200.0), "200 m");
+    }
+
+    #[test]
+    fn page_geometry() {
+        let layout = PrintLayout::new(PaperSize::A4, 254.0).with_margin(10.0);
+        let page = layout.page_size();
+        assert_eq!(page.width(), 2100.0);
+        assert_eq!(page.height(), 2970.0);
+
+        let frame = layout.map_frame();
+        assert_eq!(frame.x_min(), 100.0);
+        assert_eq!(frame.y_max(), 2870.0);
+
+        let with_title = layout.with_title("title").map_frame();
+        assert_eq!(with_title.y_min(), 220.0);
+    }
+
+    #[test]
+    fn map_view_keeps_scale() {
+        let layout = PrintLayout::new(PaperSize::A4, 192.0);
+        let view = MapView::new(&GeoPoint2d::latlon(0.0, 0.0), 10.0);
+        let print_view = layout.map_view(&view);
+
+        assert_eq!(layout.dpi_scale_factor(), 2.0);
+        assert_eq!(print_view.resolution(), 5.0);
+        assert_eq!(print_view.size().width(), layout.map_frame().width());
+    }
+
+    #[test]
+    fn renders_decorations() {
+        let layout = PrintLayout::new(PaperSize::A5, 96.0)
+            .with_title("Roads & rivers")
+            .with_legend(
+                Placement::TopRight,
+                vec![LegendEntry::new(
+                    "Road",
+                    LegendSymbol::Line {
+                        color: Color::RED,
+                        width: 2.0,
+                    },
+                )],
+            );
+        let view = MapView::new(&GeoPoint2d::latlon(0.0, 0.0), 10.0);
+        let image = DecodedImage {
+            bytes: vec![0; 4],
+            dimensions: (1, 1),
+        };
+        let document = layout.render_svg_with_image(&view, &image);
+
+        assert!(document.starts_with("<svg"));
+        assert!(document.contains(r#"width="148mm""#));
+        assert!(document.contains("Roads &amp; rivers"));
+        assert!(document.contains(">Road</text>"));
+        assert!(document.contains(" km</text>") || document.contains(" m</text>"));
+    }
+
+    #[test]
+    fn renders_page_image() {
+        let layout = PrintLayout::new(PaperSize::A5, 96.0)
+            .with_title("Roads")
+            .with_legend(
+                Placement::TopRight,
+                vec![LegendEntry::new("Park", LegendSymbol::Fill(Color::GREEN))],
+            );
+        let frame = layout.map_frame();
+        let (width, height) = (frame.width() as u32, frame.height() as u32);
+        let map_image = DecodedImage {
+            bytes: [255, 0, 0, 255].repeat((width * height) as usize),
+            dimensions: (width, height),
+        };
+        let view = MapView::new(&GeoPoint2d::latlon(0.0, 0.0), 10.0);
+        let page = layout.render_page_image(&view, &map_image);
+
+        let page_size = layout.page_size();
+        assert_eq!(
+            page.dimensions,
+            (page_size.width() as u32, page_size.height() as u32)
+        );
+
+        let pixel = |x: f64, y: f64| {
+            let index = (y as usize * page.dimensions.0 as usize + x as usize) * 4;
+            [
+                page.bytes[index],
+                page.bytes[index + 1],
+                page.bytes[index + 2],
+            ]
+        };
+        assert_eq!(pixel(1.0, 1.0), [255, 255, 255]);
+        assert_eq!(pixel(frame.center().x, frame.center().y), [255, 0, 0]);
+        assert_eq!(pixel(frame.x_min(), frame.center().y), [0, 0, 0]);
+
+        let title_pixels = (0..frame.y_min() as u32)
+            .flat_map(|y| (0..page.dimensions.0).map(move |x| (x, y)))
+            .filter(|&(x, y)| pixel(x as f64, y as f64) == [0, 0, 0])
+            .count();
+        assert!(title_pixels > 0);
+
+        let legend_pixels = page
+            .bytes
+            .chunks_exact(4)
+            .filter(|p| p[..3] == [Color::GREEN.r, Color::GREEN.g, Color::GREEN.b])
+            .count();
+        assert!(legend_pixels > 0);
+    }
+
+    #[test]
+    fn ground_distance_of_pixel() {
+        let view =
+            MapView::new(&GeoPoint2d::latlon(0.0, 0.0), 10.0).with_size(Size::new(100.0, 100.0));
+        let distance = meters_per_pixel(&view).unwrap();
+        assert!((distance - 10.0).abs() < 1e-3, "distance = {distance}");
+
+        // Web Mercator stretches the map by 2 times at 60 degrees of latitude
+        let view =
+            MapView::new(&GeoPoint2d::latlon(60.0, 0.0), 10.0).with_size(Size::new(100.0, 100.0));
+        let distance = meters_per_pixel(&view).unwrap();
+        assert!((distance - 5.0).abs() < 0.02, "distance = {distance}");
+    }
+}
@@ -8,19 +8,43 @@ use galileo_types::cartesian::traits::cartesian_point::CartesianPoint3d;
 use galileo_types::contour::Contour;
 use galileo_types::polygon::Polygon;
 use num_traits::AsPrimitive;
+use svg::SvgRenderBundle;
 use tessellating::TessellatingRenderBundle;
 
+pub mod svg;
 pub mod tessellating;
 
 #[non_exhaustive]
 pub enum RenderBundle {
     Tessellating(TessellatingRenderBundle),
+    Svg(SvgRenderBundle),
+}
+
+/// Type of a [`RenderBundle`]. Bundles can only be packed by the canvases that create bundles of the same kind.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RenderBundleKind {
+    Tessellating,
+    Svg,
 }
 
 impl RenderBundle {
+    /// Returns true if both bundles are of the same type, and can be packed by the same canvas.
+    pub fn is_same_kind(&self, other: &RenderBundle) -> bool {
+        self.kind() == other.kind()
+    }
+
+    pub fn kind(&self) -> RenderBundleKind {
+        match self {
+            RenderBundle::Tessellating(_) => RenderBundleKind::Tessellating,
+            RenderBundle::Svg(_) => RenderBundleKind::Svg,
+        }
+    }
+
     pub fn approx_buffer_size(&self) -> usize {
         match self {
             RenderBundle::Tessellating(inner) => inner.approx_buffer_size(),
+            RenderBundle::Svg(inner) => inner.approx_buffer_size(),
         }
     }
 
@@ -33,6 +57,7 @@ impl RenderBundle {
     {
         match self {
             RenderBundle::Tessellating(inner) => inner.clip_area(polygon),
+            RenderBundle::Svg(inner) => inner.clip_area(polygon),
         }
     }
 
@@ -44,6 +69,7 @@ impl RenderBundle {
     ) -> PrimitiveId {
         match self {
             RenderBundle::Tessellating(inner) => inner.add_image(image, vertices, paint),
+            RenderBundle::Svg(inner) => inner.add_image(image, vertices, paint),
         }
     }
 
@@ -54,6 +80,7 @@ impl RenderBundle {
     {
         match self {
             RenderBundle::Tessellating(inner) => inner.add_point(point, paint),
+            RenderBundle::Svg(inner) => inner.add_point(point, paint),
         }
     }
 
@@ -70,6 +97,7 @@ impl RenderBundle {
     {
         match self {
             RenderBundle::Tessellating(inner) => inner.add_line(line, paint, min_resolution),
+            RenderBundle::Svg(inner) => inner.add_line(line, paint, min_resolution),
         }
     }
 
@@ -87,18 +115,21 @@ impl RenderBundle {
     {
         match self {
             RenderBundle::Tessellating(inner) => inner.add_polygon(polygon, paint, min_resolution),
+            RenderBundle::Svg(inner) => inner.add_polygon(polygon, paint, min_resolution),
         }
     }
 
     pub fn is_empty(&self) -> bool {
         match self {
             RenderBundle::Tessellating(inner) => inner.is_empty(),
+            RenderBundle::Svg(inner) => inner.is_empty(),
         }
     }
 
     pub fn modify_line(&mut self, id: PrimitiveId, paint: LinePaint) -> Result<(), GalileoError> {
         match self {
             RenderBundle::Tessellating(inner) => inner.modify_line(id, paint),
+            RenderBundle::Svg(inner) => inner.modify_line(id, paint),
         }
     }
 
@@ -109,18 +140,21 @@ impl RenderBundle {
     ) -> Result<(), GalileoError> {
         match self {
             RenderBundle::Tessellating(inner) => inner.modify_polygon(id, paint),
+            RenderBundle::Svg(inner) => inner.modify_polygon(id, paint),
         }
     }
 
     pub fn modify_image(&mut self, id: PrimitiveId, paint: ImagePaint) -> Result<(), GalileoError> {
         match self {
             RenderBundle::Tessellating(inner) => inner.modify_image(id, paint),
+            RenderBundle::Svg(inner) => inner.modify_image(id, paint),
         }
     }
 
     pub fn sort_by_depth(&mut self, view: &MapView) {
         match self {
             RenderBundle::Tessellating(inner) => inner.sort_by_depth(view),
+            RenderBundle::Svg(_) => {}
         }
     }
 }
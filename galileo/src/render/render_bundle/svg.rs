@@ -0,0 +1,377 @@
+use crate::error::GalileoError;
+use crate::primitives::DecodedImage;
+use crate::render::point_paint::{CircleFill, PointPaint, PointShape};
+use crate::render::render_bundle::tessellating::{get_circle_sector, square_shape};
+use crate::render::{ImagePaint, LinePaint, PolygonPaint, PrimitiveId};
+use crate::Color;
+use galileo_types::cartesian::impls::point::{Point2d, Point3d};
+use galileo_types::cartesian::traits::cartesian_point::CartesianPoint3d;
+use galileo_types::contour::Contour;
+use galileo_types::polygon::Polygon;
+use nalgebra::{Point2, Vector2};
+use num_traits::AsPrimitive;
+use std::mem::size_of;
+use std::sync::Arc;
+
+/// Render bundle that stores the primitives as they are given, without tessellating them.
+///
+/// This bundle is used by [`SvgCanvas`](crate::render::svg::SvgCanvas) to produce vector output of the map.
+#[derive(Debug, Clone, Default)]
+pub struct SvgRenderBundle {
+    pub(crate) primitives: Vec<SvgPrimitive>,
+    pub(crate) clip_area: Option<Vec<Vec<Point3d>>>,
+    buffer_size: usize,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum SvgPrimitive {
+    Line {
+        points: Vec<Point3d>,
+        is_closed: bool,
+        paint: LinePaint,
+    },
+    Polygon {
+        contours: Vec<Vec<Point3d>>,
+        paint: PolygonPaint,
+    },
+    Point {
+        position: Point3d,
+        offset: Vector2<f32>,
+        shape: ScreenShape,
+    },
+    Image {
+        image: Arc<DecodedImage>,
+        vertices: [Point2d; 4],
+        opacity: u8,
+    },
+}
+
+/// Owned version of a [`PointShape`]. Coordinates are in pixels relative to the point position with Y axis pointing
+/// up, same as the screen reference coordinates of the tessellated shapes.
+#[derive(Debug, Clone)]
+pub(crate) enum ScreenShape {
+    Dot {
+        color: Color,
+    },
+    Circle {
+        fill: CircleFill,
+        radius: f32,
+        outline: Option<LinePaint>,
+    },
+    Path {
+        points: Vec<Point2<f32>>,
+        fill: CircleFill,
+        outline: Option<LinePaint>,
+    },
+    Image {
+        image: Arc<DecodedImage>,
+        opacity: u8,
+        width: f32,
+        height: f32,
+    },
+}
+
+impl SvgRenderBundle {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn approx_buffer_size(&self) -> usize {
+        self.buffer_size
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.primitives.is_empty()
+    }
+
+    pub fn clip_area<N, P, Poly>(&mut self, polygon: &Poly)
+    where
+        N: AsPrimitive<f32>,
+        P: CartesianPoint3d<Num = N>,
+        Poly: Polygon,
+        Poly::Contour: Contour<Point = P>,
+    {
+        self.clip_area = Some(Self::convert_polygon(polygon));
+    }
+
+    pub fn add_image(
+        &mut self,
+        image: DecodedImage,
+        vertices: [Point2d; 4],
+        paint: ImagePaint,
+    ) -> PrimitiveId {
+        self.buffer_size += image.bytes.len();
+        self.push(SvgPrimitive::Image {
+            image: Arc::new(image),
+            vertices,
+            opacity: paint.opacity,
+        })
+    }
+
+    pub fn add_point<N, P>(&mut self, point: &P, paint: PointPaint) -> PrimitiveId
+    where
+        N: AsPrimitive<f32>,
+        P: CartesianPoint3d<Num = N>,
+    {
+        let shape = match paint.shape {
+            PointShape::Dot { color } => ScreenShape::Dot { color },
+            PointShape::Circle {
+                fill,
+                radius,
+                outline,
+            } => ScreenShape::Circle {
+                fill,
+                radius,
+                outline,
+            },
+            PointShape::Sector(parameters) => {
+                let mut points = get_circle_sector(
+                    parameters.radius,
+                    parameters.start_angle,
+                    parameters.end_angle,
+                );
+                points.push(Point2::new(0.0, 0.0));
+                ScreenShape::Path {
+                    points,
+                    fill: parameters.fill,
+                    outline: parameters.outline,
+                }
+            }
+            PointShape::Square {
+                fill,
+                size,
+                outline,
+            } => ScreenShape::Path {
+                points: scale_points(&square_shape().points, size),
+                fill: fill.into(),
+                outline,
+            },
+            PointShape::FreeShape {
+                fill,
+                scale,
+                outline,
+                shape,
+            } => ScreenShape::Path {
+                points: scale_points(&shape.points, scale),
+                fill: fill.into(),
+                outline,
+            },
+            PointShape::Image {
+                image,
+                opacity,
+                width,
+                height,
+            } => ScreenShape::Image {
+                image,
+                opacity,
+                width,
+                height,
+            },
+        };
+
+        self.buffer_size += size_of::<SvgPrimitive>();
+        self.push(SvgPrimitive::Point {
+            position: convert_point(point),
+            offset: paint.offset,
+            shape,
+        })
+    }
+
+    pub fn add_line<N, P, C>(
+        &mut self,
+        line: &C,
+        paint: LinePaint,
+        _min_resolution: f64,
+    ) -> PrimitiveId
+    where
+        N: AsPrimitive<f32>,
+        P: CartesianPoint3d<Num = N>,
+        C: Contour<Point = P>,
+    {
+        let points: Vec<_> = line.iter_points().map(convert_point).collect();
+        self.buffer_size += points.len() * size_of::<Point3d>();
+        self.push(SvgPrimitive::Line {
+            points,
+            is_closed: line.is_closed(),
+            paint,
+        })
+    }
+
+    pub fn add_polygon<N, P, Poly>(
+        &mut self,
+        polygon: &Poly,
+        paint: PolygonPaint,
+        _min_resolution: f64,
+    ) -> PrimitiveId
+    where
+        N: AsPrimitive<f32>,
+        P: CartesianPoint3d<Num = N>,
+        Poly: Polygon,
+        Poly::Contour: Contour<Point = P>,
+    {
+        let contours = Self::convert_polygon(polygon);
+        self.buffer_size += contours.iter().map(Vec::len).sum::<usize>() * size_of::<Point3d>();
+        self.push(SvgPrimitive::Polygon { contours, paint })
+    }
+
+    pub fn modify_line(&mut self, id: PrimitiveId, paint: LinePaint) -> Result<(), GalileoError> {
+        match self.get_mut(id)? {
+            SvgPrimitive::Line { paint: current, .. } => *current = paint,
+            _ => return Err(GalileoError::Generic("invalid primitive type".into())),
+        }
+
+        Ok(())
+    }
+
+    pub fn modify_polygon(
+        &mut self,
+        id: PrimitiveId,
+        paint: PolygonPaint,
+    ) -> Result<(), GalileoError> {
+        match self.get_mut(id)? {
+            SvgPrimitive::Polygon { paint: current, .. } => *current = paint,
+            _ => return Err(GalileoError::Generic("invalid primitive type".into())),
+        }
+
+        Ok(())
+    }
+
+    pub fn modify_image(&mut self, id: PrimitiveId, paint: ImagePaint) -> Result<(), GalileoError> {
+        match self.get_mut(id)? {
+            SvgPrimitive::Image { opacity, .. }
+            | SvgPrimitive::Point {
+                shape: ScreenShape::Image { opacity, .. },
+                ..
+            } => *opacity = paint.opacity,
+            _ => return Err(GalileoError::Generic("invalid primitive type".into())),
+        }
+
+        Ok(())
+    }
+
+    fn push(&mut self, primitive: SvgPrimitive) -> PrimitiveId {
+        let id = PrimitiveId(self.primitives.len());
+        self.primitives.push(primitive);
+        id
+    }
+
+    fn get_mut(&mut self, id: PrimitiveId) -> Result<&mut SvgPrimitive, GalileoError> {
+        self.primitives
+            .get_mut(id.0)
+            .ok_or(GalileoError::Generic("primitive does not exist".into()))
+    }
+
+    fn convert_polygon<N, P, Poly>(polygon: &Poly) -> Vec<Vec<Point3d>>
+    where
+        N: AsPrimitive<f32>,
+        P: CartesianPoint3d<Num = N>,
+        Poly: Polygon,
+        Poly::Contour: Contour<Point = P>,
+    {
+        polygon
+            .iter_contours()
+            .map(|contour| contour.iter_points().map(convert_point).collect())
+            .collect()
+    }
+}
+
+fn convert_point<N, P>(point: &P) -> Point3d
+where
+    N: AsPrimitive<f32>,
+    P: CartesianPoint3d<Num = N>,
+{
+    Point3d::new(
+        point.x().as_() as f64,
+        point.y().as_() as f64,
+        point.z().as_() as f64,
+    )
+}
+
+fn scale_points(points: &[Point2<f32>], scale: f32) -> Vec<Point2<f32>> {
+    points
+        .iter()
+        .map(|p| Point2::new(p.x * scale, p.y * scale))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::render::LineCap;
+    use galileo_types::cartesian::impls::contour::{ClosedContour, Contour};
+    use galileo_types::cartesian::impls::polygon::Polygon;
+
+    #[test]
+    fn stores_and_modifies_primitives() {
+        let mut bundle = SvgRenderBundle::new();
+        let line_paint = LinePaint {
+            color: Color::RED,
+            width: 2.0,
+            offset: 0.0,
+            line_cap: LineCap::Butt,
+        };
+        let line_id = bundle.add_line(
+            &Contour::open(vec![
+                Point3d::new(0.0, 0.0, 0.0),
+                Point3d::new(1.0, 1.0, 0.0),
+            ]),
+            line_paint,
+            1.0,
+        );
+        let polygon_id = bundle.add_polygon(
+            &Polygon::new(
+                ClosedContour::new(vec![
+                    Point3d::new(0.0, 0.0, 0.0),
+                    Point3d::new(1.0, 0.0, 0.0),
+                    Point3d::new(1.0, 1.0, 0.0),
+                ]),
+                vec![],
+            ),
+            PolygonPaint { color: Color::BLUE },
+            1.0,
+        );
+
+        assert_eq!(bundle.primitives.len(), 2);
+        assert!(bundle
+            .modify_polygon(
+                polygon_id,
+                PolygonPaint {
+                    color: Color::GREEN
+                }
+            )
+            .is_ok());
+        assert!(bundle
+            .modify_polygon(
+                line_id,
+                PolygonPaint {
+                    color: Color::GREEN
+                }
+            )
+            .is_err());
+
+        let SvgPrimitive::Polygon { paint, contours } = &bundle.primitives[1] else {
+            panic!("invalid primitive type");
+        };
+        assert_eq!(paint.color, Color::GREEN);
+        assert_eq!(contours[0].len(), 3);
+    }
+
+    #[test]
+    fn square_point_is_converted_to_path() {
+        let mut bundle = SvgRenderBundle::new();
+        bundle.add_point(
+            &Point3d::new(10.0, 10.0, 0.0),
+            PointPaint::square(Color::RED, 4.0),
+        );
+
+        let SvgPrimitive::Point {
+            shape: ScreenShape::Path { points, .. },
+            ..
+        } = &bundle.primitives[0]
+        else {
+            panic!("invalid primitive type");
+        };
+        assert_eq!(points.len(), 4);
+        assert_eq!(points[0], Point2::new(-2.0, -2.0));
+    }
+}
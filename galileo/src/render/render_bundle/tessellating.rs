@@ -750,7 +750,7 @@ impl TessellatingRenderBundle {
     }
 }
 
-fn get_circle_sector(radius: f32, start_angle: f32, end_angle: f32) -> Vec<Point2<f32>> {
+pub(crate) fn get_circle_sector(radius: f32, start_angle: f32, end_angle: f32) -> Vec<Point2<f32>> {
     const TOLERANCE: f32 = 0.1;
 
     let mut contour = vec![];
@@ -780,7 +780,7 @@ fn get_circle_sector(radius: f32, start_angle: f32, end_angle: f32) -> Vec<Point
     contour
 }
 
-fn square_shape() -> ClosedContour<Point2<f32>> {
+pub(crate) fn square_shape() -> ClosedContour<Point2<f32>> {
     ClosedContour::new(vec![
         Point2::new(-0.5, -0.5),
         Point2::new(-0.5, 0.5),
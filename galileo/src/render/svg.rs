@@ -0,0 +1,613 @@
+//! Rendering of the map into SVG documents.
+
+use crate::map::Map;
+use crate::overlay::screen_view;
+use crate::primitives::DecodedImage;
+use crate::render::point_paint::CircleFill;
+use crate::render::render_bundle::svg::{ScreenShape, SvgPrimitive, SvgRenderBundle};
+use crate::render::render_bundle::{RenderBundle, RenderBundleKind};
+use crate::render::{
+    Canvas, EmptyBundle, LineCap, LinePaint, PackedBundle, PolygonPaint, RenderOptions,
+};
+use crate::view::MapView;
+use crate::Color;
+use galileo_types::cartesian::impls::point::{Point2d, Point3d};
+use galileo_types::cartesian::size::Size;
+use nalgebra::{Point2, Vector2};
+use std::any::Any;
+use std::fmt::Write;
+
+/// Canvas that draws the map into an SVG document.
+///
+/// The canvas receives the same primitives as the GPU renderer does (lines, polygons, points and images), but instead
+/// of tessellating them writes them as SVG elements, so the result can be scaled and printed without quality loss.
+///
+/// Only the layers that create their render bundles through the canvas (like
+/// [`FeatureLayer`](crate::layer::FeatureLayer)) can be exported. Bundles prepared by a GPU renderer are skipped.
+///
+/// ```no_run
+/// # use galileo::map::Map;
+/// # use galileo::render::svg::SvgCanvas;
+/// # fn export(map: &Map) {
+/// let mut canvas = SvgCanvas::new(map.view());
+/// canvas.draw_map(map);
+/// std::fs::write("map.svg", canvas.finish()).unwrap();
+/// # }
+/// ```
+pub struct SvgCanvas {
+    view: MapView,
+    background: Option<Color>,
+    dpi_scale_factor: f64,
+    defs: String,
+    body: String,
+    next_def_id: usize,
+}
+
+impl SvgCanvas {
+    /// Creates a new empty canvas with the size of the given view.
+    pub fn new(view: &MapView) -> Self {
+        Self {
+            view: view.clone(),
+            background: None,
+            dpi_scale_factor: 1.0,
+            defs: String::new(),
+            body: String::new(),
+            next_def_id: 0,
+        }
+    }
+
+    /// Sets the color the document is filled with before anything is drawn. By default the background is transparent.
+    pub fn with_background(mut self, color: Color) -> Self {
+        self.background = Some(color);
+        self
+    }
+
+    /// Sets the number of document pixels per logical pixel. Line widths, point symbol sizes and other screen
+    /// referenced dimensions are multiplied by this factor.
+    pub fn with_dpi_scale_factor(mut self, scale_factor: f64) -> Self {
+        self.dpi_scale_factor = scale_factor;
+        self
+    }
+
+    /// Draws all the layers of the map and its overlay widgets on top of them. Everything is rendered with the view
+    /// of the canvas, not the current view of the map.
+    pub fn draw_map(&mut self, map: &Map) {
+        self.draw_layers(map);
+
+        let view = self.view.clone();
+        self.view = screen_view(&view);
+        map.overlay().render(&view, self);
+        self.view = view;
+    }
+
+    /// Draws the layers of the map without the overlay widgets.
+    pub fn draw_layers(&mut self, map: &Map) {
+        let view = self.view.clone();
+        for layer in map.layers() {
+            layer.render(&view, self);
+        }
+    }
+
+    /// Returns the SVG document with everything that was drawn on the canvas.
+    pub fn finish(self) -> String {
+        let size = self.view.size();
+        let mut document = String::new();
+        let _ = write!(
+            document,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}">"#,
+            w = size.width(),
+            h = size.height(),
+        );
+
+        if !self.defs.is_empty() {
+            let _ = write!(document, "<defs>{}</defs>", self.defs);
+        }
+
+        if let Some(background) = self.background {
+            let _ = write!(
+                document,
+                r#"<rect width="100%" height="100%" {}/>"#,
+                fill_attributes(background)
+            );
+        }
+
+        document.push_str(&self.body);
+        document.push_str("</svg>");
+        document
+    }
+
+    fn next_id(&mut self, prefix: &str) -> String {
+        self.next_def_id += 1;
+        format!("{prefix}{}", self.next_def_id)
+    }
+
+    fn screen_point(&self, point: &Point3d) -> Option<Point2d> {
+        self.view.map_to_screen(*point)
+    }
+
+    fn draw_bundle(&mut self, bundle: &SvgRenderBundle, options: RenderOptions) {
+        let mut group_attributes = String::new();
+        if !options.antialias {
+            group_attributes.push_str(r#" shape-rendering="crispEdges""#);
+        }
+
+        if let Some(clip_area) = &bundle.clip_area {
+            let id = self.next_id("clip");
+            let path = self.polygon_path(clip_area);
+            let _ = write!(
+                self.defs,
+                r#"<clipPath id="{id}"><path d="{path}" clip-rule="evenodd"/></clipPath>"#
+            );
+            let _ = write!(group_attributes, r#" clip-path="url(#{id})""#);
+        }
+
+        let _ = write!(self.body, "<g{group_attributes}>");
+        for primitive in &bundle.primitives {
+            match primitive {
+                SvgPrimitive::Line {
+                    points,
+                    is_closed,
+                    paint,
+                } => self.draw_line(points, *is_closed, paint),
+                SvgPrimitive::Polygon { contours, paint } => self.draw_polygon(contours, paint),
+                SvgPrimitive::Point {
+                    position,
+                    offset,
+                    shape,
+                } => self.draw_point(position, *offset, shape),
+                SvgPrimitive::Image {
+                    image,
+                    vertices,
+                    opacity,
+                } => self.draw_map_image(image, vertices, *opacity),
+            }
+        }
+        self.body.push_str("</g>");
+    }
+
+    fn draw_line(&mut self, points: &[Point3d], is_closed: bool, paint: &LinePaint) {
+        let Some(points) = points
+            .iter()
+            .map(|p| self.screen_point(p))
+            .collect::<Option<Vec<_>>>()
+        else {
+            return;
+        };
+
+        let element = if is_closed { "polygon" } else { "polyline" };
+        let _ = write!(
+            self.body,
+            r#"<{element} points="{}" fill="none" {}/>"#,
+            points_list(&points),
+            stroke_attributes(paint, self.dpi_scale_factor),
+        );
+    }
+
+    fn draw_polygon(&mut self, contours: &[Vec<Point3d>], paint: &PolygonPaint) {
+        let path = self.polygon_path(contours);
+        if path.is_empty() {
+            return;
+        }
+
+        let _ = write!(
+            self.body,
+            r#"<path d="{path}" fill-rule="evenodd" {}/>"#,
+            fill_attributes(paint.color),
+        );
+    }
+
+    fn polygon_path(&self, contours: &[Vec<Point3d>]) -> String {
+        let mut path = String::new();
+        for contour in contours {
+            let Some(points) = contour
+                .iter()
+                .map(|p| self.screen_point(p))
+                .collect::<Option<Vec<_>>>()
+            else {
+                continue;
+            };
+
+            if points.is_empty() {
+                continue;
+            }
+
+            let _ = write!(path, "M{}Z", points_list(&points));
+        }
+
+        path
+    }
+
+    fn draw_point(&mut self, position: &Point3d, offset: Vector2<f32>, shape: &ScreenShape) {
+        if let ScreenShape::Dot { color } = shape {
+            let position = Point3d::new(
+                position.x + offset.x as f64,
+                position.y + offset.y as f64,
+                position.z,
+            );
+            if let Some(center) = self.screen_point(&position) {
+                let _ = write!(
+                    self.body,
+                    r#"<circle cx="{:.2}" cy="{:.2}" r="{}" {}/>"#,
+                    center.x,
+                    center.y,
+                    self.dpi_scale_factor,
+                    fill_attributes(*color),
+                );
+            }
+
+            return;
+        }
+
+        let Some(anchor) = self.screen_point(position) else {
+            return;
+        };
+
+        // Shape coordinates have Y axis pointing up, while in SVG it points down.
+        let scale = self.dpi_scale_factor;
+        let to_screen = |p: &Point2<f32>| {
+            Point2d::new(anchor.x + p.x as f64 * scale, anchor.y - p.y as f64 * scale)
+        };
+        let center = to_screen(&Point2::from(offset));
+
+        match shape {
+            ScreenShape::Dot { .. } => {}
+            ScreenShape::Circle {
+                fill,
+                radius,
+                outline,
+            } => {
+                let circle = format!(
+                    r#"<circle cx="{:.2}" cy="{:.2}" r="{}""#,
+                    center.x,
+                    center.y,
+                    *radius as f64 * scale
+                );
+                if let Some(outline) = outline {
+                    let _ = write!(
+                        self.body,
+                        r#"{circle} fill="none" {}/>"#,
+                        stroke_attributes(outline, 2.0 * scale)
+                    );
+                }
+
+                let fill = self.circle_fill(fill, center, *radius as f64 * scale);
+                let _ = write!(self.body, "{circle} {fill}/>");
+            }
+            ScreenShape::Path {
+                points,
+                fill,
+                outline,
+            } => {
+                let screen_points: Vec<_> =
+                    points.iter().map(|p| to_screen(&(p + offset))).collect();
+                let points = points_list(&screen_points);
+
+                if let Some(outline) = outline {
+                    let _ = write!(
+                        self.body,
+                        r#"<polygon points="{points}" fill="none" {}/>"#,
+                        stroke_attributes(outline, 2.0 * scale)
+                    );
+                }
+
+                let radius = screen_points
+                    .iter()
+                    .map(|p| (p - center).norm())
+                    .fold(0.0, f64::max);
+                let fill = self.circle_fill(fill, center, radius);
+                let _ = write!(self.body, r#"<polygon points="{points}" {fill}/>"#);
+            }
+            ScreenShape::Image {
+                image,
+                opacity,
+                width,
+                height,
+            } => {
+                let width = *width as f64 * scale;
+                let height = *height as f64 * scale;
+                let x = anchor.x - offset.x as f64 * width;
+                let y = anchor.y - offset.y as f64 * height;
+                self.draw_image(
+                    image,
+                    Point2d::new(x, y),
+                    Point2d::new(x + width, y + height),
+                    *opacity,
+                );
+            }
+        }
+    }
+
+    fn draw_map_image(&mut self, image: &DecodedImage, vertices: &[Point2d; 4], opacity: u8) {
+        let screen_vertex = |index: usize| {
+            let vertex: &Point2d = &vertices[index];
+            self.screen_point(&Point3d::new(vertex.x, vertex.y, 0.0))
+        };
+
+        // Vertices go in order: bottom-left, top-left, top-right, bottom-right.
+        let (Some(top_left), Some(bottom_right)) = (screen_vertex(1), screen_vertex(3)) else {
+            return;
+        };
+
+        self.draw_image(image, top_left, bottom_right, opacity);
+    }
+
+    fn draw_image(
+        &mut self,
+        image: &DecodedImage,
+        corner: Point2d,
+        opposite_corner: Point2d,
+        opacity: u8,
+    ) {
+        let Some(data) = encode_image(image) else {
+            log::warn!("Failed to encode image for SVG export");
+            return;
+        };
+
+        let _ = write!(
+            self.body,
+            r#"<image x="{:.2}" y="{:.2}" width="{:.2}" height="{:.2}" opacity="{}" preserveAspectRatio="none" href="data:image/png;base64,{data}"/>"#,
+            corner.x.min(opposite_corner.x),
+            corner.y.min(opposite_corner.y),
+            (opposite_corner.x - corner.x).abs(),
+            (opposite_corner.y - corner.y).abs(),
+            opacity_value(opacity),
+        );
+    }
+
+    fn circle_fill(&mut self, fill: &CircleFill, center: Point2d, radius: f64) -> String {
+        if fill.center_color == fill.side_color || radius <= 0.0 {
+            return fill_attributes(fill.center_color);
+        }
+
+        let id = self.next_id("gradient");
+        let _ = write!(
+            self.defs,
+            r#"<radialGradient id="{id}" gradientUnits="userSpaceOnUse" cx="{:.2}" cy="{:.2}" r="{radius:.2}"><stop offset="0" {}/><stop offset="1" {}/></radialGradient>"#,
+            center.x,
+            center.y,
+            stop_attributes(fill.center_color),
+            stop_attributes(fill.side_color),
+        );
+
+        format!(r#"fill="url(#{id})""#)
+    }
+}
+
+impl Canvas for SvgCanvas {
+    fn size(&self) -> Size {
+        self.view.size()
+    }
+
+    fn create_bundle(&self) -> RenderBundle {
+        RenderBundle::Svg(SvgRenderBundle::new())
+    }
+
+    fn bundle_kind(&self) -> RenderBundleKind {
+        RenderBundleKind::Svg
+    }
+
+    fn pack_bundle(&self, bundle: &RenderBundle) -> Box<dyn PackedBundle> {
+        match bundle {
+            RenderBundle::Svg(inner) => Box::new(SvgPackedBundle(inner.clone())),
+            _ => {
+                log::warn!("Only SVG render bundles can be drawn on SVG canvas");
+                Box::new(EmptyBundle {})
+            }
+        }
+    }
+
+    fn draw_bundles(&mut self, bundles: &[&dyn PackedBundle], options: RenderOptions) {
+        for bundle in bundles {
+            if let Some(SvgPackedBundle(bundle)) = bundle.as_any().downcast_ref() {
+                self.draw_bundle(bundle, options);
+            }
+        }
+    }
+}
+
+struct SvgPackedBundle(SvgRenderBundle);
+
+impl PackedBundle for SvgPackedBundle {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+fn points_list(points: &[Point2d]) -> String {
+    let mut list = String::new();
+    for (index, point) in points.iter().enumerate() {
+        if index > 0 {
+            list.push(' ');
+        }
+        let _ = write!(list, "{:.2},{:.2}", point.x, point.y);
+    }
+
+    list
+}
+
+/// Escapes the characters that have special meaning in XML text and attribute values.
+pub(crate) fn escape_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+fn rgb(color: Color) -> String {
+    format!("rgb({},{},{})", color.r, color.g, color.b)
+}
+
+fn opacity_value(alpha: u8) -> String {
+    format!("{:.3}", alpha as f32 / 255.0)
+}
+
+pub(crate) fn fill_attributes(color: Color) -> String {
+    format!(
+        r#"fill="{}" fill-opacity="{}""#,
+        rgb(color),
+        opacity_value(color.a)
+    )
+}
+
+fn stop_attributes(color: Color) -> String {
+    format!(
+        r#"stop-color="{}" stop-opacity="{}""#,
+        rgb(color),
+        opacity_value(color.a)
+    )
+}
+
+/// Stroke attributes for the line paint. Outlines of the point shapes are drawn twice as wide, and then covered by the
+/// fill, same as the tessellating renderer does.
+pub(crate) fn stroke_attributes(paint: &LinePaint, width_multiplier: f64) -> String {
+    let line_cap = match paint.line_cap {
+        LineCap::Round => "round",
+        LineCap::Butt => "butt",
+    };
+    format!(
+        r#"stroke="{}" stroke-opacity="{}" stroke-width="{}" stroke-linecap="{line_cap}" stroke-linejoin="round""#,
+        rgb(paint.color),
+        opacity_value(paint.color.a),
+        paint.width * width_multiplier,
+    )
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn encode_image(image: &DecodedImage) -> Option<String> {
+    Some(base64(&encode_png(image)?))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn encode_png(image: &DecodedImage) -> Option<Vec<u8>> {
+    let buffer =
+        image::RgbaImage::from_raw(image.dimensions.0, image.dimensions.1, image.bytes.clone())?;
+    let mut png = std::io::Cursor::new(Vec::new());
+    image::DynamicImage::ImageRgba8(buffer)
+        .write_to(&mut png, image::ImageOutputFormat::Png)
+        .ok()?;
+
+    Some(png.into_inner())
+}
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn encode_image(_image: &DecodedImage) -> Option<String> {
+    None
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut encoded = String::with_capacity(bytes.len() / 3 * 4 + 4);
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let n = ((b[0] as u32) << 16) | ((b[1] as u32) << 8) | b[2] as u32;
+
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(ALPHABET[((n >> (18 - 6 * i)) & 0x3f) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::render::point_paint::PointPaint;
+    use galileo_types::cartesian::impls::contour::{ClosedContour, Contour};
+    use galileo_types::cartesian::impls::polygon::Polygon;
+
+    fn test_canvas() -> SvgCanvas {
+        let view =
+            MapView::new_projected(&Point2d::new(0.0, 0.0), 1.0).with_size(Size::new(100.0, 100.0));
+        SvgCanvas::new(&view)
+    }
+
+    fn draw(canvas: &mut SvgCanvas, fill: impl FnOnce(&mut RenderBundle)) {
+        let mut bundle = canvas.create_bundle();
+        fill(&mut bundle);
+        let packed = canvas.pack_bundle(&bundle);
+        canvas.draw_bundles(&[&*packed], RenderOptions::default());
+    }
+
+    #[test]
+    fn base64_encoding() {
+        assert_eq!(base64(b""), "");
+        assert_eq!(base64(b"f"), "Zg==");
+        assert_eq!(base64(b"fo"), "Zm8=");
+        assert_eq!(base64(b"foo"), "Zm9v");
+        assert_eq!(base64(b"foobar"), "Zm9vYmFy");
+    }
+
+    #[test]
+    fn draws_primitives() {
+        let mut canvas = test_canvas().with_background(Color::WHITE);
+        draw(&mut canvas, |bundle| {
+            bundle.add_line(
+                &Contour::open(vec![
+                    Point3d::new(-10.0, 0.0, 0.0),
+                    Point3d::new(10.0, 20.0, 0.0),
+                ]),
+                LinePaint {
+                    color: Color::RED,
+                    width: 3.0,
+                    offset: 0.0,
+                    line_cap: LineCap::Round,
+                },
+                1.0,
+            );
+            bundle.add_polygon(
+                &Polygon::new(
+                    ClosedContour::new(vec![
+                        Point3d::new(0.0, 0.0, 0.0),
+                        Point3d::new(10.0, 0.0, 0.0),
+                        Point3d::new(10.0, 10.0, 0.0),
+                    ]),
+                    vec![],
+                ),
+                PolygonPaint { color: Color::BLUE },
+                1.0,
+            );
+            bundle.add_point(
+                &Point3d::new(0.0, 0.0, 0.0),
+                PointPaint::circle(Color::GREEN, 10.0),
+            );
+        });
+
+        let svg = canvas.finish();
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.ends_with("</svg>"));
+        assert!(svg.contains(r#"<rect width="100%" height="100%" fill="rgb(255,255,255)""#));
+        assert!(svg.contains(r#"<polyline points="40.00,50.00 60.00,30.00""#));
+        assert!(svg.contains(r#"stroke="rgb(255,0,0)""#));
+        assert!(svg.contains(r#"<path d="M50.00,50.00 60.00,50.00 60.00,40.00Z""#));
+        assert!(svg.contains(r#"<circle cx="50.00" cy="50.00" r="5""#));
+    }
+
+    #[test]
+    fn skips_foreign_bundles() {
+        let mut canvas = test_canvas();
+        let packed = canvas.pack_bundle(&RenderBundle::Tessellating(Default::default()));
+        canvas.draw_bundles(&[&*packed], RenderOptions::default());
+        let svg = canvas.finish();
+        assert!(!svg.contains("<g"));
+    }
+}
@@ -14,16 +14,17 @@ use wgpu::{
 
 use crate::layer::Layer;
 use crate::map::Map;
+use crate::overlay::screen_view;
 use crate::render::render_bundle::tessellating::{
     PointInstance, PolyVertex, TessellatingRenderBundle,
 };
-use crate::render::render_bundle::RenderBundle;
+use crate::render::render_bundle::{RenderBundle, RenderBundleKind};
 use crate::render::wgpu::pipelines::image::WgpuImage;
 use crate::render::wgpu::pipelines::Pipelines;
 use crate::view::MapView;
 use crate::Color;
 
-use super::{Canvas, PackedBundle, RenderOptions, Renderer};
+use super::{Canvas, EmptyBundle, PackedBundle, RenderOptions, Renderer};
 
 mod pipelines;
 
@@ -38,6 +39,7 @@ pub struct WgpuRenderer {
     pipelines: Pipelines,
     multisampling_view: TextureView,
     background: Color,
+    dpi_scale_factor: f32,
     stencil_view_multisample: TextureView,
     stencil_view: TextureView,
 }
@@ -90,6 +92,10 @@ impl Renderer for WgpuRenderer {
     fn pack_bundle(&self, bundle: &RenderBundle) -> Box<dyn PackedBundle> {
         match bundle {
             RenderBundle::Tessellating(inner) => Box::new(WgpuPackedBundle::new(inner, self)),
+            RenderBundle::Svg(_) => {
+                log::warn!("SVG render bundles cannot be drawn with wgpu renderer");
+                Box::new(EmptyBundle {})
+            }
         }
     }
 
@@ -144,6 +150,7 @@ impl WgpuRenderer {
             stencil_view_multisample,
             stencil_view,
             background: Color::rgba(255, 255, 255, 255),
+            dpi_scale_factor: 1.0,
         }
     }
 
@@ -200,6 +207,7 @@ impl WgpuRenderer {
             stencil_view_multisample,
             stencil_view,
             background: Color::rgba(255, 255, 255, 255),
+            dpi_scale_factor: 1.0,
         }
     }
 
@@ -207,6 +215,17 @@ impl WgpuRenderer {
         self.background = color;
     }
 
+    /// Sets the number of physical pixels per logical pixel. All screen referenced sizes (line widths, point
+    /// symbol sizes, image offsets) are multiplied by this factor, so the map looks the same on high density
+    /// screens or when rendered for print with high DPI.
+    pub fn set_dpi_scale_factor(&mut self, scale_factor: f32) {
+        self.dpi_scale_factor = scale_factor;
+    }
+
+    pub fn dpi_scale_factor(&self) -> f32 {
+        self.dpi_scale_factor
+    }
+
     fn create_instance() -> wgpu::Instance {
         wgpu::Instance::new(wgpu::InstanceDescriptor {
             backends: wgpu::Backends::all(),
@@ -309,9 +328,13 @@ impl WgpuRenderer {
         }
     }
 
+    /// Returns the RGBA pixels of the rendered image, row by row.
     pub async fn get_image(&self) -> Result<Vec<u8>, SurfaceError> {
-        let buffer_size =
-            (self.size.width() * self.size.height() * size_of::<u32>() as u32) as BufferAddress;
+        // Rows of the copied texture must be aligned in the buffer, so the padding is removed after copying.
+        let row_size = size_of::<u32>() as u32 * self.size.width();
+        let padded_row_size = row_size.div_ceil(wgpu::COPY_BYTES_PER_ROW_ALIGNMENT)
+            * wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+        let buffer_size = (padded_row_size * self.size.height()) as BufferAddress;
         let buffer_desc = BufferDescriptor {
             size: buffer_size,
             usage: BufferUsages::COPY_DST | BufferUsages::MAP_READ,
@@ -336,7 +359,7 @@ impl WgpuRenderer {
                 buffer: &buffer,
                 layout: ImageDataLayout {
                     offset: 0,
-                    bytes_per_row: Some(size_of::<u32>() as u32 * self.size.width()),
+                    bytes_per_row: Some(padded_row_size),
                     rows_per_image: Some(self.size.height()),
                 },
             },
@@ -358,10 +381,20 @@ impl WgpuRenderer {
         rx.receive().await.unwrap().unwrap();
 
         let data = buffer_slice.get_mapped_range();
-        Ok(data.to_vec())
+        Ok(data
+            .chunks_exact(padded_row_size as usize)
+            .flat_map(|row| &row[..row_size as usize])
+            .copied()
+            .collect())
     }
 
     pub fn render(&self, map: &Map) -> Result<(), SurfaceError> {
+        self.render_view(map, map.view())
+    }
+
+    /// Renders the map with the given view instead of the view of the map. Layers only draw the data they have
+    /// already loaded, so the data for the view must be loaded before calling this method.
+    pub fn render_view(&self, map: &Map, map_view: &MapView) -> Result<(), SurfaceError> {
         let texture = self.render_target.texture()?;
         let view = texture.view();
 
@@ -396,17 +429,21 @@ impl WgpuRenderer {
 
         self.queue.submit(std::iter::once(encoder.finish()));
 
-        self.render_map(map, &view);
+        self.render_map(map, map_view, &view);
         texture.present();
 
         Ok(())
     }
 
-    fn render_map(&self, map: &Map, texture_view: &TextureView) {
-        let view = map.view();
+    fn render_map(&self, map: &Map, view: &MapView, texture_view: &TextureView) {
         for layer in map.layers() {
             self.render_layer(&(**layer), view, texture_view);
         }
+
+        if !map.overlay().is_empty() {
+            let mut canvas = WgpuCanvas::new(self, texture_view, screen_view(view));
+            map.overlay().render(view, &mut canvas);
+        }
     }
 
     fn render_layer(&self, layer: &dyn Layer, view: &MapView, texture_view: &TextureView) {
@@ -444,7 +481,7 @@ impl<'a> WgpuCanvas<'a> {
                     1.0 / renderer.size.height() as f32,
                 ],
                 resolution: map_view.resolution() as f32,
-                _padding: [0.0; 1],
+                dpi_scale_factor: renderer.dpi_scale_factor,
             }]),
         );
 
@@ -461,11 +498,16 @@ impl<'a> Canvas for WgpuCanvas<'a> {
         self.renderer.create_bundle()
     }
 
+    fn bundle_kind(&self) -> RenderBundleKind {
+        RenderBundleKind::Tessellating
+    }
+
     fn pack_bundle(&self, bundle: &RenderBundle) -> Box<dyn PackedBundle> {
         match bundle {
             RenderBundle::Tessellating(inner) => {
                 Box::new(WgpuPackedBundle::new(inner, self.renderer))
             }
+            RenderBundle::Svg(_) => self.renderer.pack_bundle(bundle),
         }
     }
 
@@ -689,7 +731,7 @@ struct ViewUniform {
     view_rotation: [[f32; 4]; 4],
     inv_screen_size: [f32; 2],
     resolution: f32,
-    _padding: [f32; 1],
+    dpi_scale_factor: f32,
 }
 
 impl PointInstance {
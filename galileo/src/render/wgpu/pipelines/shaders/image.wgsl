@@ -5,6 +5,7 @@ struct ViewUniform {
     view_rotation: mat4x4<f32>,
     inv_screen_size: vec2<f32>,
     resolution: f32,
+    dpi_scale_factor: f32,
 }
 
 @group(0) @binding(0)
@@ -31,7 +32,7 @@ fn vs_main(
     out.tex_coord = model.tex_coord;
 
     var point_position = transform.view_proj * vec4<f32>(model.position, 0.0, 1.0);
-    var vertex_delta = vec4<f32>(model.offset * transform.inv_screen_size * point_position[3] * 2.0, 0.0, 0.0);
+    var vertex_delta = vec4<f32>(model.offset * transform.dpi_scale_factor * transform.inv_screen_size * point_position[3] * 2.0, 0.0, 0.0);
 
     out.clip_position = point_position + vertex_delta;
     out.opacity = model.opacity;
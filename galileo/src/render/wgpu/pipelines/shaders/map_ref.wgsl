@@ -5,6 +5,7 @@ struct ViewUniform {
     view_rotation: mat4x4<f32>,
     inv_screen_size: vec2<f32>,
     resolution: f32,
+    dpi_scale_factor: f32,
 }
 
 @group(0) @binding(0)
@@ -30,6 +31,7 @@ fn vs_main(
     out.color = model.color;
 
     var vertex_position = transform.view_proj * vec4<f32>(model.position, 1.0);
+    // Normals are in logical pixels, and the resolution is the size of a logical pixel in map units.
     var norm_length = sqrt(model.norm[0] * model.norm[0] + model.norm[1] * model.norm[1]) * transform.resolution;
 
     var norm_limit = 1.0;
@@ -37,7 +39,7 @@ fn vs_main(
         norm_limit = model.norm_limit / norm_length;
     }
 
-    var norm_scale = vec2<f32>(model.norm[0] * transform.inv_screen_size[0], model.norm[1] * transform.inv_screen_size[1]) * norm_limit;
+    var norm_scale = vec2<f32>(model.norm[0] * transform.inv_screen_size[0], model.norm[1] * transform.inv_screen_size[1]) * norm_limit * transform.dpi_scale_factor;
     var norm = vec4<f32>(norm_scale * vertex_position[3] * 2.0, 0.0, 0.0) * transform.view_rotation;
     out.clip_position = vertex_position + norm;
 
@@ -5,6 +5,7 @@ struct ViewUniform {
     view_rotation: mat4x4<f32>,
     inv_screen_size: vec2<f32>,
     resolution: f32,
+    dpi_scale_factor: f32,
 }
 
 @group(0) @binding(0)
@@ -28,7 +29,7 @@ fn vs_main(
     var out: VertexOutput;
     out.color = vec4<f32>(model.color) / 255.0;
     var point_position = transform.view_proj * vec4<f32>(model.position, 1.0);
-    var vertex_delta = vec4<f32>(model.normal * transform.inv_screen_size * point_position[3] * 2.0, 0.0, 0.0);
+    var vertex_delta = vec4<f32>(model.normal * transform.dpi_scale_factor * transform.inv_screen_size * point_position[3] * 2.0, 0.0, 0.0);
 
     out.clip_position = point_position + vertex_delta;
 
@@ -36,6 +36,12 @@ pub struct TileScheme {
     pub max_tile_scale: f64,
     pub cycle_x: bool,
     pub crs: Crs,
+    /// If set, the tiles are selected by the size of the physical pixels of the screen instead of the logical ones,
+    /// so that on high DPI screens the tiles with more details are loaded. Set it only for the tile sources with the
+    /// high resolution tiles (see [`TileScheme::web_hidpi`]), as otherwise the screens with the DPI scale factor of 2
+    /// load 4 times more tiles, and display them with the symbols and labels of half the size.
+    #[serde(default)]
+    pub hidpi: bool,
 }
 
 impl TileScheme {
@@ -86,7 +92,11 @@ impl TileScheme {
             return None;
         }
 
-        let resolution = view.resolution();
+        let resolution = if self.hidpi {
+            view.physical_resolution()
+        } else {
+            view.resolution()
+        };
         let bounding_box = view.get_bbox()?;
         self.iter_tiles_over_bbox(resolution, bounding_box)
     }
@@ -192,9 +202,29 @@ impl TileScheme {
             max_tile_scale: 1024.0,
             cycle_x: true,
             crs: Crs::EPSG3857,
+            hidpi: false,
         }
     }
 
+    /// Tile scheme of the high resolution (`@2x`) tiles of the standard web map services.
+    ///
+    /// The tiles of this scheme cover the same area as the tiles of [`TileScheme::web`] with the same index, but
+    /// have twice the number of pixels. Use it for the tile sources that provide retina tiles, so that on screens with
+    /// the DPI scale factor of 2 the tiles are displayed pixel to pixel.
+    pub fn web_hidpi(lods_count: u32) -> Self {
+        let mut scheme = Self::web(lods_count);
+        scheme.lods = scheme
+            .lods
+            .iter()
+            .map(|lod| Lod::new(lod.resolution() / 2.0, lod.z_index()).unwrap())
+            .collect();
+        scheme.tile_width *= 2;
+        scheme.tile_height *= 2;
+        scheme.hidpi = true;
+
+        scheme
+    }
+
     pub fn tile_bbox(&self, index: TileIndex) -> Option<Rect> {
         let resolution = self
             .lods
@@ -282,6 +312,7 @@ mod tests {
             max_tile_scale: 2.0,
             cycle_x: false,
             crs: Crs::EPSG3857,
+            hidpi: false,
         }
     }
 
@@ -429,6 +460,35 @@ mod tests {
         assert_eq!(schema.iter_tiles(&view).unwrap().count(), 16);
     }
 
+    #[test]
+    fn iter_tiles_with_dpi_scale_factor() {
+        let schema = simple_schema();
+        let bbox = Rect::new(0.0, 0.0, 2048.0, 2048.0);
+        let view = get_view(8.0, bbox).with_dpi_scale_factor(2.0);
+        assert_eq!(schema.iter_tiles(&view).unwrap().count(), 1);
+
+        let hidpi_schema = TileScheme {
+            hidpi: true,
+            ..schema
+        };
+        assert_eq!(hidpi_schema.iter_tiles(&view).unwrap().count(), 4);
+    }
+
+    #[test]
+    fn web_hidpi_tiles_cover_same_area() {
+        let web = TileScheme::web(4);
+        let hidpi = TileScheme::web_hidpi(4);
+        let index = TileIndex {
+            z: 2,
+            x: 1,
+            y: 2,
+            display_x: 1,
+        };
+
+        assert_eq!(hidpi.tile_width(), 512);
+        assert_eq!(web.tile_bbox(index), hidpi.tile_bbox(index));
+    }
+
     #[test]
     fn lod_over() {
         let schema = simple_schema();
@@ -1,3 +1,4 @@
+use crate::tile_scheme::TileScheme;
 use galileo_types::cartesian::impls::point::Point2d;
 use galileo_types::cartesian::rect::Rect;
 use galileo_types::cartesian::size::Size;
@@ -17,6 +18,7 @@ pub struct MapView {
     rotation_x: f64,
     rotation_z: f64,
     size: Size,
+    dpi_scale_factor: f64,
     crs: Crs,
 }
 
@@ -28,7 +30,7 @@ impl MapView {
     pub fn new_with_crs(position: &impl GeoPoint<Num = f64>, resolution: f64, crs: Crs) -> Self {
         let projected = crs
             .get_projection()
-            .and_then(|projection| projection.project(&GeoPoint2d::from(position)))
+            .and_then(|projection| projection.project(&GeoPoint2d::from(position)).ok())
             .map(|p: Point2d| Point3::new(p.x, p.y, 0.0));
         Self {
             projected_position: projected,
@@ -36,6 +38,7 @@ impl MapView {
             rotation_z: 0.0,
             rotation_x: 0.0,
             size: Default::default(),
+            dpi_scale_factor: 1.0,
             crs,
         }
     }
@@ -55,6 +58,7 @@ impl MapView {
             rotation_z: 0.0,
             rotation_x: 0.0,
             size: Default::default(),
+            dpi_scale_factor: 1.0,
             crs,
         }
     }
@@ -87,6 +91,59 @@ impl MapView {
         }
     }
 
+    /// Number of physical (device) pixels per logical pixel of the view.
+    ///
+    /// The size of the view and all the screen coordinates are given in logical pixels, and the resolution of the view
+    /// is the size of a logical pixel in map units. The scale factor is used to select the level of detail of the data
+    /// that matches the physical pixels of the screen.
+    pub fn dpi_scale_factor(&self) -> f64 {
+        self.dpi_scale_factor
+    }
+
+    pub fn with_dpi_scale_factor(&self, dpi_scale_factor: f64) -> Self {
+        Self {
+            dpi_scale_factor,
+            crs: self.crs.clone(),
+            ..*self
+        }
+    }
+
+    /// Size of a physical pixel of the screen in map units.
+    pub fn physical_resolution(&self) -> f64 {
+        self.resolution / self.dpi_scale_factor
+    }
+
+    /// Returns the view with the center and resolution set so that the given area in projected coordinates fits into
+    /// the view with at least `padding` pixels between the area and the edges of the view.
+    ///
+    /// Rotation of the view around the Z axis is taken into account, tilt is not. Returns `None` if the view is
+    /// smaller than the padding or the area has zero size.
+    pub fn fit_bounds(&self, bounds: Rect, padding: f64) -> Option<Self> {
+        let available_width = self.size.width() - 2.0 * padding;
+        let available_height = self.size.height() - 2.0 * padding;
+        if available_width <= 0.0 || available_height <= 0.0 {
+            return None;
+        }
+
+        let sin = self.rotation_z.sin().abs();
+        let cos = self.rotation_z.cos().abs();
+        let rotated_width = bounds.width() * cos + bounds.height() * sin;
+        let rotated_height = bounds.width() * sin + bounds.height() * cos;
+
+        let resolution = (rotated_width / available_width).max(rotated_height / available_height);
+        if !resolution.is_finite() || resolution <= 0.0 {
+            return None;
+        }
+
+        let center = bounds.center();
+        Some(Self {
+            projected_position: Some(Point3::new(center.x, center.y, 0.0)),
+            resolution,
+            crs: self.crs.clone(),
+            ..*self
+        })
+    }
+
     pub fn get_bbox(&self) -> Option<Rect> {
         let points = [
             Point2::new(0.0, 0.0),
@@ -222,6 +279,23 @@ impl MapView {
         Some(Point2::new(transformed.x, transformed.y))
     }
 
+    /// Projects a point in map coordinates to the screen pixel coordinates. Returns `None` if the point is behind the
+    /// camera.
+    pub fn map_to_screen(&self, point: Point3<f64>) -> Option<Point2d> {
+        let projected = self.map_to_scene_transform()? * point.to_homogeneous();
+        if projected.w <= 0.0 {
+            return None;
+        }
+
+        let x = projected.x / projected.w;
+        let y = projected.y / projected.w;
+
+        Some(Point2::new(
+            (x + 1.0) * self.size.half_width(),
+            (1.0 - y) * self.size.half_height(),
+        ))
+    }
+
     pub fn translate_by_pixels(&self, from: Point2d, to: Point2d) -> Self {
         let Some(from_projected) = self.screen_to_map(from) else {
             return self.clone();
@@ -280,24 +354,183 @@ impl MapView {
         }
     }
 
+    /// Position of the center of the view in projected coordinates.
+    pub fn position(&self) -> Option<Point3<f64>> {
+        self.projected_position
+    }
+
     pub(crate) fn interpolate(&self, target: &MapView, k: f64) -> Self {
+        self.interpolate_with(target, k, k)
+    }
+
+    /// Interpolates the position and the resolution of the view with the coefficient `position_k`, and the rotation
+    /// with `rotation_k`. The rotation around the Z axis is interpolated along the shortest arc.
+    pub(crate) fn interpolate_with(
+        &self,
+        target: &MapView,
+        position_k: f64,
+        rotation_k: f64,
+    ) -> Self {
+        let rotation_x = self.rotation_x + (target.rotation_x - self.rotation_x) * rotation_k;
+        let rotation_z_delta = (target.rotation_z - self.rotation_z + std::f64::consts::PI)
+            .rem_euclid(2.0 * std::f64::consts::PI)
+            - std::f64::consts::PI;
+        let rotation_z = self.rotation_z + rotation_z_delta * rotation_k;
+
         let Some(source_position) = self.projected_position else {
-            return self.clone();
+            return self.with_rotation(rotation_x, rotation_z);
         };
         let Some(target_position) = target.projected_position else {
-            return self.clone();
+            return self.with_rotation(rotation_x, rotation_z);
         };
 
-        let projected_position = source_position + (target_position - source_position) * k;
+        let projected_position = source_position + (target_position - source_position) * position_k;
         Self {
             projected_position: Some(projected_position),
-            resolution: self.resolution + (target.resolution - self.resolution) * k,
+            resolution: self.resolution + (target.resolution - self.resolution) * position_k,
+            rotation_x,
+            rotation_z,
             crs: self.crs.clone(),
             ..*self
         }
     }
 }
 
+/// Limits of the map view the user can navigate to.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ViewConstraints {
+    max_extent: Option<Rect>,
+    min_resolution: Option<f64>,
+    max_resolution: Option<f64>,
+    snap_resolutions: Vec<f64>,
+}
+
+impl ViewConstraints {
+    /// Creates constraints that do not limit the view.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limits the area the view can show. When the view is larger than the extent, the view is centered on it.
+    pub fn with_max_extent(mut self, extent: Rect) -> Self {
+        self.max_extent = Some(extent);
+        self
+    }
+
+    /// Limits the resolution of the view, so the user cannot zoom in or out too far.
+    pub fn with_resolution_range(mut self, min_resolution: f64, max_resolution: f64) -> Self {
+        self.min_resolution = Some(min_resolution);
+        self.max_resolution = Some(max_resolution);
+        self
+    }
+
+    /// Makes discrete zoom steps (mouse wheel, keyboard) stop only at the resolutions of the tile scheme levels.
+    pub fn with_snap_to_lods(mut self, tile_scheme: &TileScheme) -> Self {
+        self.snap_resolutions = tile_scheme
+            .lods
+            .iter()
+            .map(|lod| lod.resolution())
+            .collect();
+        self.snap_resolutions.sort_by(|a, b| a.total_cmp(b));
+        self
+    }
+
+    pub fn max_extent(&self) -> Option<Rect> {
+        self.max_extent
+    }
+
+    pub fn min_resolution(&self) -> Option<f64> {
+        self.min_resolution
+    }
+
+    pub fn max_resolution(&self) -> Option<f64> {
+        self.max_resolution
+    }
+
+    /// Returns the closest resolution allowed by the constraints.
+    pub fn clamp_resolution(&self, resolution: f64) -> f64 {
+        let resolution = match self.min_resolution {
+            Some(min) if resolution < min => min,
+            _ => resolution,
+        };
+        match self.max_resolution {
+            Some(max) if resolution > max => max,
+            _ => resolution,
+        }
+    }
+
+    /// Returns the resolution a discrete zoom step by the factor `zoom` leads to from the `current` resolution.
+    ///
+    /// If the constraints snap to tile scheme levels, the step goes at least to the next level in the direction of
+    /// zoom.
+    pub fn zoom_resolution(&self, current: f64, zoom: f64) -> f64 {
+        const TOLERANCE: f64 = 1e-6;
+
+        let target = current * zoom;
+        if self.snap_resolutions.is_empty() || zoom == 1.0 {
+            return self.clamp_resolution(target);
+        }
+
+        let nearest = self
+            .snap_resolutions
+            .iter()
+            .copied()
+            .min_by(|a, b| (a / target).ln().abs().total_cmp(&(b / target).ln().abs()))
+            .unwrap_or(target);
+
+        let snapped = if zoom < 1.0 && nearest >= current * (1.0 - TOLERANCE) {
+            self.snap_resolutions
+                .iter()
+                .rev()
+                .copied()
+                .find(|r| *r < current * (1.0 - TOLERANCE))
+                .unwrap_or(nearest)
+        } else if zoom > 1.0 && nearest <= current * (1.0 + TOLERANCE) {
+            self.snap_resolutions
+                .iter()
+                .copied()
+                .find(|r| *r > current * (1.0 + TOLERANCE))
+                .unwrap_or(nearest)
+        } else {
+            nearest
+        };
+
+        self.clamp_resolution(snapped)
+    }
+
+    /// Returns the view with the resolution and the position limited by the constraints.
+    pub fn apply(&self, view: &MapView) -> MapView {
+        let resolution = self.clamp_resolution(view.resolution());
+        let view = if resolution != view.resolution() {
+            view.with_resolution(resolution)
+        } else {
+            view.clone()
+        };
+
+        let (Some(extent), Some(position)) = (self.max_extent, view.position()) else {
+            return view;
+        };
+
+        let half_width = view.size().half_width() * resolution;
+        let half_height = view.size().half_height() * resolution;
+        let limit = |value: f64, min: f64, max: f64, half: f64| {
+            if max - min <= 2.0 * half {
+                (min + max) / 2.0
+            } else {
+                value.clamp(min + half, max - half)
+            }
+        };
+
+        let x = limit(position.x, extent.x_min(), extent.x_max(), half_width);
+        let y = limit(position.y, extent.y_min(), extent.y_max(), half_height);
+        if x == position.x && y == position.y {
+            view
+        } else {
+            view.translate(Vector2::new(position.x - x, position.y - y))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -307,6 +540,88 @@ mod tests {
         MapView::new_projected(&Point2d::new(0.0, 0.0), 1.0)
     }
 
+    #[test]
+    fn fit_bounds() {
+        let view = test_view().with_size(Size::new(120.0, 70.0));
+        let bounds = Rect::new(100.0, 100.0, 300.0, 200.0);
+
+        let fitted = view.fit_bounds(bounds, 10.0).unwrap();
+        assert_abs_diff_eq!(fitted.resolution(), 2.0);
+        assert_abs_diff_eq!(
+            fitted.screen_to_map(Point2d::new(60.0, 35.0)).unwrap(),
+            Point2d::new(200.0, 150.0),
+            epsilon = 1e-9
+        );
+
+        let rotated = view
+            .with_rotation_z(std::f64::consts::FRAC_PI_2)
+            .fit_bounds(bounds, 10.0)
+            .unwrap();
+        assert_abs_diff_eq!(rotated.resolution(), 4.0, epsilon = 1e-9);
+
+        assert!(view.fit_bounds(bounds, 40.0).is_none());
+    }
+
+    #[test]
+    fn constraints_limit_view() {
+        let constraints = ViewConstraints::new()
+            .with_resolution_range(1.0, 10.0)
+            .with_max_extent(Rect::new(0.0, 0.0, 1000.0, 1000.0));
+
+        let view = test_view().with_size(Size::new(100.0, 100.0));
+        let constrained = constraints.apply(&view.with_resolution(0.5));
+        assert_eq!(constrained.resolution(), 1.0);
+        assert_abs_diff_eq!(
+            constrained.position().unwrap(),
+            Point3::new(50.0, 50.0, 0.0),
+            epsilon = 1e-9
+        );
+
+        let constrained = constraints.apply(&view.with_resolution(100.0));
+        assert_eq!(constrained.resolution(), 10.0);
+        assert_abs_diff_eq!(
+            constrained.position().unwrap(),
+            Point3::new(500.0, 500.0, 0.0),
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn zoom_snaps_to_lods() {
+        let constraints = ViewConstraints::new().with_snap_to_lods(&TileScheme::web(18));
+        let lod = |z| TileScheme::web(18).lod_resolution(z).unwrap();
+
+        assert_eq!(constraints.zoom_resolution(lod(5), 0.9), lod(6));
+        assert_eq!(constraints.zoom_resolution(lod(5), 1.1), lod(4));
+        assert_eq!(constraints.zoom_resolution(lod(5), 0.2), lod(7));
+        assert_eq!(ViewConstraints::new().zoom_resolution(10.0, 0.5), 5.0);
+    }
+
+    #[test]
+    fn interpolate_rotation_along_shortest_arc() {
+        let from = test_view().with_rotation(0.0, 0.1);
+        let to = test_view().with_rotation(0.4, 2.0 * std::f64::consts::PI - 0.1);
+
+        let middle = from.interpolate(&to, 0.5);
+        assert_abs_diff_eq!(middle.rotation_z(), 0.0, epsilon = 1e-9);
+        assert_abs_diff_eq!(middle.rotation_x(), 0.2, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn dpi_scale_factor_keeps_logical_size() {
+        let view = test_view()
+            .with_size(Size::new(100.0, 100.0))
+            .with_dpi_scale_factor(2.0);
+
+        assert_eq!(view.physical_resolution(), 0.5);
+        assert_eq!(view.with_resolution(4.0).dpi_scale_factor(), 2.0);
+        assert_abs_diff_eq!(
+            view.screen_to_map(Point2d::new(0.0, 0.0)).unwrap(),
+            Point2d::new(-50.0, 50.0),
+            epsilon = 0.0001,
+        );
+    }
+
     #[test]
     fn screen_to_map_size() {
         let view = test_view().with_size(Size::new(100.0, 100.0));
@@ -343,6 +658,24 @@ mod tests {
         assert!(projected.is_none());
     }
 
+    #[test]
+    fn map_to_screen() {
+        let view = MapView::new_projected(&Point2d::new(-100.0, -100.0), 2.0)
+            .with_size(Size::new(100.0, 50.0));
+
+        assert_abs_diff_eq!(
+            view.map_to_screen(Point3::new(-100.0, -100.0, 0.0))
+                .unwrap(),
+            Point2d::new(50.0, 25.0),
+            epsilon = 0.0001,
+        );
+        assert_abs_diff_eq!(
+            view.map_to_screen(Point3::new(-180.0, -60.0, 0.0)).unwrap(),
+            Point2d::new(10.0, 5.0),
+            epsilon = 0.0001,
+        );
+    }
+
     #[test]
     fn screen_to_map_position() {
         let view = MapView::new_projected(&Point2d::new(-100.0, -100.0), 1.0)
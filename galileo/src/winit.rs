@@ -1,8 +1,9 @@
-use crate::control::{MouseButton, RawUserEvent, TouchEvent};
+use crate::control::{Key, KeyboardModifiers, MouseButton, RawUserEvent, TouchEvent};
 use crate::messenger::Messenger;
 use galileo_types::cartesian::impls::point::Point2d;
 use std::sync::Arc;
 use winit::event::{ElementState, MouseScrollDelta, Touch, TouchPhase, WindowEvent};
+use winit::keyboard::NamedKey;
 use winit::window::Window;
 
 #[derive(Debug, Default)]
@@ -45,6 +46,22 @@ impl WinitInputHandler {
                     Some(RawUserEvent::TouchEnd(self.get_touch_event(touch, scale)))
                 }
             },
+            WindowEvent::ModifiersChanged(modifiers) => {
+                let state = modifiers.state();
+                Some(RawUserEvent::ModifiersChanged(KeyboardModifiers {
+                    shift: state.shift_key(),
+                    ctrl: state.control_key(),
+                    alt: state.alt_key(),
+                    meta: state.super_key(),
+                }))
+            }
+            WindowEvent::KeyboardInput { event, .. } => {
+                let key = (&event.logical_key).into();
+                match event.state {
+                    ElementState::Pressed => Some(RawUserEvent::KeyPressed(key)),
+                    ElementState::Released => Some(RawUserEvent::KeyReleased(key)),
+                }
+            }
             _ => None,
         }
     }
@@ -70,6 +87,35 @@ impl From<&winit::event::MouseButton> for MouseButton {
     }
 }
 
+impl From<&winit::keyboard::Key> for Key {
+    fn from(value: &winit::keyboard::Key) -> Self {
+        match value {
+            winit::keyboard::Key::Named(named) => match named {
+                NamedKey::ArrowUp => Key::ArrowUp,
+                NamedKey::ArrowDown => Key::ArrowDown,
+                NamedKey::ArrowLeft => Key::ArrowLeft,
+                NamedKey::ArrowRight => Key::ArrowRight,
+                NamedKey::PageUp => Key::PageUp,
+                NamedKey::PageDown => Key::PageDown,
+                NamedKey::Home => Key::Home,
+                NamedKey::End => Key::End,
+                NamedKey::Escape => Key::Escape,
+                NamedKey::Enter => Key::Enter,
+                NamedKey::Space => Key::Character(' '),
+                _ => Key::Other,
+            },
+            winit::keyboard::Key::Character(text) => {
+                let mut chars = text.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => Key::Character(c),
+                    _ => Key::Other,
+                }
+            }
+            _ => Key::Other,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct WinitMessenger {
     window: Arc<Window>,